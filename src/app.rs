@@ -19,6 +19,7 @@ use ratatui::{
     backend::{Backend, CrosstermBackend},
     Terminal,
 };
+use ratatui_image::picker::Picker;
 use std::io::{self, stdout};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -66,6 +67,18 @@ impl App {
             theme,
             config.hotkeys.clone(),
         )));
+        state
+            .write()
+            .await
+            .set_download_directory(config.get_download_directory())
+            .set_starred_project_workspaces(config.starred_project_workspaces.clone());
+        // Prefer the saved workspace once workspaces are loaded
+        if let Some(workspace_gid) = &config.workspace_gid {
+            state
+                .write()
+                .await
+                .set_active_workspace(workspace_gid.clone());
+        }
 
         // Set up log capture to state BEFORE initializing tui_logger
         // We'll create a custom logger that captures logs
@@ -133,7 +146,8 @@ impl App {
         // Always start network thread - it will handle SetAccessToken event
         // Use actual token if available, or empty string as placeholder
         let initial_token = app.access_token.clone().unwrap_or_default();
-        app.start_network_with_token(rx, initial_token, shutdown.clone())?;
+        let api_base_url = app.config.get_api_base_url().to_string();
+        app.start_network_with_token(rx, initial_token, api_base_url, shutdown.clone())?;
 
         app.start_config_saver(config_save_rx, shutdown.clone());
 
@@ -162,7 +176,9 @@ impl App {
             let state = app.state.read().await;
             app.config.starred_projects = state.get_starred_project_gids();
             app.config.starred_project_names = state.get_starred_project_names();
+            app.config.starred_project_workspaces = state.get_starred_project_workspaces();
             app.config.theme_name = state.get_theme().name.clone();
+            app.config.workspace_gid = state.get_active_workspace_gid().map(str::to_string);
             app.config.hotkeys = state.get_hotkeys().clone();
             if let Err(e) = app.config.save() {
                 error!("Failed to save config on exit: {}", e);
@@ -192,7 +208,11 @@ impl App {
                         if let Ok(state_guard) = state.try_read() {
                             config.starred_projects = state_guard.get_starred_project_gids();
                             config.starred_project_names = state_guard.get_starred_project_names();
+                            config.starred_project_workspaces =
+                                state_guard.get_starred_project_workspaces();
                             config.theme_name = state_guard.get_theme().name.clone();
+                            config.workspace_gid =
+                                state_guard.get_active_workspace_gid().map(str::to_string);
                             config.hotkeys = state_guard.get_hotkeys().clone();
                             if let Err(e) = config.save() {
                                 error!("Failed to save config: {}", e);
@@ -218,6 +238,7 @@ impl App {
         &self,
         net_receiver: NetworkEventReceiver,
         initial_token: String,
+        api_base_url: String,
         shutdown: Arc<AtomicBool>,
     ) -> AppResult<()> {
         debug!("Starting asynchronous networking task...");
@@ -225,7 +246,19 @@ impl App {
         // Use spawn_blocking to bridge the blocking receiver to async context
         tokio::task::spawn_blocking(move || {
             let handle = tokio::runtime::Handle::current();
-            let mut asana = Asana::new(&initial_token);
+            let mut asana = Asana::new(&initial_token, &api_base_url);
+            // Apply retry updates from a single task reading a channel so they land
+            // in order, without the network loop ever waiting on the UI lock
+            let state_for_retries = Arc::clone(&cloned_state);
+            let (retry_tx, mut retry_rx) = tokio::sync::mpsc::unbounded_channel();
+            handle.spawn(async move {
+                while let Some(status) = retry_rx.recv().await {
+                    state_for_retries.write().await.set_retry_status(status);
+                }
+            });
+            asana.set_retry_callback(Arc::new(move |status| {
+                let _ = retry_tx.send(status);
+            }));
             handle.block_on(async {
                 // Bridge blocking receiver to async channel
                 let (async_tx, mut async_rx) = tokio::sync::mpsc::unbounded_channel();
@@ -327,6 +360,11 @@ impl App {
         execute!(stdout, EnableMouseCapture)?;
         enable_raw_mode()?;
 
+        // Query graphics support before the event loop starts reading stdin
+        let picker = image_picker();
+        info!("Rendering images with {:?}.", picker.protocol_type);
+        self.state.write().await.set_image_picker(Some(picker));
+
         let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
         terminal.hide_cursor()?;
 
@@ -369,3 +407,17 @@ impl App {
         Ok(())
     }
 }
+
+/// Return a picker for the best image protocol the terminal supports,
+/// falling back to halfblocks.
+///
+fn image_picker() -> Picker {
+    #[cfg(unix)]
+    let picker = Picker::from_termios().ok();
+    #[cfg(not(unix))]
+    let picker = None;
+    // Assume a common cell size when the terminal doesn't report its font
+    let mut picker = picker.unwrap_or_else(|| Picker::new((8, 16)));
+    picker.guess_protocol();
+    picker
+}
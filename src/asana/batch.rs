@@ -0,0 +1,150 @@
+//! Asana Batch API support.
+//!
+//! This module defines the actions that can be grouped into a single request to
+//! the `/batch` endpoint and the per-action results it reports back.
+
+use super::error::AsanaError;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Maximum number of actions Asana accepts in a single batch request.
+///
+pub const BATCH_LIMIT: usize = 10;
+
+/// Defines a single API call within a batch request.
+///
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BatchAction {
+    relative_path: String,
+    method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+impl BatchAction {
+    /// Return an action updating the given fields of a task.
+    ///
+    pub fn update_task(task_gid: &str, data: Value) -> Self {
+        BatchAction {
+            relative_path: format!("/tasks/{}", task_gid),
+            method: String::from("put"),
+            data: Some(data),
+        }
+    }
+
+    /// Return an action moving a task into a section of its project.
+    ///
+    pub fn add_task_to_section(task_gid: &str, section_gid: &str) -> Self {
+        BatchAction {
+            relative_path: format!("/sections/{}/addTask", section_gid),
+            method: String::from("post"),
+            data: Some(json!({ "task": task_gid })),
+        }
+    }
+
+    /// Return an action deleting a task.
+    ///
+    pub fn delete_task(task_gid: &str) -> Self {
+        BatchAction {
+            relative_path: format!("/tasks/{}", task_gid),
+            method: String::from("delete"),
+            data: None,
+        }
+    }
+
+    /// Return an action adding a comment to a task.
+    ///
+    pub fn create_story(task_gid: &str, text: &str) -> Self {
+        BatchAction {
+            relative_path: format!("/tasks/{}/stories", task_gid),
+            method: String::from("post"),
+            data: Some(json!({ "text": text })),
+        }
+    }
+
+    /// Return the path of the API call relative to the base URL.
+    ///
+    pub fn relative_path(&self) -> &str {
+        &self.relative_path
+    }
+}
+
+/// Defines the outcome of a single action in a batch request.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchResult {
+    pub relative_path: String,
+    pub status: u16,
+    pub body: Value,
+}
+
+impl BatchResult {
+    /// Return the `data` of a successful action or the typed error of a
+    /// failed one.
+    ///
+    pub fn into_result(self) -> Result<Value, AsanaError> {
+        if (200..300).contains(&self.status) {
+            Ok(self.body.get("data").cloned().unwrap_or(Value::Null))
+        } else {
+            Err(AsanaError::from_response(
+                self.status,
+                &self.relative_path,
+                &self.body.to_string(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_actions() {
+        let action = BatchAction::add_task_to_section("1", "2");
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            json!({
+                "relative_path": "/sections/2/addTask",
+                "method": "post",
+                "data": { "task": "1" }
+            })
+        );
+        let action = BatchAction::update_task("1", json!({ "completed": true }));
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            json!({
+                "relative_path": "/tasks/1",
+                "method": "put",
+                "data": { "completed": true }
+            })
+        );
+        let action = BatchAction::delete_task("1");
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            json!({ "relative_path": "/tasks/1", "method": "delete" })
+        );
+        let action = BatchAction::create_story("1", "Done");
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            json!({
+                "relative_path": "/tasks/1/stories",
+                "method": "post",
+                "data": { "text": "Done" }
+            })
+        );
+    }
+
+    #[test]
+    fn failed_result_is_typed() {
+        let result = BatchResult {
+            relative_path: String::from("/tasks/123"),
+            status: 404,
+            body: json!({ "errors": [{ "message": "Not found" }] }),
+        };
+        assert!(matches!(
+            result.into_result(),
+            Err(AsanaError::TaskNotFound { gid }) if gid == "123"
+        ));
+    }
+}
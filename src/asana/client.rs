@@ -3,9 +3,126 @@
 //! This module provides a low-level HTTP client wrapper for making requests
 //! to the Asana API, handling authentication, pagination, and response parsing.
 
+use super::error::AsanaError;
 use super::models::*;
 use anyhow::Result;
-use reqwest::{Method, Response};
+use rand::Rng;
+use reqwest::{header::RETRY_AFTER, Method, RequestBuilder, Response, StatusCode};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Bounds for retrying rate-limited and failed requests.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Return the backoff before the given retry attempt (starting at 1),
+    /// preferring the server supplied `Retry-After` value when present. Both
+    /// are capped at `max_delay`.
+    ///
+    pub fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        // Equal jitter: keep at least half of the backoff so waits still grow
+        let half = backoff / 2;
+        let jitter_ms = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter_ms)
+    }
+}
+
+/// Details of a pending retry, reported to the retry callback.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryStatus {
+    pub attempt: u32,
+    pub max_retries: u32,
+    pub wait: Duration,
+    pub status: u16,
+}
+
+/// Callback invoked with the pending retry, or `None` once a request settles.
+///
+pub type RetryCallback = Arc<dyn Fn(Option<RetryStatus>) + Send + Sync>;
+
+/// Reports that a request has settled when dropped, once a retry has been
+/// announced, so the retry status is cleared on every exit path.
+///
+struct RetrySettled<'a> {
+    callback: Option<&'a RetryCallback>,
+    retried: bool,
+}
+
+impl Drop for RetrySettled<'_> {
+    fn drop(&mut self) {
+        if self.retried {
+            if let Some(callback) = self.callback {
+                callback(None);
+            }
+        }
+    }
+}
+
+/// Return true if a request with the given method should be retried after
+/// the status code. Server errors are only retried for idempotent methods,
+/// since a failed POST may already have been applied.
+///
+fn is_retryable(method: &Method, status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || (status.is_server_error()
+            && matches!(
+                *method,
+                Method::GET | Method::HEAD | Method::PUT | Method::DELETE
+            ))
+}
+
+/// Parse a `Retry-After` header given in delta seconds.
+///
+fn parse_retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// A single page of list results and the offset token for the next page.
+///
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub next_offset: Option<String>,
+}
+
+impl<T> Page<T> {
+    fn empty() -> Self {
+        Page {
+            data: vec![],
+            next_offset: None,
+        }
+    }
+}
 
 /// Makes requests to Asana and tries to conform response data to given model.
 ///
@@ -14,6 +131,8 @@ pub struct Client {
     pub(crate) base_url: String,
     endpoint: String,
     pub(crate) http_client: reqwest::Client,
+    pub(crate) retry_policy: RetryPolicy,
+    on_retry: Option<RetryCallback>,
 }
 
 impl Client {
@@ -31,20 +150,99 @@ impl Client {
             http_client: reqwest::Client::builder()
                 .build()
                 .expect("Failed to create HTTP client - this should never happen"),
+            retry_policy: RetryPolicy::default(),
+            on_retry: None,
+        }
+    }
+
+    /// Register a callback that is notified about pending retries.
+    ///
+    pub fn set_retry_callback(&mut self, callback: RetryCallback) {
+        self.on_retry = Some(callback);
+    }
+
+    /// Send request, retrying rate-limited (429) responses, and server error
+    /// (5xx) responses of idempotent requests, with bounded exponential
+    /// backoff. The final response is returned as-is once retries are
+    /// exhausted.
+    ///
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let mut attempt = 0;
+        let mut settled = RetrySettled {
+            callback: self.on_retry.as_ref(),
+            retried: false,
+        };
+        loop {
+            let current = match request.try_clone() {
+                Some(current) => current,
+                // Streaming bodies can't be replayed, so send them once
+                None => return Ok(request.send().await?),
+            };
+            let (client, current) = current.build_split();
+            let current = current?;
+            let method = current.method().clone();
+            let response = client.execute(current).await?;
+            let status = response.status();
+
+            if !is_retryable(&method, status) || attempt >= self.retry_policy.max_retries {
+                return Ok(response);
+            }
+
+            attempt += 1;
+            let wait = self
+                .retry_policy
+                .delay_for(attempt, parse_retry_after(&response));
+            log::warn!(
+                "Request to {} failed with status {}, retry {}/{} in {:.1}s",
+                response.url().path(),
+                status,
+                attempt,
+                self.retry_policy.max_retries,
+                wait.as_secs_f64()
+            );
+            settled.retried = true;
+            if let Some(callback) = &self.on_retry {
+                callback(Some(RetryStatus {
+                    attempt,
+                    max_retries: self.retry_policy.max_retries,
+                    wait,
+                    status: status.as_u16(),
+                }));
+            }
+            tokio::time::sleep(wait).await;
         }
     }
 
     /// Return model data for entity with GID or error.
     ///
     pub async fn get<T: Model>(&mut self, gid: &str) -> Result<T> {
-        let model: Wrapper<T> = self
-            .call::<T>(Method::GET, Some(gid), None)
-            .await?
-            .json()
-            .await?;
+        let response = self.call::<T>(Method::GET, Some(gid), None).await?;
+        let model: Wrapper<T> = Self::ensure_success(response).await?.json().await?;
         Ok(model.data)
     }
 
+    /// Return the response if successful, otherwise the typed `AsanaError`
+    /// parsed from its status and body.
+    ///
+    pub(crate) async fn ensure_success(response: Response) -> Result<Response> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let path = response.url().path().to_string();
+        let response_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| String::from("Unable to read response"));
+        log::error!(
+            "API request to {} failed with status {}: {}",
+            path,
+            status,
+            response_text
+        );
+        Err(AsanaError::from_response(status.as_u16(), &path, &response_text).into())
+    }
+
     /// Return vector of model data with pagination support.
     /// Uses Asana's token-based pagination as per https://developers.asana.com/docs/pagination
     ///
@@ -54,144 +252,158 @@ impl Client {
         limit: Option<usize>,
     ) -> Result<Vec<T>> {
         let limit = limit.unwrap_or(100); // Default to 100 items per page (Asana's max)
+
+        // Keep the relational endpoint for every page, not just the first
+        let endpoint = std::mem::take(&mut self.endpoint);
         let mut all_data = Vec::new();
         let mut offset_token: Option<String> = None;
         let mut page = 0;
 
         loop {
-            // Build params with owned strings stored in variables that live long enough
-            let mut page_params_vec: Vec<(String, String)> = Vec::new();
+            self.endpoint = endpoint.clone();
+            let page_data = self
+                .list_page::<T>(params.clone(), limit, offset_token.as_deref())
+                .await?;
+            let page_size = page_data.data.len();
+            all_data.extend(page_data.data);
 
-            // Add original params if any (convert to owned strings)
-            if let Some(ref original_params) = params {
-                for (k, v) in original_params.iter() {
-                    page_params_vec.push((k.to_string(), v.to_string()));
-                }
-            }
+            log::debug!(
+                "Fetched page {}: {} items (total so far: {})",
+                page,
+                page_size,
+                all_data.len()
+            );
 
-            // Add pagination parameters
-            page_params_vec.push(("limit".to_string(), limit.to_string()));
-            if let Some(ref offset) = offset_token {
-                page_params_vec.push(("offset".to_string(), offset.clone()));
+            match page_data.next_offset {
+                Some(offset) => offset_token = Some(offset),
+                None => break,
             }
+            page += 1;
+        }
 
-            // Convert to string slices for the call
-            let page_params: Vec<(&str, &str)> = page_params_vec
-                .iter()
-                .map(|(k, v)| (k.as_str(), v.as_str()))
-                .collect();
-
-            let response = self.call::<T>(Method::GET, None, Some(page_params)).await?;
-            let status = response.status();
+        self.endpoint.clear();
+        log::debug!(
+            "Completed paginated fetch: {} total items across {} pages",
+            all_data.len(),
+            page + 1
+        );
+        Ok(all_data)
+    }
 
-            // Check status before trying to deserialize
-            if !status.is_success() {
-                let response_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| String::from("Unable to read response"));
-                log::error!(
-                    "API request failed with status {}: {}",
-                    status,
-                    response_text
-                );
-                anyhow::bail!(
-                    "API request failed with status {}: {}",
-                    status,
-                    response_text
-                );
+    /// Return every page of a GET list request to the given path with its own
+    /// query, for endpoints whose `opt_fields` or paths don't fit a model.
+    /// Follows the same offset tokens as `list_paginated`.
+    ///
+    pub(crate) async fn get_all_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>> {
+        let url = format!("{}/{}", self.base_url, path);
+        let mut all_data = Vec::new();
+        let mut offset_token: Option<String> = None;
+        loop {
+            let mut request = self
+                .http_client
+                .get(&url)
+                .bearer_auth(&self.access_token)
+                .query(query)
+                .query(&[("limit", "100")]);
+            if let Some(offset) = &offset_token {
+                request = request.query(&[("offset", offset.as_str())]);
+            }
+            let response = Self::ensure_success(self.send(request).await?).await?;
+            let page: ListWrapper<T> = response.json().await?;
+            let page_size = page.data.len();
+            all_data.extend(page.data);
+            match page.next_page {
+                Some(next) if page_size > 0 => offset_token = Some(next.offset),
+                _ => break,
             }
+        }
+        Ok(all_data)
+    }
 
-            // Clone the response bytes so we can log them if deserialization fails
-            let response_bytes = response.bytes().await?;
-            let response_text = String::from_utf8_lossy(&response_bytes);
-
-            // Try to deserialize, with better error message if it fails
-            match serde_json::from_slice::<ListWrapper<T>>(&response_bytes) {
-                Ok(model) => {
-                    let page_data = model.data;
-                    let page_size = page_data.len();
-                    all_data.extend(page_data);
-
-                    log::debug!(
-                        "Fetched page {}: {} items (total so far: {})",
-                        page,
-                        page_size,
-                        all_data.len()
-                    );
-
-                    // Asana pagination: if we got fewer items than the limit, we're done
-                    if page_size < limit {
-                        // Got fewer items than requested, we're done
-                        break;
-                    }
+    /// Return a single page of model data starting at the given offset token,
+    /// along with the token for the next page if there is one. Lets callers
+    /// consume large lists page by page instead of waiting for all of them.
+    ///
+    pub async fn list_page<T: Model>(
+        &mut self,
+        params: Option<Vec<(&str, &str)>>,
+        limit: usize,
+        offset: Option<&str>,
+    ) -> Result<Page<T>> {
+        let mut page_params: Vec<(&str, &str)> = params.unwrap_or_default();
 
-                    // Extract offset token from next_page for the next request
-                    // Asana uses token-based pagination, not numeric offsets
-                    offset_token = model.next_page.map(|next| next.offset);
+        // Add pagination parameters
+        let limit_str = limit.to_string();
+        page_params.push(("limit", limit_str.as_str()));
+        if let Some(offset) = offset {
+            page_params.push(("offset", offset));
+        }
 
-                    // If there's no next_page token, we're done
-                    if offset_token.is_none() {
-                        break;
-                    }
+        let response = self.call::<T>(Method::GET, None, Some(page_params)).await?;
+        let response = Self::ensure_success(response).await?;
 
-                    // If we got no items, we're done
-                    if page_size == 0 {
-                        break;
-                    }
+        // Clone the response bytes so we can log them if deserialization fails
+        let response_bytes = response.bytes().await?;
+        let response_text = String::from_utf8_lossy(&response_bytes);
 
-                    page += 1;
+        // Try to deserialize, with better error message if it fails
+        match serde_json::from_slice::<ListWrapper<T>>(&response_bytes) {
+            Ok(model) => {
+                let page_size = model.data.len();
+                // Asana pagination: we're done if we got fewer items than the limit,
+                // no items at all, or no next_page token (token-based, not numeric)
+                let next_offset = if page_size < limit || page_size == 0 {
+                    None
+                } else {
+                    model.next_page.map(|next| next.offset)
+                };
+                Ok(Page {
+                    data: model.data,
+                    next_offset,
+                })
+            }
+            Err(e) => {
+                // Check if the response is empty or has a different structure
+                if response_bytes.is_empty() {
+                    log::warn!("Received empty response from API, returning collected data");
+                    return Ok(Page::empty());
                 }
-                Err(e) => {
-                    // Check if the response is empty or has a different structure
-                    if response_bytes.is_empty() {
-                        log::warn!("Received empty response from API, returning collected data");
-                        self.endpoint.clear();
-                        break;
-                    }
 
-                    // Try to parse as JSON to see if it's an error response
-                    if let Ok(json_value) =
-                        serde_json::from_slice::<serde_json::Value>(&response_bytes)
-                    {
-                        // Check if it's an error response
-                        if json_value.get("errors").is_some() {
-                            let errors = json_value.get("errors").and_then(|e| e.as_array());
-                            log::error!("API returned errors: {:?}", errors);
-                            anyhow::bail!("API returned errors: {:?}", errors);
-                        }
-                        // Check if it's missing the data field but otherwise valid JSON
-                        if json_value.get("data").is_none() {
-                            log::warn!(
-                                "API response missing 'data' field, but otherwise valid. Response: {}",
-                                response_text
-                            );
-                            self.endpoint.clear();
-                            break;
-                        }
+                // Try to parse as JSON to see if it's an error response
+                if let Ok(json_value) = serde_json::from_slice::<serde_json::Value>(&response_bytes)
+                {
+                    // Check if it's an error response
+                    if json_value.get("errors").is_some() {
+                        let errors = json_value.get("errors").and_then(|e| e.as_array());
+                        log::error!("API returned errors: {:?}", errors);
+                        anyhow::bail!("API returned errors: {:?}", errors);
+                    }
+                    // Check if it's missing the data field but otherwise valid JSON
+                    if json_value.get("data").is_none() {
+                        log::warn!(
+                            "API response missing 'data' field, but otherwise valid. Response: {}",
+                            response_text
+                        );
+                        return Ok(Page::empty());
                     }
-
-                    log::error!(
-                        "Failed to deserialize API response: {}. Response body: {}",
-                        e,
-                        response_text
-                    );
-                    anyhow::bail!(
-                        "Failed to deserialize API response: {}. Response body: {}",
-                        e,
-                        response_text
-                    );
                 }
+
+                log::error!(
+                    "Failed to deserialize API response: {}. Response body: {}",
+                    e,
+                    response_text
+                );
+                anyhow::bail!(
+                    "Failed to deserialize API response: {}. Response body: {}",
+                    e,
+                    response_text
+                );
             }
         }
-
-        self.endpoint.clear();
-        log::debug!(
-            "Completed paginated fetch: {} total items across {} pages",
-            all_data.len(),
-            page + 1
-        );
-        Ok(all_data)
     }
 
     /// Prepare endpoint for relational model data.
@@ -269,6 +481,77 @@ impl Client {
             request = request.json(&body);
         }
 
-        Ok(request.send().await?)
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_prefers_retry_after() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.delay_for(3, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+        // A server can't stall requests beyond the maximum delay
+        assert_eq!(
+            policy.delay_for(1, Some(Duration::from_secs(3600))),
+            policy.max_delay
+        );
+    }
+
+    #[test]
+    fn delay_grows_and_is_bounded() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+        for attempt in 1..=10 {
+            let backoff = (Duration::from_millis(100) * 2u32.pow(attempt - 1))
+                .min(Duration::from_millis(1000));
+            let delay = policy.delay_for(attempt, None);
+            assert!(delay >= backoff / 2);
+            assert!(delay <= backoff);
+        }
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        assert!(is_retryable(&Method::GET, StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(&Method::GET, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable(&Method::GET, StatusCode::NOT_FOUND));
+        assert!(!is_retryable(&Method::GET, StatusCode::OK));
+        assert!(is_retryable(&Method::POST, StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_retryable(&Method::POST, StatusCode::BAD_GATEWAY));
+    }
+
+    #[tokio::test]
+    async fn post_server_error_is_sent_once() -> Result<()> {
+        let server = httpmock::MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/tasks");
+                then.status(503);
+            })
+            .await;
+
+        let mut client = Client::new("token", &server.base_url());
+        client.retry_policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+        };
+        let request = client
+            .http_client
+            .post(format!("{}/tasks", server.base_url()))
+            .json(&serde_json::json!({ "data": { "name": "Task" } }));
+        let response = client.send(request).await?;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        mock.assert_hits_async(1).await;
+        Ok(())
     }
 }
@@ -9,7 +9,6 @@ pub enum AsanaError {
 
     /// API returned an error response
     #[error("API error (status {status}): {message}")]
-    #[allow(dead_code)]
     ApiError { status: u16, message: String },
 
     /// Failed to deserialize API response
@@ -23,22 +22,18 @@ pub enum AsanaError {
 
     /// Custom field validation failed
     #[error("Custom field validation failed: {0}")]
-    #[allow(dead_code)]
     CustomFieldValidation(String),
 
     /// Task not found
     #[error("Task not found: {gid}")]
-    #[allow(dead_code)]
     TaskNotFound { gid: String },
 
     /// Project not found
     #[error("Project not found: {gid}")]
-    #[allow(dead_code)]
     ProjectNotFound { gid: String },
 
     /// Section not found
     #[error("Section not found: {gid}")]
-    #[allow(dead_code)]
     SectionNotFound { gid: String },
 
     /// Generic API error
@@ -46,6 +41,84 @@ pub enum AsanaError {
     Other(String),
 }
 
+impl AsanaError {
+    /// Build the matching variant for a failed response from its status, the
+    /// request path and Asana's `{"errors": [{"message": ...}]}` body.
+    ///
+    pub fn from_response(status: u16, path: &str, body: &str) -> AsanaError {
+        let message = serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|json| {
+                let messages: Vec<String> = json
+                    .get("errors")?
+                    .as_array()?
+                    .iter()
+                    .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                    .map(String::from)
+                    .collect();
+                if messages.is_empty() {
+                    None
+                } else {
+                    Some(messages.join("; "))
+                }
+            })
+            .unwrap_or_else(|| body.trim().to_string());
+
+        match status {
+            404 => match resource_from_path(path) {
+                Some(("tasks", gid)) => AsanaError::TaskNotFound { gid },
+                Some(("projects", gid)) => AsanaError::ProjectNotFound { gid },
+                Some(("sections", gid)) => AsanaError::SectionNotFound { gid },
+                _ => AsanaError::ApiError { status, message },
+            },
+            400 if message.to_lowercase().contains("custom_field")
+                || message.to_lowercase().contains("custom field") =>
+            {
+                AsanaError::CustomFieldValidation(message)
+            }
+            _ => AsanaError::ApiError { status, message },
+        }
+    }
+
+    /// Return true if the access token was rejected.
+    ///
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self, AsanaError::ApiError { status: 401, .. })
+    }
+}
+
+/// Recover a typed error from an `anyhow` error returned by the client,
+/// falling back to `Other` with the error message.
+///
+impl From<anyhow::Error> for AsanaError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<AsanaError>() {
+            Ok(asana_error) => asana_error,
+            Err(error) => AsanaError::Other(error.to_string()),
+        }
+    }
+}
+
+/// Return the first known resource type and GID found in a request path,
+/// e.g. `("tasks", "123")` for `/api/1.0/tasks/123/stories`.
+///
+fn resource_from_path(path: &str) -> Option<(&'static str, String)> {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    segments.windows(2).find_map(|pair| {
+        let resource = match pair[0] {
+            "tasks" => "tasks",
+            "projects" => "projects",
+            "sections" => "sections",
+            _ => return None,
+        };
+        if !pair[1].is_empty() && pair[1].chars().all(|c| c.is_ascii_digit()) {
+            Some((resource, pair[1].to_string()))
+        } else {
+            None
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +155,45 @@ mod tests {
         assert!(error_str.contains("404"));
         assert!(error_str.contains("Not found"));
     }
+
+    #[test]
+    fn test_asana_error_from_response() {
+        let body = r#"{"errors":[{"message":"task: Unknown object: 42"}]}"#;
+        assert!(matches!(
+            AsanaError::from_response(404, "/api/1.0/tasks/42/stories", body),
+            AsanaError::TaskNotFound { gid } if gid == "42"
+        ));
+        assert!(matches!(
+            AsanaError::from_response(404, "/projects/7/sections", body),
+            AsanaError::ProjectNotFound { gid } if gid == "7"
+        ));
+        assert!(matches!(
+            AsanaError::from_response(404, "/users/me", body),
+            AsanaError::ApiError { status: 404, .. }
+        ));
+
+        let body = r#"{"errors":[{"message":"custom_fields: 123 is not a valid enum option"}]}"#;
+        assert!(matches!(
+            AsanaError::from_response(400, "/tasks/1", body),
+            AsanaError::CustomFieldValidation(m) if m.contains("123")
+        ));
+
+        let error = AsanaError::from_response(401, "/users/me", "Not Authorized");
+        assert!(error.is_unauthorized());
+        assert!(error.to_string().contains("Not Authorized"));
+    }
+
+    #[test]
+    fn test_asana_error_from_anyhow() {
+        let typed: anyhow::Error = AsanaError::SectionNotFound {
+            gid: "9".to_string(),
+        }
+        .into();
+        assert!(matches!(
+            AsanaError::from(typed),
+            AsanaError::SectionNotFound { .. }
+        ));
+        let untyped = anyhow::anyhow!("boom");
+        assert!(matches!(AsanaError::from(untyped), AsanaError::Other(m) if m == "boom"));
+    }
 }
@@ -6,15 +6,25 @@
 //! - Custom field validation and building
 //! - Error handling for API operations
 
+mod batch;
 mod client;
 mod custom_fields;
 mod error;
 mod models;
 mod resource;
+mod rich_text;
+mod search;
 
+pub use batch::{BatchAction, BatchResult};
+pub use client::{RetryCallback, RetryStatus};
 pub use custom_fields::{BuildMode, CustomFieldBuilder};
 pub use error::AsanaError;
 pub use resource::*;
+pub use rich_text::{
+    html_to_markdown, markdown_to_html, parse_html, unsupported_tags, LineKind, Link, Marks,
+    RichLine,
+};
+pub use search::{custom_field_filter, TaskSearch, SEARCH_PAGE_SIZE};
 
 use crate::model;
 use anyhow::Result;
@@ -22,6 +32,21 @@ use chrono::prelude::*;
 use client::Client;
 use log::*;
 use models::Wrapper;
+use std::path::{Path, PathBuf};
+
+/// Base URL of the public Asana REST API.
+///
+pub const DEFAULT_BASE_URL: &str = "https://app.asana.com/api/1.0";
+
+/// Number of matches requested from the typeahead endpoint.
+///
+const TYPEAHEAD_COUNT: &str = "20";
+
+/// Fields requested for project tasks on the board: sections and assignees
+/// via memberships, with everything the model needs since these override
+/// its own fields.
+///
+const PROJECT_TASK_FIELDS: &str = "resource_type,name,completed,memberships.section.gid,memberships.section.name,assignee.name,assignee.email,tags.name,dependencies.completed";
 
 /// Responsible for asynchronous interaction with the Asana API including
 /// transformation of response data into explicitly-defined types.
@@ -31,18 +56,31 @@ pub struct Asana {
 }
 
 impl Asana {
-    /// Returns a new instance for the given access token.
+    /// Returns a new instance for the given access token and API base URL.
     ///
-    pub fn new(access_token: &str) -> Asana {
+    pub fn new(access_token: &str, base_url: &str) -> Asana {
         debug!(
-            "Initializing Asana client with personal access token {}...",
-            access_token
+            "Initializing Asana client for {} with personal access token {}...",
+            base_url, access_token
         );
         Asana {
-            client: Client::new(access_token, "https://app.asana.com/api/1.0"),
+            client: Client::new(access_token, base_url),
         }
     }
 
+    /// Replace the personal access token used for subsequent requests.
+    ///
+    pub fn set_access_token(&mut self, access_token: &str) {
+        self.client.access_token = access_token.to_owned();
+    }
+
+    /// Register a callback that is notified while rate-limited or failed
+    /// requests are being retried.
+    ///
+    pub fn set_retry_callback(&mut self, callback: RetryCallback) {
+        self.client.set_retry_callback(callback);
+    }
+
     /// Returns a tuple containing the current user and the workspaces to which
     /// they have access.
     ///
@@ -74,20 +112,35 @@ impl Asana {
         ))
     }
 
-    /// Returns a vector of projects for the workspace.
+    /// Returns a vector of projects for the workspace. Archived projects are
+    /// only included when `include_archived` is set.
     ///
-    pub async fn projects(&mut self, workspace_gid: &str) -> Result<Vec<Project>> {
+    pub async fn projects(
+        &mut self,
+        workspace_gid: &str,
+        include_archived: bool,
+    ) -> Result<Vec<Project>> {
         debug!(
             "Requesting projects for workspace GID {} (with pagination)...",
             workspace_gid
         );
 
-        model!(ProjectModel "projects" { name: String });
+        model!(ProjectModel "projects" {
+            name: String,
+            archived: Option<bool>,
+            color: Option<String>,
+            notes: Option<String>,
+        });
+
+        let mut params = vec![("workspace", workspace_gid)];
+        if !include_archived {
+            params.push(("archived", "false"));
+        }
 
         // Use pagination to handle workspaces with many projects
         let data: Vec<ProjectModel> = self
             .client
-            .list_paginated::<ProjectModel>(Some(vec![("workspace", workspace_gid)]), Some(100))
+            .list_paginated::<ProjectModel>(Some(params), Some(100))
             .await?;
 
         debug!(
@@ -101,174 +154,819 @@ impl Asana {
             .map(|p| Project {
                 gid: p.gid,
                 name: p.name,
-                archived: false,
-                color: String::new(),
-                notes: String::new(),
+                archived: p.archived.unwrap_or(false),
+                color: p.color.unwrap_or_default(),
+                notes: p.notes.unwrap_or_default(),
             })
             .collect())
     }
 
-    /// Returns a vector of tasks for the project.
-    /// By default, only fetches incomplete tasks for efficiency.
+    /// Get the current user's My Tasks list in a workspace. It is returned as
+    /// a project, since its sections are read and changed the same way.
     ///
-    pub async fn tasks(
-        &mut self,
-        project_gid: &str,
-        _workspace_gid: Option<&str>,
-        include_completed: bool,
-    ) -> Result<Vec<Task>> {
+    pub async fn user_task_list(&mut self, workspace_gid: &str) -> Result<Project> {
         debug!(
-            "Requesting tasks for project GID {} (with pagination, include_completed: {})...",
-            project_gid, include_completed
+            "Requesting My Tasks list for workspace GID {}...",
+            workspace_gid
         );
+        let request = self
+            .client
+            .http_client
+            .get(format!("{}/users/me/user_task_list", &self.client.base_url))
+            .query(&[("workspace", workspace_gid), ("opt_fields", "name")])
+            .bearer_auth(&self.client.access_token);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let body: serde_json::Value = response.json().await?;
+        let data = &body["data"];
+        let gid = data["gid"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("User task list response has no gid"))?;
+        Ok(Project {
+            gid: gid.to_string(),
+            name: data["name"].as_str().unwrap_or("My Tasks").to_string(),
+            archived: false,
+            color: String::new(),
+            notes: String::new(),
+        })
+    }
 
-        model!(TaskModel "tasks" { name: String, completed: bool });
+    /// Returns the teams the current user belongs to in the workspace.
+    ///
+    pub async fn teams(&mut self, workspace_gid: &str) -> Result<Vec<Team>> {
+        debug!("Fetching teams for workspace GID {}...", workspace_gid);
 
-        // Build query parameters
-        // According to Asana API: "Must specify exactly one of project, tag, section, user task list, or assignee + workspace"
-        // So we should NOT include workspace when we have project - they're mutually exclusive
-        // The workspace_gid parameter is kept for API compatibility but not used
-        let mut params: Vec<(&str, &str)> = vec![("project", project_gid)];
+        model!(UserModel "users" { name: String });
+        model!(TeamModel "teams" { name: String });
 
-        // Add opt_fields to request section and assignee information via memberships
-        // This is needed for kanban board to group tasks by section and show assignees
-        // Note: This will override the model's opt_fields, so we need to include all fields we want
-        let opt_fields = "resource_type,name,completed,memberships.section.gid,memberships.section.name,assignee.name,assignee.email";
-        params.push(("opt_fields", opt_fields));
+        // Use the relational endpoint: GET /users/me/teams
+        let data: Vec<TeamModel> = self
+            .client
+            .from::<UserModel>("me")
+            .list_paginated::<TeamModel>(Some(vec![("workspace", workspace_gid)]), Some(100))
+            .await?;
 
-        // Only filter to incomplete tasks if we don't want completed tasks
-        // This is more efficient for large projects when we only need incomplete tasks
-        // Store the string in a variable that lives long enough
-        let completed_since_str = if !include_completed {
-            Some(Utc::now().format("%Y-%m-%dT%H:%M:%S%.fZ").to_string())
+        Ok(data
+            .into_iter()
+            .map(|t| Team {
+                gid: t.gid,
+                name: t.name,
+            })
+            .collect())
+    }
+
+    /// Returns the projects of a team, leaving out archived ones unless
+    /// asked for.
+    ///
+    pub async fn team_projects(
+        &mut self,
+        team_gid: &str,
+        include_archived: bool,
+    ) -> Result<Vec<Project>> {
+        debug!("Requesting projects for team GID {}...", team_gid);
+
+        model!(TeamModel "teams" { name: String });
+        model!(ProjectModel "projects" {
+            name: String,
+            archived: Option<bool>,
+            color: Option<String>,
+            notes: Option<String>,
+        });
+
+        let params = if include_archived {
+            vec![]
         } else {
-            None
+            vec![("archived", "false")]
         };
 
-        if let Some(ref completed_since) = completed_since_str {
-            params.push(("completed_since", completed_since.as_str()));
+        // Use the relational endpoint: GET /teams/{team_gid}/projects
+        let data: Vec<ProjectModel> = self
+            .client
+            .from::<TeamModel>(team_gid)
+            .list_paginated::<ProjectModel>(Some(params), Some(100))
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(|p| Project {
+                gid: p.gid,
+                name: p.name,
+                archived: p.archived.unwrap_or(false),
+                color: p.color.unwrap_or_default(),
+                notes: p.notes.unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Create a project in a workspace, or in one of its teams when a team
+    /// is given. Organizations require a team.
+    ///
+    pub async fn create_project(
+        &mut self,
+        workspace_gid: &str,
+        team_gid: Option<&str>,
+        name: &str,
+        notes: &str,
+        color: Option<&str>,
+    ) -> Result<Project> {
+        debug!(
+            "Creating project '{}' in workspace GID {} (team {:?})...",
+            name, workspace_gid, team_gid
+        );
+
+        model!(ProjectModel "projects" {
+            name: String,
+            archived: Option<bool>,
+            color: Option<String>,
+            notes: Option<String>,
+        });
+
+        let mut data = serde_json::json!({
+            "name": name,
+            "notes": notes,
+            "workspace": workspace_gid,
+        });
+        if let Some(team) = team_gid {
+            data["team"] = serde_json::Value::String(team.to_string());
         }
+        if let Some(color) = color {
+            data["color"] = serde_json::Value::String(color.to_string());
+        }
+        let body = serde_json::json!({ "data": data });
+        let request = self
+            .client
+            .http_client
+            .post(format!("{}/projects", &self.client.base_url))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<ProjectModel> = response.json().await?;
+        Ok(Project {
+            gid: model.data.gid,
+            name: model.data.name,
+            archived: model.data.archived.unwrap_or(false),
+            color: model.data.color.unwrap_or_default(),
+            notes: model.data.notes.unwrap_or_default(),
+        })
+    }
+
+    /// Update the name, notes and color of a project. A color of `None`
+    /// clears it.
+    ///
+    pub async fn update_project(
+        &mut self,
+        project_gid: &str,
+        name: &str,
+        notes: &str,
+        color: Option<&str>,
+    ) -> Result<()> {
+        debug!("Updating project GID {}...", project_gid);
+        let body = serde_json::json!({
+            "data": { "name": name, "notes": notes, "color": color }
+        });
+        self.put_project(project_gid, body).await
+    }
+
+    /// Archive or unarchive a project.
+    ///
+    pub async fn set_project_archived(&mut self, project_gid: &str, archived: bool) -> Result<()> {
+        debug!(
+            "Setting archived={} on project GID {}...",
+            archived, project_gid
+        );
+        let body = serde_json::json!({ "data": { "archived": archived } });
+        self.put_project(project_gid, body).await
+    }
+
+    /// Send a PUT request with the given body to a project.
+    ///
+    async fn put_project(&mut self, project_gid: &str, body: serde_json::Value) -> Result<()> {
+        model!(ProjectModel "projects" { name: String });
 
-        // Use pagination to handle large result sets
-        let data: Vec<TaskModel> = self
+        let response = self
             .client
-            .list_paginated::<TaskModel>(Some(params), Some(100))
+            .call_with_body::<ProjectModel>(
+                reqwest::Method::PUT,
+                Some(project_gid),
+                None,
+                Some(body),
+            )
             .await?;
+        Client::ensure_success(response).await?;
+        Ok(())
+    }
 
+    /// Returns the portfolios the current user owns in the workspace.
+    ///
+    pub async fn portfolios(&mut self, workspace_gid: &str) -> Result<Vec<Portfolio>> {
         debug!(
-            "Retrieved {} tasks for project GID {}",
-            data.len(),
-            project_gid
+            "Requesting portfolios for workspace GID {}...",
+            workspace_gid
         );
 
+        model!(PortfolioModel "portfolios" { name: String });
+
+        let data: Vec<PortfolioModel> = self
+            .client
+            .list_paginated::<PortfolioModel>(
+                Some(vec![("workspace", workspace_gid), ("owner", "me")]),
+                Some(100),
+            )
+            .await?;
+
         Ok(data
             .into_iter()
-            .map(|t| {
-                // Extract section from memberships in extra fields
-                let section = if let Some(memberships) =
-                    t.extra.get("memberships").and_then(|m| m.as_array())
-                {
-                    memberships.iter().find_map(|m| {
-                        m.get("section").and_then(|s| s.as_object()).and_then(|s| {
-                            Some(Section {
-                                gid: s.get("gid")?.as_str()?.to_string(),
-                                name: s.get("name")?.as_str()?.to_string(),
-                            })
-                        })
-                    })
-                } else {
-                    None
-                };
+            .map(|p| Portfolio {
+                gid: p.gid,
+                name: p.name,
+            })
+            .collect())
+    }
 
-                // Extract assignee from extra fields
-                let assignee = if let Some(assignee_val) = t.extra.get("assignee") {
-                    if let Some(assignee_obj) = assignee_val.as_object() {
-                        if let (Some(gid_val), Some(name_val)) = (
-                            assignee_obj.get("gid").and_then(|v| v.as_str()),
-                            assignee_obj.get("name").and_then(|v| v.as_str()),
-                        ) {
-                            Some(User {
-                                gid: gid_val.to_string(),
-                                name: name_val.to_string(),
-                                email: assignee_obj
-                                    .get("email")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("")
-                                    .to_string(),
-                            })
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
+    /// Returns the projects of a portfolio, each with its latest status
+    /// update. Portfolios nested in the portfolio are left out.
+    ///
+    pub async fn portfolio_projects(
+        &mut self,
+        portfolio_gid: &str,
+    ) -> Result<Vec<PortfolioProject>> {
+        debug!("Requesting items of portfolio GID {}...", portfolio_gid);
+
+        model!(UserModel "users" { name: Option<String> });
+        model!(StatusUpdateModel "status_updates" {
+            status_type: Option<String>,
+            title: Option<String>,
+            text: Option<String>,
+            created_at: Option<String>,
+            created_by: Option<UserModel>,
+        } UserModel);
+        model!(ItemModel "projects" {
+            name: String,
+            archived: Option<bool>,
+            color: Option<String>,
+            notes: Option<String>,
+            current_status_update: Option<StatusUpdateModel>,
+        } StatusUpdateModel);
+
+        // Use the relational endpoint: GET /portfolios/{portfolio_gid}/items
+        let data: Vec<ItemModel> = self
+            .client
+            .get_all_pages(
+                &format!("portfolios/{}/items", portfolio_gid),
+                &[(
+                    "opt_fields",
+                    "resource_type,name,archived,color,notes,current_status_update.status_type,\
+                     current_status_update.title,current_status_update.text,\
+                     current_status_update.created_at,current_status_update.created_by.name",
+                )],
+            )
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .filter(|item| item.resource_type == "project")
+            .map(|item| PortfolioProject {
+                project: Project {
+                    gid: item.gid,
+                    name: item.name,
+                    archived: item.archived.unwrap_or(false),
+                    color: item.color.unwrap_or_default(),
+                    notes: item.notes.unwrap_or_default(),
+                },
+                status_update: item.current_status_update.map(|s| StatusUpdate {
+                    gid: s.gid,
+                    status_type: s.status_type.unwrap_or_default(),
+                    title: s.title.unwrap_or_default(),
+                    text: s.text.unwrap_or_default(),
+                    created_at: s.created_at,
+                    created_by: s.created_by.map(|u| User {
+                        gid: u.gid,
+                        name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
+                        email: String::new(),
+                    }),
+                }),
+            })
+            .collect())
+    }
+
+    /// Post a status update on a project and return it.
+    ///
+    pub async fn create_status_update(
+        &mut self,
+        parent_gid: &str,
+        status_type: &str,
+        title: &str,
+        text: &str,
+    ) -> Result<StatusUpdate> {
+        debug!("Posting status update on project GID {}...", parent_gid);
+
+        model!(UserModel "users" { name: Option<String> });
+        model!(StatusUpdateModel "status_updates" {
+            status_type: Option<String>,
+            title: Option<String>,
+            text: Option<String>,
+            created_at: Option<String>,
+            created_by: Option<UserModel>,
+        } UserModel);
+
+        let body = serde_json::json!({
+            "data": {
+                "parent": parent_gid,
+                "status_type": status_type,
+                "title": title,
+                "text": text,
+            }
+        });
+        let response = self
+            .client
+            .call_with_body::<StatusUpdateModel>(reqwest::Method::POST, None, None, Some(body))
+            .await?;
+        let response = Client::ensure_success(response).await?;
+        let model: Wrapper<StatusUpdateModel> = response.json().await?;
+
+        Ok(StatusUpdate {
+            gid: model.data.gid,
+            status_type: model
+                .data
+                .status_type
+                .unwrap_or_else(|| status_type.to_string()),
+            title: model.data.title.unwrap_or_else(|| title.to_string()),
+            text: model.data.text.unwrap_or_else(|| text.to_string()),
+            created_at: model.data.created_at,
+            created_by: model.data.created_by.map(|u| User {
+                gid: u.gid,
+                name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
+                email: String::new(),
+            }),
+        })
+    }
+
+    /// Returns one page of the workspace tasks matching a search, newest
+    /// first, along with the creation time to continue from if there may be
+    /// more results. The search endpoint has no offsets, so later pages only
+    /// include tasks created before the last task of the previous page.
+    ///
+    pub async fn search_tasks(
+        &mut self,
+        workspace_gid: &str,
+        search: &TaskSearch,
+        created_before: Option<&str>,
+    ) -> Result<(Vec<Task>, Option<String>)> {
+        debug!("Searching tasks in workspace GID {}...", workspace_gid);
+
+        model!(TaskModel "tasks" { name: String, completed: bool });
+
+        let limit = SEARCH_PAGE_SIZE.to_string();
+        let mut params = search.params(created_before);
+        params.push((
+            "opt_fields".to_string(),
+            "name,completed,created_at,due_on,assignee.name,assignee.email,tags.name,\
+             memberships.project.name,memberships.section.name"
+                .to_string(),
+        ));
+        params.push(("limit".to_string(), limit));
+
+        // Use the workspace endpoint: GET /workspaces/{workspace_gid}/tasks/search
+        let request = self
+            .client
+            .http_client
+            .get(format!(
+                "{}/workspaces/{}/tasks/search",
+                &self.client.base_url, workspace_gid
+            ))
+            .query(&params)
+            .bearer_auth(&self.client.access_token);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let wrapper: Wrapper<Vec<TaskModel>> = response.json().await?;
 
+        let tasks: Vec<Task> = wrapper
+            .data
+            .into_iter()
+            .map(|t| {
+                let memberships = Self::parse_memberships(t.extra.get("memberships"));
+                let string = |key: &str| {
+                    t.extra
+                        .get(key)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                };
                 Task {
                     gid: t.gid,
                     name: t.name,
                     completed: t.completed,
                     notes: None,
-                    assignee,
+                    html_notes: None,
+                    assignee: t.extra.get("assignee").and_then(|a| {
+                        Some(User {
+                            gid: a.get("gid")?.as_str()?.to_string(),
+                            name: a.get("name")?.as_str()?.to_string(),
+                            email: a
+                                .get("email")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                        })
+                    }),
                     due_date: None,
-                    due_on: None,
+                    due_on: string("due_on"),
+                    due_at: None,
                     start_on: None,
-                    section,
-                    tags: vec![],
+                    section: memberships.iter().find_map(|m| m.section.clone()),
+                    tags: Self::parse_tags(t.extra.get("tags")),
                     custom_fields: vec![],
-                    created_at: None,
+                    created_at: string("created_at"),
                     modified_at: None,
                     num_subtasks: 0,
                     num_comments: 0,
+                    dependencies: vec![],
+                    dependents: vec![],
+                    memberships,
+                    followers: vec![],
                 }
             })
-            .collect())
+            .collect();
+
+        debug!("Search returned {} tasks", tasks.len());
+        let next = if tasks.len() >= SEARCH_PAGE_SIZE {
+            tasks.last().and_then(|t| t.created_at.clone())
+        } else {
+            None
+        };
+        Ok((tasks, next))
     }
 
-    fn parse_custom_fields_from_task_data(
-        extra: &std::collections::HashMap<String, serde_json::Value>,
-    ) -> Vec<CustomField> {
-        let mut custom_fields = Vec::new();
+    /// Returns one page of tasks for the project starting at the given offset,
+    /// along with the offset of the next page if there are more tasks.
+    /// Includes completed tasks only when `include_completed` is set.
+    ///
+    pub async fn tasks_page(
+        &mut self,
+        project_gid: &str,
+        include_completed: bool,
+        offset: Option<&str>,
+    ) -> Result<(Vec<Task>, Option<String>)> {
+        self.task_list_page(
+            ("project", project_gid),
+            PROJECT_TASK_FIELDS,
+            include_completed,
+            offset,
+        )
+        .await
+    }
 
-        if let Some(custom_fields_array) = extra.get("custom_fields").and_then(|v| v.as_array()) {
-            for cf_val in custom_fields_array {
-                if let Some(cf_obj) = cf_val.as_object() {
-                    let gid = cf_obj
-                        .get("gid")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-                    let name = cf_obj
-                        .get("name")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-                    let resource_subtype = cf_obj
-                        .get("resource_subtype")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("text")
-                        .to_string();
-                    let enabled = cf_obj
-                        .get("enabled")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(true);
+    /// Returns a task of the project with only the fields of `tasks_page`,
+    /// or `None` if it isn't in the project, such as a subtask.
+    ///
+    pub async fn get_project_task(
+        &mut self,
+        project_gid: &str,
+        task_gid: &str,
+    ) -> Result<Option<Task>> {
+        model!(TaskModel "tasks" { name: String, completed: bool });
 
-                    // Parse enum options
-                    let mut enum_options = Vec::new();
-                    if let Some(enum_opts_array) =
-                        cf_obj.get("enum_options").and_then(|v| v.as_array())
-                    {
-                        for eo_val in enum_opts_array {
-                            if let Some(eo_obj) = eo_val.as_object() {
-                                enum_options.push(EnumOption {
-                                    gid: eo_obj
-                                        .get("gid")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    name: eo_obj
+        let uri = format!(
+            "tasks/{}?opt_fields={},memberships.project.gid",
+            task_gid, PROJECT_TASK_FIELDS
+        );
+        let request_url = format!("{}/{}", self.client.base_url, uri);
+        let request = self.client.http_client.get(&request_url).header(
+            "Authorization",
+            format!("Bearer {}", self.client.access_token),
+        );
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let mut t = response.json::<Wrapper<TaskModel>>().await?.data;
+
+        // Keep only the membership of this project so the task lands in its section
+        let memberships: Vec<serde_json::Value> = t
+            .extra
+            .get("memberships")
+            .and_then(|m| m.as_array())
+            .map(|memberships| {
+                memberships
+                    .iter()
+                    .filter(|m| {
+                        m.get("project")
+                            .and_then(|p| p.get("gid"))
+                            .and_then(|g| g.as_str())
+                            == Some(project_gid)
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        if memberships.is_empty() {
+            return Ok(None);
+        }
+        t.extra.insert(
+            "memberships".to_string(),
+            serde_json::Value::Array(memberships),
+        );
+        Ok(Some(Self::list_task(t.gid, t.name, t.completed, &t.extra)))
+    }
+
+    /// Returns one page of tasks in a user's My Tasks list, like `tasks_page`.
+    /// Tasks are grouped by the user's own My Tasks sections rather than by
+    /// the sections of the projects they belong to.
+    ///
+    pub async fn user_task_list_tasks_page(
+        &mut self,
+        user_task_list_gid: &str,
+        include_completed: bool,
+        offset: Option<&str>,
+    ) -> Result<(Vec<Task>, Option<String>)> {
+        let opt_fields = "resource_type,name,completed,assignee_section.name,assignee.name,assignee.email,tags.name,dependencies.completed";
+        self.task_list_page(
+            ("user_task_list", user_task_list_gid),
+            opt_fields,
+            include_completed,
+            offset,
+        )
+        .await
+    }
+
+    /// Returns one page of the tasks matching a single container filter, such
+    /// as `("project", gid)` or `("user_task_list", gid)`.
+    ///
+    async fn task_list_page(
+        &mut self,
+        container: (&str, &str),
+        opt_fields: &str,
+        include_completed: bool,
+        offset: Option<&str>,
+    ) -> Result<(Vec<Task>, Option<String>)> {
+        model!(TaskModel "tasks" { name: String, completed: bool });
+
+        // Build query parameters
+        // According to Asana API: "Must specify exactly one of project, tag, section, user task list, or assignee + workspace"
+        // So we should NOT include workspace when we have a container - they're mutually exclusive
+        let mut params: Vec<(&str, &str)> = vec![container, ("opt_fields", opt_fields)];
+
+        // Only filter to incomplete tasks if we don't want completed tasks
+        // This is more efficient for large projects when we only need incomplete tasks
+        // Store the string in a variable that lives long enough
+        let completed_since_str = if !include_completed {
+            Some(Utc::now().format("%Y-%m-%dT%H:%M:%S%.fZ").to_string())
+        } else {
+            None
+        };
+
+        if let Some(ref completed_since) = completed_since_str {
+            params.push(("completed_since", completed_since.as_str()));
+        }
+
+        let page = self
+            .client
+            .list_page::<TaskModel>(Some(params), 100, offset)
+            .await?;
+
+        debug!(
+            "Retrieved page of {} tasks for {} GID {}",
+            page.data.len(),
+            container.0,
+            container.1
+        );
+
+        let tasks = page
+            .data
+            .into_iter()
+            .map(|t| Self::list_task(t.gid, t.name, t.completed, &t.extra))
+            .collect();
+        Ok((tasks, page.next_offset))
+    }
+
+    /// Build a task from the fields requested by task list pages.
+    ///
+    fn list_task(
+        gid: String,
+        name: String,
+        completed: bool,
+        extra: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Task {
+        // Extract section from memberships in extra fields
+        let section = if let Some(memberships) = extra.get("memberships").and_then(|m| m.as_array())
+        {
+            memberships.iter().find_map(|m| {
+                m.get("section").and_then(|s| s.as_object()).and_then(|s| {
+                    Some(Section {
+                        gid: s.get("gid")?.as_str()?.to_string(),
+                        name: s.get("name")?.as_str()?.to_string(),
+                    })
+                })
+            })
+        } else {
+            None
+        };
+        // My Tasks lists group tasks by the assignee's own sections instead
+        let section = section.or_else(|| {
+            let s = extra.get("assignee_section")?.as_object()?;
+            Some(Section {
+                gid: s.get("gid")?.as_str()?.to_string(),
+                name: s.get("name")?.as_str()?.to_string(),
+            })
+        });
+
+        // Extract assignee from extra fields
+        let assignee = if let Some(assignee_val) = extra.get("assignee") {
+            if let Some(assignee_obj) = assignee_val.as_object() {
+                if let (Some(gid_val), Some(name_val)) = (
+                    assignee_obj.get("gid").and_then(|v| v.as_str()),
+                    assignee_obj.get("name").and_then(|v| v.as_str()),
+                ) {
+                    Some(User {
+                        gid: gid_val.to_string(),
+                        name: name_val.to_string(),
+                        email: assignee_obj
+                            .get("email")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                    })
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        Task {
+            gid,
+            name,
+            completed,
+            notes: None,
+            html_notes: None,
+            assignee,
+            due_date: None,
+            due_on: None,
+            due_at: None,
+            start_on: None,
+            section,
+            tags: Self::parse_tags(extra.get("tags")),
+            custom_fields: vec![],
+            created_at: None,
+            modified_at: None,
+            num_subtasks: 0,
+            num_comments: 0,
+            dependencies: Self::parse_task_compacts(extra.get("dependencies")),
+            dependents: vec![],
+            memberships: vec![],
+            followers: vec![],
+        }
+    }
+
+    /// Parse an array of compact tag references.
+    ///
+    fn parse_tags(value: Option<&serde_json::Value>) -> Vec<Tag> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|tags| {
+                tags.iter()
+                    .filter_map(|t| {
+                        Some(Tag {
+                            gid: t.get("gid")?.as_str()?.to_string(),
+                            name: t.get("name")?.as_str()?.to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Parse an array of compact task references, such as dependencies.
+    ///
+    fn parse_task_compacts(value: Option<&serde_json::Value>) -> Vec<TaskCompact> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|tasks| {
+                tasks
+                    .iter()
+                    .filter_map(|t| {
+                        Some(TaskCompact {
+                            gid: t.get("gid")?.as_str()?.to_string(),
+                            name: t
+                                .get("name")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            completed: t
+                                .get("completed")
+                                .and_then(|v| v.as_bool())
+                                .unwrap_or(false),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Parse compact users, such as a task's followers. Users whose email
+    /// is not visible get an empty one.
+    ///
+    fn parse_users(value: Option<&serde_json::Value>) -> Vec<User> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|users| {
+                users
+                    .iter()
+                    .filter_map(|u| {
+                        Some(User {
+                            gid: u.get("gid")?.as_str()?.to_string(),
+                            name: u
+                                .get("name")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            email: u
+                                .get("email")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Parse the project memberships of a task, skipping entries without a
+    /// project.
+    ///
+    fn parse_memberships(value: Option<&serde_json::Value>) -> Vec<Membership> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|memberships| {
+                memberships
+                    .iter()
+                    .filter_map(|m| {
+                        let project = m.get("project")?;
+                        let section = m.get("section").and_then(|s| {
+                            Some(Section {
+                                gid: s.get("gid")?.as_str()?.to_string(),
+                                name: s
+                                    .get("name")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or_default()
+                                    .to_string(),
+                            })
+                        });
+                        Some(Membership {
+                            project_gid: project.get("gid")?.as_str()?.to_string(),
+                            project_name: project
+                                .get("name")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            section,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn parse_custom_fields_from_task_data(
+        extra: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Vec<CustomField> {
+        let mut custom_fields = Vec::new();
+
+        if let Some(custom_fields_array) = extra.get("custom_fields").and_then(|v| v.as_array()) {
+            for cf_val in custom_fields_array {
+                if let Some(cf_obj) = cf_val.as_object() {
+                    let gid = cf_obj
+                        .get("gid")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let name = cf_obj
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let resource_subtype = cf_obj
+                        .get("resource_subtype")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("text")
+                        .to_string();
+                    let enabled = cf_obj
+                        .get("enabled")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true);
+
+                    // Parse enum options
+                    let mut enum_options = Vec::new();
+                    if let Some(enum_opts_array) =
+                        cf_obj.get("enum_options").and_then(|v| v.as_array())
+                    {
+                        for eo_val in enum_opts_array {
+                            if let Some(eo_obj) = eo_val.as_object() {
+                                enum_options.push(EnumOption {
+                                    gid: eo_obj
+                                        .get("gid")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("")
+                                        .to_string(),
+                                    name: eo_obj
                                         .get("name")
                                         .and_then(|v| v.as_str())
                                         .unwrap_or("")
@@ -434,46 +1132,31 @@ impl Asana {
             name: String,
             completed: bool,
             notes: Option<String>,
+            html_notes: Option<String>,
             due_date: Option<String>,
             due_on: Option<String>,
+            due_at: Option<String>,
             start_on: Option<String>,
             created_at: Option<String>,
             modified_at: Option<String>,
+            num_subtasks: Option<usize>,
         });
 
         // Request task with assignee, section, tags, and custom fields as nested fields
         // For GET /tasks/{task_gid}, we pass opt_fields but NO other params (no project, workspace, etc.)
         // The API returns nested objects as partial (gid + resource_type) unless we request specific fields
         // IMPORTANT: Always include resource_type in opt_fields as it's required by the model
-        let opt_fields = "resource_type,name,completed,notes,due_on,start_on,created_at,modified_at,assignee.name,assignee.email,memberships.section.name,tags.name,custom_fields.gid,custom_fields.name,custom_fields.resource_subtype,custom_fields.enum_options.gid,custom_fields.enum_options.name,custom_fields.enum_options.enabled,custom_fields.enum_options.color,custom_fields.text_value,custom_fields.number_value,custom_fields.date_value.date,custom_fields.enum_value.gid,custom_fields.enum_value.name,custom_fields.multi_enum_values.gid,custom_fields.multi_enum_values.name,custom_fields.people_value.gid,custom_fields.people_value.name,custom_fields.enabled";
+        let opt_fields = "resource_type,name,completed,notes,html_notes,due_on,due_at,start_on,created_at,modified_at,num_subtasks,assignee.name,assignee.email,memberships.project.name,memberships.section.name,followers.name,followers.email,tags.name,dependencies.name,dependencies.completed,dependents.name,dependents.completed,custom_fields.gid,custom_fields.name,custom_fields.resource_subtype,custom_fields.enum_options.gid,custom_fields.enum_options.name,custom_fields.enum_options.enabled,custom_fields.enum_options.color,custom_fields.text_value,custom_fields.number_value,custom_fields.date_value.date,custom_fields.enum_value.gid,custom_fields.enum_value.name,custom_fields.multi_enum_values.gid,custom_fields.multi_enum_values.name,custom_fields.people_value.gid,custom_fields.people_value.name,custom_fields.enabled";
 
         // Build URL manually to avoid client adding conflicting params
         let uri = format!("tasks/{}?opt_fields={}", task_gid, opt_fields);
-        let request_url = format!("{}/{}", "https://app.asana.com/api/1.0", uri);
-
-        let response = self
-            .client
-            .http_client
-            .get(&request_url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.client.access_token),
-            )
-            .send()
-            .await?;
+        let request_url = format!("{}/{}", self.client.base_url, uri);
 
-        let status = response.status();
-        if !status.is_success() {
-            let response_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read response"));
-            anyhow::bail!(
-                "API request failed with status {}: {}",
-                status,
-                response_text
-            );
-        }
+        let request = self.client.http_client.get(&request_url).header(
+            "Authorization",
+            format!("Bearer {}", self.client.access_token),
+        );
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
 
         let model: Wrapper<TaskModelSimple> = response.json().await?;
         let task_data = model.data;
@@ -526,35 +1209,7 @@ impl Asana {
         };
 
         // Extract tags
-        let tags = if let Some(tags_array) = task_data.extra.get("tags").and_then(|v| v.as_array())
-        {
-            tags_array
-                .iter()
-                .filter_map(|tag_val| {
-                    if let Some(tag_obj) = tag_val.as_object() {
-                        if let (Some(gid_val), Some(name_val)) = (
-                            tag_obj.get("gid").and_then(|v| v.as_str()),
-                            tag_obj.get("name").and_then(|v| v.as_str()),
-                        ) {
-                            Some(Tag {
-                                gid: gid_val.to_string(),
-                                name: name_val.to_string(),
-                            })
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            vec![]
-        };
-
-        // Get subtasks count - for now, just return 0 to avoid API errors
-        // TODO: Implement proper subtasks fetching
-        let subtasks: Vec<String> = vec![];
+        let tags = Self::parse_tags(task_data.extra.get("tags"));
 
         // Get stories/comments
         let stories = match self.get_task_stories(task_gid).await {
@@ -573,16 +1228,18 @@ impl Asana {
             name: task_data.name,
             completed: task_data.completed,
             notes: task_data.notes,
+            html_notes: task_data.html_notes,
             assignee,
             due_date: task_data.due_date,
             due_on: task_data.due_on,
+            due_at: task_data.due_at,
             start_on: task_data.start_on,
             section,
             tags,
             custom_fields,
             created_at: task_data.created_at,
             modified_at: task_data.modified_at,
-            num_subtasks: subtasks.len(),
+            num_subtasks: task_data.num_subtasks.unwrap_or(0),
             num_comments: stories
                 .iter()
                 .filter(|s| match &s.resource_subtype {
@@ -590,471 +1247,449 @@ impl Asana {
                     None => s.created_by.is_some(),
                 })
                 .count(),
+            dependencies: Self::parse_task_compacts(task_data.extra.get("dependencies")),
+            dependents: Self::parse_task_compacts(task_data.extra.get("dependents")),
+            memberships: Self::parse_memberships(task_data.extra.get("memberships")),
+            followers: Self::parse_users(task_data.extra.get("followers")),
         })
     }
 
-    /// Update a task (e.g., mark as complete/incomplete).
+    /// Mark tasks as blocking the given task.
     ///
-    pub async fn update_task(&mut self, task_gid: &str, completed: Option<bool>) -> Result<Task> {
-        debug!("Updating task GID {}...", task_gid);
+    pub async fn add_dependencies(
+        &mut self,
+        task_gid: &str,
+        dependency_gids: &[String],
+    ) -> Result<()> {
+        self.post_task_relations(task_gid, "addDependencies", "dependencies", dependency_gids)
+            .await
+    }
 
-        model!(TaskModel "tasks" { name: String, completed: bool });
+    /// Add users as followers of a task.
+    ///
+    pub async fn add_followers(&mut self, task_gid: &str, user_gids: &[String]) -> Result<()> {
+        self.post_task_relations(task_gid, "addFollowers", "followers", user_gids)
+            .await
+    }
 
-        let body = if let Some(completed) = completed {
-            serde_json::json!({
-                "data": {
-                    "completed": completed
-                }
-            })
-        } else {
-            serde_json::json!({})
-        };
+    /// Remove users from the followers of a task.
+    ///
+    pub async fn remove_followers(&mut self, task_gid: &str, user_gids: &[String]) -> Result<()> {
+        self.post_task_relations(task_gid, "removeFollowers", "followers", user_gids)
+            .await
+    }
 
-        let model: Wrapper<TaskModel> = self
-            .client
-            .call_with_body::<TaskModel>(reqwest::Method::PUT, Some(task_gid), None, Some(body))
-            .await?
-            .json()
-            .await?;
+    /// Stop tasks from blocking the given task.
+    ///
+    pub async fn remove_dependencies(
+        &mut self,
+        task_gid: &str,
+        dependency_gids: &[String],
+    ) -> Result<()> {
+        self.post_task_relations(
+            task_gid,
+            "removeDependencies",
+            "dependencies",
+            dependency_gids,
+        )
+        .await
+    }
 
-        Ok(Task {
-            gid: model.data.gid,
-            name: model.data.name,
-            completed: model.data.completed,
-            notes: None,
-            assignee: None,
-            due_date: None,
-            due_on: None,
-            start_on: None,
-            section: None,
-            tags: vec![],
-            custom_fields: vec![],
-            created_at: None,
-            modified_at: None,
-            num_subtasks: 0,
-            num_comments: 0,
-        })
+    /// Add a tag to a task.
+    ///
+    pub async fn add_tag(&mut self, task_gid: &str, tag_gid: &str) -> Result<()> {
+        debug!("Adding tag GID {} to task GID {}...", tag_gid, task_gid);
+        self.post_task_action(task_gid, "addTag", serde_json::json!({ "tag": tag_gid }))
+            .await
     }
 
-    /// Get all sections for a project (for kanban board).
+    /// Remove a tag from a task.
     ///
-    pub async fn get_project_sections(&mut self, project_gid: &str) -> Result<Vec<Section>> {
-        debug!("Fetching sections for project GID {}...", project_gid);
+    pub async fn remove_tag(&mut self, task_gid: &str, tag_gid: &str) -> Result<()> {
+        debug!("Removing tag GID {} from task GID {}...", tag_gid, task_gid);
+        self.post_task_action(task_gid, "removeTag", serde_json::json!({ "tag": tag_gid }))
+            .await
+    }
 
-        model!(ProjectModel "projects" { name: String });
-        model!(SectionModel "sections" { name: String });
+    /// Returns the tags of a workspace.
+    ///
+    pub async fn get_tags(&mut self, workspace_gid: &str) -> Result<Vec<Tag>> {
+        debug!("Fetching tags for workspace GID {}...", workspace_gid);
 
-        // Use the relational endpoint: GET /projects/{project_gid}/sections
-        let data: Vec<SectionModel> = self
+        model!(TagModel "tags" { name: String });
+
+        let data: Vec<TagModel> = self
             .client
-            .from::<ProjectModel>(project_gid)
-            .list_paginated::<SectionModel>(None, Some(100))
+            .list_paginated::<TagModel>(Some(vec![("workspace", workspace_gid)]), Some(100))
             .await?;
 
         Ok(data
             .into_iter()
-            .map(|s| Section {
-                gid: s.gid,
-                name: s.name,
+            .map(|t| Tag {
+                gid: t.gid,
+                name: t.name,
             })
             .collect())
     }
 
-    /// Get custom fields for a project.
+    /// Create a tag in a workspace.
     ///
-    pub async fn get_project_custom_fields(
-        &mut self,
-        project_gid: &str,
-    ) -> Result<Vec<CustomField>> {
-        debug!("Fetching custom fields for project GID {}...", project_gid);
-
-        // Use GET /projects/{project_gid}/custom_field_settings
-        // Then fetch each custom field definition
-        let uri = format!(
-            "projects/{}/custom_field_settings?opt_fields=custom_field.gid,custom_field.name,custom_field.resource_subtype,custom_field.representation_type,custom_field.id_prefix,custom_field.enum_options.gid,custom_field.enum_options.name,custom_field.enum_options.enabled,custom_field.enum_options.color",
-            project_gid
+    pub async fn create_tag(&mut self, workspace_gid: &str, name: &str) -> Result<Tag> {
+        debug!(
+            "Creating tag '{}' in workspace GID {}...",
+            name, workspace_gid
         );
-        let request_url = format!("{}/{}", "https://app.asana.com/api/1.0", uri);
 
-        let response = self
+        model!(TagModel "tags" { name: String });
+
+        let body = serde_json::json!({
+            "data": { "name": name, "workspace": workspace_gid }
+        });
+        let request = self
             .client
             .http_client
-            .get(&request_url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.client.access_token),
-            )
-            .send()
-            .await?;
+            .post(format!("{}/tags", &self.client.base_url))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<TagModel> = response.json().await?;
+        Ok(Tag {
+            gid: model.data.gid,
+            name: model.data.name,
+        })
+    }
 
-        let status = response.status();
-        if !status.is_success() {
-            let response_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read response"));
-            warn!(
-                "Failed to fetch custom fields: {} - {}",
-                status, response_text
-            );
-            return Ok(vec![]); // Return empty vec on error (custom fields are optional)
-        }
+    /// Post a list of related task GIDs to a task action endpoint.
+    ///
+    async fn post_task_relations(
+        &mut self,
+        task_gid: &str,
+        action: &str,
+        key: &str,
+        gids: &[String],
+    ) -> Result<()> {
+        debug!(
+            "Calling {} on task GID {} with {:?}...",
+            action, task_gid, gids
+        );
+        self.post_task_action(task_gid, action, serde_json::json!({ key: gids }))
+            .await
+    }
 
-        let json: serde_json::Value = response.json().await?;
-        let mut custom_fields = Vec::new();
+    /// Post data to a task action endpoint such as `addTag`.
+    ///
+    async fn post_task_action(
+        &mut self,
+        task_gid: &str,
+        action: &str,
+        data: serde_json::Value,
+    ) -> Result<()> {
+        let body = serde_json::json!({ "data": data });
+        let request = self
+            .client
+            .http_client
+            .post(format!(
+                "{}/tasks/{}/{}",
+                &self.client.base_url, task_gid, action
+            ))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        Client::ensure_success(self.client.send(request).await?).await?;
+        Ok(())
+    }
 
-        if let Some(data_array) = json.get("data").and_then(|v| v.as_array()) {
-            for item in data_array {
-                if let Some(cf_obj) = item.get("custom_field").and_then(|v| v.as_object()) {
-                    let gid = cf_obj
-                        .get("gid")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-                    let name = cf_obj
-                        .get("name")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-                    let resource_subtype = cf_obj
-                        .get("resource_subtype")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("text")
-                        .to_string();
-                    let representation_type = cf_obj
-                        .get("representation_type")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string());
-                    let id_prefix = cf_obj
-                        .get("id_prefix")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string());
+    /// Returns the attachments of a task.
+    ///
+    pub async fn get_attachments(&mut self, task_gid: &str) -> Result<Vec<Attachment>> {
+        debug!("Fetching attachments for task GID {}...", task_gid);
 
-                    // Parse enum options
-                    let mut enum_options = Vec::new();
-                    if let Some(enum_opts_array) =
-                        cf_obj.get("enum_options").and_then(|v| v.as_array())
-                    {
-                        for eo_val in enum_opts_array {
-                            if let Some(eo_obj) = eo_val.as_object() {
-                                enum_options.push(EnumOption {
-                                    gid: eo_obj
-                                        .get("gid")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    name: eo_obj
-                                        .get("name")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    enabled: eo_obj
-                                        .get("enabled")
-                                        .and_then(|v| v.as_bool())
-                                        .unwrap_or(true),
-                                    color: eo_obj
-                                        .get("color")
-                                        .and_then(|v| v.as_str())
-                                        .map(|s| s.to_string()),
-                                });
-                            }
-                        }
-                    }
+        model!(AttachmentModel "attachments" {
+            name: String,
+            host: Option<String>,
+            size: Option<u64>,
+            created_at: Option<String>,
+            view_url: Option<String>,
+        });
 
-                    custom_fields.push(CustomField {
-                        gid,
-                        name,
-                        resource_subtype,
-                        representation_type,
-                        id_prefix,
-                        enum_options,
-                        text_value: None,
-                        number_value: None,
-                        date_value: None,
-                        enum_value: None,
-                        multi_enum_values: vec![],
-                        people_value: vec![],
-                        enabled: true,
-                    });
-                }
-            }
-        }
+        let data: Vec<AttachmentModel> = self
+            .client
+            .get_all_pages(
+                "attachments",
+                &[
+                    ("parent", task_gid),
+                    (
+                        "opt_fields",
+                        "resource_type,name,host,size,created_at,view_url",
+                    ),
+                ],
+            )
+            .await?;
 
-        Ok(custom_fields)
+        Ok(data
+            .into_iter()
+            .map(|a| Attachment {
+                gid: a.gid,
+                name: a.name,
+                host: a.host.unwrap_or_else(|| "asana".to_string()),
+                size: a.size,
+                created_at: a.created_at,
+                view_url: a.view_url,
+            })
+            .collect())
     }
 
-    /// Get all stories/comments for a task.
+    /// Download an attachment into the directory, returning the path written.
+    /// Existing files are never overwritten; a numbered name is used instead.
     ///
-    pub async fn get_task_stories(&mut self, task_gid: &str) -> Result<Vec<Story>> {
-        debug!("Fetching stories/comments for task GID {}...", task_gid);
+    pub async fn download_attachment(
+        &mut self,
+        attachment_gid: &str,
+        directory: &Path,
+    ) -> Result<PathBuf> {
+        debug!("Downloading attachment GID {}...", attachment_gid);
+        let (name, bytes) = self.get_attachment_bytes(attachment_gid).await?;
+        tokio::fs::create_dir_all(directory).await?;
+        let path = unique_file_path(directory, &name);
+        tokio::fs::write(&path, &bytes).await?;
+        Ok(path)
+    }
 
-        // In story responses, created_by only has gid and resource_type (no name/email)
-        model!(UserModel "users" { name: Option<String>, email: Option<String> });
-        model!(StoryModel "stories" {
-            text: Option<String>,  // Some story types (reminders, reactions) don't have text
-            created_at: Option<String>,
-            created_by: Option<UserModel>,
-            resource_subtype: Option<String>,
-        } UserModel);
+    /// Returns the name and contents of an attachment hosted by Asana.
+    ///
+    pub async fn get_attachment_bytes(
+        &mut self,
+        attachment_gid: &str,
+    ) -> Result<(String, Vec<u8>)> {
+        model!(AttachmentModel "attachments" {
+            name: String,
+            host: Option<String>,
+            download_url: Option<String>,
+        });
 
-        // Use relational endpoint: GET /tasks/{task_gid}/stories
-        let response = self
+        // Download URLs expire after a few minutes, so always fetch a fresh one
+        let request = self
             .client
             .http_client
             .get(format!(
-                "{}/tasks/{}/stories",
-                &self.client.base_url, task_gid
+                "{}/attachments/{}",
+                &self.client.base_url, attachment_gid
             ))
             .bearer_auth(&self.client.access_token)
-            .send()
-            .await?;
+            .query(&[("opt_fields", "resource_type,name,host,download_url")]);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<AttachmentModel> = response.json().await?;
+        let attachment = model.data;
+        let url = match attachment.download_url {
+            Some(url) => url,
+            None => anyhow::bail!(
+                "Attachment '{}' is hosted by {} and can't be downloaded",
+                attachment.name,
+                attachment
+                    .host
+                    .unwrap_or_else(|| "another service".to_string())
+            ),
+        };
 
-        // Log the raw response
-        let response_text = response.text().await?;
-        debug!("Stories API response: {}", &response_text);
+        // The signed URL carries its own credentials
+        let request = self.client.http_client.get(url);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let bytes = response.bytes().await?;
+        Ok((attachment.name, bytes.to_vec()))
+    }
 
-        // Parse the response
-        let wrapper: Wrapper<Vec<StoryModel>> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                error!(
-                    "Failed to deserialize stories response: {}. Response body: {}",
-                    e, &response_text
-                );
-                e
-            })?;
+    /// Upload a local file as an attachment on a task.
+    ///
+    pub async fn upload_attachment(
+        &mut self,
+        task_gid: &str,
+        file_path: &Path,
+    ) -> Result<Attachment> {
+        debug!(
+            "Uploading {} to task GID {}...",
+            file_path.display(),
+            task_gid
+        );
 
-        Ok(wrapper
-            .data
-            .into_iter()
-            .filter_map(|s| {
-                // Skip stories without text (reminders, reactions, etc.)
-                let text = s.text?;
+        model!(AttachmentModel "attachments" {
+            name: String,
+            host: Option<String>,
+            size: Option<u64>,
+            created_at: Option<String>,
+            view_url: Option<String>,
+        });
 
-                debug!(
-                    "Story: gid={}, created_by={:?}, text={}",
-                    &s.gid, &s.created_by, &text
-                );
+        let file_name = file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or_else(|| anyhow::anyhow!("{} is not a file", file_path.display()))?;
+        let bytes = tokio::fs::read(file_path).await?;
+        let form = reqwest::multipart::Form::new()
+            .text("parent", task_gid.to_string())
+            .part(
+                "file",
+                reqwest::multipart::Part::bytes(bytes).file_name(file_name),
+            );
+        let request = self
+            .client
+            .http_client
+            .post(format!("{}/attachments", &self.client.base_url))
+            .bearer_auth(&self.client.access_token)
+            .multipart(form);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<AttachmentModel> = response.json().await?;
 
-                Some(Story {
-                    gid: s.gid.clone(),
-                    text,
-                    created_at: s.created_at.clone(),
-                    created_by: s.created_by.map(|u| {
-                        debug!(
-                            "  User: gid={}, name={:?}, email={:?}",
-                            &u.gid, &u.name, &u.email
-                        );
-                        User {
-                            gid: u.gid,
-                            name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
-                            email: u.email.unwrap_or_default(),
-                        }
-                    }),
-                    resource_subtype: s.resource_subtype.clone(),
-                })
-            })
-            .collect())
+        Ok(Attachment {
+            gid: model.data.gid,
+            name: model.data.name,
+            host: model.data.host.unwrap_or_else(|| "asana".to_string()),
+            size: model.data.size,
+            created_at: model.data.created_at,
+            view_url: model.data.view_url,
+        })
     }
 
-    /// Create a story/comment on a task.
+    /// Returns the subtasks of a task.
     ///
-    pub async fn create_story(&mut self, task_gid: &str, text: &str) -> Result<Story> {
-        debug!("Creating story/comment on task GID {}...", task_gid);
+    pub async fn get_subtasks(&mut self, task_gid: &str) -> Result<Vec<Task>> {
+        debug!("Fetching subtasks for task GID {}...", task_gid);
 
-        // In story responses, created_by only has gid and resource_type (no name/email)
         model!(UserModel "users" { name: Option<String>, email: Option<String> });
-        model!(StoryModel "stories" {
-            text: String,
-            created_at: Option<String>,
-            created_by: Option<UserModel>,
+        model!(SubtaskModel "tasks" {
+            name: String,
+            completed: bool,
+            due_on: Option<String>,
+            assignee: Option<UserModel>,
+            num_subtasks: Option<usize>,
         } UserModel);
-        model!(TaskModel "tasks" { name: String });
-
-        let body = serde_json::json!({
-            "data": {
-                "text": text
-            }
-        });
 
-        // Post to /tasks/{task_gid}/stories
-        let response = self
+        let data: Vec<SubtaskModel> = self
             .client
-            .from::<TaskModel>(task_gid)
-            .call_with_body::<StoryModel>(reqwest::Method::POST, None, None, Some(body))
-            .await?;
-
-        // Story creation returns { "data": { ... } } wrapper
-        let model: Wrapper<StoryModel> = response.json().await?;
-
-        Ok(Story {
-            gid: model.data.gid,
-            text: model.data.text,
-            created_at: model.data.created_at,
-            created_by: model.data.created_by.map(|u| User {
-                gid: u.gid,
-                name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
-                email: u.email.unwrap_or_default(),
-            }),
-            resource_subtype: None, // Not included in create response
-        })
-    }
-
-    /// Get all users in a workspace.
-    ///
-    pub async fn get_workspace_users(&mut self, workspace_gid: &str) -> Result<Vec<User>> {
-        debug!("Fetching users for workspace GID {}...", workspace_gid);
-
-        model!(UserModel "users" { name: String, email: String });
-
-        let data: Vec<UserModel> = self
-            .client
-            .list_paginated::<UserModel>(Some(vec![("workspace", workspace_gid)]), Some(100))
+            .get_all_pages(
+                &format!("tasks/{}/subtasks", task_gid),
+                &[(
+                    "opt_fields",
+                    "resource_type,name,completed,due_on,assignee.name,assignee.email,num_subtasks",
+                )],
+            )
             .await?;
 
         Ok(data
             .into_iter()
-            .map(|u| User {
-                gid: u.gid,
-                name: u.name,
-                email: u.email,
+            .map(|t| Task {
+                gid: t.gid,
+                name: t.name,
+                completed: t.completed,
+                notes: None,
+                html_notes: None,
+                assignee: t.assignee.map(|u| User {
+                    gid: u.gid,
+                    name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
+                    email: u.email.unwrap_or_default(),
+                }),
+                due_date: None,
+                due_on: t.due_on,
+                due_at: None,
+                start_on: None,
+                section: None,
+                tags: vec![],
+                custom_fields: vec![],
+                created_at: None,
+                modified_at: None,
+                num_subtasks: t.num_subtasks.unwrap_or(0),
+                num_comments: 0,
+                dependencies: vec![],
+                dependents: vec![],
+                memberships: vec![],
+                followers: vec![],
             })
             .collect())
     }
 
-    /// Create a new task.
+    /// Create a subtask under the given parent task.
     ///
-    #[allow(clippy::too_many_arguments)]
-    pub async fn create_task(
-        &mut self,
-        project_gid: &str,
-        name: &str,
-        notes: Option<&str>,
-        assignee: Option<&str>,
-        due_on: Option<&str>,
-        section: Option<&str>,
-        custom_fields: &std::collections::HashMap<String, crate::state::CustomFieldValue>,
-    ) -> Result<Task> {
-        debug!("Creating new task in project GID {}...", project_gid);
+    pub async fn create_subtask(&mut self, parent_gid: &str, name: &str) -> Result<Task> {
+        debug!(
+            "Creating subtask '{}' under task GID {}...",
+            name, parent_gid
+        );
 
         model!(TaskModel "tasks" { name: String, completed: bool });
 
-        let mut data = serde_json::json!({
-            "name": name,
-            "projects": [project_gid]
+        let body = serde_json::json!({
+            "data": {
+                "name": name
+            }
         });
+        let request = self
+            .client
+            .http_client
+            .post(format!(
+                "{}/tasks/{}/subtasks",
+                &self.client.base_url, parent_gid
+            ))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<TaskModel> = response.json().await?;
 
-        if let Some(notes_val) = notes {
-            data["notes"] = serde_json::Value::String(notes_val.to_string());
-        }
-        if let Some(assignee_val) = assignee {
-            data["assignee"] = serde_json::Value::String(assignee_val.to_string());
-        }
-        if let Some(due_on_val) = due_on {
-            data["due_on"] = serde_json::Value::String(due_on_val.to_string());
-        }
+        Ok(Task {
+            gid: model.data.gid,
+            name: model.data.name,
+            completed: model.data.completed,
+            notes: None,
+            html_notes: None,
+            assignee: None,
+            due_date: None,
+            due_on: None,
+            due_at: None,
+            start_on: None,
+            section: None,
+            tags: vec![],
+            custom_fields: vec![],
+            created_at: None,
+            modified_at: None,
+            num_subtasks: 0,
+            num_comments: 0,
+            dependencies: vec![],
+            dependents: vec![],
+            memberships: vec![],
+            followers: vec![],
+        })
+    }
 
-        // Add custom fields - validate GIDs and skip invalid ones
-        if !custom_fields.is_empty() {
-            // Get valid custom field GIDs for this project
-            // Get full custom field definitions (not just GIDs) so we can validate enum options
-            let project_custom_fields: Vec<CustomField> =
-                match self.get_project_custom_fields(project_gid).await {
-                    Ok(cfs) => {
-                        // Filter out custom_id fields - they cannot be manually set
-                        cfs.iter()
-                            .filter(|cf| {
-                                let is_custom_id = cf
-                                    .representation_type
-                                    .as_ref()
-                                    .map(|s| s == "custom_id")
-                                    .unwrap_or(false)
-                                    || cf.id_prefix.is_some();
-                                !is_custom_id
-                            })
-                            .cloned()
-                            .collect()
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Failed to fetch project custom fields for validation: {}",
-                            e
-                        );
-                        Vec::new()
-                    }
-                };
+    /// Update a task (e.g., mark as complete/incomplete).
+    ///
+    pub async fn update_task(&mut self, task_gid: &str, completed: Option<bool>) -> Result<Task> {
+        debug!("Updating task GID {}...", task_gid);
 
-            let _valid_cf_gids: std::collections::HashSet<String> = project_custom_fields
-                .iter()
-                .map(|cf| cf.gid.clone())
-                .collect();
+        model!(TaskModel "tasks" { name: String, completed: bool });
 
-            // Build a map of custom field GID -> enum option GIDs for validation
-            let mut cf_enum_options: std::collections::HashMap<
-                String,
-                std::collections::HashSet<String>,
-            > = std::collections::HashMap::new();
-            for cf in &project_custom_fields {
-                if cf.resource_subtype == "enum" || cf.resource_subtype == "multi_enum" {
-                    let enum_gids: std::collections::HashSet<String> =
-                        cf.enum_options.iter().map(|eo| eo.gid.clone()).collect();
-                    cf_enum_options.insert(cf.gid.clone(), enum_gids);
+        let body = if let Some(completed) = completed {
+            serde_json::json!({
+                "data": {
+                    "completed": completed
                 }
-            }
-
-            // Use shared custom field builder
-            let custom_fields_obj = CustomFieldBuilder::validate_and_build(
-                custom_fields,
-                &project_custom_fields,
-                BuildMode::Create,
-            )?;
-            if !custom_fields_obj.is_empty() {
-                info!(
-                    "Adding {} custom fields to create task request (as object)",
-                    custom_fields_obj.len()
-                );
-                info!("Custom fields object: {:?}", custom_fields_obj);
-                data["custom_fields"] = serde_json::Value::Object(custom_fields_obj);
-            } else {
-                info!("No valid custom fields to add to create task request");
-            }
-        }
-
-        let body = serde_json::json!({
-            "data": data
-        });
+            })
+        } else {
+            serde_json::json!({})
+        };
 
-        info!(
-            "Create task request body: {}",
-            serde_json::to_string_pretty(&body).unwrap_or_default()
-        );
         let response = self
             .client
-            .call_with_body::<TaskModel>(reqwest::Method::POST, None, None, Some(body))
+            .call_with_body::<TaskModel>(reqwest::Method::PUT, Some(task_gid), None, Some(body))
             .await?;
-
-        // Check response status before trying to deserialize
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
-            error!("Failed to create task: {}", error_text);
-            anyhow::bail!("Failed to create task: {}", error_text);
-        }
-
-        let model: Wrapper<TaskModel> = response.json().await?;
-
-        // If section is specified, add task to that section
-        if let Some(section_gid) = section {
-            self.add_task_to_section(&model.data.gid, section_gid)
-                .await?;
-        }
+        let model: Wrapper<TaskModel> = Client::ensure_success(response).await?.json().await?;
 
         Ok(Task {
             gid: model.data.gid,
             name: model.data.name,
             completed: model.data.completed,
             notes: None,
+            html_notes: None,
             assignee: None,
             due_date: None,
             due_on: None,
+            due_at: None,
             start_on: None,
             section: None,
             tags: vec![],
@@ -1063,506 +1698,2780 @@ impl Asana {
             modified_at: None,
             num_subtasks: 0,
             num_comments: 0,
+            dependencies: vec![],
+            dependents: vec![],
+            memberships: vec![],
+            followers: vec![],
         })
     }
 
-    /// Update task fields.
+    /// Get all sections for a project (for kanban board).
     ///
-    #[allow(clippy::too_many_arguments)]
-    pub async fn update_task_fields(
-        &mut self,
-        task_gid: &str,
-        name: Option<&str>,
-        notes: Option<&str>,
-        assignee: Option<&str>,
-        due_on: Option<&str>,
-        section: Option<&str>,
-        completed: Option<bool>,
-        custom_fields: &std::collections::HashMap<String, crate::state::CustomFieldValue>,
-    ) -> Result<Task> {
-        debug!("Updating task fields for GID {}...", task_gid);
+    pub async fn get_project_sections(&mut self, project_gid: &str) -> Result<Vec<Section>> {
+        debug!("Fetching sections for project GID {}...", project_gid);
 
-        // Include all fields we might send in the update
-        // Note: section is NOT included here because it's handled via a separate endpoint
-        model!(TaskModel "tasks" {
-            name: String,
-            completed: bool,
-            notes: Option<String>,
-            assignee: Option<String>,
-            due_on: Option<String>,
-        });
+        model!(ProjectModel "projects" { name: String });
+        model!(SectionModel "sections" { name: String });
 
-        let mut data = serde_json::json!({});
+        // Use the relational endpoint: GET /projects/{project_gid}/sections
+        let data: Vec<SectionModel> = self
+            .client
+            .from::<ProjectModel>(project_gid)
+            .list_paginated::<SectionModel>(None, Some(100))
+            .await?;
 
-        // Only add fields that are Some and non-empty (after trimming)
-        // This ensures we never send empty strings to the API
-        if let Some(name_val) = name {
-            let trimmed = name_val.trim();
-            if !trimmed.is_empty() {
-                data["name"] = serde_json::Value::String(trimmed.to_string());
-            } else {
-                warn!("Skipping empty name field");
-            }
-        }
-        if let Some(notes_val) = notes {
-            let trimmed = notes_val.trim();
-            if !trimmed.is_empty() {
-                data["notes"] = serde_json::Value::String(trimmed.to_string());
-            } else {
-                warn!(
-                    "Skipping empty notes field (original length: {})",
-                    notes_val.len()
-                );
-            }
-        }
-        if let Some(assignee_val) = assignee {
-            let trimmed = assignee_val.trim();
-            if !trimmed.is_empty() {
-                data["assignee"] = serde_json::Value::String(trimmed.to_string());
-            } else {
-                warn!("Skipping empty assignee field");
-            }
-        }
-        if let Some(due_on_val) = due_on {
-            let trimmed = due_on_val.trim();
-            if !trimmed.is_empty() {
-                data["due_on"] = serde_json::Value::String(trimmed.to_string());
-            } else {
-                warn!("Skipping empty due_on field");
-            }
-        }
-        if let Some(completed_val) = completed {
-            data["completed"] = serde_json::Value::Bool(completed_val);
-        }
+        Ok(data
+            .into_iter()
+            .map(|s| Section {
+                gid: s.gid,
+                name: s.name,
+            })
+            .collect())
+    }
 
-        // Add custom fields - validate GIDs and skip invalid ones
-        // NOTE: For updates, custom_fields must be an object/map, NOT an array
-        // Format: { "custom_field_gid": value } where value is:
-        //   - enum: just the enum GID string
-        //   - text: just the text string
-        //   - number: just the number
-        //   - date: date string (YYYY-MM-DD)
-        //   - multi_enum: array of enum GID strings
-        //   - people: array of user GID strings
-        if !custom_fields.is_empty() {
-            // Check each custom field to see if it's a custom_id field (which cannot be manually updated)
-            let mut custom_id_gids = std::collections::HashSet::new();
-            for gid in custom_fields.keys() {
-                // Fetch the custom field definition to check if it's a custom_id field
-                let cf_uri = format!(
-                    "custom_fields/{}?opt_fields=representation_type,id_prefix",
-                    gid
-                );
-                let cf_url = format!("{}/{}", "https://app.asana.com/api/1.0", cf_uri);
-                let cf_response = self
-                    .client
-                    .http_client
-                    .get(&cf_url)
-                    .header(
-                        "Authorization",
-                        format!("Bearer {}", self.client.access_token),
-                    )
-                    .send()
-                    .await;
-
-                if let Ok(resp) = cf_response {
-                    if resp.status().is_success() {
-                        if let Ok(json) = resp.json::<serde_json::Value>().await {
-                            // Check if this is a custom_id field
-                            let is_custom_id = json
-                                .get("data")
-                                .map(|d| {
-                                    // Check representation_type == "custom_id" or if id_prefix is not null
-                                    let rep_type = d
-                                        .get("representation_type")
-                                        .and_then(|v| v.as_str())
-                                        .map(|s| s == "custom_id")
-                                        .unwrap_or(false);
-                                    let has_id_prefix =
-                                        d.get("id_prefix").map(|v| !v.is_null()).unwrap_or(false);
-                                    rep_type || has_id_prefix
-                                })
-                                .unwrap_or(false);
-
-                            if is_custom_id {
-                                warn!(
-                                    "Skipping custom_id field {} - cannot be manually updated",
-                                    gid
-                                );
-                                custom_id_gids.insert(gid.clone());
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Filter out custom_id fields from the custom fields map before building
-            let mut filtered_custom_fields = custom_fields.clone();
-            filtered_custom_fields.retain(|gid, _| !custom_id_gids.contains(gid));
-
-            // For updates, we can't easily get project_gid without fetching the task
-            // For now, skip project validation and just validate GIDs
-            // The CustomFieldBuilder will handle basic validation (empty GIDs, etc.)
-            let project_custom_fields = Vec::new(); // Skip project validation for updates
-
-            // Use shared custom field builder
-            let custom_fields_obj = CustomFieldBuilder::validate_and_build(
-                &filtered_custom_fields,
-                &project_custom_fields,
-                BuildMode::Update,
-            )?;
-            if !custom_fields_obj.is_empty() {
-                info!(
-                    "Adding {} custom fields to update task request (as object)",
-                    custom_fields_obj.len()
-                );
-                info!("Custom fields object: {:?}", custom_fields_obj);
-                data["custom_fields"] = serde_json::Value::Object(custom_fields_obj);
-            } else {
-                info!("No valid custom fields to add to update task request");
-            }
-        }
-
-        // If only section changed, skip the PUT request and just move the section
-        let has_section_only = section.is_some()
-            && name.is_none()
-            && notes.is_none()
-            && assignee.is_none()
-            && due_on.is_none()
-            && completed.is_none()
-            && custom_fields.is_empty();
+    /// Create a section at the end of a project.
+    ///
+    pub async fn create_section(&mut self, project_gid: &str, name: &str) -> Result<Section> {
+        debug!(
+            "Creating section '{}' in project GID {}...",
+            name, project_gid
+        );
 
-        if has_section_only {
-            // Just move the section, no PUT request needed
-            if let Some(section_gid) = section {
-                self.add_task_to_section(task_gid, section_gid).await?;
-            }
-            return self.get_task(task_gid).await;
-        }
+        model!(SectionModel "sections" { name: String });
 
-        // Ensure we have at least one field to update
-        if let Some(obj) = data.as_object() {
-            if obj.is_empty() {
-                warn!("⚠️ No fields to update, skipping API call");
-                // If section is specified, handle it separately
-                if let Some(section_gid) = section {
-                    self.add_task_to_section(task_gid, section_gid).await?;
-                }
-                // Return the current task without making an API call
-                return self.get_task(task_gid).await;
-            }
-        }
+        let body = serde_json::json!({ "data": { "name": name } });
+        let request = self
+            .client
+            .http_client
+            .post(format!(
+                "{}/projects/{}/sections",
+                &self.client.base_url, project_gid
+            ))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let model: Wrapper<SectionModel> = response.json().await?;
+        Ok(Section {
+            gid: model.data.gid,
+            name: model.data.name,
+        })
+    }
 
-        // Final validation: ensure no empty string values in data
-        if let Some(obj) = data.as_object_mut() {
-            let mut removed_fields = Vec::new();
-            obj.retain(|key, value| match value {
-                serde_json::Value::String(s) => {
-                    if s.trim().is_empty() {
-                        removed_fields.push(key.clone());
-                        error!("⚠️ REMOVING EMPTY STRING FIELD: {} (value: '{:?}')", key, s);
-                        false
-                    } else {
-                        true
-                    }
-                }
-                serde_json::Value::Null => {
-                    removed_fields.push(key.clone());
-                    error!("⚠️ REMOVING NULL FIELD: {}", key);
-                    false
-                }
-                _ => true,
-            });
-            if !removed_fields.is_empty() {
-                error!(
-                    "❌ Removed {} empty/null fields: {:?}",
-                    removed_fields.len(),
-                    removed_fields
-                );
-            }
-        }
+    /// Rename a section.
+    ///
+    pub async fn rename_section(&mut self, section_gid: &str, name: &str) -> Result<()> {
+        debug!("Renaming section GID {} to '{}'...", section_gid, name);
 
-        let body = serde_json::json!({
-            "data": data
-        });
+        model!(SectionModel "sections" { name: String });
 
-        info!(
-            "Update task request body: {}",
-            serde_json::to_string_pretty(&body).unwrap_or_default()
-        );
+        let body = serde_json::json!({ "data": { "name": name } });
         let response = self
             .client
-            .call_with_body::<TaskModel>(reqwest::Method::PUT, Some(task_gid), None, Some(body))
+            .call_with_body::<SectionModel>(
+                reqwest::Method::PUT,
+                Some(section_gid),
+                None,
+                Some(body),
+            )
             .await?;
-
-        // Check response status
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
-            anyhow::bail!("Failed to update task: {}", error_text);
-        }
-
-        // If section is specified, move task to that section
-        if let Some(section_gid) = section {
-            self.add_task_to_section(task_gid, section_gid).await?;
-        }
-
-        // Return updated task by fetching it again (ignore the PUT response)
-        self.get_task(task_gid).await
+        Client::ensure_success(response).await?;
+        Ok(())
     }
 
-    /// Add a task to a section (move task in kanban).
+    /// Delete a section. Asana only deletes sections without tasks.
     ///
-    pub async fn add_task_to_section(&mut self, task_gid: &str, section_gid: &str) -> Result<()> {
-        debug!("Moving task {} to section {}...", task_gid, section_gid);
+    pub async fn delete_section(&mut self, section_gid: &str) -> Result<()> {
+        debug!("Deleting section GID {}...", section_gid);
 
-        // Get the project GID from the task's memberships
-
-        // Get the project GID from the task's memberships
-        // We need to fetch the task with memberships.project included
-        let opt_fields = "resource_type,memberships.project.gid,memberships.section.gid";
-        let uri = format!("tasks/{}?opt_fields={}", task_gid, opt_fields);
-        let request_url = format!("{}/{}", self.client.base_url, uri);
+        model!(SectionModel "sections" { name: String });
 
         let response = self
             .client
-            .http_client
-            .get(&request_url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.client.access_token),
-            )
-            .send()
+            .call_with_body::<SectionModel>(reqwest::Method::DELETE, Some(section_gid), None, None)
             .await?;
+        Client::ensure_success(response).await?;
+        Ok(())
+    }
 
-        let task_json: serde_json::Value = response.json().await?;
-        let task_data = task_json
-            .get("data")
-            .ok_or_else(|| anyhow::anyhow!("No data in response"))?;
+    /// Move a section before or after another section of the same project.
+    ///
+    pub async fn insert_section(
+        &mut self,
+        project_gid: &str,
+        section_gid: &str,
+        before_section: Option<&str>,
+        after_section: Option<&str>,
+    ) -> Result<()> {
+        debug!(
+            "Moving section GID {} in project GID {} (before {:?}, after {:?})...",
+            section_gid, project_gid, before_section, after_section
+        );
 
-        // Extract project GID from memberships
-        let project_gid = task_data
-            .get("memberships")
-            .and_then(|m| m.as_array())
-            .and_then(|arr| arr.first())
-            .and_then(|m| m.get("project"))
-            .and_then(|p| p.as_object())
-            .and_then(|p| p.get("gid"))
-            .and_then(|g| g.as_str());
+        let mut data = serde_json::json!({ "section": section_gid });
+        if let Some(before) = before_section {
+            data["before_section"] = serde_json::Value::String(before.to_string());
+        }
+        if let Some(after) = after_section {
+            data["after_section"] = serde_json::Value::String(after.to_string());
+        }
+        let body = serde_json::json!({ "data": data });
+        let request = self
+            .client
+            .http_client
+            .post(format!(
+                "{}/projects/{}/sections/insert",
+                &self.client.base_url, project_gid
+            ))
+            .bearer_auth(&self.client.access_token)
+            .json(&body);
+        Client::ensure_success(self.client.send(request).await?).await?;
+        Ok(())
+    }
 
-        let current_section_gid = task_data
-            .get("memberships")
-            .and_then(|m| m.as_array())
-            .and_then(|arr| arr.first())
-            .and_then(|m| m.get("section"))
-            .and_then(|s| s.as_object())
-            .and_then(|s| s.get("gid"))
-            .and_then(|g| g.as_str());
+    /// Get custom fields for a project.
+    ///
+    pub async fn get_project_custom_fields(
+        &mut self,
+        project_gid: &str,
+    ) -> Result<Vec<CustomField>> {
+        debug!("Fetching custom fields for project GID {}...", project_gid);
 
-        info!(
-            "Current section: {:?}, Target section: {}",
-            current_section_gid, section_gid
+        // Use GET /projects/{project_gid}/custom_field_settings
+        // Then fetch each custom field definition
+        let uri = format!(
+            "projects/{}/custom_field_settings?opt_fields=custom_field.gid,custom_field.name,custom_field.resource_subtype,custom_field.representation_type,custom_field.id_prefix,custom_field.enum_options.gid,custom_field.enum_options.name,custom_field.enum_options.enabled,custom_field.enum_options.color",
+            project_gid
         );
+        let request_url = format!("{}/{}", self.client.base_url, uri);
 
-        // If the task is already in the target section, no need to move
-        if current_section_gid == Some(section_gid) {
-            info!("Task is already in the target section, no move needed");
-            return Ok(());
+        let request = self.client.http_client.get(&request_url).header(
+            "Authorization",
+            format!("Bearer {}", self.client.access_token),
+        );
+        let response = self.client.send(request).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let response_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| String::from("Unable to read response"));
+            warn!(
+                "Failed to fetch custom fields: {} - {}",
+                status, response_text
+            );
+            return Ok(vec![]); // Return empty vec on error (custom fields are optional)
         }
 
-        // Use POST /tasks/{task_gid}/addProject with section specified
-        // This is the recommended way to move a task to a different section
-        // According to Asana API docs, this will move the task to the new section
-        let project_gid = project_gid.ok_or_else(|| anyhow::anyhow!("Task is not in a project"))?;
+        let json: serde_json::Value = response.json().await?;
+        let mut custom_fields = Vec::new();
 
-        let body = serde_json::json!({
-            "data": {
-                "project": project_gid,
-                "section": section_gid
+        if let Some(data_array) = json.get("data").and_then(|v| v.as_array()) {
+            for item in data_array {
+                if let Some(cf_obj) = item.get("custom_field").and_then(|v| v.as_object()) {
+                    let gid = cf_obj
+                        .get("gid")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let name = cf_obj
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let resource_subtype = cf_obj
+                        .get("resource_subtype")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("text")
+                        .to_string();
+                    let representation_type = cf_obj
+                        .get("representation_type")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string());
+                    let id_prefix = cf_obj
+                        .get("id_prefix")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string());
+
+                    // Parse enum options
+                    let mut enum_options = Vec::new();
+                    if let Some(enum_opts_array) =
+                        cf_obj.get("enum_options").and_then(|v| v.as_array())
+                    {
+                        for eo_val in enum_opts_array {
+                            if let Some(eo_obj) = eo_val.as_object() {
+                                enum_options.push(EnumOption {
+                                    gid: eo_obj
+                                        .get("gid")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("")
+                                        .to_string(),
+                                    name: eo_obj
+                                        .get("name")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("")
+                                        .to_string(),
+                                    enabled: eo_obj
+                                        .get("enabled")
+                                        .and_then(|v| v.as_bool())
+                                        .unwrap_or(true),
+                                    color: eo_obj
+                                        .get("color")
+                                        .and_then(|v| v.as_str())
+                                        .map(|s| s.to_string()),
+                                });
+                            }
+                        }
+                    }
+
+                    custom_fields.push(CustomField {
+                        gid,
+                        name,
+                        resource_subtype,
+                        representation_type,
+                        id_prefix,
+                        enum_options,
+                        text_value: None,
+                        number_value: None,
+                        date_value: None,
+                        enum_value: None,
+                        multi_enum_values: vec![],
+                        people_value: vec![],
+                        enabled: true,
+                    });
+                }
+            }
+        }
+
+        Ok(custom_fields)
+    }
+
+    /// Get all stories/comments for a task.
+    ///
+    pub async fn get_task_stories(&mut self, task_gid: &str) -> Result<Vec<Story>> {
+        debug!("Fetching stories/comments for task GID {}...", task_gid);
+
+        // In story responses, created_by only has gid and resource_type (no name/email)
+        model!(UserModel "users" { name: Option<String>, email: Option<String> });
+        model!(StoryModel "stories" {
+            text: Option<String>,  // Some story types (reminders, reactions) don't have text
+            created_at: Option<String>,
+            created_by: Option<UserModel>,
+            resource_subtype: Option<String>,
+            is_pinned: Option<bool>,
+            liked: Option<bool>,
+        } UserModel);
+
+        // Use relational endpoint: GET /tasks/{task_gid}/stories
+        let request = self
+            .client
+            .http_client
+            .get(format!(
+                "{}/tasks/{}/stories",
+                &self.client.base_url, task_gid
+            ))
+            .query(&[(
+                "opt_fields",
+                "resource_type,text,created_at,created_by.name,created_by.email,resource_subtype,is_pinned,liked",
+            )])
+            .bearer_auth(&self.client.access_token);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+
+        // Log the raw response
+        let response_text = response.text().await?;
+        debug!("Stories API response: {}", &response_text);
+
+        // Parse the response
+        let wrapper: Wrapper<Vec<StoryModel>> =
+            serde_json::from_str(&response_text).map_err(|e| {
+                error!(
+                    "Failed to deserialize stories response: {}. Response body: {}",
+                    e, &response_text
+                );
+                e
+            })?;
+
+        Ok(wrapper
+            .data
+            .into_iter()
+            .filter_map(|s| {
+                // Skip stories without text (reminders, reactions, etc.)
+                let text = s.text?;
+
+                debug!(
+                    "Story: gid={}, created_by={:?}, text={}",
+                    &s.gid, &s.created_by, &text
+                );
+
+                Some(Story {
+                    gid: s.gid.clone(),
+                    text,
+                    created_at: s.created_at.clone(),
+                    created_by: s.created_by.map(|u| {
+                        debug!(
+                            "  User: gid={}, name={:?}, email={:?}",
+                            &u.gid, &u.name, &u.email
+                        );
+                        User {
+                            gid: u.gid,
+                            name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
+                            email: u.email.unwrap_or_default(),
+                        }
+                    }),
+                    resource_subtype: s.resource_subtype.clone(),
+                    is_pinned: s.is_pinned.unwrap_or(false),
+                    liked: s.liked.unwrap_or(false),
+                })
+            })
+            .collect())
+    }
+
+    /// Create a story/comment on a task.
+    ///
+    pub async fn create_story(&mut self, task_gid: &str, text: &str) -> Result<Story> {
+        debug!("Creating story/comment on task GID {}...", task_gid);
+
+        // In story responses, created_by only has gid and resource_type (no name/email)
+        model!(UserModel "users" { name: Option<String>, email: Option<String> });
+        model!(StoryModel "stories" {
+            text: String,
+            created_at: Option<String>,
+            created_by: Option<UserModel>,
+        } UserModel);
+        model!(TaskModel "tasks" { name: String });
+
+        let body = serde_json::json!({
+            "data": {
+                "text": text
             }
         });
 
-        let url = format!("{}/tasks/{}/addProject", self.client.base_url, task_gid);
+        // Post to /tasks/{task_gid}/stories
+        let response = self
+            .client
+            .from::<TaskModel>(task_gid)
+            .call_with_body::<StoryModel>(reqwest::Method::POST, None, None, Some(body))
+            .await?;
+        let response = Client::ensure_success(response).await?;
+
+        // Story creation returns { "data": { ... } } wrapper
+        let model: Wrapper<StoryModel> = response.json().await?;
+
+        Ok(Story {
+            gid: model.data.gid,
+            text: model.data.text,
+            created_at: model.data.created_at,
+            created_by: model.data.created_by.map(|u| User {
+                gid: u.gid,
+                name: u.name.unwrap_or_else(|| "Unknown User".to_string()),
+                email: u.email.unwrap_or_default(),
+            }),
+            resource_subtype: None, // Not included in create response
+            is_pinned: false,
+            liked: false,
+        })
+    }
+
+    /// Change the text of a comment.
+    ///
+    pub async fn update_story(&mut self, story_gid: &str, text: &str) -> Result<()> {
+        debug!("Updating story GID {}...", story_gid);
+        self.put_story(story_gid, serde_json::json!({ "data": { "text": text } }))
+            .await
+    }
+
+    /// Like or unlike a story as the current user. Stories have no like
+    /// endpoints of their own, so this updates the story's `liked` field.
+    ///
+    pub async fn set_story_liked(&mut self, story_gid: &str, liked: bool) -> Result<()> {
+        debug!("Setting liked={} on story GID {}...", liked, story_gid);
+        self.put_story(story_gid, serde_json::json!({ "data": { "liked": liked } }))
+            .await
+    }
+
+    /// Pin or unpin a story to the top of its task.
+    ///
+    pub async fn set_story_pinned(&mut self, story_gid: &str, pinned: bool) -> Result<()> {
+        debug!("Setting is_pinned={} on story GID {}...", pinned, story_gid);
+        self.put_story(
+            story_gid,
+            serde_json::json!({ "data": { "is_pinned": pinned } }),
+        )
+        .await
+    }
+
+    /// Delete a comment.
+    ///
+    pub async fn delete_story(&mut self, story_gid: &str) -> Result<()> {
+        debug!("Deleting story GID {}...", story_gid);
+
+        model!(StoryModel "stories" { text: Option<String> });
+
+        let response = self
+            .client
+            .call_with_body::<StoryModel>(reqwest::Method::DELETE, Some(story_gid), None, None)
+            .await?;
+        Client::ensure_success(response).await?;
+        Ok(())
+    }
+
+    /// Send a PUT request with the given body to a story.
+    ///
+    async fn put_story(&mut self, story_gid: &str, body: serde_json::Value) -> Result<()> {
+        model!(StoryModel "stories" { text: Option<String> });
+
+        let response = self
+            .client
+            .call_with_body::<StoryModel>(reqwest::Method::PUT, Some(story_gid), None, Some(body))
+            .await?;
+        Client::ensure_success(response).await?;
+        Ok(())
+    }
+
+    /// Get all users in a workspace.
+    ///
+    pub async fn get_workspace_users(&mut self, workspace_gid: &str) -> Result<Vec<User>> {
+        debug!("Fetching users for workspace GID {}...", workspace_gid);
+
+        model!(UserModel "users" { name: String, email: String });
+
+        let data: Vec<UserModel> = self
+            .client
+            .list_paginated::<UserModel>(Some(vec![("workspace", workspace_gid)]), Some(100))
+            .await?;
+
+        Ok(data
+            .into_iter()
+            .map(|u| User {
+                gid: u.gid,
+                name: u.name,
+                email: u.email,
+            })
+            .collect())
+    }
+
+    /// Returns the workspace users best matching a query, as the typeahead
+    /// endpoint ranks them. An empty query returns recently used users.
+    ///
+    pub async fn typeahead_users(&mut self, workspace_gid: &str, query: &str) -> Result<Vec<User>> {
+        model!(UserModel "users" { name: String, email: Option<String> });
+
+        let data: Vec<UserModel> = self
+            .typeahead(workspace_gid, "user", query, "name,email")
+            .await?;
+        Ok(data
+            .into_iter()
+            .map(|u| User {
+                gid: u.gid,
+                name: u.name,
+                email: u.email.unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Returns the workspace projects best matching a query.
+    ///
+    pub async fn typeahead_projects(
+        &mut self,
+        workspace_gid: &str,
+        query: &str,
+    ) -> Result<Vec<Project>> {
+        model!(ProjectModel "projects" {
+            name: String,
+            archived: Option<bool>,
+            color: Option<String>,
+        });
+
+        let data: Vec<ProjectModel> = self
+            .typeahead(workspace_gid, "project", query, "name,archived,color")
+            .await?;
+        Ok(data
+            .into_iter()
+            .map(|p| Project {
+                gid: p.gid,
+                name: p.name,
+                archived: p.archived.unwrap_or(false),
+                color: p.color.unwrap_or_default(),
+                notes: String::new(),
+            })
+            .collect())
+    }
+
+    /// Returns the workspace tags best matching a query.
+    ///
+    pub async fn typeahead_tags(&mut self, workspace_gid: &str, query: &str) -> Result<Vec<Tag>> {
+        model!(TagModel "tags" { name: String });
+
+        let data: Vec<TagModel> = self.typeahead(workspace_gid, "tag", query, "name").await?;
+        Ok(data
+            .into_iter()
+            .map(|t| Tag {
+                gid: t.gid,
+                name: t.name,
+            })
+            .collect())
+    }
+
+    /// Returns the objects of one resource type best matching a query.
+    ///
+    async fn typeahead<T: models::Model>(
+        &mut self,
+        workspace_gid: &str,
+        resource_type: &str,
+        query: &str,
+        opt_fields: &str,
+    ) -> Result<Vec<T>> {
+        debug!(
+            "Requesting {} typeahead for '{}' in workspace GID {}...",
+            resource_type, query, workspace_gid
+        );
+
+        // Use the workspace endpoint: GET /workspaces/{workspace_gid}/typeahead
+        let request = self
+            .client
+            .http_client
+            .get(format!(
+                "{}/workspaces/{}/typeahead",
+                &self.client.base_url, workspace_gid
+            ))
+            .query(&[
+                ("resource_type", resource_type),
+                ("query", query),
+                ("count", TYPEAHEAD_COUNT),
+                ("opt_fields", opt_fields),
+            ])
+            .bearer_auth(&self.client.access_token);
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+        let wrapper: Wrapper<Vec<T>> = response.json().await?;
+        Ok(wrapper.data)
+    }
+
+    /// Create a new task. Notes are Markdown, sent as rich text.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_task(
+        &mut self,
+        project_gid: &str,
+        name: &str,
+        notes: Option<&str>,
+        assignee: Option<&str>,
+        dates: &TaskDates,
+        section: Option<&str>,
+        tags: &[String],
+        custom_fields: &std::collections::HashMap<String, crate::state::CustomFieldValue>,
+    ) -> Result<Task> {
+        debug!("Creating new task in project GID {}...", project_gid);
+
+        model!(TaskModel "tasks" { name: String, completed: bool });
+
+        let mut data = serde_json::json!({
+            "name": name,
+            "projects": [project_gid]
+        });
+
+        if let Some(notes_val) = notes {
+            data["html_notes"] = serde_json::Value::String(markdown_to_html(notes_val));
+        }
+        if let Some(assignee_val) = assignee {
+            data["assignee"] = serde_json::Value::String(assignee_val.to_string());
+        }
+        dates.apply(&mut data);
+        if !tags.is_empty() {
+            data["tags"] = serde_json::json!(tags);
+        }
+
+        // Add custom fields - validate GIDs and skip invalid ones
+        if !custom_fields.is_empty() {
+            // Get valid custom field GIDs for this project
+            // Get full custom field definitions (not just GIDs) so we can validate enum options
+            let project_custom_fields: Vec<CustomField> =
+                match self.get_project_custom_fields(project_gid).await {
+                    Ok(cfs) => {
+                        // Filter out custom_id fields - they cannot be manually set
+                        cfs.iter()
+                            .filter(|cf| {
+                                let is_custom_id = cf
+                                    .representation_type
+                                    .as_ref()
+                                    .map(|s| s == "custom_id")
+                                    .unwrap_or(false)
+                                    || cf.id_prefix.is_some();
+                                !is_custom_id
+                            })
+                            .cloned()
+                            .collect()
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to fetch project custom fields for validation: {}",
+                            e
+                        );
+                        Vec::new()
+                    }
+                };
+
+            let _valid_cf_gids: std::collections::HashSet<String> = project_custom_fields
+                .iter()
+                .map(|cf| cf.gid.clone())
+                .collect();
+
+            // Build a map of custom field GID -> enum option GIDs for validation
+            let mut cf_enum_options: std::collections::HashMap<
+                String,
+                std::collections::HashSet<String>,
+            > = std::collections::HashMap::new();
+            for cf in &project_custom_fields {
+                if cf.resource_subtype == "enum" || cf.resource_subtype == "multi_enum" {
+                    let enum_gids: std::collections::HashSet<String> =
+                        cf.enum_options.iter().map(|eo| eo.gid.clone()).collect();
+                    cf_enum_options.insert(cf.gid.clone(), enum_gids);
+                }
+            }
+
+            // Use shared custom field builder
+            let custom_fields_obj = CustomFieldBuilder::validate_and_build(
+                custom_fields,
+                &project_custom_fields,
+                BuildMode::Create,
+            )?;
+            if !custom_fields_obj.is_empty() {
+                info!(
+                    "Adding {} custom fields to create task request (as object)",
+                    custom_fields_obj.len()
+                );
+                info!("Custom fields object: {:?}", custom_fields_obj);
+                data["custom_fields"] = serde_json::Value::Object(custom_fields_obj);
+            } else {
+                info!("No valid custom fields to add to create task request");
+            }
+        }
+
+        let body = serde_json::json!({
+            "data": data
+        });
+
+        info!(
+            "Create task request body: {}",
+            serde_json::to_string_pretty(&body).unwrap_or_default()
+        );
+        let response = self
+            .client
+            .call_with_body::<TaskModel>(reqwest::Method::POST, None, None, Some(body))
+            .await?;
+
+        // Check response status before trying to deserialize
+        let response = Client::ensure_success(response).await?;
+
+        let model: Wrapper<TaskModel> = response.json().await?;
+
+        // If section is specified, add task to that section
+        if let Some(section_gid) = section {
+            self.add_task_to_section(&model.data.gid, section_gid)
+                .await?;
+        }
+
+        Ok(Task {
+            gid: model.data.gid,
+            name: model.data.name,
+            completed: model.data.completed,
+            notes: None,
+            html_notes: None,
+            assignee: None,
+            due_date: None,
+            due_on: None,
+            due_at: None,
+            start_on: None,
+            section: None,
+            tags: vec![],
+            custom_fields: vec![],
+            created_at: None,
+            modified_at: None,
+            num_subtasks: 0,
+            num_comments: 0,
+            dependencies: vec![],
+            dependents: vec![],
+            memberships: vec![],
+            followers: vec![],
+        })
+    }
+
+    /// Update task fields. Notes are Markdown, sent as rich text.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_task_fields(
+        &mut self,
+        task_gid: &str,
+        name: Option<&str>,
+        notes: Option<&str>,
+        assignee: Option<&str>,
+        dates: &TaskDates,
+        section: Option<&str>,
+        completed: Option<bool>,
+        custom_fields: &std::collections::HashMap<String, crate::state::CustomFieldValue>,
+    ) -> Result<Task> {
+        debug!("Updating task fields for GID {}...", task_gid);
+
+        // Include all fields we might send in the update
+        // Note: section is NOT included here because it's handled via a separate endpoint
+        model!(TaskModel "tasks" {
+            name: String,
+            completed: bool,
+            notes: Option<String>,
+            assignee: Option<String>,
+            due_on: Option<String>,
+        });
+
+        let mut data = serde_json::json!({});
+
+        // Only add fields that are Some and non-empty (after trimming)
+        // This ensures we never send empty strings to the API
+        if let Some(name_val) = name {
+            let trimmed = name_val.trim();
+            if !trimmed.is_empty() {
+                data["name"] = serde_json::Value::String(trimmed.to_string());
+            } else {
+                warn!("Skipping empty name field");
+            }
+        }
+        if let Some(notes_val) = notes {
+            let trimmed = notes_val.trim();
+            if !trimmed.is_empty() {
+                data["html_notes"] = serde_json::Value::String(markdown_to_html(trimmed));
+            } else {
+                warn!(
+                    "Skipping empty notes field (original length: {})",
+                    notes_val.len()
+                );
+            }
+        }
+        if let Some(assignee_val) = assignee {
+            let trimmed = assignee_val.trim();
+            if !trimmed.is_empty() {
+                data["assignee"] = serde_json::Value::String(trimmed.to_string());
+            } else {
+                warn!("Skipping empty assignee field");
+            }
+        }
+        // Empty dates are sent as null to clear them
+        dates.apply(&mut data);
+        if let Some(completed_val) = completed {
+            data["completed"] = serde_json::Value::Bool(completed_val);
+        }
+
+        // Add custom fields - validate GIDs and skip invalid ones
+        // NOTE: For updates, custom_fields must be an object/map, NOT an array
+        // Format: { "custom_field_gid": value } where value is:
+        //   - enum: just the enum GID string
+        //   - text: just the text string
+        //   - number: just the number
+        //   - date: date string (YYYY-MM-DD)
+        //   - multi_enum: array of enum GID strings
+        //   - people: array of user GID strings
+        if !custom_fields.is_empty() {
+            // Check each custom field to see if it's a custom_id field (which cannot be manually updated)
+            let mut custom_id_gids = std::collections::HashSet::new();
+            for gid in custom_fields.keys() {
+                // Fetch the custom field definition to check if it's a custom_id field
+                let cf_uri = format!(
+                    "custom_fields/{}?opt_fields=representation_type,id_prefix",
+                    gid
+                );
+                let cf_url = format!("{}/{}", self.client.base_url, cf_uri);
+                let cf_request = self.client.http_client.get(&cf_url).header(
+                    "Authorization",
+                    format!("Bearer {}", self.client.access_token),
+                );
+                let cf_response = self.client.send(cf_request).await;
+
+                if let Ok(resp) = cf_response {
+                    if resp.status().is_success() {
+                        if let Ok(json) = resp.json::<serde_json::Value>().await {
+                            // Check if this is a custom_id field
+                            let is_custom_id = json
+                                .get("data")
+                                .map(|d| {
+                                    // Check representation_type == "custom_id" or if id_prefix is not null
+                                    let rep_type = d
+                                        .get("representation_type")
+                                        .and_then(|v| v.as_str())
+                                        .map(|s| s == "custom_id")
+                                        .unwrap_or(false);
+                                    let has_id_prefix =
+                                        d.get("id_prefix").map(|v| !v.is_null()).unwrap_or(false);
+                                    rep_type || has_id_prefix
+                                })
+                                .unwrap_or(false);
+
+                            if is_custom_id {
+                                warn!(
+                                    "Skipping custom_id field {} - cannot be manually updated",
+                                    gid
+                                );
+                                custom_id_gids.insert(gid.clone());
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Filter out custom_id fields from the custom fields map before building
+            let mut filtered_custom_fields = custom_fields.clone();
+            filtered_custom_fields.retain(|gid, _| !custom_id_gids.contains(gid));
+
+            // For updates, we can't easily get project_gid without fetching the task
+            // For now, skip project validation and just validate GIDs
+            // The CustomFieldBuilder will handle basic validation (empty GIDs, etc.)
+            let project_custom_fields = Vec::new(); // Skip project validation for updates
+
+            // Use shared custom field builder
+            let custom_fields_obj = CustomFieldBuilder::validate_and_build(
+                &filtered_custom_fields,
+                &project_custom_fields,
+                BuildMode::Update,
+            )?;
+            if !custom_fields_obj.is_empty() {
+                info!(
+                    "Adding {} custom fields to update task request (as object)",
+                    custom_fields_obj.len()
+                );
+                info!("Custom fields object: {:?}", custom_fields_obj);
+                data["custom_fields"] = serde_json::Value::Object(custom_fields_obj);
+            } else {
+                info!("No valid custom fields to add to update task request");
+            }
+        }
+
+        // If only section changed, skip the PUT request and just move the section
+        let has_section_only = section.is_some()
+            && name.is_none()
+            && notes.is_none()
+            && assignee.is_none()
+            && dates.is_empty()
+            && completed.is_none()
+            && custom_fields.is_empty();
+
+        if has_section_only {
+            // Just move the section, no PUT request needed
+            if let Some(section_gid) = section {
+                self.add_task_to_section(task_gid, section_gid).await?;
+            }
+            return self.get_task(task_gid).await;
+        }
+
+        // Ensure we have at least one field to update
+        if let Some(obj) = data.as_object() {
+            if obj.is_empty() {
+                warn!("⚠️ No fields to update, skipping API call");
+                // If section is specified, handle it separately
+                if let Some(section_gid) = section {
+                    self.add_task_to_section(task_gid, section_gid).await?;
+                }
+                // Return the current task without making an API call
+                return self.get_task(task_gid).await;
+            }
+        }
+
+        // Final validation: ensure no empty string values in data
+        if let Some(obj) = data.as_object_mut() {
+            let mut removed_fields = Vec::new();
+            obj.retain(|key, value| match value {
+                serde_json::Value::String(s) => {
+                    if s.trim().is_empty() {
+                        removed_fields.push(key.clone());
+                        error!("⚠️ REMOVING EMPTY STRING FIELD: {} (value: '{:?}')", key, s);
+                        false
+                    } else {
+                        true
+                    }
+                }
+                // Null clears a date
+                serde_json::Value::Null if TaskDates::FIELDS.contains(&key.as_str()) => true,
+                serde_json::Value::Null => {
+                    removed_fields.push(key.clone());
+                    error!("⚠️ REMOVING NULL FIELD: {}", key);
+                    false
+                }
+                _ => true,
+            });
+            if !removed_fields.is_empty() {
+                error!(
+                    "❌ Removed {} empty/null fields: {:?}",
+                    removed_fields.len(),
+                    removed_fields
+                );
+            }
+        }
+
+        let body = serde_json::json!({
+            "data": data
+        });
+
+        info!(
+            "Update task request body: {}",
+            serde_json::to_string_pretty(&body).unwrap_or_default()
+        );
+        let response = self
+            .client
+            .call_with_body::<TaskModel>(reqwest::Method::PUT, Some(task_gid), None, Some(body))
+            .await?;
+
+        // Check response status
+        Client::ensure_success(response).await?;
+
+        // If section is specified, move task to that section
+        if let Some(section_gid) = section {
+            self.add_task_to_section(task_gid, section_gid).await?;
+        }
+
+        // Return updated task by fetching it again (ignore the PUT response)
+        self.get_task(task_gid).await
+    }
+
+    /// Add a task to a section (move task in kanban).
+    ///
+    pub async fn add_task_to_section(&mut self, task_gid: &str, section_gid: &str) -> Result<()> {
+        debug!("Moving task {} to section {}...", task_gid, section_gid);
+
+        // Get the project GID from the task's memberships
+
+        // Get the project GID from the task's memberships
+        // We need to fetch the task with memberships.project included
+        let opt_fields = "resource_type,memberships.project.gid,memberships.section.gid";
+        let uri = format!("tasks/{}?opt_fields={}", task_gid, opt_fields);
+        let request_url = format!("{}/{}", self.client.base_url, uri);
+
+        let request = self.client.http_client.get(&request_url).header(
+            "Authorization",
+            format!("Bearer {}", self.client.access_token),
+        );
+        let response = Client::ensure_success(self.client.send(request).await?).await?;
+
+        let task_json: serde_json::Value = response.json().await?;
+        let task_data = task_json
+            .get("data")
+            .ok_or_else(|| anyhow::anyhow!("No data in response"))?;
+
+        // Extract project GID from memberships
+        let project_gid = task_data
+            .get("memberships")
+            .and_then(|m| m.as_array())
+            .and_then(|arr| arr.first())
+            .and_then(|m| m.get("project"))
+            .and_then(|p| p.as_object())
+            .and_then(|p| p.get("gid"))
+            .and_then(|g| g.as_str());
+
+        let current_section_gid = task_data
+            .get("memberships")
+            .and_then(|m| m.as_array())
+            .and_then(|arr| arr.first())
+            .and_then(|m| m.get("section"))
+            .and_then(|s| s.as_object())
+            .and_then(|s| s.get("gid"))
+            .and_then(|g| g.as_str());
+
+        info!(
+            "Current section: {:?}, Target section: {}",
+            current_section_gid, section_gid
+        );
+
+        // If the task is already in the target section, no need to move
+        if current_section_gid == Some(section_gid) {
+            info!("Task is already in the target section, no move needed");
+            return Ok(());
+        }
+
+        // Use POST /tasks/{task_gid}/addProject with section specified
+        // This is the recommended way to move a task to a different section
+        // According to Asana API docs, this will move the task to the new section
+        let project_gid = project_gid.ok_or_else(|| anyhow::anyhow!("Task is not in a project"))?;
+
+        let body = serde_json::json!({
+            "data": {
+                "project": project_gid,
+                "section": section_gid
+            }
+        });
+
+        let url = format!("{}/tasks/{}/addProject", self.client.base_url, task_gid);
+
+        let request = self
+            .client
+            .http_client
+            .post(&url)
+            .header(
+                "Authorization",
+                format!("Bearer {}", self.client.access_token),
+            )
+            .json(&body);
+        Client::ensure_success(self.client.send(request).await?).await?;
+
+        Ok(())
+    }
+
+    /// Add a task to a project, optionally in one of its sections.
+    ///
+    pub async fn add_project_to_task(
+        &mut self,
+        task_gid: &str,
+        project_gid: &str,
+        section_gid: Option<&str>,
+    ) -> Result<()> {
+        debug!(
+            "Adding task GID {} to project GID {} (section {:?})...",
+            task_gid, project_gid, section_gid
+        );
+        let mut data = serde_json::json!({ "project": project_gid });
+        if let Some(section) = section_gid {
+            data["section"] = serde_json::Value::String(section.to_string());
+        }
+        self.post_task_action(task_gid, "addProject", data).await
+    }
+
+    /// Remove a task from a project.
+    ///
+    pub async fn remove_project_from_task(
+        &mut self,
+        task_gid: &str,
+        project_gid: &str,
+    ) -> Result<()> {
+        debug!(
+            "Removing task GID {} from project GID {}...",
+            task_gid, project_gid
+        );
+        self.post_task_action(
+            task_gid,
+            "removeProject",
+            serde_json::json!({ "project": project_gid }),
+        )
+        .await
+    }
+
+    /// Delete a task.
+    ///
+    pub async fn delete_task(&mut self, task_gid: &str) -> Result<()> {
+        debug!("Deleting task GID {}...", task_gid);
+
+        model!(TaskModel "tasks" { name: String, completed: bool });
+
+        let response = self
+            .client
+            .call_with_body::<TaskModel>(reqwest::Method::DELETE, Some(task_gid), None, None)
+            .await?;
+        Client::ensure_success(response).await?;
+
+        Ok(())
+    }
+
+    /// Run the given actions through the Batch API, sending up to ten per
+    /// request. Returns one result per action, in the order given.
+    ///
+    pub async fn batch(&mut self, actions: Vec<BatchAction>) -> Result<Vec<BatchResult>> {
+        debug!("Sending {} actions through the Batch API...", actions.len());
+
+        #[derive(serde::Deserialize)]
+        struct ResultModel {
+            status_code: u16,
+            #[serde(default)]
+            body: serde_json::Value,
+        }
+
+        let mut results = Vec::with_capacity(actions.len());
+        for chunk in actions.chunks(batch::BATCH_LIMIT) {
+            let body = serde_json::json!({ "data": { "actions": chunk } });
+            let request = self
+                .client
+                .http_client
+                .post(format!("{}/batch", &self.client.base_url))
+                .bearer_auth(&self.client.access_token)
+                .json(&body);
+            let response = Client::ensure_success(self.client.send(request).await?).await?;
+            let model: Wrapper<Vec<ResultModel>> = response.json().await?;
+            if model.data.len() != chunk.len() {
+                anyhow::bail!(
+                    "Batch API returned {} results for {} actions",
+                    model.data.len(),
+                    chunk.len()
+                );
+            }
+            results.extend(
+                model
+                    .data
+                    .into_iter()
+                    .zip(chunk)
+                    .map(|(result, action)| BatchResult {
+                        relative_path: action.relative_path().to_string(),
+                        status: result.status_code,
+                        body: result.body,
+                    }),
+            );
+        }
+        Ok(results)
+    }
+
+    /// Returns the events on a resource since the given sync token. Without a
+    /// token, or when the token has expired, Asana returns a fresh token and no
+    /// events, which is reported by setting `reset` on the batch.
+    ///
+    pub async fn events(&mut self, resource_gid: &str, sync: Option<&str>) -> Result<EventBatch> {
+        debug!(
+            "Requesting events for resource GID {} (sync token: {:?})...",
+            resource_gid, sync
+        );
+
+        // Events are not resources themselves and carry no gid, so the model macro doesn't fit
+        #[derive(serde::Deserialize)]
+        struct CompactModel {
+            gid: String,
+            resource_type: String,
+        }
+        #[derive(serde::Deserialize)]
+        struct EventModel {
+            action: String,
+            resource: CompactModel,
+            #[serde(default)]
+            parent: Option<CompactModel>,
+        }
+        #[derive(serde::Deserialize)]
+        struct EventsWrapper {
+            #[serde(default)]
+            data: Vec<EventModel>,
+            sync: String,
+            #[serde(default)]
+            has_more: bool,
+        }
+
+        let mut params = vec![("resource", resource_gid)];
+        if let Some(sync) = sync {
+            params.push(("sync", sync));
+        }
+        let request = self
+            .client
+            .http_client
+            .get(format!("{}/events", &self.client.base_url))
+            .bearer_auth(&self.client.access_token)
+            .query(&params);
+        let response = self.client.send(request).await?;
+
+        // 412 Precondition Failed carries a new sync token to start from
+        if response.status() == reqwest::StatusCode::PRECONDITION_FAILED {
+            let wrapper: EventsWrapper = response.json().await?;
+            return Ok(EventBatch {
+                events: vec![],
+                sync: wrapper.sync,
+                has_more: false,
+                reset: true,
+            });
+        }
+        let response = Client::ensure_success(response).await?;
+        let wrapper: EventsWrapper = response.json().await?;
+
+        debug!(
+            "Retrieved {} events for resource GID {}",
+            wrapper.data.len(),
+            resource_gid
+        );
+
+        Ok(EventBatch {
+            events: wrapper
+                .data
+                .into_iter()
+                .map(|e| ResourceEvent {
+                    action: e.action,
+                    resource_gid: e.resource.gid,
+                    resource_type: e.resource.resource_type,
+                    parent_gid: e.parent.as_ref().map(|p| p.gid.clone()),
+                    parent_type: e.parent.map(|p| p.resource_type),
+                })
+                .collect(),
+            sync: wrapper.sync,
+            has_more: wrapper.has_more,
+            reset: false,
+        })
+    }
+}
+
+/// Return a path in the directory for the file name that doesn't exist yet,
+/// numbering the name like "log (1).txt" when needed.
+///
+fn unique_file_path(directory: &Path, name: &str) -> PathBuf {
+    // Never let a remote name escape the directory
+    let name = Path::new(name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "attachment".to_string());
+    let candidate = directory.join(&name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{}", extension)),
+        _ => (name.as_str(), String::new()),
+    };
+    (1..)
+        .map(|n| directory.join(format!("{} ({}){}", stem, n, extension)))
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+    use httpmock::MockServer;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn me_success() -> Result<()> {
+        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001")
+            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
+        let user: User = Faker.fake();
+        let workspaces: [Workspace; 2] = [Faker.fake(), Faker.fake()];
+
+        let server = MockServer::start();
+        let mock = server.mock_async(|when, then| {
+            when.method("GET")
+                .path("/users/me")
+                .header("Authorization", format!("Bearer {}", &token));
+            then.status(200).json_body(json!({
+                "data": {
+                    "gid": user.gid,
+                    "name": user.name,
+                    "email": user.email,
+                    "resource_type": "user",
+                    "workspaces": [
+                        { "gid": workspaces[0].gid, "resource_type": "workspace", "name": workspaces[0].name },
+                        { "gid": workspaces[1].gid, "resource_type": "workspace", "name": workspaces[1].name },
+                    ]
+                }
+            }));
+        }).await;
+
+        // Wait a moment for server to be ready
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        let mut asana = Asana {
+            client: Client::new(&token.to_string(), &server.base_url()),
+        };
+        asana.me().await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn me_unauthorized() {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/users/me");
+                then.status(401);
+            })
+            .await;
+
+        let mut asana = Asana {
+            client: Client::new("", &server.base_url()),
+        };
+        assert!(asana.me().await.is_err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn me_retries_server_errors() {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/users/me");
+                then.status(503);
+            })
+            .await;
+
+        let mut client = Client::new("", &server.base_url());
+        client.retry_policy = client::RetryPolicy {
+            max_retries: 2,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        };
+        let reported = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let reported_clone = reported.clone();
+        client.set_retry_callback(std::sync::Arc::new(move |status| {
+            reported_clone
+                .lock()
+                .unwrap()
+                .push(status.map(|s: RetryStatus| s.attempt));
+        }));
+        let mut asana = Asana { client };
+        assert!(asana.me().await.is_err());
+        mock.assert_hits_async(3).await;
+        assert_eq!(*reported.lock().unwrap(), vec![Some(1), Some(2), None]);
+    }
+
+    #[tokio::test]
+    async fn retry_status_is_cleared_when_a_retry_fails_to_connect() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Answer the first request with a 503, then stop listening so the
+        // retry fails at the transport level
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(
+                    b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+
+        let mut client = Client::new("", &base_url);
+        client.retry_policy = client::RetryPolicy {
+            max_retries: 2,
+            base_delay: std::time::Duration::from_millis(50),
+            max_delay: std::time::Duration::from_millis(50),
+        };
+        let reported = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let reported_clone = reported.clone();
+        client.set_retry_callback(std::sync::Arc::new(move |status| {
+            reported_clone
+                .lock()
+                .unwrap()
+                .push(status.map(|s: RetryStatus| s.attempt));
+        }));
+        let mut asana = Asana { client };
+        assert!(asana.me().await.is_err());
+        server.await.unwrap();
+        assert_eq!(reported.lock().unwrap().last(), Some(&None));
+    }
+
+    #[tokio::test]
+    async fn projects_success() -> Result<()> {
+        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")
+            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
+        let workspace: Workspace = Faker.fake();
+        let projects: [Project; 2] = Faker.fake();
+
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/projects/")
+                    .header("Authorization", format!("Bearer {}", &token))
+                    .query_param("workspace", &workspace.gid);
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "gid": projects[0].gid,
+                            "resource_type": "task",
+                            "name": projects[0].name,
+                        },
+                        {
+                            "gid": projects[1].gid,
+                            "resource_type": "task",
+                            "name": projects[1].name,
+                        }
+                    ]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana {
+            client: Client::new(&token.to_string(), &server.base_url()),
+        };
+        asana.projects(&workspace.gid, false).await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tasks_success() -> Result<()> {
+        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002")
+            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
+        let project: Project = Faker.fake();
+        let tasks: [Task; 2] = Faker.fake();
+
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .header("Authorization", format!("Bearer {}", &token))
+                    .query_param("project", &project.gid);
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "gid": tasks[0].gid,
+                            "resource_type": "task",
+                            "name": tasks[0].name,
+                            "completed": tasks[0].completed,
+                        },
+                        {
+                            "gid": tasks[1].gid,
+                            "resource_type": "task",
+                            "name": tasks[1].name,
+                            "completed": tasks[1].completed,
+                        }
+                    ]
+                }));
+            })
+            .await;
+
+        // Wait a moment for server to be ready
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        let mut asana = Asana {
+            client: Client::new(&token.to_string(), &server.base_url()),
+        };
+        asana.tasks_page(&project.gid, false, None).await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tasks_page_returns_next_offset() -> Result<()> {
+        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002")
+            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
+        let project: Project = Faker.fake();
+        let tasks: Vec<serde_json::Value> = (0..100)
+            .map(|i| json!({ "gid": i.to_string(), "resource_type": "task", "name": "Task", "completed": false }))
+            .collect();
+
+        let server = MockServer::start();
+        let first = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .query_param("project", &project.gid)
+                    .matches(|req| {
+                        !req.query_params
+                            .iter()
+                            .flatten()
+                            .any(|(key, _)| key == "offset")
+                    });
+                then.status(200).json_body(json!({
+                    "data": tasks,
+                    "next_page": { "offset": "page2", "path": "/tasks?offset=page2", "uri": "" }
+                }));
+            })
+            .await;
+        let second = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .query_param("project", &project.gid)
+                    .query_param("offset", "page2");
+                then.status(200).json_body(json!({
+                    "data": [{ "gid": "last", "resource_type": "task", "name": "Last", "completed": true }],
+                    "next_page": null
+                }));
+            })
+            .await;
+
+        let mut asana = Asana {
+            client: Client::new(&token.to_string(), &server.base_url()),
+        };
+        let (page, next) = asana.tasks_page(&project.gid, true, None).await?;
+        assert_eq!(page.len(), 100);
+        assert_eq!(next.as_deref(), Some("page2"));
+        let (page, next) = asana
+            .tasks_page(&project.gid, true, next.as_deref())
+            .await?;
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].gid, "last");
+        assert!(next.is_none());
+        first.assert_async().await;
+        second.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_task_uses_base_url() -> Result<()> {
+        let task: Task = Faker.fake();
+
+        let server = MockServer::start();
+        let task_mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path(format!("/tasks/{}", task.gid));
+                then.status(200).json_body(json!({
+                    "data": {
+                        "gid": task.gid,
+                        "resource_type": "task",
+                        "name": task.name,
+                        "completed": task.completed,
+                    }
+                }));
+            })
+            .await;
+        let stories_mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path(format!("/tasks/{}/stories", task.gid));
+                then.status(200).json_body(json!({ "data": [] }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let fetched = asana.get_task(&task.gid).await?;
+        assert_eq!(fetched.gid, task.gid);
+        task_mock.assert_async().await;
+        stories_mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_project_task_uses_project_membership() -> Result<()> {
+        let server = MockServer::start();
+        let task_mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tasks/1");
+                then.status(200).json_body(json!({
+                    "data": {
+                        "gid": "1",
+                        "resource_type": "task",
+                        "name": "Task",
+                        "completed": true,
+                        "memberships": [
+                            { "project": { "gid": "other" }, "section": { "gid": "s0", "name": "Elsewhere" } },
+                            { "project": { "gid": "p" }, "section": { "gid": "s1", "name": "Doing" } }
+                        ]
+                    }
+                }));
+            })
+            .await;
+        let subtask_mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tasks/2");
+                then.status(200).json_body(json!({
+                    "data": { "gid": "2", "resource_type": "task", "name": "Subtask", "completed": false, "memberships": [] }
+                }));
+            })
+            .await;
+        let stories_mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tasks/1/stories");
+                then.status(200).json_body(json!({ "data": [] }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let task = asana
+            .get_project_task("p", "1")
+            .await?
+            .expect("task in project");
+        assert!(task.completed);
+        assert_eq!(task.section.map(|s| s.gid).as_deref(), Some("s1"));
+        assert!(asana.get_project_task("p", "2").await?.is_none());
+        task_mock.assert_async().await;
+        subtask_mock.assert_async().await;
+        stories_mock.assert_hits_async(0).await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_without_token_resets() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/events")
+                    .query_param("resource", "123");
+                then.status(412).json_body(json!({
+                    "errors": [{ "message": "Sync token invalid or too old." }],
+                    "sync": "fresh"
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let batch = asana.events("123", None).await?;
+        mock.assert_async().await;
+        assert!(batch.reset);
+        assert!(batch.events.is_empty());
+        assert_eq!(batch.sync, "fresh");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_with_token() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/events")
+                    .query_param("resource", "123")
+                    .query_param("sync", "abc");
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "action": "changed",
+                            "resource": { "gid": "t1", "resource_type": "task" },
+                            "parent": null
+                        },
+                        {
+                            "action": "added",
+                            "resource": { "gid": "s1", "resource_type": "story" },
+                            "parent": { "gid": "t1", "resource_type": "task" }
+                        }
+                    ],
+                    "sync": "def",
+                    "has_more": false
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let batch = asana.events("123", Some("abc")).await?;
+        mock.assert_async().await;
+        assert!(!batch.reset);
+        assert_eq!(batch.sync, "def");
+        assert_eq!(batch.events.len(), 2);
+        assert_eq!(batch.events[0].resource_gid, "t1");
+        assert_eq!(batch.events[0].parent_gid, None);
+        assert_eq!(batch.events[1].action, "added");
+        assert_eq!(batch.events[1].parent_type.as_deref(), Some("task"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_chunks_actions() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/batch");
+                then.status(200).json_body(json!({
+                    "data": (0..10)
+                        .map(|_| json!({ "status_code": 200, "body": { "data": {} } }))
+                        .collect::<Vec<_>>()
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let actions: Vec<BatchAction> = (0..15)
+            .map(|i| BatchAction::update_task(&i.to_string(), json!({ "completed": true })))
+            .collect();
+        let result = asana.batch(actions).await;
+        // The second request has 5 actions but the mock answers with 10 results
+        assert!(result.is_err());
+        mock.assert_hits_async(2).await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_reports_each_result() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/batch").json_body(json!({
+                    "data": { "actions": [
+                        { "relative_path": "/sections/9/addTask", "method": "post", "data": { "task": "1" } },
+                        { "relative_path": "/sections/9/addTask", "method": "post", "data": { "task": "2" } }
+                    ] }
+                }));
+                then.status(200).json_body(json!({
+                    "data": [
+                        { "status_code": 200, "body": { "data": {} } },
+                        { "status_code": 404, "body": { "errors": [{ "message": "Not found" }] } }
+                    ]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let results = asana
+            .batch(vec![
+                BatchAction::add_task_to_section("1", "9"),
+                BatchAction::add_task_to_section("2", "9"),
+            ])
+            .await?;
+        mock.assert_async().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].clone().into_result().is_ok());
+        assert!(matches!(
+            results[1].clone().into_result(),
+            Err(AsanaError::SectionNotFound { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_subtasks_follows_pages() -> Result<()> {
+        let server = MockServer::start();
+        let first = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/123/subtasks")
+                    .query_param("limit", "100")
+                    .matches(|req| {
+                        !req.query_params
+                            .iter()
+                            .flatten()
+                            .any(|(key, _)| key == "offset")
+                    });
+                then.status(200).json_body(json!({
+                    "data": [{ "gid": "1", "resource_type": "task", "name": "First", "completed": false }],
+                    "next_page": { "offset": "page2", "path": "", "uri": "" }
+                }));
+            })
+            .await;
+        let second = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/123/subtasks")
+                    .query_param("offset", "page2");
+                then.status(200).json_body(json!({
+                    "data": [{ "gid": "2", "resource_type": "task", "name": "Second", "completed": true }],
+                    "next_page": null
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let subtasks = asana.get_subtasks("123").await?;
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(
+            subtasks.iter().map(|t| t.gid.as_str()).collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_subtasks_success() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tasks/123/subtasks");
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "gid": "1",
+                            "resource_type": "task",
+                            "name": "First",
+                            "completed": true,
+                            "assignee": { "gid": "9", "resource_type": "user", "name": "Ada" },
+                            "num_subtasks": 2
+                        },
+                        {
+                            "gid": "2",
+                            "resource_type": "task",
+                            "name": "Second",
+                            "completed": false,
+                            "assignee": null
+                        }
+                    ]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let subtasks = asana.get_subtasks("123").await?;
+        mock.assert_async().await;
+        assert_eq!(subtasks.len(), 2);
+        assert!(subtasks[0].completed);
+        assert_eq!(subtasks[0].num_subtasks, 2);
+        assert_eq!(
+            subtasks[0].assignee.as_ref().map(|u| u.name.as_str()),
+            Some("Ada")
+        );
+        assert!(subtasks[1].assignee.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_subtask_success() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/subtasks")
+                    .json_body(json!({ "data": { "name": "Write tests" } }));
+                then.status(201).json_body(json!({
+                    "data": {
+                        "gid": "456",
+                        "resource_type": "task",
+                        "name": "Write tests",
+                        "completed": false
+                    }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let subtask = asana.create_subtask("123", "Write tests").await?;
+        mock.assert_async().await;
+        assert_eq!(subtask.gid, "456");
+        assert_eq!(subtask.name, "Write tests");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tasks_page_parses_dependencies() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .query_param("project", "1");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "2",
+                        "resource_type": "task",
+                        "name": "Ship",
+                        "completed": false,
+                        "dependencies": [
+                            { "gid": "3", "resource_type": "task", "completed": true },
+                            { "gid": "4", "resource_type": "task", "completed": false }
+                        ]
+                    }],
+                    "next_page": null
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let (tasks, _) = asana.tasks_page("1", true, None).await?;
+        mock.assert_async().await;
+        assert_eq!(tasks[0].dependencies.len(), 2);
+        assert!(tasks[0].is_blocked());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tasks_page_parses_tags() -> Result<()> {
+        let server = MockServer::start();
+        server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .query_param("project", "1");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "2",
+                        "resource_type": "task",
+                        "name": "Crash on save",
+                        "completed": false,
+                        "tags": [{ "gid": "7", "resource_type": "tag", "name": "bug" }]
+                    }],
+                    "next_page": null
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let (tasks, _) = asana.tasks_page("1", true, None).await?;
+        assert_eq!(
+            tasks[0].tags,
+            vec![Tag {
+                gid: String::from("7"),
+                name: String::from("bug")
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_tags_success() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tags/")
+                    .query_param("workspace", "1");
+                then.status(200).json_body(json!({
+                    "data": [
+                        { "gid": "7", "resource_type": "tag", "name": "bug" },
+                        { "gid": "8", "resource_type": "tag", "name": "needs-repro" }
+                    ],
+                    "next_page": null
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let tags = asana.get_tags("1").await?;
+        mock.assert_async().await;
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1].name, "needs-repro");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_tag_posts_workspace() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tags")
+                    .json_body(json!({ "data": { "name": "bug", "workspace": "1" } }));
+                then.status(201).json_body(json!({
+                    "data": { "gid": "7", "resource_type": "tag", "name": "bug" }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let tag = asana.create_tag("1", "bug").await?;
+        mock.assert_async().await;
+        assert_eq!(tag.gid, "7");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_and_remove_tag_post_tag_gid() -> Result<()> {
+        let server = MockServer::start();
+        let add = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/addTag")
+                    .json_body(json!({ "data": { "tag": "7" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+        let remove = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/removeTag")
+                    .json_body(json!({ "data": { "tag": "7" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.add_tag("123", "7").await?;
+        asana.remove_tag("123", "7").await?;
+        add.assert_async().await;
+        remove.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_section_posts_name() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/projects/1/sections")
+                    .json_body(json!({ "data": { "name": "Review" } }));
+                then.status(201).json_body(json!({
+                    "data": { "gid": "5", "resource_type": "section", "name": "Review" }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let section = asana.create_section("1", "Review").await?;
+        mock.assert_async().await;
+        assert_eq!(section.gid, "5");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rename_and_delete_section() -> Result<()> {
+        let server = MockServer::start();
+        let rename = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/sections/5")
+                    .json_body(json!({ "data": { "name": "Done" } }));
+                then.status(200).json_body(json!({
+                    "data": { "gid": "5", "resource_type": "section", "name": "Done" }
+                }));
+            })
+            .await;
+        let delete = server
+            .mock_async(|when, then| {
+                when.method("DELETE").path("/sections/5");
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.rename_section("5", "Done").await?;
+        asana.delete_section("5").await?;
+        rename.assert_async().await;
+        delete.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_section_posts_neighbour() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/projects/1/sections/insert")
+                    .json_body(json!({ "data": { "section": "5", "before_section": "4" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.insert_section("1", "5", Some("4"), None).await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn projects_reads_archived_color_and_notes() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/projects/")
+                    .query_param("workspace", "1")
+                    .query_param("archived", "false");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "2",
+                        "resource_type": "project",
+                        "name": "Sprint 12",
+                        "archived": false,
+                        "color": "dark-green",
+                        "notes": "Ship it",
+                    }]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let projects = asana.projects("1", false).await?;
+        mock.assert_async().await;
+        assert!(!projects[0].archived);
+        assert_eq!(projects[0].color, "dark-green");
+        assert_eq!(projects[0].notes, "Ship it");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn team_projects_lists_unarchived_projects_of_team() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/teams/7/projects/")
+                    .query_param("archived", "false");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "2",
+                        "resource_type": "project",
+                        "name": "Roadmap",
+                        "color": "light-blue",
+                    }]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let projects = asana.team_projects("7", false).await?;
+        mock.assert_async().await;
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Roadmap");
+        assert_eq!(projects[0].color, "light-blue");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn portfolio_projects_skip_nested_portfolios() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/portfolios/9/items");
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "gid": "2",
+                            "resource_type": "project",
+                            "name": "Launch",
+                            "current_status_update": {
+                                "gid": "5",
+                                "resource_type": "status_update",
+                                "status_type": "at_risk",
+                                "title": "Week 12",
+                                "created_at": "2024-03-22T09:00:00.000Z",
+                                "created_by": {
+                                    "gid": "3",
+                                    "resource_type": "user",
+                                    "name": "Sam",
+                                },
+                            },
+                        },
+                        {
+                            "gid": "4",
+                            "resource_type": "project",
+                            "name": "Hiring",
+                            "current_status_update": null,
+                        },
+                        {
+                            "gid": "6",
+                            "resource_type": "portfolio",
+                            "name": "Nested",
+                        },
+                    ]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let projects = asana.portfolio_projects("9").await?;
+        mock.assert_async().await;
+        assert_eq!(projects.len(), 2);
+        let status = projects[0].status_update.as_ref().unwrap();
+        assert_eq!(status.status_type, "at_risk");
+        assert_eq!(status.title, "Week 12");
+        assert_eq!(status.created_by.as_ref().unwrap().name, "Sam");
+        assert_eq!(projects[1].project.name, "Hiring");
+        assert!(projects[1].status_update.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_status_update_posts_parent_and_status() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/status_updates/")
+                    .json_body(json!({
+                        "data": {
+                            "parent": "2",
+                            "status_type": "on_track",
+                            "title": "Week 13",
+                            "text": "All good",
+                        }
+                    }));
+                then.status(201).json_body(json!({
+                    "data": {
+                        "gid": "7",
+                        "resource_type": "status_update",
+                        "status_type": "on_track",
+                        "title": "Week 13",
+                        "text": "All good",
+                    }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let update = asana
+            .create_status_update("2", "on_track", "Week 13", "All good")
+            .await?;
+        mock.assert_async().await;
+        assert_eq!(update.gid, "7");
+        assert_eq!(update.title, "Week 13");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_tasks_sends_filters_and_pages_by_creation_time() -> Result<()> {
+        let server = MockServer::start();
+        let tasks: Vec<serde_json::Value> = (0..SEARCH_PAGE_SIZE)
+            .map(|i| {
+                json!({
+                    "gid": i.to_string(),
+                    "resource_type": "task",
+                    "name": format!("Bug {}", i),
+                    "completed": false,
+                    "created_at": format!("2024-03-{:02}T09:00:00.000Z", 28 - i % 28),
+                    "due_on": "2024-04-01",
+                    "assignee": { "gid": "3", "name": "Sam" },
+                    "memberships": [{
+                        "project": { "gid": "8", "name": "Web" },
+                        "section": { "gid": "9", "name": "Triage" },
+                    }],
+                })
+            })
+            .collect();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/workspaces/1/tasks/search")
+                    .query_param("text", "bug")
+                    .query_param("projects.any", "8")
+                    .query_param("completed", "false")
+                    .query_param("created_at.before", "2024-04-01T00:00:00.001Z");
+                then.status(200).json_body(json!({ "data": tasks }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let search = TaskSearch {
+            text: Some("bug".to_string()),
+            project_gid: Some("8".to_string()),
+            completed: Some(false),
+            ..TaskSearch::default()
+        };
+        let (tasks, next) = asana
+            .search_tasks("1", &search, Some("2024-04-01T00:00:00.000Z"))
+            .await?;
+        mock.assert_async().await;
+        assert_eq!(tasks.len(), SEARCH_PAGE_SIZE);
+        assert_eq!(tasks[0].assignee.as_ref().unwrap().name, "Sam");
+        assert_eq!(tasks[0].memberships[0].project_name, "Web");
+        assert_eq!(tasks[0].section.as_ref().unwrap().name, "Triage");
+        assert_eq!(tasks[0].due_on.as_deref(), Some("2024-04-01"));
+        assert_eq!(next, tasks.last().unwrap().created_at);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn typeahead_users_queries_workspace_typeahead() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/workspaces/1/typeahead")
+                    .query_param("resource_type", "user")
+                    .query_param("query", "ali")
+                    .query_param("count", "20");
+                then.status(200).json_body(json!({
+                    "data": [
+                        {
+                            "gid": "2",
+                            "resource_type": "user",
+                            "name": "Alice",
+                            "email": "alice@example.com",
+                        },
+                        {
+                            "gid": "3",
+                            "resource_type": "user",
+                            "name": "Alison",
+                        },
+                    ]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let users = asana.typeahead_users("1", "ali").await?;
+        mock.assert_async().await;
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].email, "alice@example.com");
+        assert_eq!(users[1].name, "Alison");
+        assert_eq!(users[1].email, "");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_task_sends_start_date_and_due_time() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/tasks/").json_body(json!({
+                    "data": {
+                        "name": "Rotate on-call",
+                        "projects": ["1"],
+                        "start_on": "2024-04-29",
+                        "due_at": "2024-05-01T16:00:00.000Z",
+                    }
+                }));
+                then.status(201).json_body(json!({
+                    "data": {
+                        "gid": "2",
+                        "resource_type": "task",
+                        "name": "Rotate on-call",
+                        "completed": false,
+                    }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let dates = TaskDates {
+            start_on: Some("2024-04-29".to_string()),
+            due_on: Some("2024-05-01".to_string()),
+            due_at: Some("2024-05-01T16:00:00.000Z".to_string()),
+        };
+        let task = asana
+            .create_task(
+                "1",
+                "Rotate on-call",
+                None,
+                None,
+                &dates,
+                None,
+                &[],
+                &std::collections::HashMap::new(),
+            )
+            .await?;
+        mock.assert_async().await;
+        assert_eq!(task.gid, "2");
+
+        // Empty dates clear the field
+        let mut data = json!({});
+        TaskDates {
+            start_on: Some(String::new()),
+            due_on: Some("2024-05-02".to_string()),
+            due_at: None,
+        }
+        .apply(&mut data);
+        assert_eq!(data, json!({ "start_on": null, "due_on": "2024-05-02" }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_task_sends_markdown_notes_as_rich_text() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/tasks/").json_body(json!({
+                    "data": {
+                        "name": "Write docs",
+                        "projects": ["1"],
+                        "html_notes": "<body>See <strong>setup</strong>\n\
+                                       <ul><li>install</li></ul></body>",
+                    }
+                }));
+                then.status(201).json_body(json!({
+                    "data": {
+                        "gid": "2",
+                        "resource_type": "task",
+                        "name": "Write docs",
+                        "completed": false,
+                    }
+                }));
+            })
+            .await;
 
-        let response = self
-            .client
-            .http_client
-            .post(&url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.client.access_token),
+        let mut asana = Asana::new("token", &server.base_url());
+        asana
+            .create_task(
+                "1",
+                "Write docs",
+                Some("See **setup**\n- install"),
+                None,
+                &TaskDates::default(),
+                None,
+                &[],
+                &std::collections::HashMap::new(),
             )
-            .json(&body)
-            .send()
             .await?;
+        mock.assert_async().await;
+        Ok(())
+    }
 
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response.text().await?;
-            error!("Failed to move task to section: {}", error_text);
-            anyhow::bail!("Failed to move task to section: {}", error_text);
-        }
+    #[tokio::test]
+    async fn create_project_posts_team_and_color() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST").path("/projects").json_body(json!({
+                    "data": {
+                        "name": "Sprint 13",
+                        "notes": "",
+                        "workspace": "1",
+                        "team": "7",
+                        "color": "light-blue",
+                    }
+                }));
+                then.status(201).json_body(json!({
+                    "data": {
+                        "gid": "3",
+                        "resource_type": "project",
+                        "name": "Sprint 13",
+                        "color": "light-blue",
+                    }
+                }));
+            })
+            .await;
 
+        let mut asana = Asana::new("token", &server.base_url());
+        let project = asana
+            .create_project("1", Some("7"), "Sprint 13", "", Some("light-blue"))
+            .await?;
+        mock.assert_async().await;
+        assert_eq!(project.gid, "3");
+        assert!(!project.archived);
         Ok(())
     }
 
-    /// Delete a task.
-    ///
-    pub async fn delete_task(&mut self, task_gid: &str) -> Result<()> {
-        debug!("Deleting task GID {}...", task_gid);
+    #[tokio::test]
+    async fn set_project_archived_puts_flag() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/projects/3")
+                    .json_body(json!({ "data": { "archived": true } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
 
-        model!(TaskModel "tasks" { name: String, completed: bool });
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.set_project_archived("3", true).await?;
+        mock.assert_async().await;
+        Ok(())
+    }
 
-        self.client
-            .call_with_body::<TaskModel>(reqwest::Method::DELETE, Some(task_gid), None, None)
-            .await?;
+    #[tokio::test]
+    async fn add_and_remove_project_post_to_task() -> Result<()> {
+        let server = MockServer::start();
+        let add = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/addProject")
+                    .json_body(json!({ "data": { "project": "2", "section": "5" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+        let remove = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/removeProject")
+                    .json_body(json!({ "data": { "project": "1" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
 
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.add_project_to_task("123", "2", Some("5")).await?;
+        asana.remove_project_from_task("123", "1").await?;
+        add.assert_async().await;
+        remove.assert_async().await;
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use fake::{Fake, Faker};
-    use httpmock::MockServer;
-    use serde_json::json;
-    use uuid::Uuid;
+    #[test]
+    fn parse_memberships_reads_project_and_section() {
+        let memberships = Asana::parse_memberships(Some(&json!([
+            { "project": { "gid": "1", "name": "Roadmap" }, "section": { "gid": "5", "name": "Now" } },
+            { "project": { "gid": "2", "name": "Sprint" } },
+            { "section": { "gid": "6", "name": "Orphan" } },
+        ])));
+        assert_eq!(memberships.len(), 2);
+        assert_eq!(memberships[0].project_name, "Roadmap");
+        assert_eq!(memberships[0].section.as_ref().unwrap().gid, "5");
+        assert_eq!(memberships[1].project_gid, "2");
+        assert!(memberships[1].section.is_none());
+    }
 
     #[tokio::test]
-    async fn me_success() -> Result<()> {
-        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001")
-            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
-        let user: User = Faker.fake();
-        let workspaces: [Workspace; 2] = [Faker.fake(), Faker.fake()];
+    async fn add_and_remove_followers_post_gids() -> Result<()> {
+        let server = MockServer::start();
+        let add = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/addFollowers")
+                    .json_body(json!({ "data": { "followers": ["7"] } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+        let remove = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/removeFollowers")
+                    .json_body(json!({ "data": { "followers": ["8"] } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.add_followers("123", &[String::from("7")]).await?;
+        asana.remove_followers("123", &[String::from("8")]).await?;
+        add.assert_async().await;
+        remove.assert_async().await;
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn user_task_list_resolves_for_workspace() -> Result<()> {
         let server = MockServer::start();
-        let mock = server.mock_async(|when, then| {
-            when.method("GET")
-                .path("/users/me")
-                .header("Authorization", format!("Bearer {}", &token));
-            then.status(200).json_body(json!({
-                "data": {
-                    "gid": user.gid,
-                    "name": user.name,
-                    "email": user.email,
-                    "resource_type": "user",
-                    "workspaces": [
-                        { "gid": workspaces[0].gid, "resource_type": "workspace", "name": workspaces[0].name },
-                        { "gid": workspaces[1].gid, "resource_type": "workspace", "name": workspaces[1].name },
-                    ]
-                }
-            }));
-        }).await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/users/me/user_task_list")
+                    .query_param("workspace", "1");
+                then.status(200).json_body(json!({
+                    "data": { "gid": "77", "resource_type": "user_task_list", "name": "My Tasks" }
+                }));
+            })
+            .await;
 
-        // Wait a moment for server to be ready
-        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let mut asana = Asana::new("token", &server.base_url());
+        let list = asana.user_task_list("1").await?;
+        mock.assert_async().await;
+        assert_eq!(list.gid, "77");
+        assert_eq!(list.name, "My Tasks");
+        Ok(())
+    }
 
-        let mut asana = Asana {
-            client: Client::new(&token.to_string(), &server.base_url()),
-        };
-        asana.me().await?;
+    #[tokio::test]
+    async fn user_task_list_tasks_are_grouped_by_assignee_section() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tasks/")
+                    .query_param("user_task_list", "77");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "1",
+                        "resource_type": "task",
+                        "name": "Reply to Sam",
+                        "completed": false,
+                        "assignee_section": { "gid": "5", "name": "Today" },
+                    }]
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let (tasks, next) = asana.user_task_list_tasks_page("77", false, None).await?;
         mock.assert_async().await;
+        assert_eq!(next, None);
+        assert_eq!(
+            tasks[0].section.as_ref().map(|s| s.name.as_str()),
+            Some("Today")
+        );
         Ok(())
     }
 
     #[tokio::test]
-    async fn me_unauthorized() {
+    async fn get_task_stories_reads_pinned_and_liked() -> Result<()> {
         let server = MockServer::start();
         let mock = server
             .mock_async(|when, then| {
-                when.method("GET").path("/users/me");
-                then.status(401);
+                when.method("GET").path("/tasks/123/stories");
+                then.status(200).json_body(json!({
+                    "data": [{
+                        "gid": "9",
+                        "resource_type": "story",
+                        "text": "Typo fixed",
+                        "resource_subtype": "comment_added",
+                        "is_pinned": true,
+                        "liked": true,
+                    }]
+                }));
             })
             .await;
 
-        let mut asana = Asana {
-            client: Client::new("", &server.base_url()),
-        };
-        assert!(asana.me().await.is_err());
+        let mut asana = Asana::new("token", &server.base_url());
+        let stories = asana.get_task_stories("123").await?;
         mock.assert_async().await;
+        assert!(stories[0].is_pinned);
+        assert!(stories[0].liked);
+        Ok(())
     }
 
     #[tokio::test]
-    async fn projects_success() -> Result<()> {
-        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")
-            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
-        let workspace: Workspace = Faker.fake();
-        let projects: [Project; 2] = Faker.fake();
+    async fn edit_pin_and_delete_story() -> Result<()> {
+        let server = MockServer::start();
+        let edit = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/stories/9")
+                    .json_body(json!({ "data": { "text": "Fixed" } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+        let pin = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/stories/9")
+                    .json_body(json!({ "data": { "is_pinned": true } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+        let delete = server
+            .mock_async(|when, then| {
+                when.method("DELETE").path("/stories/9");
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.update_story("9", "Fixed").await?;
+        asana.set_story_pinned("9", true).await?;
+        asana.delete_story("9").await?;
+        edit.assert_async().await;
+        pin.assert_async().await;
+        delete.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn like_and_unlike_story() -> Result<()> {
+        let server = MockServer::start();
+        let like = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/stories/9")
+                    .json_body(json!({ "data": { "liked": true } }));
+                then.status(200)
+                    .json_body(json!({ "data": { "gid": "9", "liked": true } }));
+            })
+            .await;
+        let unlike = server
+            .mock_async(|when, then| {
+                when.method("PUT")
+                    .path("/stories/9")
+                    .json_body(json!({ "data": { "liked": false } }));
+                then.status(200)
+                    .json_body(json!({ "data": { "gid": "9", "liked": false } }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana.set_story_liked("9", true).await?;
+        asana.set_story_liked("9", false).await?;
+        like.assert_async().await;
+        unlike.assert_async().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_dependencies_posts_gids() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/tasks/123/addDependencies")
+                    .json_body(json!({ "data": { "dependencies": ["456"] } }));
+                then.status(200).json_body(json!({ "data": {} }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        asana
+            .add_dependencies("123", &[String::from("456")])
+            .await?;
+        mock.assert_async().await;
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn get_attachments_success() -> Result<()> {
         let server = MockServer::start();
         let mock = server
             .mock_async(|when, then| {
                 when.method("GET")
-                    .path("/projects/")
-                    .header("Authorization", format!("Bearer {}", &token))
-                    .query_param("workspace", &workspace.gid);
+                    .path("/attachments")
+                    .query_param("parent", "123");
                 then.status(200).json_body(json!({
                     "data": [
                         {
-                            "gid": projects[0].gid,
-                            "resource_type": "task",
-                            "name": projects[0].name,
+                            "gid": "1",
+                            "resource_type": "attachment",
+                            "name": "crash.log",
+                            "host": "asana",
+                            "size": 2048
                         },
                         {
-                            "gid": projects[1].gid,
-                            "resource_type": "task",
-                            "name": projects[1].name,
+                            "gid": "2",
+                            "resource_type": "attachment",
+                            "name": "Spec",
+                            "host": "gdrive"
                         }
                     ]
                 }));
             })
             .await;
 
-        let mut asana = Asana {
-            client: Client::new(&token.to_string(), &server.base_url()),
-        };
-        asana.projects(&workspace.gid).await?;
+        let mut asana = Asana::new("token", &server.base_url());
+        let attachments = asana.get_attachments("123").await?;
         mock.assert_async().await;
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].size, Some(2048));
+        assert_eq!(attachments[1].host, "gdrive");
         Ok(())
     }
 
     #[tokio::test]
-    async fn tasks_success() -> Result<()> {
-        let token: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002")
-            .map_err(|e| anyhow::anyhow!("Failed to parse test UUID: {}", e))?;
-        let project: Project = Faker.fake();
-        let tasks: [Task; 2] = Faker.fake();
+    async fn download_attachment_writes_unique_file() -> Result<()> {
+        let server = MockServer::start();
+        let download_url = server.url("/files/crash.log");
+        let metadata = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/attachments/1");
+                then.status(200).json_body(json!({
+                    "data": {
+                        "gid": "1",
+                        "resource_type": "attachment",
+                        "name": "crash.log",
+                        "host": "asana",
+                        "download_url": download_url
+                    }
+                }));
+            })
+            .await;
+        let file = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/files/crash.log");
+                then.status(200).body("panic at line 1");
+            })
+            .await;
+
+        let directory = std::env::temp_dir().join(format!("asana-tui-{}", rand::random::<u64>()));
+        let mut asana = Asana::new("token", &server.base_url());
+        let first = asana.download_attachment("1", &directory).await?;
+        let second = asana.download_attachment("1", &directory).await?;
+        metadata.assert_hits_async(2).await;
+        file.assert_hits_async(2).await;
+        assert_eq!(first, directory.join("crash.log"));
+        assert_eq!(second, directory.join("crash (1).log"));
+        assert_eq!(std::fs::read_to_string(&second)?, "panic at line 1");
+        std::fs::remove_dir_all(&directory)?;
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn download_attachment_rejects_external_files() -> Result<()> {
         let server = MockServer::start();
-        let mock = server
+        server
             .mock_async(|when, then| {
-                when.method("GET")
-                    .path("/tasks/")
-                    .header("Authorization", format!("Bearer {}", &token))
-                    .query_param("project", &project.gid);
+                when.method("GET").path("/attachments/2");
                 then.status(200).json_body(json!({
-                    "data": [
-                        {
-                            "gid": tasks[0].gid,
-                            "resource_type": "task",
-                            "name": tasks[0].name,
-                            "completed": tasks[0].completed,
-                        },
-                        {
-                            "gid": tasks[1].gid,
-                            "resource_type": "task",
-                            "name": tasks[1].name,
-                            "completed": tasks[1].completed,
-                        }
-                    ]
+                    "data": {
+                        "gid": "2",
+                        "resource_type": "attachment",
+                        "name": "Spec",
+                        "host": "gdrive",
+                        "download_url": null
+                    }
                 }));
             })
             .await;
 
-        // Wait a moment for server to be ready
-        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let mut asana = Asana::new("token", &server.base_url());
+        let result = asana.download_attachment("2", &std::env::temp_dir()).await;
+        assert!(result.unwrap_err().to_string().contains("gdrive"));
+        Ok(())
+    }
 
-        let mut asana = Asana {
-            client: Client::new(&token.to_string(), &server.base_url()),
-        };
-        asana.tasks(&project.gid, None, false).await?;
+    #[tokio::test]
+    async fn upload_attachment_posts_multipart() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("asana-tui-{}.txt", rand::random::<u64>()));
+        std::fs::write(&path, "steps to reproduce")?;
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+
+        let server = MockServer::start();
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("POST")
+                    .path("/attachments")
+                    .header_exists("content-type")
+                    .body_contains("steps to reproduce")
+                    .body_contains(&file_name)
+                    .body_contains("name=\"parent\"");
+                then.status(200).json_body(json!({
+                    "data": {
+                        "gid": "9",
+                        "resource_type": "attachment",
+                        "name": file_name,
+                        "host": "asana"
+                    }
+                }));
+            })
+            .await;
+
+        let mut asana = Asana::new("token", &server.base_url());
+        let attachment = asana.upload_attachment("123", &path).await?;
         mock.assert_async().await;
+        assert_eq!(attachment.gid, "9");
+        std::fs::remove_file(&path)?;
         Ok(())
     }
 }
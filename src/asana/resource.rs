@@ -38,6 +38,14 @@ pub struct Tag {
     pub name: String,
 }
 
+/// Defines team data structure.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct Team {
+    pub gid: String,
+    pub name: String,
+}
+
 /// Defines enum option for custom fields.
 ///
 #[derive(Clone, Debug, Dummy, PartialEq, Eq)]
@@ -68,6 +76,24 @@ pub struct CustomField {
     pub enabled: bool,                      // Whether the custom field is enabled on this task
 }
 
+/// Defines a compact reference to another task, such as a dependency.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct TaskCompact {
+    pub gid: String,
+    pub name: String,
+    pub completed: bool,
+}
+
+/// Defines a task's membership in a project, with its section there.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct Membership {
+    pub project_gid: String,
+    pub project_name: String,
+    pub section: Option<Section>,
+}
+
 /// Defines task data structure.
 ///
 #[derive(Clone, Debug, Dummy, PartialEq)]
@@ -76,9 +102,11 @@ pub struct Task {
     pub name: String,
     pub completed: bool,
     pub notes: Option<String>,
+    pub html_notes: Option<String>, // Rich text version of the notes
     pub assignee: Option<User>,
     pub due_date: Option<String>,
     pub due_on: Option<String>,
+    pub due_at: Option<String>, // UTC timestamp when the task is due at a time of day
     pub start_on: Option<String>,
     pub section: Option<Section>,
     pub tags: Vec<Tag>,
@@ -87,6 +115,57 @@ pub struct Task {
     pub modified_at: Option<String>,
     pub num_subtasks: usize,
     pub num_comments: usize,
+    pub dependencies: Vec<TaskCompact>, // Tasks blocking this one
+    pub dependents: Vec<TaskCompact>,   // Tasks blocked by this one
+    pub memberships: Vec<Membership>,   // Every project the task belongs to
+    pub followers: Vec<User>,           // Users notified about changes to the task
+}
+
+impl Task {
+    /// Return whether any of the task's dependencies is still incomplete.
+    ///
+    pub fn is_blocked(&self) -> bool {
+        self.dependencies.iter().any(|t| !t.completed)
+    }
+}
+
+/// Defines the dates set when creating or updating a task. Unset dates are
+/// left unchanged and empty ones are cleared. A due time replaces the due
+/// date, as Asana derives one from the other.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskDates {
+    pub start_on: Option<String>, // YYYY-MM-DD
+    pub due_on: Option<String>,   // YYYY-MM-DD
+    pub due_at: Option<String>,   // UTC timestamp
+}
+
+impl TaskDates {
+    /// Request fields the dates are sent as.
+    ///
+    pub const FIELDS: [&'static str; 3] = ["start_on", "due_on", "due_at"];
+
+    /// Return whether no date is set or cleared.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.start_on.is_none() && self.due_on.is_none() && self.due_at.is_none()
+    }
+
+    /// Add the dates to a task request body, clearing empty ones with null.
+    ///
+    pub fn apply(&self, data: &mut serde_json::Value) {
+        let due = match &self.due_at {
+            Some(_) => ("due_at", &self.due_at),
+            None => ("due_on", &self.due_on),
+        };
+        for (key, value) in [("start_on", &self.start_on), due] {
+            match value.as_deref().map(str::trim) {
+                Some("") => data[key] = serde_json::Value::Null,
+                Some(value) => data[key] = serde_json::Value::String(value.to_string()),
+                None => {}
+            }
+        }
+    }
 }
 
 /// Defines story/comment data structure.
@@ -98,6 +177,20 @@ pub struct Story {
     pub created_at: Option<String>,
     pub created_by: Option<User>,
     pub resource_subtype: Option<String>, // "comment_added" for comments, system activity otherwise
+    pub is_pinned: bool,
+    pub liked: bool, // Whether the current user likes the story
+}
+
+/// Defines file attachment data structure.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct Attachment {
+    pub gid: String,
+    pub name: String,
+    pub host: String, // "asana" for uploaded files, otherwise e.g. "dropbox", "gdrive"
+    pub size: Option<u64>, // Bytes, only known for files hosted by Asana
+    pub created_at: Option<String>,
+    pub view_url: Option<String>, // Link to the file in its host
 }
 
 /// Defines project data structure.
@@ -110,3 +203,61 @@ pub struct Project {
     pub color: String,
     pub notes: String,
 }
+
+/// Defines project status update data structure.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct StatusUpdate {
+    pub gid: String,
+    pub status_type: String, // on_track, at_risk, off_track, on_hold or complete
+    pub title: String,
+    pub text: String,
+    pub created_at: Option<String>,
+    pub created_by: Option<User>,
+}
+
+/// Defines portfolio data structure.
+///
+#[derive(Clone, Debug, Dummy, PartialEq, Eq)]
+pub struct Portfolio {
+    pub gid: String,
+    pub name: String,
+}
+
+/// Defines a project of a portfolio along with its latest status update.
+///
+#[derive(Clone, Debug, Dummy, PartialEq)]
+pub struct PortfolioProject {
+    pub project: Project,
+    pub status_update: Option<StatusUpdate>,
+}
+
+/// Defines a change to a resource as reported by the Events API.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceEvent {
+    pub action: String, // added, changed, removed, deleted, undeleted
+    pub resource_gid: String,
+    pub resource_type: String, // task, section, story, ...
+    pub parent_gid: Option<String>,
+    pub parent_type: Option<String>,
+}
+
+/// Defines a set of events along with the sync token to resume from.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventBatch {
+    pub events: Vec<ResourceEvent>,
+    pub sync: String,
+    pub has_more: bool,
+    pub reset: bool, // The given sync token was missing or expired; no events were returned
+}
+
+/// Defines the kinds of object looked up through the typeahead endpoint.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeaheadKind {
+    User,
+    Project,
+    Tag,
+}
@@ -0,0 +1,1009 @@
+//! Asana rich text support.
+//!
+//! This module converts between the XML subset Asana uses for `html_notes`
+//! and the Markdown typed into the notes editor. Both are parsed into lines
+//! of formatted runs, which is also what the task detail view renders.
+
+/// Defines where a run of rich text links to.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Link {
+    Url(String),
+    Mention(String), // GID of the mentioned user, task or project
+}
+
+/// Defines the formatting of a run of rich text.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Marks {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strike: bool,
+    pub code: bool,
+    pub link: Option<Link>,
+}
+
+/// Defines a run of text with the same formatting.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub text: String,
+    pub marks: Marks,
+}
+
+/// Specifying what kind of block a line of rich text belongs to.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineKind {
+    Paragraph,
+    Heading(u8),            // Level 1 or 2
+    Bullet(usize),          // Nesting depth
+    Numbered(usize, usize), // Nesting depth and item number
+    Quote,
+    Code, // Line of a code block
+    Rule,
+}
+
+/// Defines a line of rich text.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RichLine {
+    pub kind: LineKind,
+    pub runs: Vec<Run>,
+}
+
+impl RichLine {
+    fn new(kind: LineKind) -> Self {
+        RichLine { kind, runs: vec![] }
+    }
+
+    /// Append text, merging it into the last run if formatted the same.
+    ///
+    fn push(&mut self, text: &str, marks: &Marks) {
+        if text.is_empty() {
+            return;
+        }
+        match self.runs.last_mut() {
+            Some(run) if run.marks == *marks => run.text.push_str(text),
+            _ => self.runs.push(Run {
+                text: text.to_string(),
+                marks: marks.clone(),
+            }),
+        }
+    }
+
+    /// Return the line's text without formatting.
+    ///
+    pub fn text(&self) -> String {
+        self.runs.iter().map(|r| r.text.as_str()).collect()
+    }
+}
+
+/// Return the Markdown for Asana rich text.
+///
+pub fn html_to_markdown(html: &str) -> String {
+    to_markdown(&parse_html(html))
+}
+
+/// Return Asana rich text for Markdown.
+///
+pub fn markdown_to_html(markdown: &str) -> String {
+    to_html(&parse_markdown(markdown))
+}
+
+/// Parse Asana rich text into lines. Unknown tags are skipped but their
+/// text is kept.
+///
+pub fn parse_html(html: &str) -> Vec<RichLine> {
+    read_html(html).finish()
+}
+
+/// Return the names of the tags in Asana rich text that parsing skips, like
+/// inline images, in the order they first appear. Saving lines parsed from
+/// such text would drop them.
+///
+pub fn unsupported_tags(html: &str) -> Vec<String> {
+    read_html(html).unsupported
+}
+
+fn read_html(html: &str) -> HtmlParser {
+    let mut parser = HtmlParser::default();
+    let mut rest = html;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let end = match tag_end(rest) {
+                    Some(end) => end,
+                    None => break,
+                };
+                parser.tag(&rest[1..end]);
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                parser.text(&decode_entities(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                parser.text(&decode_entities(rest));
+                rest = "";
+            }
+        }
+    }
+    parser
+}
+
+/// Return the index of the `>` that ends the tag at the start of the text,
+/// skipping any inside quoted attribute values.
+///
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut previous = None; // Last character outside whitespace
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if (c == '"' || c == '\'') && previous == Some('=') => quote = Some(c),
+            None if c == '>' => return Some(i),
+            None => {}
+        }
+        if !c.is_whitespace() {
+            previous = Some(c);
+        }
+    }
+    None
+}
+
+/// Builds lines from the tags and text of Asana rich text.
+///
+#[derive(Default)]
+struct HtmlParser {
+    lines: Vec<RichLine>,
+    line: Option<RichLine>,
+    marks: Vec<Marks>,         // Formatting of each open inline tag
+    lists: Vec<(bool, usize)>, // Whether each open list is ordered, and its item count
+    block: Option<LineKind>,   // Kind of lines in an open heading, quote or code block
+    after_block: bool,         // Whether a block just ended, so a newline is redundant
+    unsupported: Vec<String>,  // Names of the skipped tags
+}
+
+impl HtmlParser {
+    fn marks(&self) -> Marks {
+        self.marks.last().cloned().unwrap_or_default()
+    }
+
+    /// Return the kind of line text starts outside of a list item.
+    ///
+    fn kind(&self) -> LineKind {
+        self.block.unwrap_or(LineKind::Paragraph)
+    }
+
+    fn end_line(&mut self) {
+        if let Some(line) = self.line.take() {
+            self.lines.push(line);
+        }
+    }
+
+    fn end_block(&mut self) {
+        self.end_line();
+        self.after_block = true;
+    }
+
+    /// End the current line before a block starts. The newline before a block
+    /// only ends the previous line, so the empty line it started is dropped.
+    ///
+    fn start_block(&mut self) {
+        if self
+            .line
+            .as_ref()
+            .is_some_and(|l| l.kind == LineKind::Paragraph && l.runs.is_empty())
+        {
+            self.line = None;
+        }
+        self.end_block();
+    }
+
+    fn text(&mut self, text: &str) {
+        let mut text = text;
+        if self.after_block {
+            text = text.strip_prefix('\n').unwrap_or(text);
+        }
+        // Whitespace between list tags isn't content
+        if self.line.is_none() && !self.lists.is_empty() && text.trim().is_empty() {
+            return;
+        }
+        if text.is_empty() {
+            return;
+        }
+        self.after_block = false;
+        let marks = self.marks();
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                let kind = self.line.as_ref().map(|l| l.kind);
+                let line = self
+                    .line
+                    .take()
+                    .unwrap_or_else(|| RichLine::new(self.kind()));
+                self.lines.push(line);
+                // Further lines of a list item continue as paragraphs
+                let next = match kind {
+                    Some(LineKind::Bullet(_) | LineKind::Numbered(..)) | None => self.kind(),
+                    Some(kind) => kind,
+                };
+                self.line = Some(RichLine::new(next));
+            }
+            let kind = self.kind();
+            self.line
+                .get_or_insert_with(|| RichLine::new(kind))
+                .push(part, &marks);
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_start_matches('/').trim_end_matches('/');
+        let (name, attributes) = match tag.find(char::is_whitespace) {
+            Some(i) => (&tag[..i], &tag[i..]),
+            None => (tag, ""),
+        };
+        let name = name.to_lowercase();
+        match (name.as_str(), closing) {
+            ("ul" | "ol", false) => {
+                self.start_block();
+                self.lists.push((name == "ol", 0));
+            }
+            ("ul" | "ol", true) => {
+                self.end_block();
+                self.lists.pop();
+            }
+            ("li", false) => {
+                self.end_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let kind = match self.lists.last_mut() {
+                    Some((true, count)) => {
+                        *count += 1;
+                        LineKind::Numbered(depth, *count)
+                    }
+                    _ => LineKind::Bullet(depth),
+                };
+                self.line = Some(RichLine::new(kind));
+                self.after_block = false;
+            }
+            ("li", true) => self.end_block(),
+            ("h1" | "h2" | "blockquote" | "pre", false) => {
+                self.start_block();
+                self.block = Some(match name.as_str() {
+                    "h1" => LineKind::Heading(1),
+                    "h2" => LineKind::Heading(2),
+                    "blockquote" => LineKind::Quote,
+                    _ => LineKind::Code,
+                });
+                self.after_block = false;
+            }
+            ("h1" | "h2" | "blockquote" | "pre", true) => {
+                self.end_block();
+                self.block = None;
+            }
+            ("hr", _) => {
+                self.start_block();
+                self.lines.push(RichLine::new(LineKind::Rule));
+            }
+            ("strong" | "b" | "em" | "i" | "u" | "s" | "strike" | "del" | "code" | "a", false) => {
+                let mut marks = self.marks();
+                match name.as_str() {
+                    "strong" | "b" => marks.bold = true,
+                    "em" | "i" => marks.italic = true,
+                    "u" => marks.underline = true,
+                    "s" | "strike" | "del" => marks.strike = true,
+                    "code" => marks.code = true,
+                    _ => {
+                        marks.link = match attribute(attributes, "data-asana-gid") {
+                            Some(gid) => Some(Link::Mention(gid)),
+                            None => attribute(attributes, "href").map(Link::Url),
+                        }
+                    }
+                }
+                if self_closing {
+                    // A mention written without its name
+                    if let Some(Link::Mention(gid)) = &marks.link {
+                        let text = format!("@{}", gid);
+                        self.marks.push(marks);
+                        self.text(&text);
+                        self.marks.pop();
+                    }
+                } else {
+                    self.marks.push(marks);
+                }
+            }
+            ("strong" | "b" | "em" | "i" | "u" | "s" | "strike" | "del" | "code" | "a", true) => {
+                self.marks.pop();
+            }
+            ("body", _) => {}
+            _ => {
+                if !name.is_empty() && !self.unsupported.contains(&name) {
+                    self.unsupported.push(name);
+                }
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<RichLine> {
+        self.end_line();
+        while self
+            .lines
+            .last()
+            .is_some_and(|l| l.kind == LineKind::Paragraph && l.runs.is_empty())
+        {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+/// Return the decoded value of an attribute of a tag. Values may be double
+/// quoted, single quoted or bare, and text inside other values is skipped.
+///
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (found, next) = match after.chars().next() {
+                Some(q @ ('"' | '\'')) => match after[1..].find(q) {
+                    Some(end) => (&after[1..end + 1], &after[end + 2..]),
+                    None => (&after[1..], ""),
+                },
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    after.split_at(end)
+                }
+            };
+            value = found;
+            rest = next.trim_start();
+        }
+        if key.eq_ignore_ascii_case(name) {
+            return Some(decode_entities(value));
+        }
+    }
+    None
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn encode_entities(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Return Asana rich text for lines. Every run is wrapped in its own tags so
+/// the result always nests correctly, and lines of text are separated from
+/// blocks by a newline so blank lines survive a round trip.
+///
+pub fn to_html(lines: &[RichLine]) -> String {
+    let mut html = String::from("<body>");
+    let mut lists: Vec<bool> = vec![]; // Whether each open list is ordered
+    let close_list = |html: &mut String, ordered: bool| {
+        html.push_str(if ordered { "</li></ol>" } else { "</li></ul>" });
+    };
+    let mut previous: Option<LineKind> = None;
+    for line in lines {
+        let item = match line.kind {
+            LineKind::Bullet(depth) => Some((depth, false)),
+            LineKind::Numbered(depth, _) => Some((depth, true)),
+            _ => None,
+        };
+        let depth = item.map(|(depth, _)| depth + 1).unwrap_or(0);
+        while lists.len() > depth {
+            close_list(&mut html, lists.pop().unwrap_or_default());
+        }
+        if let Some((depth, ordered)) = item {
+            if lists.len() == depth + 1 && lists[depth] != ordered {
+                close_list(&mut html, lists.pop().unwrap_or_default());
+            }
+            if lists.len() == depth + 1 {
+                html.push_str("</li><li>");
+            }
+            if lists.is_empty() && previous == Some(LineKind::Paragraph) {
+                html.push('\n');
+            }
+            while lists.len() < depth + 1 {
+                html.push_str(if ordered { "<ol><li>" } else { "<ul><li>" });
+                lists.push(ordered);
+            }
+            push_runs_html(&mut html, &line.runs);
+            previous = Some(line.kind);
+            continue;
+        }
+
+        match (line.kind, previous) {
+            (LineKind::Paragraph, Some(_)) | (_, Some(LineKind::Paragraph)) => html.push('\n'),
+            (LineKind::Quote, Some(LineKind::Quote)) | (LineKind::Code, Some(LineKind::Code)) => {
+                // Continue the open block on a new line
+                let close = if line.kind == LineKind::Quote {
+                    "</blockquote>"
+                } else {
+                    "</pre>"
+                };
+                html.truncate(html.len() - close.len());
+                html.push('\n');
+                push_block_content(&mut html, line);
+                html.push_str(close);
+                previous = Some(line.kind);
+                continue;
+            }
+            _ => {}
+        }
+        match line.kind {
+            LineKind::Heading(level) => {
+                html.push_str(&format!("<h{}>", level.clamp(1, 2)));
+                push_runs_html(&mut html, &line.runs);
+                html.push_str(&format!("</h{}>", level.clamp(1, 2)));
+            }
+            LineKind::Quote => {
+                html.push_str("<blockquote>");
+                push_block_content(&mut html, line);
+                html.push_str("</blockquote>");
+            }
+            LineKind::Code => {
+                html.push_str("<pre>");
+                push_block_content(&mut html, line);
+                html.push_str("</pre>");
+            }
+            LineKind::Rule => html.push_str("<hr/>"),
+            _ => push_runs_html(&mut html, &line.runs),
+        }
+        previous = Some(line.kind);
+    }
+    while let Some(ordered) = lists.pop() {
+        close_list(&mut html, ordered);
+    }
+    html.push_str("</body>");
+    html
+}
+
+/// Append a line of a quote or code block. Code keeps its text as typed.
+///
+fn push_block_content(html: &mut String, line: &RichLine) {
+    if line.kind == LineKind::Code {
+        html.push_str(&encode_entities(&line.text()));
+    } else {
+        push_runs_html(html, &line.runs);
+    }
+}
+
+fn push_runs_html(html: &mut String, runs: &[Run]) {
+    for run in runs {
+        let marks = &run.marks;
+        if let Some(Link::Mention(gid)) = &marks.link {
+            // Asana fills in the name of whatever is mentioned
+            html.push_str(&format!("<a data-asana-gid=\"{}\"/>", encode_entities(gid)));
+            continue;
+        }
+        let tags: Vec<&str> = [
+            (marks.bold, "strong"),
+            (marks.italic, "em"),
+            (marks.underline, "u"),
+            (marks.strike, "s"),
+            (marks.code, "code"),
+        ]
+        .into_iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, tag)| tag)
+        .collect();
+        if let Some(Link::Url(url)) = &marks.link {
+            html.push_str(&format!("<a href=\"{}\">", encode_entities(url)));
+        }
+        for tag in &tags {
+            html.push_str(&format!("<{}>", tag));
+        }
+        html.push_str(&encode_entities(&run.text));
+        for tag in tags.iter().rev() {
+            html.push_str(&format!("</{}>", tag));
+        }
+        if marks.link.is_some() {
+            html.push_str("</a>");
+        }
+    }
+}
+
+/// Return Markdown for lines: `**bold**`, `*italic*`, `__underline__`,
+/// `~~strike~~`, `` `code` ``, `[text](url)` and `@[name](gid)` mentions.
+///
+pub fn to_markdown(lines: &[RichLine]) -> String {
+    let mut markdown = vec![];
+    let mut in_code = false;
+    for line in lines {
+        if in_code != (line.kind == LineKind::Code) {
+            markdown.push("```".to_string());
+            in_code = !in_code;
+        }
+        let text = match line.kind {
+            LineKind::Paragraph => escape_block_marker(runs_markdown(&line.runs)),
+            LineKind::Heading(level) => format!(
+                "{} {}",
+                "#".repeat(level.clamp(1, 2).into()),
+                runs_markdown(&line.runs)
+            ),
+            LineKind::Bullet(depth) => {
+                format!("{}- {}", "  ".repeat(depth), runs_markdown(&line.runs))
+            }
+            LineKind::Numbered(depth, number) => format!(
+                "{}{}. {}",
+                "  ".repeat(depth),
+                number,
+                runs_markdown(&line.runs)
+            ),
+            LineKind::Quote => format!("> {}", runs_markdown(&line.runs)),
+            LineKind::Code => line.text(),
+            LineKind::Rule => "---".to_string(),
+        };
+        markdown.push(text);
+    }
+    if in_code {
+        markdown.push("```".to_string());
+    }
+    markdown.join("\n")
+}
+
+fn runs_markdown(runs: &[Run]) -> String {
+    let mut markdown = String::new();
+    for run in runs {
+        let marks = &run.marks;
+        let text = if marks.code {
+            // Fence code with more backticks than it holds, padded so it can
+            // start or end with one
+            let longest = run.text.split(|c| c != '`').map(str::len).max();
+            match longest.unwrap_or(0) {
+                0 => format!("`{}`", run.text),
+                longest => {
+                    let fence = "`".repeat(longest + 1);
+                    format!("{} {} {}", fence, run.text, fence)
+                }
+            }
+        } else {
+            escape_markdown(&run.text)
+        };
+        // Delimiters must hug the text, so surrounding spaces go outside
+        let trimmed = text.trim();
+        let (leading, trailing) = match text.find(trimmed) {
+            Some(start) if !trimmed.is_empty() => (&text[..start], &text[start + trimmed.len()..]),
+            _ => {
+                markdown.push_str(&text);
+                continue;
+            }
+        };
+        let delimiters: String = [
+            (marks.bold, "**"),
+            (marks.italic, "*"),
+            (marks.underline, "__"),
+            (marks.strike, "~~"),
+        ]
+        .into_iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, delimiter)| delimiter)
+        .collect();
+        let closing: String = delimiters.chars().rev().collect();
+        let mut inner = format!("{}{}{}", delimiters, trimmed, closing);
+        match &marks.link {
+            Some(Link::Url(url)) => inner = format!("[{}]({})", inner, url),
+            Some(Link::Mention(gid)) => {
+                inner = format!("@[{}]({})", inner.trim_start_matches('@'), gid)
+            }
+            None => {}
+        }
+        markdown.push_str(leading);
+        markdown.push_str(&inner);
+        markdown.push_str(trailing);
+    }
+    markdown
+}
+
+/// Escape paragraph text that would otherwise read as a heading, list item,
+/// quote or rule.
+///
+fn escape_block_marker(text: String) -> String {
+    let trimmed = text.trim_start();
+    let rule = trimmed.trim_end();
+    let is_rule = rule.len() >= 3 && rule.chars().all(|c| c == '-');
+    let is_marker = ["- ", "+ ", "# ", "## ", ">"]
+        .iter()
+        .any(|marker| trimmed.starts_with(marker))
+        || numbered_item(trimmed).is_some();
+    if !is_rule && !is_marker {
+        return text;
+    }
+    let indent = text.len() - trimmed.len();
+    format!("{}\\{}", &text[..indent], trimmed)
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Parse Markdown into lines. Lists nest by two spaces of indentation.
+///
+pub fn parse_markdown(markdown: &str) -> Vec<RichLine> {
+    let mut lines = vec![];
+    let mut in_code = false;
+    for text in markdown.lines() {
+        // Backticks after a fence make it inline code instead
+        let fence = text.trim_start();
+        if fence.starts_with("```") && !fence.trim_start_matches('`').contains('`') {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            let mut line = RichLine::new(LineKind::Code);
+            line.push(text, &Marks::default());
+            lines.push(line);
+            continue;
+        }
+        let indent = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        let (kind, content) = if trimmed.len() >= 3
+            && ["-", "*", "_"]
+                .iter()
+                .any(|c| trimmed.chars().all(|t| t.to_string() == *c))
+        {
+            (LineKind::Rule, "")
+        } else if let Some(content) = trimmed.strip_prefix("## ") {
+            (LineKind::Heading(2), content)
+        } else if let Some(content) = trimmed.strip_prefix("# ") {
+            (LineKind::Heading(1), content)
+        } else if let Some(content) = trimmed.strip_prefix('>') {
+            (LineKind::Quote, content.trim_start())
+        } else if let Some(content) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(bullet))
+        {
+            (LineKind::Bullet(indent / 2), content)
+        } else {
+            match numbered_item(trimmed) {
+                Some((number, content)) => (LineKind::Numbered(indent / 2, number), content),
+                None => (LineKind::Paragraph, text.trim_end()),
+            }
+        };
+        let mut line = RichLine::new(kind);
+        parse_inline(content, &Marks::default(), &mut line);
+        lines.push(line);
+    }
+    lines
+}
+
+/// Return the number and content of a numbered list item like `2. text`.
+///
+fn numbered_item(text: &str) -> Option<(usize, &str)> {
+    let (number, content) = text.split_once(". ")?;
+    Some((number.parse().ok()?, content))
+}
+
+/// Parse Markdown inline formatting into runs. A delimiter opens only before
+/// text and closes only after it, so `2 * 3` stays as typed.
+///
+fn parse_inline(text: &str, base: &Marks, line: &mut RichLine) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut marks = base.clone();
+    let mut buffer = String::new();
+    let mut i = 0;
+    let word = |i: usize| chars.get(i).is_some_and(|c| c.is_alphanumeric());
+    let space = |i: Option<usize>| {
+        i.and_then(|i| chars.get(i))
+            .is_none_or(|c| c.is_whitespace())
+    };
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && i + 1 < chars.len() {
+            buffer.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+        if c == '`' {
+            // Code closes at the next run of as many backticks as opened it
+            let fence = chars[i..].iter().take_while(|c| **c == '`').count();
+            let mut close = None;
+            let mut j = i + fence;
+            while j < chars.len() {
+                let run = chars[j..].iter().take_while(|c| **c == '`').count();
+                if run == fence {
+                    close = Some(j);
+                    break;
+                }
+                j += run.max(1);
+            }
+            match close {
+                Some(close) => {
+                    line.push(&buffer, &marks);
+                    buffer.clear();
+                    let mut code: String = chars[i + fence..close].iter().collect();
+                    if fence > 1 && code.len() >= 2 && code.starts_with(' ') && code.ends_with(' ')
+                    {
+                        code = code[1..code.len() - 1].to_string();
+                    }
+                    line.push(
+                        &code,
+                        &Marks {
+                            code: true,
+                            ..marks.clone()
+                        },
+                    );
+                    i = close + fence;
+                }
+                None => {
+                    buffer.extend(&chars[i..i + fence]);
+                    i += fence;
+                }
+            }
+            continue;
+        }
+        if c == '[' || (c == '@' && chars.get(i + 1) == Some(&'[')) {
+            let start = if c == '@' { i + 1 } else { i };
+            // Only GIDs can be mentioned; otherwise the @ is text before a link
+            let link = link(&chars[start..])
+                .filter(|(_, target, _)| c == '[' || target.chars().all(|d| d.is_ascii_digit()));
+            if let Some((label, target, len)) = link {
+                line.push(&buffer, &marks);
+                buffer.clear();
+                let mut link_marks = marks.clone();
+                if c == '@' {
+                    link_marks.link = Some(Link::Mention(target));
+                    line.push(&format!("@{}", label), &link_marks);
+                } else {
+                    link_marks.link = Some(Link::Url(target));
+                    parse_inline(&label, &link_marks, line);
+                }
+                i = start + len;
+                continue;
+            }
+        }
+        let delimiter = ["**", "__", "~~", "*", "_"].into_iter().find(|d| {
+            d.chars()
+                .enumerate()
+                .all(|(k, d)| chars.get(i + k) == Some(&d))
+        });
+        if let Some(delimiter) = delimiter {
+            let len = delimiter.len();
+            let open = match delimiter {
+                "**" => marks.bold,
+                "__" => marks.underline,
+                "~~" => marks.strike,
+                _ => marks.italic,
+            };
+            let before = i.checked_sub(1);
+            let toggles = if open {
+                !space(before)
+            } else {
+                !space(Some(i + len))
+            };
+            // Underscores inside words, like snake_case, stay as typed
+            let in_word = delimiter.starts_with('_')
+                && if open {
+                    word(i + len)
+                } else {
+                    before.is_some_and(word)
+                };
+            if toggles && !in_word {
+                line.push(&buffer, &marks);
+                buffer.clear();
+                match delimiter {
+                    "**" => marks.bold = !open,
+                    "__" => marks.underline = !open,
+                    "~~" => marks.strike = !open,
+                    _ => marks.italic = !open,
+                }
+                i += len;
+                continue;
+            }
+        }
+        buffer.push(c);
+        i += 1;
+    }
+    line.push(&buffer, &marks);
+}
+
+/// Return the label, target and length of a `[label](target)` link at the
+/// start of the text.
+///
+fn link(chars: &[char]) -> Option<(String, String, usize)> {
+    let close = chars.iter().position(|c| *c == ']')?;
+    if chars.get(close + 1) != Some(&'(') {
+        return None;
+    }
+    let end = chars[close + 2..].iter().position(|c| *c == ')')? + close + 2;
+    let label: String = chars[1..close].iter().collect();
+    let target: String = chars[close + 2..end].iter().collect();
+    if target.trim().is_empty() {
+        return None;
+    }
+    Some((label, target.trim().to_string(), end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(text: &str, marks: Marks) -> Run {
+        Run {
+            text: text.to_string(),
+            marks,
+        }
+    }
+
+    #[test]
+    fn parse_html_reads_blocks_marks_and_mentions() {
+        let html = "<body>Hi <a data-asana-gid=\"7\" data-asana-type=\"user\" \
+                    href=\"https://app.asana.com/0/7/list\">@Jane</a>, <strong>ship</strong> \
+                    &amp; <em>test</em>\n<ul><li>one</li><li>two<ol><li>a</li></ol></li></ul>\
+                    <h1>Title</h1><pre>let x = 1;\nlet y = 2;</pre>\
+                    <a href=\"https://example.com\">docs</a><hr/>Bye</body>";
+        let lines = parse_html(html);
+        let kinds: Vec<LineKind> = lines.iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LineKind::Paragraph,
+                LineKind::Bullet(0),
+                LineKind::Bullet(0),
+                LineKind::Numbered(1, 1),
+                LineKind::Heading(1),
+                LineKind::Code,
+                LineKind::Code,
+                LineKind::Paragraph,
+                LineKind::Rule,
+                LineKind::Paragraph,
+            ]
+        );
+        assert_eq!(
+            lines[0].runs,
+            vec![
+                run("Hi ", Marks::default()),
+                run(
+                    "@Jane",
+                    Marks {
+                        link: Some(Link::Mention("7".to_string())),
+                        ..Marks::default()
+                    }
+                ),
+                run(", ", Marks::default()),
+                run(
+                    "ship",
+                    Marks {
+                        bold: true,
+                        ..Marks::default()
+                    }
+                ),
+                run(" & ", Marks::default()),
+                run(
+                    "test",
+                    Marks {
+                        italic: true,
+                        ..Marks::default()
+                    }
+                ),
+            ]
+        );
+        assert_eq!(lines[6].text(), "let y = 2;");
+        assert_eq!(
+            lines[7].runs[0].marks.link,
+            Some(Link::Url("https://example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn unsupported_tags_are_reported() {
+        let html = "<body>See <img data-asana-gid=\"5\"/> and <IMG src=\"x\"/>\
+                    <table><tr><td>cell</td></tr></table></body>";
+        assert_eq!(unsupported_tags(html), vec!["img", "table", "tr", "td"]);
+        assert!(unsupported_tags("<body><strong>ok</strong><hr/></body>").is_empty());
+    }
+
+    #[test]
+    fn quoted_attributes_may_contain_brackets_and_names() {
+        let html = "<body><a href=\"https://example.com/?a>b\">one</a> \
+                    <a title=\"x > y href=&quot;no&quot;\" href='https://example.com/two'>two</a>\
+                    </body>";
+        let lines = parse_html(html);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text(), "one two");
+        assert_eq!(
+            lines[0].runs[0].marks.link,
+            Some(Link::Url("https://example.com/?a>b".to_string()))
+        );
+        assert_eq!(
+            lines[0].runs[2].marks.link,
+            Some(Link::Url("https://example.com/two".to_string()))
+        );
+        assert_eq!(
+            attribute(" title=\"x > y\" data-asana-gid=7", "data-asana-gid"),
+            Some("7".to_string())
+        );
+    }
+
+    #[test]
+    fn markdown_converts_to_valid_html() {
+        let markdown = "Ship **by *Friday*** with @[Jane](7)\n\
+                        - [docs](https://example.com?a=1&b=2)\n  \
+                        1. `a < b`\n\
+                        > quoted\n\
+                        ```\nfn main() {}\n```\n\
+                        2 * 3 and snake_case @[me](https://example.com)";
+        assert_eq!(
+            markdown_to_html(markdown),
+            "<body>Ship <strong>by </strong><strong><em>Friday</em></strong> with \
+             <a data-asana-gid=\"7\"/>\n\
+             <ul><li><a href=\"https://example.com?a=1&amp;b=2\">docs</a>\
+             <ol><li><code>a &lt; b</code></li></ol></li></ul>\
+             <blockquote>quoted</blockquote><pre>fn main() {}</pre>\n\
+             2 * 3 and snake_case @<a href=\"https://example.com\">me</a></body>"
+        );
+    }
+
+    #[test]
+    fn markdown_round_trips_through_html() {
+        let markdown = "# Plan\n\
+                        Text with **bold**, *italic*, __underline__, ~~strike~~ and `code`\n\
+                        \n\
+                        - item with [a link](https://example.com)\n  \
+                        - nested\n\
+                        1. first\n\
+                        2. second\n\
+                        > quote\n\
+                        ---\n\
+                        ```\nlet x = *y;\n```\n\
+                        Literal \\*stars\\*";
+        let html = markdown_to_html(markdown);
+        assert_eq!(html_to_markdown(&html), markdown);
+        assert_eq!(parse_html(&html), parse_markdown(markdown));
+    }
+
+    #[test]
+    fn markdown_escapes_block_markers_and_code_backticks() {
+        let paragraph = |text: &str, marks: Marks| RichLine {
+            kind: LineKind::Paragraph,
+            runs: vec![run(text, marks)],
+        };
+        let code = Marks {
+            code: true,
+            ..Marks::default()
+        };
+        let lines = vec![
+            paragraph("- not a list", Marks::default()),
+            paragraph("1. not numbered", Marks::default()),
+            paragraph("# not a heading", Marks::default()),
+            paragraph("> not a quote", Marks::default()),
+            paragraph("---", Marks::default()),
+            paragraph("a`b", code.clone()),
+            paragraph("``", code),
+        ];
+        let markdown = to_markdown(&lines);
+        assert_eq!(
+            markdown,
+            "\\- not a list\n\\1. not numbered\n\\# not a heading\n\\> not a quote\n\\---\n\
+             `` a`b ``\n``` `` ```"
+        );
+        assert_eq!(parse_markdown(&markdown), lines);
+        assert_eq!(html_to_markdown(&to_html(&lines)), markdown);
+    }
+}
@@ -0,0 +1,170 @@
+//! Asana task search support.
+//!
+//! This module defines the filters of a workspace-wide task search and turns
+//! them into query parameters for the `/workspaces/{gid}/tasks/search` endpoint.
+
+use super::resource::CustomField;
+
+/// Number of tasks requested per page of search results.
+///
+pub const SEARCH_PAGE_SIZE: usize = 50;
+
+/// Defines the filters of a workspace-wide task search. Unset filters match
+/// every task.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskSearch {
+    pub text: Option<String>,
+    pub assignee_gid: Option<String>,
+    pub project_gid: Option<String>,
+    pub section_gid: Option<String>,
+    pub tag_gid: Option<String>,
+    pub completed: Option<bool>,
+    pub due_after: Option<String>,              // YYYY-MM-DD
+    pub due_before: Option<String>,             // YYYY-MM-DD
+    pub custom_field: Option<(String, String)>, // Query parameter and value
+}
+
+impl TaskSearch {
+    /// Return the query parameters of the search, newest tasks first. Pages
+    /// after the first only include tasks created at or before
+    /// `created_before`. Asana's bound is exclusive, so it is moved one
+    /// millisecond later to keep tasks created at the same time as the last
+    /// one seen, and the caller drops the repeats.
+    ///
+    pub fn params(&self, created_before: Option<&str>) -> Vec<(String, String)> {
+        let mut params: Vec<(String, String)> = vec![
+            ("sort_by".to_string(), "created_at".to_string()),
+            ("sort_ascending".to_string(), "false".to_string()),
+        ];
+        let mut push = |key: &str, value: &Option<String>| {
+            if let Some(value) = value {
+                params.push((key.to_string(), value.clone()));
+            }
+        };
+        push("text", &self.text);
+        push("assignee.any", &self.assignee_gid);
+        push("projects.any", &self.project_gid);
+        push("sections.any", &self.section_gid);
+        push("tags.any", &self.tag_gid);
+        push("due_on.after", &self.due_after);
+        push("due_on.before", &self.due_before);
+        if let Some(completed) = self.completed {
+            params.push(("completed".to_string(), completed.to_string()));
+        }
+        if let Some((key, value)) = &self.custom_field {
+            params.push((key.clone(), value.clone()));
+        }
+        if let Some(created_before) = created_before {
+            let inclusive = chrono::DateTime::parse_from_rfc3339(created_before)
+                .map(|time| {
+                    (time + chrono::Duration::milliseconds(1))
+                        .to_utc()
+                        .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+                        .to_string()
+                })
+                .unwrap_or_else(|_| created_before.to_string());
+            params.push(("created_at.before".to_string(), inclusive));
+        }
+        params
+    }
+}
+
+/// Return the query parameter and value matching tasks whose custom field
+/// has the given value. Enum values are matched by option name, and an empty
+/// value matches tasks with the field set to anything.
+///
+pub fn custom_field_filter(field: &CustomField, value: &str) -> Result<(String, String), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok((
+            format!("custom_fields.{}.is_set", field.gid),
+            "true".to_string(),
+        ));
+    }
+    match field.resource_subtype.as_str() {
+        "enum" | "multi_enum" => field
+            .enum_options
+            .iter()
+            .find(|o| o.name.eq_ignore_ascii_case(value))
+            .map(|o| (format!("custom_fields.{}.value", field.gid), o.gid.clone()))
+            .ok_or_else(|| format!("{} has no option '{}'", field.name, value)),
+        "number" => value
+            .parse::<f64>()
+            .map(|_| {
+                (
+                    format!("custom_fields.{}.value", field.gid),
+                    value.to_string(),
+                )
+            })
+            .map_err(|_| format!("{} must be a number", field.name)),
+        _ => Ok((
+            format!("custom_fields.{}.contains", field.gid),
+            value.to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asana::EnumOption;
+    use fake::{Fake, Faker};
+
+    #[test]
+    fn params_include_only_set_filters() {
+        let search = TaskSearch {
+            text: Some("login bug".to_string()),
+            assignee_gid: Some("1".to_string()),
+            completed: Some(false),
+            due_before: Some("2024-05-01".to_string()),
+            ..TaskSearch::default()
+        };
+        let params = search.params(Some("2024-04-01T00:00:00.000Z"));
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("text"), Some("login bug"));
+        assert_eq!(get("assignee.any"), Some("1"));
+        assert_eq!(get("completed"), Some("false"));
+        assert_eq!(get("due_on.before"), Some("2024-05-01"));
+        assert_eq!(get("created_at.before"), Some("2024-04-01T00:00:00.001Z"));
+        assert_eq!(get("sort_by"), Some("created_at"));
+        assert_eq!(get("projects.any"), None);
+        assert_eq!(get("due_on.after"), None);
+    }
+
+    #[test]
+    fn custom_field_filter_matches_by_type() {
+        let mut field: CustomField = Faker.fake();
+        field.gid = "7".to_string();
+        field.name = "Priority".to_string();
+        field.resource_subtype = "enum".to_string();
+        field.enum_options = vec![EnumOption {
+            gid: "70".to_string(),
+            name: "High".to_string(),
+            enabled: true,
+            color: None,
+        }];
+        assert_eq!(
+            custom_field_filter(&field, "high"),
+            Ok(("custom_fields.7.value".to_string(), "70".to_string()))
+        );
+        assert!(custom_field_filter(&field, "Urgent").is_err());
+        assert_eq!(
+            custom_field_filter(&field, ""),
+            Ok(("custom_fields.7.is_set".to_string(), "true".to_string()))
+        );
+
+        field.resource_subtype = "number".to_string();
+        assert!(custom_field_filter(&field, "abc").is_err());
+        field.resource_subtype = "text".to_string();
+        assert_eq!(
+            custom_field_filter(&field, "api"),
+            Ok(("custom_fields.7.contains".to_string(), "api".to_string()))
+        );
+    }
+}
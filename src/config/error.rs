@@ -42,6 +42,10 @@ pub enum ConfigError {
     /// Failed to deserialize configuration
     #[error("Failed to deserialize configuration: {0}")]
     DeserializationFailed(String),
+
+    /// API base URL is not a valid http(s) URL
+    #[error("Invalid API base URL '{0}': expected an http or https URL")]
+    InvalidApiBaseUrl(String),
 }
 
 #[cfg(test)]
@@ -28,23 +28,56 @@ pub enum HotkeyAction {
     Quit,
     OpenThemeSelector,
     OpenHotkeyEditor,
+    OpenWorkspaceSelector,
+    CreateProject,
+    EditProject,
+    ArchiveProject,
+    ToggleArchivedProjects,
+    ToggleTeamBrowser,
+    OpenTaskSearch,
 
     // ProjectTasks view actions
     ViewTask,
     CreateTask,
     MoveTask,
+    MarkTask,
     ToggleTaskComplete,
     DeleteTask,
     Back,
     FilterByAssignee,
+    AddSection,
+    RenameSection,
+    DeleteSection,
+    MoveSectionLeft,
+    MoveSectionRight,
 
     // TaskDetail view actions
     EditTask,
     AddComment,
+    AddSubtask,
+    AddDependency,
+    RemoveDependency,
+    UploadAttachment,
+    DownloadAttachment,
+    AddToProject,
+    MoveToProject,
+    RemoveFromProject,
+    EditFollowers,
+    ToggleFollow,
+    EditComment,
+    DeleteComment,
+    LikeComment,
+    PinComment,
 
     // CreateTask/EditTask view actions
     EditField,
     SubmitForm,
+    CalendarPrevMonth,
+    CalendarNextMonth,
+    CalendarToday,
+
+    // Portfolios view actions
+    PostStatusUpdate,
 
     // Special mode actions (for search, debug, modals, etc.)
     // Note: Navigation in special modes uses global NavigateNext/NavigatePrev
@@ -263,6 +296,10 @@ pub struct ViewHotkeys {
     #[serde(default = "HashMap::new", skip_serializing_if = "HashMap::is_empty")]
     pub edit_task: HashMap<HotkeyAction, Hotkey>,
     #[serde(default = "HashMap::new", skip_serializing_if = "HashMap::is_empty")]
+    pub portfolios: HashMap<HotkeyAction, Hotkey>,
+    #[serde(default = "HashMap::new", skip_serializing_if = "HashMap::is_empty")]
+    pub task_search: HashMap<HotkeyAction, Hotkey>,
+    #[serde(default = "HashMap::new", skip_serializing_if = "HashMap::is_empty")]
     pub search_mode: HashMap<HotkeyAction, Hotkey>,
     #[serde(default = "HashMap::new", skip_serializing_if = "HashMap::is_empty")]
     pub debug_mode: HashMap<HotkeyAction, Hotkey>,
@@ -300,6 +337,8 @@ impl ViewHotkeys {
             task_detail: HashMap::new(),
             create_task: HashMap::new(),
             edit_task: HashMap::new(),
+            portfolios: HashMap::new(),
+            task_search: HashMap::new(),
             search_mode: HashMap::new(),
             debug_mode: HashMap::new(),
             delete_confirmation: HashMap::new(),
@@ -321,6 +360,8 @@ impl ViewHotkeys {
                 &self.task_detail,
                 &self.create_task,
                 &self.edit_task,
+                &self.portfolios,
+                &self.task_search,
                 &self.debug_mode,
                 &self.move_task,
                 &self.theme_selector,
@@ -381,6 +422,20 @@ impl ViewHotkeys {
                 overrides.edit_task.insert(action.clone(), hotkey.clone());
             }
         }
+        for (action, hotkey) in &self.portfolios {
+            if !global_nav_actions.contains(action)
+                && defaults.portfolios.get(action) != Some(hotkey)
+            {
+                overrides.portfolios.insert(action.clone(), hotkey.clone());
+            }
+        }
+        for (action, hotkey) in &self.task_search {
+            if !global_nav_actions.contains(action)
+                && defaults.task_search.get(action) != Some(hotkey)
+            {
+                overrides.task_search.insert(action.clone(), hotkey.clone());
+            }
+        }
         for (action, hotkey) in &self.search_mode {
             if defaults.search_mode.get(action) != Some(hotkey) {
                 overrides.search_mode.insert(action.clone(), hotkey.clone());
@@ -441,6 +496,8 @@ impl ViewHotkeys {
                 .or_else(|| overrides.task_detail.get(action))
                 .or_else(|| overrides.create_task.get(action))
                 .or_else(|| overrides.edit_task.get(action))
+                .or_else(|| overrides.portfolios.get(action))
+                .or_else(|| overrides.task_search.get(action))
                 .or_else(|| overrides.debug_mode.get(action))
                 .or_else(|| overrides.move_task.get(action))
                 .or_else(|| overrides.theme_selector.get(action))
@@ -453,6 +510,8 @@ impl ViewHotkeys {
                 merged.task_detail.insert(action.clone(), hotkey.clone());
                 merged.create_task.insert(action.clone(), hotkey.clone());
                 merged.edit_task.insert(action.clone(), hotkey.clone());
+                merged.portfolios.insert(action.clone(), hotkey.clone());
+                merged.task_search.insert(action.clone(), hotkey.clone());
                 merged.debug_mode.insert(action.clone(), hotkey.clone());
                 merged.move_task.insert(action.clone(), hotkey.clone());
                 merged.theme_selector.insert(action.clone(), hotkey.clone());
@@ -488,6 +547,16 @@ impl ViewHotkeys {
                 merged.edit_task.insert(action.clone(), hotkey.clone());
             }
         }
+        for (action, hotkey) in &overrides.portfolios {
+            if !global_nav_actions.contains(action) {
+                merged.portfolios.insert(action.clone(), hotkey.clone());
+            }
+        }
+        for (action, hotkey) in &overrides.task_search {
+            if !global_nav_actions.contains(action) {
+                merged.task_search.insert(action.clone(), hotkey.clone());
+            }
+        }
         for (action, hotkey) in &overrides.search_mode {
             merged.search_mode.insert(action.clone(), hotkey.clone());
         }
@@ -553,21 +622,52 @@ pub fn get_hotkey_groups() -> Vec<HotkeyGroup> {
                 HotkeyAction::EditTask,
                 HotkeyAction::DeleteTask,
                 HotkeyAction::MoveTask,
+                HotkeyAction::MarkTask,
                 HotkeyAction::ToggleTaskComplete,
                 HotkeyAction::ToggleStar,
+                HotkeyAction::CreateProject,
+                HotkeyAction::EditProject,
+                HotkeyAction::ArchiveProject,
+                HotkeyAction::ToggleArchivedProjects,
+                HotkeyAction::ToggleTeamBrowser,
                 HotkeyAction::AddComment,
+                HotkeyAction::AddSubtask,
+                HotkeyAction::AddDependency,
+                HotkeyAction::RemoveDependency,
+                HotkeyAction::UploadAttachment,
+                HotkeyAction::DownloadAttachment,
+                HotkeyAction::AddToProject,
+                HotkeyAction::MoveToProject,
+                HotkeyAction::RemoveFromProject,
+                HotkeyAction::EditFollowers,
+                HotkeyAction::ToggleFollow,
+                HotkeyAction::EditComment,
+                HotkeyAction::DeleteComment,
+                HotkeyAction::LikeComment,
+                HotkeyAction::PinComment,
                 HotkeyAction::EditField,
                 HotkeyAction::SubmitForm,
+                HotkeyAction::CalendarPrevMonth,
+                HotkeyAction::CalendarNextMonth,
+                HotkeyAction::CalendarToday,
+                HotkeyAction::PostStatusUpdate,
                 HotkeyAction::FilterByAssignee,
+                HotkeyAction::AddSection,
+                HotkeyAction::RenameSection,
+                HotkeyAction::DeleteSection,
+                HotkeyAction::MoveSectionLeft,
+                HotkeyAction::MoveSectionRight,
             ],
         },
         HotkeyGroup {
             name: "System".to_string(),
             actions: vec![
                 HotkeyAction::EnterSearch,
+                HotkeyAction::OpenTaskSearch,
                 HotkeyAction::EnterDebug,
                 HotkeyAction::OpenThemeSelector,
                 HotkeyAction::OpenHotkeyEditor,
+                HotkeyAction::OpenWorkspaceSelector,
                 HotkeyAction::Cancel,
                 HotkeyAction::Back,
                 HotkeyAction::Quit,
@@ -628,6 +728,8 @@ pub fn get_all_hotkeys_grouped(hotkeys: &ViewHotkeys) -> GroupedHotkeys {
                         .or_else(|| hotkeys.task_detail.get(action))
                         .or_else(|| hotkeys.create_task.get(action))
                         .or_else(|| hotkeys.edit_task.get(action))
+                        .or_else(|| hotkeys.portfolios.get(action))
+                        .or_else(|| hotkeys.task_search.get(action))
                         .or_else(|| hotkeys.search_mode.get(action))
                         .or_else(|| hotkeys.debug_mode.get(action))
                         .or_else(|| hotkeys.delete_confirmation.get(action))
@@ -660,31 +762,87 @@ pub fn find_action_view(action: &HotkeyAction) -> Vec<View> {
             views.push(View::TaskDetail);
             views.push(View::CreateTask);
             views.push(View::EditTask);
+            views.push(View::Portfolios);
+            views.push(View::TaskSearch);
         }
         HotkeyAction::ToggleStar
         | HotkeyAction::EnterSearch
         | HotkeyAction::EnterDebug
-        | HotkeyAction::Select
         | HotkeyAction::OpenThemeSelector
-        | HotkeyAction::OpenHotkeyEditor => {
+        | HotkeyAction::OpenHotkeyEditor
+        | HotkeyAction::OpenWorkspaceSelector
+        | HotkeyAction::CreateProject
+        | HotkeyAction::EditProject
+        | HotkeyAction::ArchiveProject
+        | HotkeyAction::ToggleArchivedProjects
+        | HotkeyAction::ToggleTeamBrowser => {
             views.push(View::Welcome);
         }
+        HotkeyAction::Select => {
+            views.push(View::Welcome);
+            views.push(View::Portfolios);
+            views.push(View::TaskSearch);
+        }
+        HotkeyAction::OpenTaskSearch => {
+            views.push(View::Welcome);
+            views.push(View::TaskSearch);
+        }
         HotkeyAction::ViewTask
         | HotkeyAction::CreateTask
         | HotkeyAction::MoveTask
-        | HotkeyAction::ToggleTaskComplete
+        | HotkeyAction::MarkTask
         | HotkeyAction::DeleteTask
-        | HotkeyAction::Back
-        | HotkeyAction::FilterByAssignee => {
+        | HotkeyAction::FilterByAssignee
+        | HotkeyAction::AddSection
+        | HotkeyAction::RenameSection
+        | HotkeyAction::DeleteSection
+        | HotkeyAction::MoveSectionLeft
+        | HotkeyAction::MoveSectionRight => {
             views.push(View::ProjectTasks);
         }
-        HotkeyAction::EditTask | HotkeyAction::AddComment => {
+        HotkeyAction::Back => {
+            views.push(View::ProjectTasks);
+            views.push(View::Portfolios);
+            views.push(View::TaskSearch);
+        }
+        HotkeyAction::ToggleTaskComplete => {
+            // Completes the selected task on the board or subtask in detail
+            views.push(View::ProjectTasks);
             views.push(View::TaskDetail);
         }
-        HotkeyAction::EditField | HotkeyAction::SubmitForm => {
+        HotkeyAction::AddComment => {
+            // Comments on the open task, or on every marked task on the board
+            views.push(View::ProjectTasks);
+            views.push(View::TaskDetail);
+        }
+        HotkeyAction::EditTask
+        | HotkeyAction::AddSubtask
+        | HotkeyAction::AddDependency
+        | HotkeyAction::RemoveDependency
+        | HotkeyAction::UploadAttachment
+        | HotkeyAction::DownloadAttachment
+        | HotkeyAction::AddToProject
+        | HotkeyAction::MoveToProject
+        | HotkeyAction::RemoveFromProject
+        | HotkeyAction::EditFollowers
+        | HotkeyAction::ToggleFollow
+        | HotkeyAction::EditComment
+        | HotkeyAction::DeleteComment
+        | HotkeyAction::LikeComment
+        | HotkeyAction::PinComment => {
+            views.push(View::TaskDetail);
+        }
+        HotkeyAction::EditField
+        | HotkeyAction::SubmitForm
+        | HotkeyAction::CalendarPrevMonth
+        | HotkeyAction::CalendarNextMonth
+        | HotkeyAction::CalendarToday => {
             views.push(View::CreateTask);
             views.push(View::EditTask);
         }
+        HotkeyAction::PostStatusUpdate => {
+            views.push(View::Portfolios);
+        }
         HotkeyAction::SearchModeExit => {
             // Search mode is available in multiple views
             views.push(View::Welcome);
@@ -715,6 +873,8 @@ pub fn find_action_view(action: &HotkeyAction) -> Vec<View> {
             views.push(View::TaskDetail);
             views.push(View::CreateTask);
             views.push(View::EditTask);
+            views.push(View::Portfolios);
+            views.push(View::TaskSearch);
         }
     }
 
@@ -745,6 +905,10 @@ pub fn update_hotkey_for_action(hotkeys: &mut ViewHotkeys, action: &HotkeyAction
         hotkeys.create_task.insert(action.clone(), hotkey.clone());
         hotkeys.edit_task.remove(action);
         hotkeys.edit_task.insert(action.clone(), hotkey.clone());
+        hotkeys.portfolios.remove(action);
+        hotkeys.portfolios.insert(action.clone(), hotkey.clone());
+        hotkeys.task_search.remove(action);
+        hotkeys.task_search.insert(action.clone(), hotkey.clone());
         hotkeys.debug_mode.remove(action);
         hotkeys.debug_mode.insert(action.clone(), hotkey.clone());
         hotkeys.move_task.remove(action);
@@ -770,7 +934,7 @@ pub fn update_hotkey_for_action(hotkeys: &mut ViewHotkeys, action: &HotkeyAction
                     hotkeys.task_detail.remove(action);
                     hotkeys.task_detail.insert(action.clone(), hotkey.clone());
                 }
-                View::CreateTask => {
+                View::CreateTask | View::CreateStatusUpdate => {
                     hotkeys.create_task.remove(action);
                     hotkeys.create_task.insert(action.clone(), hotkey.clone());
                 }
@@ -778,6 +942,14 @@ pub fn update_hotkey_for_action(hotkeys: &mut ViewHotkeys, action: &HotkeyAction
                     hotkeys.edit_task.remove(action);
                     hotkeys.edit_task.insert(action.clone(), hotkey.clone());
                 }
+                View::Portfolios => {
+                    hotkeys.portfolios.remove(action);
+                    hotkeys.portfolios.insert(action.clone(), hotkey.clone());
+                }
+                View::TaskSearch => {
+                    hotkeys.task_search.remove(action);
+                    hotkeys.task_search.insert(action.clone(), hotkey.clone());
+                }
             }
         }
 
@@ -848,6 +1020,8 @@ fn remove_key_from_all_actions(
     remove_from_map(&mut hotkeys.task_detail);
     remove_from_map(&mut hotkeys.create_task);
     remove_from_map(&mut hotkeys.edit_task);
+    remove_from_map(&mut hotkeys.portfolios);
+    remove_from_map(&mut hotkeys.task_search);
     remove_from_map(&mut hotkeys.search_mode);
     remove_from_map(&mut hotkeys.debug_mode);
     remove_from_map(&mut hotkeys.delete_confirmation);
@@ -961,6 +1135,13 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    welcome.insert(
+        HotkeyAction::OpenWorkspaceSelector,
+        Hotkey {
+            code: KeyCode::Char('w'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
     welcome.insert(
         HotkeyAction::OpenHotkeyEditor,
         Hotkey {
@@ -968,6 +1149,48 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    welcome.insert(
+        HotkeyAction::CreateProject,
+        Hotkey {
+            code: KeyCode::Char('n'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    welcome.insert(
+        HotkeyAction::EditProject,
+        Hotkey {
+            code: KeyCode::Char('e'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    welcome.insert(
+        HotkeyAction::ArchiveProject,
+        Hotkey {
+            code: KeyCode::Char('a'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    welcome.insert(
+        HotkeyAction::ToggleArchivedProjects,
+        Hotkey {
+            code: KeyCode::Char('v'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    welcome.insert(
+        HotkeyAction::ToggleTeamBrowser,
+        Hotkey {
+            code: KeyCode::Char('g'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    welcome.insert(
+        HotkeyAction::OpenTaskSearch,
+        Hotkey {
+            code: KeyCode::Char('f'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
 
     let mut project_tasks = HashMap::new();
     // Apply global navigation actions
@@ -993,6 +1216,20 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    project_tasks.insert(
+        HotkeyAction::MarkTask,
+        Hotkey {
+            code: KeyCode::Char('v'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    project_tasks.insert(
+        HotkeyAction::AddComment,
+        Hotkey {
+            code: KeyCode::Char('o'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
     project_tasks.insert(
         HotkeyAction::ToggleTaskComplete,
         Hotkey {
@@ -1042,6 +1279,41 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    project_tasks.insert(
+        HotkeyAction::AddSection,
+        Hotkey {
+            code: KeyCode::Char('n'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    project_tasks.insert(
+        HotkeyAction::RenameSection,
+        Hotkey {
+            code: KeyCode::Char('r'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    project_tasks.insert(
+        HotkeyAction::DeleteSection,
+        Hotkey {
+            code: KeyCode::Char('z'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    project_tasks.insert(
+        HotkeyAction::MoveSectionLeft,
+        Hotkey {
+            code: KeyCode::Char('['),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    project_tasks.insert(
+        HotkeyAction::MoveSectionRight,
+        Hotkey {
+            code: KeyCode::Char(']'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
 
     let mut task_detail = HashMap::new();
     // Apply global navigation actions
@@ -1060,6 +1332,111 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    task_detail.insert(
+        HotkeyAction::AddSubtask,
+        Hotkey {
+            code: KeyCode::Char('n'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::AddDependency,
+        Hotkey {
+            code: KeyCode::Char('b'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::RemoveDependency,
+        Hotkey {
+            code: KeyCode::Char('r'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::UploadAttachment,
+        Hotkey {
+            code: KeyCode::Char('u'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::DownloadAttachment,
+        Hotkey {
+            code: KeyCode::Char('s'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::AddToProject,
+        Hotkey {
+            code: KeyCode::Char('p'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::MoveToProject,
+        Hotkey {
+            code: KeyCode::Char('m'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::RemoveFromProject,
+        Hotkey {
+            code: KeyCode::Char('o'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::EditFollowers,
+        Hotkey {
+            code: KeyCode::Char('w'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::ToggleFollow,
+        Hotkey {
+            code: KeyCode::Char('f'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::EditComment,
+        Hotkey {
+            code: KeyCode::Char('i'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::DeleteComment,
+        Hotkey {
+            code: KeyCode::Char('z'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::LikeComment,
+        Hotkey {
+            code: KeyCode::Char('v'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::PinComment,
+        Hotkey {
+            code: KeyCode::Char('t'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_detail.insert(
+        HotkeyAction::ToggleTaskComplete,
+        Hotkey {
+            code: KeyCode::Char('x'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
     task_detail.insert(
         HotkeyAction::DeleteTask,
         Hotkey {
@@ -1113,9 +1490,109 @@ pub fn default_hotkeys() -> ViewHotkeys {
             modifiers: KeyModifiers::empty(),
         },
     );
+    // Only active while the due date calendar is open
+    create_task.insert(
+        HotkeyAction::CalendarPrevMonth,
+        Hotkey {
+            code: KeyCode::Char('H'),
+            modifiers: KeyModifiers::SHIFT,
+        },
+    );
+    create_task.insert(
+        HotkeyAction::CalendarNextMonth,
+        Hotkey {
+            code: KeyCode::Char('L'),
+            modifiers: KeyModifiers::SHIFT,
+        },
+    );
+    create_task.insert(
+        HotkeyAction::CalendarToday,
+        Hotkey {
+            code: KeyCode::Char('t'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
 
     let edit_task = create_task.clone();
 
+    let mut portfolios = HashMap::new();
+    // Apply global navigation actions
+    apply_global_navigation(&mut portfolios);
+    portfolios.insert(
+        HotkeyAction::Select,
+        Hotkey {
+            code: KeyCode::Enter,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    portfolios.insert(
+        HotkeyAction::PostStatusUpdate,
+        Hotkey {
+            code: KeyCode::Char('u'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    portfolios.insert(
+        HotkeyAction::Back,
+        Hotkey {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    portfolios.insert(
+        HotkeyAction::Cancel,
+        Hotkey {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    portfolios.insert(
+        HotkeyAction::Quit,
+        Hotkey {
+            code: KeyCode::Char('q'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+
+    let mut task_search = HashMap::new();
+    // Apply global navigation actions
+    apply_global_navigation(&mut task_search);
+    task_search.insert(
+        HotkeyAction::Select,
+        Hotkey {
+            code: KeyCode::Enter,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_search.insert(
+        HotkeyAction::OpenTaskSearch,
+        Hotkey {
+            code: KeyCode::Char('f'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_search.insert(
+        HotkeyAction::Back,
+        Hotkey {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_search.insert(
+        HotkeyAction::Cancel,
+        Hotkey {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+    task_search.insert(
+        HotkeyAction::Quit,
+        Hotkey {
+            code: KeyCode::Char('q'),
+            modifiers: KeyModifiers::empty(),
+        },
+    );
+
     let mut search_mode = HashMap::new();
     search_mode.insert(
         HotkeyAction::SearchModeExit,
@@ -1246,6 +1723,8 @@ pub fn default_hotkeys() -> ViewHotkeys {
         task_detail,
         create_task,
         edit_task,
+        portfolios,
+        task_search,
         search_mode,
         debug_mode,
         delete_confirmation,
@@ -1272,8 +1751,10 @@ pub fn get_action_for_event(
         View::Welcome => &hotkeys.welcome,
         View::ProjectTasks => &hotkeys.project_tasks,
         View::TaskDetail => &hotkeys.task_detail,
-        View::CreateTask => &hotkeys.create_task,
+        View::CreateTask | View::CreateStatusUpdate => &hotkeys.create_task,
         View::EditTask => &hotkeys.edit_task,
+        View::Portfolios => &hotkeys.portfolios,
+        View::TaskSearch => &hotkeys.task_search,
     };
 
     view_hotkeys
@@ -1561,3 +2042,35 @@ pub fn build_custom_field_instructions(hotkeys: &ViewHotkeys) -> String {
 
     parts.join(", ")
 }
+
+/// Builds the compact key hint shown on the due date calendar (e.g. "h/l k/j H/L t ⏎").
+/// Day and week moves reuse the form's navigation keys.
+///
+pub fn build_calendar_instructions(hotkeys: &ViewHotkeys, view: &View) -> String {
+    let form = match view {
+        View::EditTask => &hotkeys.edit_task,
+        _ => &hotkeys.create_task,
+    };
+    // Shifted letters already read as uppercase, so drop the "Shift+" prefix
+    let key = |action: HotkeyAction| {
+        form.get(&action)
+            .map(|hotkey| match hotkey.code {
+                KeyCode::Char(c) if hotkey.modifiers == KeyModifiers::SHIFT => c.to_string(),
+                KeyCode::Enter => "⏎".to_string(),
+                _ => format_hotkey_display(hotkey),
+            })
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    format!(
+        "{}/{} {}/{} {}/{} {} {}",
+        key(HotkeyAction::NavigateLeft),
+        key(HotkeyAction::NavigateRight),
+        key(HotkeyAction::NavigatePrev),
+        key(HotkeyAction::NavigateNext),
+        key(HotkeyAction::CalendarPrevMonth),
+        key(HotkeyAction::CalendarNextMonth),
+        key(HotkeyAction::CalendarToday),
+        key(HotkeyAction::EditField),
+    )
+}
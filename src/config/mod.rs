@@ -27,8 +27,13 @@ pub struct Config {
     pub access_token: Option<String>,
     pub starred_projects: Vec<String>, // GIDs
     pub starred_project_names: std::collections::HashMap<String, String>, // GID -> Name
+    pub starred_project_workspaces: std::collections::HashMap<String, String>, // GID -> Workspace GID
     pub theme_name: String,
     pub hotkeys: ViewHotkeys,
+    pub api_base_url: String,
+    pub api_base_url_override: Option<String>, // From --api-url, not saved to file
+    pub download_directory: Option<String>,    // Where attachments are saved
+    pub workspace_gid: Option<String>,         // Workspace chosen in the workspace selector
     file_path: Option<PathBuf>,
 }
 
@@ -41,16 +46,41 @@ struct FileSpec {
     pub starred_projects: Vec<String>, // GIDs
     #[serde(default)]
     pub starred_project_names: std::collections::HashMap<String, String>, // GID -> Name
+    #[serde(default)]
+    pub starred_project_workspaces: std::collections::HashMap<String, String>, // GID -> Workspace GID
     #[serde(default = "default_theme_name")]
     pub theme_name: String,
     #[serde(default)]
     pub hotkeys: ViewHotkeys,
+    #[serde(default = "default_api_base_url")]
+    pub api_base_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_directory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_gid: Option<String>,
 }
 
 fn default_theme_name() -> String {
     "tokyo-night".to_string()
 }
 
+fn default_api_base_url() -> String {
+    crate::asana::DEFAULT_BASE_URL.to_string()
+}
+
+/// Return the URL without trailing slashes, or an error if it isn't an
+/// http(s) URL.
+///
+pub fn normalize_api_base_url(url: &str) -> Result<String, ConfigError> {
+    let trimmed = url.trim().trim_end_matches('/');
+    match reqwest::Url::parse(trimmed) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => {
+            Ok(trimmed.to_string())
+        }
+        _ => Err(ConfigError::InvalidApiBaseUrl(url.to_string())),
+    }
+}
+
 impl Config {
     /// Return a new empty instance.
     ///
@@ -60,8 +90,37 @@ impl Config {
             access_token: None,
             starred_projects: vec![],
             starred_project_names: std::collections::HashMap::new(),
+            starred_project_workspaces: std::collections::HashMap::new(),
             theme_name: default_theme_name(),
             hotkeys: ViewHotkeys::default(),
+            api_base_url: default_api_base_url(),
+            api_base_url_override: None,
+            download_directory: None,
+            workspace_gid: None,
+        }
+    }
+
+    /// Return the API base URL for this session, preferring the command line
+    /// override over the configuration file.
+    ///
+    pub fn get_api_base_url(&self) -> &str {
+        self.api_base_url_override
+            .as_deref()
+            .unwrap_or(&self.api_base_url)
+    }
+
+    /// Return the directory attachments are downloaded to: the configured one
+    /// with a leading `~` expanded, else the user's download directory.
+    ///
+    pub fn get_download_directory(&self) -> PathBuf {
+        match &self.download_directory {
+            Some(dir) => match (dir.strip_prefix("~/"), dirs::home_dir()) {
+                (Some(rest), Some(home)) => home.join(rest),
+                _ => PathBuf::from(dir),
+            },
+            None => dirs::download_dir()
+                .or_else(dirs::home_dir)
+                .unwrap_or_else(|| PathBuf::from(".")),
         }
     }
 
@@ -100,9 +159,13 @@ impl Config {
             self.access_token = Some(data.access_token);
             self.starred_projects = data.starred_projects;
             self.starred_project_names = data.starred_project_names;
+            self.starred_project_workspaces = data.starred_project_workspaces;
             self.theme_name = data.theme_name;
             // Merge user overrides with defaults
             self.hotkeys = ViewHotkeys::merge_with_defaults(&data.hotkeys);
+            self.api_base_url = normalize_api_base_url(&data.api_base_url)?;
+            self.download_directory = data.download_directory;
+            self.workspace_gid = data.workspace_gid;
         }
         // Otherwise, leave access_token as None - will be handled in TUI onboarding
         // Don't prompt via stdin, let the TUI handle it
@@ -126,8 +189,12 @@ impl Config {
             access_token: access_token.clone(),
             starred_projects: self.starred_projects.clone(),
             starred_project_names: self.starred_project_names.clone(),
+            starred_project_workspaces: self.starred_project_workspaces.clone(),
             theme_name: self.theme_name.clone(),
             hotkeys: hotkey_overrides,
+            api_base_url: self.api_base_url.clone(),
+            download_directory: self.download_directory.clone(),
+            workspace_gid: self.workspace_gid.clone(),
         };
         let content = serde_yaml::to_string(&data)
             .map_err(|e| ConfigError::SerializationFailed(e.to_string()))?;
@@ -172,8 +239,12 @@ impl Config {
                 .ok_or(ConfigError::AccessTokenNotSet)?,
             starred_projects: self.starred_projects.clone(),
             starred_project_names: self.starred_project_names.clone(),
+            starred_project_workspaces: self.starred_project_workspaces.clone(),
             theme_name: self.theme_name.clone(),
             hotkeys: hotkey_overrides,
+            api_base_url: self.api_base_url.clone(),
+            download_directory: self.download_directory.clone(),
+            workspace_gid: self.workspace_gid.clone(),
         };
         let content = serde_yaml::to_string(&data)
             .map_err(|e| ConfigError::SerializationFailed(e.to_string()))?;
@@ -235,3 +306,82 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_api_base_url_trims_trailing_slash() {
+        assert_eq!(
+            normalize_api_base_url("http://localhost:8080/api/1.0/").unwrap(),
+            "http://localhost:8080/api/1.0"
+        );
+        assert_eq!(
+            normalize_api_base_url(crate::asana::DEFAULT_BASE_URL).unwrap(),
+            crate::asana::DEFAULT_BASE_URL
+        );
+    }
+
+    #[test]
+    fn normalize_api_base_url_rejects_invalid() {
+        assert!(normalize_api_base_url("app.asana.com").is_err());
+        assert!(normalize_api_base_url("ftp://example.com").is_err());
+    }
+
+    #[test]
+    fn download_directory_expands_home() {
+        let mut config = Config::new();
+        config.download_directory = Some("~/asana".to_string());
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(config.get_download_directory(), home.join("asana"));
+        }
+        config.download_directory = Some("/tmp/asana".to_string());
+        assert_eq!(config.get_download_directory(), PathBuf::from("/tmp/asana"));
+    }
+
+    #[test]
+    fn api_base_url_override_takes_precedence() {
+        let mut config = Config::new();
+        assert_eq!(config.get_api_base_url(), crate::asana::DEFAULT_BASE_URL);
+        config.api_base_url_override = Some("http://localhost:8080".to_string());
+        assert_eq!(config.get_api_base_url(), "http://localhost:8080");
+    }
+
+    #[test]
+    fn calendar_keys_follow_form_hotkeys() {
+        use crate::state::View;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use hotkeys::{
+            build_calendar_instructions, default_hotkeys, get_action_for_event,
+            update_hotkey_for_action, Hotkey,
+        };
+
+        let mut hotkeys = default_hotkeys();
+        let shift_h = KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT);
+        assert_eq!(
+            get_action_for_event(&shift_h, &View::EditTask, &hotkeys),
+            Some(HotkeyAction::CalendarPrevMonth)
+        );
+        assert_eq!(
+            build_calendar_instructions(&hotkeys, &View::CreateTask),
+            "h/l k/j H/L t ⏎"
+        );
+
+        // Rebinding today frees 't' for the user's own binding
+        update_hotkey_for_action(
+            &mut hotkeys,
+            &HotkeyAction::CalendarToday,
+            Hotkey {
+                code: KeyCode::Char('g'),
+                modifiers: KeyModifiers::empty(),
+            },
+        );
+        let t = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::empty());
+        assert_eq!(get_action_for_event(&t, &View::CreateTask, &hotkeys), None);
+        assert_eq!(
+            build_calendar_instructions(&hotkeys, &View::EditTask),
+            "h/l k/j H/L g ⏎"
+        );
+    }
+}
@@ -3,12 +3,16 @@
 //! This module handles all network-related events, including Asana API interactions,
 //! task management, project operations, and data synchronization with the application state.
 
-use crate::asana::Asana;
+use crate::asana::{
+    Asana, AsanaError, BatchAction, ResourceEvent, TaskDates, TaskSearch, TypeaheadKind,
+};
 use crate::error::{AppError, AppResult};
 use crate::state::State;
 use crate::utils::text_processing::replace_profile_urls;
+use crate::utils::thumbnails;
+use image::DynamicImage;
 use log::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -22,6 +26,11 @@ pub enum Event {
     },
     Me,
     ProjectTasks,
+    ProjectTasksPage {
+        project_gid: String,
+        offset: String,
+        generation: u64,
+    },
     UpdateTask {
         gid: String,
         completed: Option<bool>,
@@ -44,8 +53,63 @@ pub enum Event {
         task_gid: String,
         text: String,
     },
-    GetWorkspaceUsers {
+    UpdateStory {
+        task_gid: String,
+        story_gid: String,
+        text: String,
+    },
+    DeleteStory {
+        task_gid: String,
+        story_gid: String,
+    },
+    SetStoryLiked {
+        task_gid: String,
+        story_gid: String,
+        liked: bool,
+    },
+    SetStoryPinned {
+        task_gid: String,
+        story_gid: String,
+        pinned: bool,
+    },
+    CreateSubtask {
+        parent_gid: String,
+        name: String,
+    },
+    AddDependency {
+        task_gid: String,
+        dependency_gid: String,
+    },
+    RemoveDependency {
+        task_gid: String,
+        dependency_gid: String,
+    },
+    DownloadAttachment {
+        gid: String,
+    },
+    UploadAttachment {
+        task_gid: String,
+        path: std::path::PathBuf,
+    },
+    LoadAttachmentPreview {
+        gid: String,
+    },
+    GetWorkspaceTags {
+        workspace_gid: String,
+    },
+    Typeahead {
+        workspace_gid: String,
+        kind: TypeaheadKind,
+        query: String,
+    },
+    CreateTag {
         workspace_gid: String,
+        name: String,
+    },
+    SetTaskTags {
+        gid: String,
+        add: Vec<String>,
+        remove: Vec<String>,
     },
     #[allow(dead_code)] // Used via dispatch system
     CreateTask {
@@ -53,8 +117,9 @@ pub enum Event {
         name: String,
         notes: Option<String>,
         assignee: Option<String>,
-        due_on: Option<String>,
+        dates: TaskDates,
         section: Option<String>,
+        tags: Vec<String>,
         custom_fields: HashMap<String, crate::state::CustomFieldValue>,
     },
     #[allow(dead_code)] // Used via dispatch system
@@ -63,15 +128,106 @@ pub enum Event {
         name: Option<String>,
         notes: Option<String>,
         assignee: Option<String>,
-        due_on: Option<String>,
+        dates: TaskDates,
         section: Option<String>,
         completed: Option<bool>,
         custom_fields: HashMap<String, crate::state::CustomFieldValue>,
     },
-    MoveTaskToSection {
-        task_gid: String,
+    UpdateTasks {
+        gids: Vec<String>,
+        completed: bool,
+    },
+    MoveTasksToSection {
+        task_gids: Vec<String>,
         section_gid: String,
     },
+    DeleteTasks {
+        gids: Vec<String>,
+    },
+    CreateStories {
+        task_gids: Vec<String>,
+        text: String,
+    },
+    CreateSection {
+        project_gid: String,
+        name: String,
+    },
+    RenameSection {
+        project_gid: String,
+        section_gid: String,
+        name: String,
+    },
+    DeleteSection {
+        project_gid: String,
+        section_gid: String,
+    },
+    MoveSection {
+        project_gid: String,
+        section_gid: String,
+        before_section: Option<String>,
+        after_section: Option<String>,
+    },
+    SyncProject,
+    MyTasks,
+    GetProjects,
+    GetTeams {
+        workspace_gid: String,
+    },
+    GetTeamProjects {
+        team_gid: String,
+    },
+    CreateProject {
+        workspace_gid: String,
+        team_gid: Option<String>,
+        name: String,
+        notes: String,
+        color: Option<String>,
+    },
+    UpdateProject {
+        gid: String,
+        name: String,
+        notes: String,
+        color: Option<String>,
+    },
+    SetProjectArchived {
+        gid: String,
+        archived: bool,
+    },
+    GetPortfolios,
+    GetPortfolioProjects {
+        portfolio_gid: String,
+    },
+    CreateStatusUpdate {
+        project_gid: String,
+        status_type: String,
+        title: String,
+        text: String,
+    },
+    GetProjectPickerSections {
+        project_gid: String,
+    },
+    SearchTasks {
+        search: TaskSearch,
+        created_before: Option<String>, // Continue after an earlier page
+    },
+    GetSearchProjectFields {
+        project_gid: String,
+    },
+    AddTaskToProject {
+        task_gid: String,
+        project_gid: String,
+        section_gid: Option<String>,
+        remove_project_gid: Option<String>, // Project to leave afterwards, when moving
+    },
+    RemoveTaskFromProject {
+        task_gid: String,
+        project_gid: String,
+    },
+    SetTaskFollowers {
+        task_gid: String,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
 }
 
 /// Specify struct for managing state with network events.
@@ -88,10 +244,46 @@ impl<'a> Handler<'a> {
         Handler { state, asana }
     }
 
-    /// Handle network events by type.
+    /// Handle network events by type, reacting to typed Asana errors before
+    /// returning them.
     ///
     pub async fn handle(&mut self, event: Event) -> AppResult<()> {
         debug!("Processing network event '{:?}'...", event);
+        let result = self.handle_event(event).await;
+        if let Err(AppError::Asana(ref error)) = result {
+            self.handle_asana_error(error).await;
+        }
+        result
+    }
+
+    /// Update state according to the kind of Asana error.
+    ///
+    async fn handle_asana_error(&mut self, error: &AsanaError) {
+        let mut state = self.state.write().await;
+        match error {
+            AsanaError::TaskNotFound { gid } => {
+                warn!("Task {} no longer exists, removing it from view.", gid);
+                state.remove_task(gid);
+            }
+            AsanaError::CustomFieldValidation(message) => {
+                let marked = state.mark_custom_field_error(message);
+                if !marked {
+                    warn!("Could not match custom field error to a field: {}", message);
+                }
+            }
+            error if error.is_unauthorized() => {
+                warn!("Access token was rejected, prompting for a new one.");
+                state.require_access_token(String::from(
+                    "Access token was rejected. Please enter a new personal access token.",
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// Dispatch network event to its handler method.
+    ///
+    async fn handle_event(&mut self, event: Event) -> AppResult<()> {
         match event {
             Event::SetAccessToken { token } => {
                 // Clear any previous error
@@ -118,8 +310,8 @@ impl<'a> Handler<'a> {
 
                     info!("Access token saved to config file successfully.");
 
-                    // Update Asana client with new token
-                    *self.asana = crate::asana::Asana::new(&token);
+                    // Update Asana client with new token, keeping its other settings
+                    self.asana.set_access_token(&token);
 
                     // Fetch user data - this may fail if token is invalid
                     self.me().await?;
@@ -159,6 +351,14 @@ impl<'a> Handler<'a> {
             }
             Event::Me => self.me().await?,
             Event::ProjectTasks => self.project_tasks().await?,
+            Event::ProjectTasksPage {
+                project_gid,
+                offset,
+                generation,
+            } => {
+                self.project_tasks_page(project_gid, Some(offset), generation)
+                    .await?
+            }
             Event::UpdateTask { gid, completed } => self.update_task(gid, completed).await?,
             Event::DeleteTask { gid } => self.delete_task(gid).await?,
             Event::RefreshTasks => self.refresh_tasks().await?,
@@ -170,16 +370,62 @@ impl<'a> Handler<'a> {
                 self.get_project_custom_fields(project_gid).await?
             }
             Event::CreateStory { task_gid, text } => self.create_story(task_gid, text).await?,
-            Event::GetWorkspaceUsers { workspace_gid } => {
-                self.get_workspace_users(workspace_gid).await?
+            Event::UpdateStory {
+                task_gid,
+                story_gid,
+                text,
+            } => self.update_story(task_gid, story_gid, text).await?,
+            Event::DeleteStory {
+                task_gid,
+                story_gid,
+            } => self.delete_story(task_gid, story_gid).await?,
+            Event::SetStoryLiked {
+                task_gid,
+                story_gid,
+                liked,
+            } => self.set_story_liked(task_gid, story_gid, liked).await?,
+            Event::SetStoryPinned {
+                task_gid,
+                story_gid,
+                pinned,
+            } => self.set_story_pinned(task_gid, story_gid, pinned).await?,
+            Event::CreateSubtask { parent_gid, name } => {
+                self.create_subtask(parent_gid, name).await?
             }
+            Event::AddDependency {
+                task_gid,
+                dependency_gid,
+            } => self.add_dependency(task_gid, dependency_gid).await?,
+            Event::RemoveDependency {
+                task_gid,
+                dependency_gid,
+            } => self.remove_dependency(task_gid, dependency_gid).await?,
+            Event::DownloadAttachment { gid } => self.download_attachment(gid).await?,
+            Event::UploadAttachment { task_gid, path } => {
+                self.upload_attachment(task_gid, path).await?
+            }
+            Event::LoadAttachmentPreview { gid } => self.load_attachment_preview(gid).await?,
+            Event::GetWorkspaceTags { workspace_gid } => {
+                self.get_workspace_tags(workspace_gid).await?
+            }
+            Event::Typeahead {
+                workspace_gid,
+                kind,
+                query,
+            } => self.typeahead(workspace_gid, kind, query).await?,
+            Event::CreateTag {
+                workspace_gid,
+                name,
+            } => self.create_tag(workspace_gid, name).await?,
+            Event::SetTaskTags { gid, add, remove } => self.set_task_tags(gid, add, remove).await?,
             Event::CreateTask {
                 project_gid,
                 name,
                 notes,
                 assignee,
-                due_on,
+                dates,
                 section,
+                tags,
                 custom_fields,
             } => {
                 self.create_task(
@@ -187,8 +433,9 @@ impl<'a> Handler<'a> {
                     name,
                     notes,
                     assignee,
-                    due_on,
+                    dates,
                     section,
+                    tags,
                     custom_fields,
                 )
                 .await?
@@ -198,7 +445,7 @@ impl<'a> Handler<'a> {
                 name,
                 notes,
                 assignee,
-                due_on,
+                dates,
                 section,
                 completed,
                 custom_fields,
@@ -208,17 +455,108 @@ impl<'a> Handler<'a> {
                     name,
                     notes,
                     assignee,
-                    due_on,
+                    dates,
                     section,
                     completed,
                     custom_fields,
                 )
                 .await?
             }
-            Event::MoveTaskToSection {
+            Event::UpdateTasks { gids, completed } => self.update_tasks(gids, completed).await?,
+            Event::MoveTasksToSection {
+                task_gids,
+                section_gid,
+            } => self.move_tasks_to_section(task_gids, section_gid).await?,
+            Event::DeleteTasks { gids } => self.delete_tasks(gids).await?,
+            Event::CreateStories { task_gids, text } => {
+                self.create_stories(task_gids, text).await?
+            }
+            Event::CreateSection { project_gid, name } => {
+                self.create_section(project_gid, name).await?
+            }
+            Event::RenameSection {
+                project_gid,
+                section_gid,
+                name,
+            } => self.rename_section(project_gid, section_gid, name).await?,
+            Event::DeleteSection {
+                project_gid,
+                section_gid,
+            } => self.delete_section(project_gid, section_gid).await?,
+            Event::MoveSection {
+                project_gid,
+                section_gid,
+                before_section,
+                after_section,
+            } => {
+                self.move_section(project_gid, section_gid, before_section, after_section)
+                    .await?
+            }
+            Event::SyncProject => self.sync_project().await?,
+            Event::MyTasks => self.my_tasks().await?,
+            Event::GetProjects => self.get_projects().await?,
+            Event::GetTeams { workspace_gid } => self.get_teams(workspace_gid).await?,
+            Event::GetTeamProjects { team_gid } => self.get_team_projects(team_gid).await?,
+            Event::CreateProject {
+                workspace_gid,
+                team_gid,
+                name,
+                notes,
+                color,
+            } => {
+                self.create_project(workspace_gid, team_gid, name, notes, color)
+                    .await?
+            }
+            Event::UpdateProject {
+                gid,
+                name,
+                notes,
+                color,
+            } => self.update_project(gid, name, notes, color).await?,
+            Event::SetProjectArchived { gid, archived } => {
+                self.set_project_archived(gid, archived).await?
+            }
+            Event::GetPortfolios => self.get_portfolios().await?,
+            Event::GetPortfolioProjects { portfolio_gid } => {
+                self.get_portfolio_projects(portfolio_gid).await?
+            }
+            Event::CreateStatusUpdate {
+                project_gid,
+                status_type,
+                title,
+                text,
+            } => {
+                self.create_status_update(project_gid, status_type, title, text)
+                    .await?
+            }
+            Event::GetProjectPickerSections { project_gid } => {
+                self.get_project_picker_sections(project_gid).await?
+            }
+            Event::SearchTasks {
+                search,
+                created_before,
+            } => self.search_tasks(search, created_before).await?,
+            Event::GetSearchProjectFields { project_gid } => {
+                self.get_search_project_fields(project_gid).await?
+            }
+            Event::AddTaskToProject {
                 task_gid,
+                project_gid,
                 section_gid,
-            } => self.move_task_to_section(task_gid, section_gid).await?,
+                remove_project_gid,
+            } => {
+                self.add_task_to_project(task_gid, project_gid, section_gid, remove_project_gid)
+                    .await?
+            }
+            Event::RemoveTaskFromProject {
+                task_gid,
+                project_gid,
+            } => self.remove_task_from_project(task_gid, project_gid).await?,
+            Event::SetTaskFollowers {
+                task_gid,
+                add,
+                remove,
+            } => self.set_task_followers(task_gid, add, remove).await?,
         }
         Ok(())
     }
@@ -232,24 +570,24 @@ impl<'a> Handler<'a> {
             .asana
             .me()
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
         {
             let mut state = self.state.write().await;
             state.set_user(user);
             if !workspaces.is_empty() {
+                // Keep the workspace saved in config if the user still belongs to it
+                let workspace_gid = workspaces
+                    .iter()
+                    .find(|w| Some(w.gid.as_str()) == state.get_active_workspace_gid())
+                    .unwrap_or(&workspaces[0])
+                    .gid
+                    .clone();
                 state.set_workspaces(workspaces.clone());
-                state.set_active_workspace(workspaces[0].gid.clone());
+                state.set_active_workspace(workspace_gid);
             }
         }
         if !workspaces.is_empty() {
-            info!("Fetching projects for active workspace...");
-            let projects = self
-                .asana
-                .projects(&workspaces[0].gid)
-                .await
-                .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
-            let mut state = self.state.write().await;
-            state.set_projects(projects);
+            self.get_projects().await?;
         }
         info!("Loaded initial application data.");
         Ok(())
@@ -258,55 +596,76 @@ impl<'a> Handler<'a> {
     /// Update state with tasks for project.
     ///
     async fn project_tasks(&mut self) -> AppResult<()> {
-        let project;
-        let workspace_gid;
-        {
-            let state = self.state.read().await;
-            project = match state.get_project() {
-                Some(p) => p.clone(),
+        let (project_gid, generation) = {
+            let mut state = self.state.write().await;
+            match state.get_project() {
+                Some(p) => (p.gid.clone(), state.start_tasks_load()),
                 None => {
                     warn!("Skipping tasks request for unset project.");
                     return Ok(());
                 }
-            };
-            workspace_gid = state.get_active_workspace().map(|w| w.gid.clone());
-        }
-        info!(
-            "Fetching tasks for project '{}' (GID: {})...",
-            &project.name, &project.gid
-        );
-        // Always include completed tasks since we're using kanban view
-        let include_completed = true;
+            }
+        };
+        info!("Fetching tasks for project {}...", &project_gid);
+        self.project_tasks_page(project_gid, None, generation).await
+    }
+
+    /// Update state with a page of tasks for project, starting over without
+    /// an offset. The next page is queued as its own event so other requests
+    /// are handled while the board streams in. Pages of a load that has since
+    /// been started over are dropped, even once fetched.
+    ///
+    async fn project_tasks_page(
+        &mut self,
+        project_gid: String,
+        offset: Option<String>,
+        generation: u64,
+    ) -> AppResult<()> {
+        let project;
+        let my_tasks;
         let view_mode;
         {
             let state = self.state.read().await;
+            project = match state.get_project() {
+                Some(p) if p.gid == project_gid => p.clone(),
+                _ => {
+                    info!(
+                        "Project changed while fetching tasks for project {}; dropping remaining pages.",
+                        &project_gid
+                    );
+                    return Ok(());
+                }
+            };
+            // Pages of a load started over since are dropped
+            if !state.is_current_tasks_load(generation) {
+                return Ok(());
+            }
+            my_tasks = state.is_my_tasks();
             view_mode = state.get_view_mode();
         }
+        // Always include completed tasks since we're using kanban view, except
+        // in My Tasks where they would span every task ever assigned to the user
+        let include_completed = !my_tasks;
 
-        let tasks_result = self
-            .asana
-            .tasks(&project.gid, workspace_gid.as_deref(), include_completed)
-            .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())));
+        let page_result = if my_tasks {
+            self.asana
+                .user_task_list_tasks_page(&project.gid, include_completed, offset.as_deref())
+                .await
+        } else {
+            self.asana
+                .tasks_page(&project.gid, include_completed, offset.as_deref())
+                .await
+        }
+        .map_err(|e| AppError::Asana(e.into()));
 
-        match tasks_result {
-            Ok(tasks) => {
-                info!(
-                    "Received {} tasks for project '{}'.",
-                    tasks.len(),
-                    &project.name
-                );
+        let (tasks, next) = match page_result {
+            Ok(page) => page,
+            Err(e) => {
                 let mut state = self.state.write().await;
-                state.set_tasks(tasks);
-                // If in kanban mode, also load sections
-                if view_mode == crate::state::ViewMode::Kanban {
-                    let project_gid = project.gid.clone();
-                    drop(state);
-                    self.get_project_sections(project_gid).await?;
+                if state.is_current_tasks_load(generation) {
+                    state.set_next_tasks_offset(None);
                 }
-                Ok(())
-            }
-            Err(e) => {
+                drop(state);
                 error!(
                     "Failed to fetch tasks for project '{}' (GID: {}): {}",
                     &project.name, &project.gid, e
@@ -317,9 +676,50 @@ impl<'a> Handler<'a> {
                     error!("  Project tasks error chain - Caused by: {}", err);
                     source = err.source();
                 }
-                Err(e)
+                return Err(e);
+            }
+        };
+
+        let first_page = offset.is_none();
+        {
+            let mut state = self.state.write().await;
+            // Stop if the user switched projects while the page was loading
+            if state.get_project().map(|p| p.gid.as_str()) != Some(project.gid.as_str()) {
+                info!(
+                    "Project changed while fetching tasks for '{}'; dropping remaining pages.",
+                    &project.name
+                );
+                return Ok(());
+            }
+            // A reload may have started while the page was loading
+            if !state.is_current_tasks_load(generation) {
+                return Ok(());
             }
+            if first_page {
+                state.set_tasks(tasks);
+            } else {
+                state.append_tasks(tasks);
+            }
+            state.set_next_tasks_offset(next.clone());
+            match next {
+                Some(offset) => state.dispatch(Event::ProjectTasksPage {
+                    project_gid: project.gid.clone(),
+                    offset,
+                    generation,
+                }),
+                None => info!(
+                    "Received {} tasks for project '{}'.",
+                    state.get_tasks().len(),
+                    &project.name
+                ),
+            }
+        }
+
+        // If in kanban mode, load sections once the first page is shown
+        if first_page && view_mode == crate::state::ViewMode::Kanban {
+            self.get_project_sections(project.gid.clone()).await?;
         }
+        Ok(())
     }
 
     /// Update state with tasks assigned to the user.
@@ -328,13 +728,117 @@ impl<'a> Handler<'a> {
         self.asana
             .update_task(&task_gid, completed)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
         info!("Task {} updated successfully.", task_gid);
         // Refresh the current task list
         self.refresh_current_view().await?;
         Ok(())
     }
 
+    /// Mark several tasks complete or incomplete in batched requests.
+    ///
+    async fn update_tasks(&mut self, gids: Vec<String>, completed: bool) -> AppResult<()> {
+        info!(
+            "Updating {} tasks (completed: {})...",
+            gids.len(),
+            completed
+        );
+        let actions = gids
+            .iter()
+            .map(|gid| BatchAction::update_task(gid, serde_json::json!({ "completed": completed })))
+            .collect();
+        self.run_batch("update", &gids, actions).await?;
+        self.refresh_current_view().await?;
+        Ok(())
+    }
+
+    /// Move one or more tasks to a section in batched requests.
+    ///
+    async fn move_tasks_to_section(
+        &mut self,
+        task_gids: Vec<String>,
+        section_gid: String,
+    ) -> AppResult<()> {
+        info!(
+            "Moving {} tasks to section {}...",
+            task_gids.len(),
+            section_gid
+        );
+        let actions = task_gids
+            .iter()
+            .map(|gid| BatchAction::add_task_to_section(gid, &section_gid))
+            .collect();
+        self.run_batch("move", &task_gids, actions).await?;
+        self.project_tasks().await?;
+        Ok(())
+    }
+
+    /// Delete several tasks in batched requests.
+    ///
+    async fn delete_tasks(&mut self, gids: Vec<String>) -> AppResult<()> {
+        info!("Deleting {} tasks...", gids.len());
+        let actions = gids
+            .iter()
+            .map(|gid| BatchAction::delete_task(gid))
+            .collect();
+        self.run_batch("delete", &gids, actions).await?;
+        self.refresh_current_view().await?;
+        Ok(())
+    }
+
+    /// Add the same comment to several tasks in batched requests.
+    ///
+    async fn create_stories(&mut self, task_gids: Vec<String>, text: String) -> AppResult<()> {
+        info!("Commenting on {} tasks...", task_gids.len());
+        let actions = task_gids
+            .iter()
+            .map(|gid| BatchAction::create_story(gid, &text))
+            .collect();
+        self.run_batch("comment on", &task_gids, actions).await?;
+        Ok(())
+    }
+
+    /// Send actions on the given tasks through the Batch API, naming each task
+    /// whose action failed and reacting to it the same way as to a failed
+    /// single request.
+    ///
+    async fn run_batch(
+        &mut self,
+        verb: &str,
+        task_gids: &[String],
+        actions: Vec<BatchAction>,
+    ) -> AppResult<()> {
+        let total = actions.len();
+        let results = self
+            .asana
+            .batch(actions)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut failed = 0;
+        for (gid, result) in task_gids.iter().zip(results) {
+            if let Err(error) = result.into_result() {
+                failed += 1;
+                let name = self
+                    .state
+                    .read()
+                    .await
+                    .get_tasks()
+                    .iter()
+                    .find(|t| &t.gid == gid)
+                    .map(|t| format!("'{}'", t.name))
+                    .unwrap_or_else(|| gid.clone());
+                error!("Failed to {} task {}: {}", verb, name, error);
+                self.handle_asana_error(&error).await;
+            }
+        }
+        if failed > 0 {
+            warn!("{} of {} batch actions failed.", failed, total);
+        } else {
+            info!("{} batch actions completed successfully.", total);
+        }
+        Ok(())
+    }
+
     /// Delete a task.
     ///
     async fn delete_task(&mut self, task_gid: String) -> AppResult<()> {
@@ -342,7 +846,7 @@ impl<'a> Handler<'a> {
         self.asana
             .delete_task(&task_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
         info!("Task {} deleted successfully.", task_gid);
         // Refresh the current task list
         self.refresh_current_view().await?;
@@ -356,19 +860,235 @@ impl<'a> Handler<'a> {
         Ok(())
     }
 
-    /// Refresh the current view if it's ProjectTasks.
+    /// Poll the Events API for changes made elsewhere to the open project and
+    /// apply them to state in place.
     ///
-    async fn refresh_current_view(&mut self) -> AppResult<()> {
-        let view;
-        {
+    async fn sync_project(&mut self) -> AppResult<()> {
+        let (project_gid, token) = {
             let state = self.state.read().await;
-            view = state.current_view().clone();
+            match state.get_project() {
+                // The Events API doesn't accept My Tasks lists as a resource
+                Some(_) if state.is_my_tasks() => return Ok(()),
+                Some(p) => (p.gid.clone(), state.get_sync_token(&p.gid).cloned()),
+                None => return Ok(()),
+            }
+        };
+
+        let mut events = vec![];
+        let mut sync = token.clone();
+        let mut reset = false;
+        loop {
+            let batch = self
+                .asana
+                .events(&project_gid, sync.as_deref())
+                .await
+                .map_err(|e| AppError::Asana(e.into()))?;
+            sync = Some(batch.sync);
+            if batch.reset {
+                reset = true;
+                break;
+            }
+            events.extend(batch.events);
+            if !batch.has_more {
+                break;
+            }
         }
-        if view == crate::state::View::ProjectTasks {
-            self.project_tasks().await?;
+
+        if !self.is_current_project(&project_gid).await {
+            return Ok(());
+        }
+        if let Some(sync) = sync {
+            self.state
+                .write()
+                .await
+                .set_sync_token(project_gid.clone(), sync);
+        }
+        if reset {
+            // Changes since an expired token can't be replayed, so reload the board
+            if token.is_some() {
+                warn!(
+                    "Sync token for project {} expired, reloading tasks.",
+                    project_gid
+                );
+                self.project_tasks().await?;
+            }
+            return Ok(());
+        }
+        if events.is_empty() {
+            return Ok(());
         }
-        Ok(())
-    }
+
+        info!(
+            "Applying {} changes to project {}...",
+            events.len(),
+            project_gid
+        );
+        self.apply_project_events(&project_gid, events).await
+    }
+
+    /// Apply project events to state: changed tasks are fetched again with the
+    /// board's fields, removed ones dropped, and sections reloaded when they
+    /// change.
+    ///
+    async fn apply_project_events(
+        &mut self,
+        project_gid: &str,
+        events: Vec<ResourceEvent>,
+    ) -> AppResult<()> {
+        let mut tasks: Vec<(String, bool)> = vec![]; // (gid, removed), latest event wins
+        let mut story_tasks = HashSet::new();
+        let mut removed_sections = vec![];
+        let mut reload_sections = false;
+        for event in events {
+            let removed = match event.action.as_str() {
+                "deleted" => true,
+                // Leaving a section means joining another; only leaving the project removes it
+                "removed" => event.parent_type.as_deref() != Some("section"),
+                _ => false,
+            };
+            match event.resource_type.as_str() {
+                "task" => {
+                    tasks.retain(|(gid, _)| *gid != event.resource_gid);
+                    tasks.push((event.resource_gid, removed));
+                }
+                "section" if removed => removed_sections.push(event.resource_gid),
+                "section" => reload_sections = true,
+                "story" if event.parent_type.as_deref() == Some("task") => {
+                    story_tasks.extend(event.parent_gid);
+                }
+                _ => {}
+            }
+        }
+
+        // New comments refresh tasks already on the board
+        let (detail_gid, user_map) = {
+            let state = self.state.read().await;
+            for gid in &story_tasks {
+                let on_board = state.get_tasks().iter().any(|t| &t.gid == gid);
+                if on_board && !tasks.iter().any(|(g, _)| g == gid) {
+                    tasks.push((gid.clone(), false));
+                }
+            }
+            let user_map: HashMap<String, String> = state
+                .get_workspace_users()
+                .iter()
+                .map(|u| (u.gid.clone(), u.name.clone()))
+                .collect();
+            (state.get_task_detail().map(|t| t.gid.clone()), user_map)
+        };
+
+        for (gid, removed) in tasks {
+            let result = if removed {
+                Ok(None)
+            } else {
+                self.asana
+                    .get_project_task(project_gid, &gid)
+                    .await
+                    .map_err(|e| AppError::Asana(e.into()))
+            };
+            if !self.is_current_project(project_gid).await {
+                return Ok(());
+            }
+            let mut state = self.state.write().await;
+            match result {
+                Ok(Some(task)) if detail_gid.as_deref() == Some(gid.as_str()) => {
+                    drop(state);
+                    // Only the open detail needs the full task
+                    let detail = self.asana.get_task(&gid).await;
+                    let mut state = self.state.write().await;
+                    match detail {
+                        Ok(mut detail) => {
+                            if let Some(ref mut notes) = detail.notes {
+                                *notes = replace_profile_urls(notes, &user_map);
+                            }
+                            state.refresh_task_detail(detail);
+                        }
+                        Err(e) => warn!("Failed to sync details of task {}: {}", gid, e),
+                    }
+                    state.upsert_task(task);
+                    state.highlight_task(gid);
+                }
+                Ok(Some(task)) => {
+                    // Tasks hidden by the filter are kept for when it's cleared
+                    let matches = state.task_matches_filter(&task);
+                    state.upsert_task(task);
+                    if matches {
+                        state.highlight_task(gid);
+                    }
+                }
+                // Subtasks and tasks moved to other projects don't belong on the board
+                Ok(None) | Err(AppError::Asana(AsanaError::TaskNotFound { .. })) => {
+                    state.remove_task(&gid);
+                }
+                Err(e) => warn!("Failed to sync task {}: {}", gid, e),
+            }
+        }
+
+        if !removed_sections.is_empty() {
+            let mut state = self.state.write().await;
+            for gid in &removed_sections {
+                state.remove_section(gid);
+            }
+        }
+        if reload_sections {
+            self.get_project_sections(project_gid.to_string()).await?;
+        }
+        if let Some(detail_gid) = detail_gid.filter(|gid| story_tasks.contains(gid)) {
+            self.get_task_stories(detail_gid).await?;
+        }
+        Ok(())
+    }
+
+    /// Resolve the current user's My Tasks list in the active workspace, then
+    /// load its tasks like a project's.
+    ///
+    async fn my_tasks(&mut self) -> AppResult<()> {
+        let workspace_gid = {
+            let state = self.state.read().await;
+            match state.get_active_workspace() {
+                Some(workspace) => workspace.gid.clone(),
+                None => {
+                    warn!("Skipping My Tasks request for unset workspace.");
+                    return Ok(());
+                }
+            }
+        };
+        info!("Fetching My Tasks list for workspace {}...", workspace_gid);
+        let list = self
+            .asana
+            .user_task_list(&workspace_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        {
+            let mut state = self.state.write().await;
+            if !state.is_my_tasks() {
+                return Ok(());
+            }
+            state.set_my_tasks_list(list);
+        }
+        self.project_tasks().await
+    }
+
+    /// Return whether the given project is still the one open.
+    ///
+    async fn is_current_project(&self, project_gid: &str) -> bool {
+        let state = self.state.read().await;
+        state.get_project().map(|p| p.gid.as_str()) == Some(project_gid)
+    }
+
+    /// Refresh the current view if it's ProjectTasks.
+    ///
+    async fn refresh_current_view(&mut self) -> AppResult<()> {
+        let view;
+        {
+            let state = self.state.read().await;
+            view = state.current_view().clone();
+        }
+        if view == crate::state::View::ProjectTasks {
+            self.project_tasks().await?;
+        }
+        Ok(())
+    }
 
     /// Get full task details.
     ///
@@ -378,7 +1098,7 @@ impl<'a> Handler<'a> {
             .asana
             .get_task(&task_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
 
         // Process task notes - replace profile URLs with @username when data comes from API
         let user_map: HashMap<String, String> = {
@@ -408,8 +1128,9 @@ impl<'a> Handler<'a> {
         }
 
         // Fetch assignees, sections, and custom fields on the fly
-        if let Some(workspace_gid) = workspace_gid {
-            self.get_workspace_users(workspace_gid).await?;
+        if workspace_gid.is_some() {
+            let mut state = self.state.write().await;
+            state.request_typeahead(TypeaheadKind::User, "");
         }
         if let Some(project_gid) = project_gid {
             let project_gid_clone = project_gid.clone();
@@ -417,9 +1138,21 @@ impl<'a> Handler<'a> {
             self.get_project_custom_fields(project_gid).await?;
         }
 
-        // Also load stories/comments (which will also be processed)
-        self.get_task_stories(task_gid).await?;
-        info!("Task details loaded successfully.");
+        // Also load subtasks, attachments and stories/comments (which will also be processed).
+        // Each loads on its own so one failing leaves the task and the others shown.
+        if let Err(e) = self.get_task_subtasks(task_gid.clone()).await {
+            warn!("Failed to load subtasks of task {}: {}", task_gid, e);
+            self.state.write().await.set_task_subtasks(vec![]);
+        }
+        if let Err(e) = self.get_task_attachments(task_gid.clone()).await {
+            warn!("Failed to load attachments of task {}: {}", task_gid, e);
+            self.state.write().await.set_task_attachments(vec![]);
+        }
+        if let Err(e) = self.get_task_stories(task_gid.clone()).await {
+            warn!("Failed to load comments of task {}: {}", task_gid, e);
+            self.state.write().await.set_task_stories(vec![]);
+        }
+        info!("Task details loaded.");
         Ok(())
     }
 
@@ -431,13 +1164,421 @@ impl<'a> Handler<'a> {
             .asana
             .get_project_sections(&project_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
         let mut state = self.state.write().await;
         state.set_sections(sections);
         info!("Sections loaded successfully.");
         Ok(())
     }
 
+    /// Add a section to the end of a project, then reload the board columns.
+    ///
+    async fn create_section(&mut self, project_gid: String, name: String) -> AppResult<()> {
+        info!("Creating section '{}' in project {}...", name, project_gid);
+        self.asana
+            .create_section(&project_gid, &name)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Section '{}' created successfully.", name);
+        self.get_project_sections(project_gid).await
+    }
+
+    /// Rename a section, then reload the board columns.
+    ///
+    async fn rename_section(
+        &mut self,
+        project_gid: String,
+        section_gid: String,
+        name: String,
+    ) -> AppResult<()> {
+        info!("Renaming section {} to '{}'...", section_gid, name);
+        self.asana
+            .rename_section(&section_gid, &name)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Section renamed successfully.");
+        self.get_project_sections(project_gid).await
+    }
+
+    /// Delete an empty section, then reload the board columns.
+    ///
+    async fn delete_section(&mut self, project_gid: String, section_gid: String) -> AppResult<()> {
+        info!("Deleting section {}...", section_gid);
+        self.asana
+            .delete_section(&section_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Section deleted successfully.");
+        self.get_project_sections(project_gid).await
+    }
+
+    /// Move a section next to another one, then reload the board columns.
+    ///
+    async fn move_section(
+        &mut self,
+        project_gid: String,
+        section_gid: String,
+        before_section: Option<String>,
+        after_section: Option<String>,
+    ) -> AppResult<()> {
+        info!("Moving section {}...", section_gid);
+        self.asana
+            .insert_section(
+                &project_gid,
+                &section_gid,
+                before_section.as_deref(),
+                after_section.as_deref(),
+            )
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Section moved successfully.");
+        self.get_project_sections(project_gid).await
+    }
+
+    /// Update state with the projects of the active workspace, including
+    /// archived ones when they are shown. While the team browser is shown,
+    /// only the projects of its expanded teams are reloaded instead.
+    ///
+    async fn get_projects(&mut self) -> AppResult<()> {
+        {
+            let mut state = self.state.write().await;
+            if state.is_team_browser() {
+                state.refresh_team_projects();
+                return Ok(());
+            }
+        }
+        let (workspace_gid, include_archived) = {
+            let state = self.state.read().await;
+            match state.get_active_workspace() {
+                Some(workspace) => (workspace.gid.clone(), state.shows_archived_projects()),
+                None => {
+                    warn!("Skipping projects request for unset workspace.");
+                    return Ok(());
+                }
+            }
+        };
+        info!("Fetching projects for active workspace...");
+        let projects = self
+            .asana
+            .projects(&workspace_gid, include_archived)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.set_projects(projects);
+        Ok(())
+    }
+
+    /// Update state with the teams of the current user in a workspace.
+    ///
+    async fn get_teams(&mut self, workspace_gid: String) -> AppResult<()> {
+        info!("Fetching teams for workspace {}...", workspace_gid);
+        let teams = self
+            .asana
+            .teams(&workspace_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.set_teams(teams);
+        info!("Teams loaded successfully.");
+        Ok(())
+    }
+
+    /// Update state with the projects of a team, for the team browser.
+    ///
+    async fn get_team_projects(&mut self, team_gid: String) -> AppResult<()> {
+        info!("Fetching projects for team {}...", team_gid);
+        let include_archived = self.state.read().await.shows_archived_projects();
+        let projects = self
+            .asana
+            .team_projects(&team_gid, include_archived)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.set_team_projects(team_gid, projects);
+        Ok(())
+    }
+
+    /// Update state with the portfolios of the current user, then with the
+    /// projects of each portfolio.
+    ///
+    async fn get_portfolios(&mut self) -> AppResult<()> {
+        let workspace_gid = {
+            let state = self.state.read().await;
+            match state.get_active_workspace() {
+                Some(workspace) => workspace.gid.clone(),
+                None => {
+                    warn!("Skipping portfolios request for unset workspace.");
+                    return Ok(());
+                }
+            }
+        };
+        info!("Fetching portfolios for workspace {}...", workspace_gid);
+        let portfolios = self
+            .asana
+            .portfolios(&workspace_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.state.write().await.set_portfolios(portfolios);
+        info!("Portfolios loaded successfully.");
+        Ok(())
+    }
+
+    /// Load the projects of a portfolio, showing why under the portfolio if
+    /// they fail to load.
+    ///
+    async fn get_portfolio_projects(&mut self, portfolio_gid: String) -> AppResult<()> {
+        info!("Fetching projects for portfolio {}...", portfolio_gid);
+        match self.asana.portfolio_projects(&portfolio_gid).await {
+            Ok(projects) => {
+                self.state
+                    .write()
+                    .await
+                    .set_portfolio_projects(portfolio_gid, projects);
+                info!("Portfolio projects loaded successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                self.state
+                    .write()
+                    .await
+                    .set_portfolio_projects_error(portfolio_gid, e.to_string());
+                Err(AppError::Asana(e.into()))
+            }
+        }
+    }
+
+    /// Post a status update on a project and show it as the project's
+    /// latest status.
+    ///
+    async fn create_status_update(
+        &mut self,
+        project_gid: String,
+        status_type: String,
+        title: String,
+        text: String,
+    ) -> AppResult<()> {
+        info!("Posting status update on project {}...", project_gid);
+        let status_update = self
+            .asana
+            .create_status_update(&project_gid, &status_type, &title, &text)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.set_project_status_update(&project_gid, status_update);
+        info!("Status update posted.");
+        Ok(())
+    }
+
+    /// Fetch a page of workspace-wide task search results.
+    ///
+    async fn search_tasks(
+        &mut self,
+        search: TaskSearch,
+        created_before: Option<String>,
+    ) -> AppResult<()> {
+        let workspace_gid = {
+            let state = self.state.read().await;
+            match state.get_active_workspace() {
+                Some(workspace) => workspace.gid.clone(),
+                None => {
+                    warn!("Skipping task search for unset workspace.");
+                    return Ok(());
+                }
+            }
+        };
+        info!("Searching tasks in workspace {}...", workspace_gid);
+        let result = self
+            .asana
+            .search_tasks(&workspace_gid, &search, created_before.as_deref())
+            .await;
+        let mut state = self.state.write().await;
+        match result {
+            Ok((tasks, next)) => {
+                info!("Found {} tasks.", tasks.len());
+                state.add_task_search_results(&search, tasks, next);
+                Ok(())
+            }
+            Err(e) => {
+                state.fail_task_search();
+                Err(AppError::Asana(e.into()))
+            }
+        }
+    }
+
+    /// Fetch the sections and custom fields a task search can filter by
+    /// within a project.
+    ///
+    async fn get_search_project_fields(&mut self, project_gid: String) -> AppResult<()> {
+        info!("Fetching search filters for project {}...", project_gid);
+        let sections = self
+            .asana
+            .get_project_sections(&project_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let custom_fields = self
+            .asana
+            .get_project_custom_fields(&project_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.set_search_project_fields(project_gid, sections, custom_fields);
+        Ok(())
+    }
+
+    /// Create a project, then reload the projects list.
+    ///
+    async fn create_project(
+        &mut self,
+        workspace_gid: String,
+        team_gid: Option<String>,
+        name: String,
+        notes: String,
+        color: Option<String>,
+    ) -> AppResult<()> {
+        info!("Creating project '{}'...", name);
+        self.asana
+            .create_project(
+                &workspace_gid,
+                team_gid.as_deref(),
+                &name,
+                &notes,
+                color.as_deref(),
+            )
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Project '{}' created successfully.", name);
+        self.get_projects().await
+    }
+
+    /// Update the name, notes and color of a project, then reload the
+    /// projects list.
+    ///
+    async fn update_project(
+        &mut self,
+        gid: String,
+        name: String,
+        notes: String,
+        color: Option<String>,
+    ) -> AppResult<()> {
+        info!("Updating project {}...", gid);
+        self.asana
+            .update_project(&gid, &name, &notes, color.as_deref())
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Project updated successfully.");
+        self.get_projects().await
+    }
+
+    /// Archive or unarchive a project, then reload the projects list.
+    ///
+    async fn set_project_archived(&mut self, gid: String, archived: bool) -> AppResult<()> {
+        info!("Setting archived={} on project {}...", archived, gid);
+        self.asana
+            .set_project_archived(&gid, archived)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Project archive state updated successfully.");
+        self.get_projects().await
+    }
+
+    /// Update the project picker with the sections of its chosen project.
+    ///
+    async fn get_project_picker_sections(&mut self, project_gid: String) -> AppResult<()> {
+        let sections = self
+            .asana
+            .get_project_sections(&project_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()));
+        let mut state = self.state.write().await;
+        match sections {
+            Ok(sections) => {
+                state.set_project_picker_sections(&project_gid, sections);
+                Ok(())
+            }
+            Err(e) => {
+                // Still let the task be added to the project, just without a section
+                state.set_project_picker_sections_error(&project_gid, e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Add a task to a project, leaving another one when moving, then
+    /// refresh what shows it.
+    ///
+    async fn add_task_to_project(
+        &mut self,
+        task_gid: String,
+        project_gid: String,
+        section_gid: Option<String>,
+        remove_project_gid: Option<String>,
+    ) -> AppResult<()> {
+        info!("Adding task {} to project {}...", task_gid, project_gid);
+        self.asana
+            .add_project_to_task(&task_gid, &project_gid, section_gid.as_deref())
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        match remove_project_gid {
+            Some(remove_project_gid) => {
+                self.remove_task_from_project(task_gid, remove_project_gid)
+                    .await?
+            }
+            None => self.refresh_linked_tasks(&[task_gid]).await?,
+        }
+        info!("Task added to project successfully.");
+        Ok(())
+    }
+
+    /// Remove a task from a project, then refresh what shows it. A task that
+    /// leaves the open project also leaves its board.
+    ///
+    async fn remove_task_from_project(
+        &mut self,
+        task_gid: String,
+        project_gid: String,
+    ) -> AppResult<()> {
+        info!("Removing task {} from project {}...", task_gid, project_gid);
+        self.asana
+            .remove_project_from_task(&task_gid, &project_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        {
+            let mut state = self.state.write().await;
+            if state.get_project().map(|p| &p.gid) == Some(&project_gid) {
+                state.remove_board_task(&task_gid);
+            }
+        }
+        self.refresh_linked_tasks(&[task_gid]).await?;
+        info!("Task removed from project successfully.");
+        Ok(())
+    }
+
+    /// Add and remove followers of a task, then refresh what shows it.
+    ///
+    async fn set_task_followers(
+        &mut self,
+        task_gid: String,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> AppResult<()> {
+        info!("Updating followers of task {}...", task_gid);
+        if !add.is_empty() {
+            self.asana
+                .add_followers(&task_gid, &add)
+                .await
+                .map_err(|e| AppError::Asana(e.into()))?;
+        }
+        if !remove.is_empty() {
+            self.asana
+                .remove_followers(&task_gid, &remove)
+                .await
+                .map_err(|e| AppError::Asana(e.into()))?;
+        }
+        self.refresh_linked_tasks(&[task_gid]).await?;
+        info!("Followers updated successfully.");
+        Ok(())
+    }
+
     /// Get custom fields for a project.
     ///
     async fn get_project_custom_fields(&mut self, project_gid: String) -> AppResult<()> {
@@ -446,7 +1587,7 @@ impl<'a> Handler<'a> {
             .asana
             .get_project_custom_fields(&project_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
 
         {
             let mut state = self.state.write().await;
@@ -467,7 +1608,7 @@ impl<'a> Handler<'a> {
             .asana
             .get_task_stories(&task_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
 
         // Process URLs when data comes from API - replace profile URLs with @username
         let user_map: HashMap<String, String> = {
@@ -499,25 +1640,447 @@ impl<'a> Handler<'a> {
         self.asana
             .create_story(&task_gid, &text)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
         // Refresh stories after creating
         self.get_task_stories(task_gid).await?;
         info!("Comment created successfully.");
         Ok(())
     }
 
+    /// Change the text of a comment, then reload the task's comments.
+    ///
+    async fn update_story(
+        &mut self,
+        task_gid: String,
+        story_gid: String,
+        text: String,
+    ) -> AppResult<()> {
+        info!("Editing comment {} on task {}...", story_gid, task_gid);
+        self.asana
+            .update_story(&story_gid, &text)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.get_task_stories(task_gid).await?;
+        info!("Comment edited successfully.");
+        Ok(())
+    }
+
+    /// Delete a comment, then reload the task's comments.
+    ///
+    async fn delete_story(&mut self, task_gid: String, story_gid: String) -> AppResult<()> {
+        info!("Deleting comment {} on task {}...", story_gid, task_gid);
+        self.asana
+            .delete_story(&story_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.get_task_stories(task_gid).await?;
+        info!("Comment deleted successfully.");
+        Ok(())
+    }
+
+    /// Like or unlike a comment, then reload the task's comments.
+    ///
+    async fn set_story_liked(
+        &mut self,
+        task_gid: String,
+        story_gid: String,
+        liked: bool,
+    ) -> AppResult<()> {
+        info!("Setting liked={} on comment {}...", liked, story_gid);
+        self.asana
+            .set_story_liked(&story_gid, liked)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.get_task_stories(task_gid).await?;
+        Ok(())
+    }
+
+    /// Pin or unpin a comment, then reload the task's comments.
+    ///
+    async fn set_story_pinned(
+        &mut self,
+        task_gid: String,
+        story_gid: String,
+        pinned: bool,
+    ) -> AppResult<()> {
+        info!("Setting pinned={} on comment {}...", pinned, story_gid);
+        self.asana
+            .set_story_pinned(&story_gid, pinned)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.get_task_stories(task_gid).await?;
+        Ok(())
+    }
+
+    /// Get the subtasks of a task.
+    ///
+    async fn get_task_subtasks(&mut self, task_gid: String) -> AppResult<()> {
+        info!("Fetching subtasks for task {}...", task_gid);
+        let subtasks = self
+            .asana
+            .get_subtasks(&task_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        // Skip if the user has moved on to another task meanwhile
+        if state.get_task_detail().map(|t| t.gid.as_str()) == Some(task_gid.as_str()) {
+            state.set_task_subtasks(subtasks);
+        }
+        info!("Subtasks loaded successfully.");
+        Ok(())
+    }
+
+    /// Create a subtask under a task.
+    ///
+    async fn create_subtask(&mut self, parent_gid: String, name: String) -> AppResult<()> {
+        info!("Creating subtask under task {}...", parent_gid);
+        self.asana
+            .create_subtask(&parent_gid, &name)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        // Refresh subtasks after creating
+        self.get_task_subtasks(parent_gid).await?;
+        info!("Subtask created successfully.");
+        Ok(())
+    }
+
+    /// Get the attachments of a task.
+    ///
+    async fn get_task_attachments(&mut self, task_gid: String) -> AppResult<()> {
+        info!("Fetching attachments for task {}...", task_gid);
+        let attachments = self
+            .asana
+            .get_attachments(&task_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        // Skip if the user has moved on to another task meanwhile
+        if state.get_task_detail().map(|t| t.gid.as_str()) == Some(task_gid.as_str()) {
+            state.set_task_attachments(attachments);
+        }
+        info!("Attachments loaded successfully.");
+        Ok(())
+    }
+
+    /// Download an attachment into the configured directory.
+    ///
+    async fn download_attachment(&mut self, gid: String) -> AppResult<()> {
+        let directory = {
+            let state = self.state.read().await;
+            state.get_download_directory().clone()
+        };
+        info!("Downloading attachment {}...", gid);
+        let path = self
+            .asana
+            .download_attachment(&gid, &directory)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        info!("Attachment saved to {}.", path.display());
+        Ok(())
+    }
+
+    /// Load the thumbnail of an image attachment, downloading and caching it
+    /// on disk the first time.
+    ///
+    async fn load_attachment_preview(&mut self, gid: String) -> AppResult<()> {
+        match self.fetch_attachment_preview(&gid).await {
+            Ok(thumbnail) => {
+                let mut state = self.state.write().await;
+                state.set_attachment_preview(gid, thumbnail);
+                Ok(())
+            }
+            Err(e) => {
+                let mut state = self.state.write().await;
+                state.set_attachment_preview_error(gid, e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Return the thumbnail of an attachment from the cache, or download and
+    /// cache it.
+    ///
+    async fn fetch_attachment_preview(&mut self, gid: &str) -> AppResult<DynamicImage> {
+        let directory = thumbnails::cache_directory();
+        let cached = match directory.clone() {
+            Some(directory) => {
+                let gid = gid.to_string();
+                tokio::task::spawn_blocking(move || thumbnails::load_cached(&directory, &gid))
+                    .await
+                    .map_err(|e| AppError::Other(e.to_string()))?
+            }
+            None => None,
+        };
+        let thumbnail = match cached {
+            Some(thumbnail) => thumbnail,
+            None => {
+                debug!("Downloading attachment {} for preview...", gid);
+                let (_, bytes) = self
+                    .asana
+                    .get_attachment_bytes(gid)
+                    .await
+                    .map_err(|e| AppError::Asana(e.into()))?;
+                let cache_gid = gid.to_string();
+                tokio::task::spawn_blocking(move || {
+                    let thumbnail = thumbnails::create_thumbnail(&bytes)
+                        .map_err(|e| AppError::Other(format!("Failed to decode image: {}", e)))?;
+                    if let Some(directory) = directory {
+                        if let Err(e) = thumbnails::save_cached(&directory, &cache_gid, &thumbnail)
+                        {
+                            warn!("Failed to cache thumbnail: {}", e);
+                        }
+                    }
+                    Ok::<_, AppError>(thumbnail)
+                })
+                .await
+                .map_err(|e| AppError::Other(e.to_string()))??
+            }
+        };
+        Ok(thumbnail)
+    }
+
+    /// Upload a local file as an attachment on a task.
+    ///
+    async fn upload_attachment(
+        &mut self,
+        task_gid: String,
+        path: std::path::PathBuf,
+    ) -> AppResult<()> {
+        info!("Uploading {} to task {}...", path.display(), task_gid);
+        self.asana
+            .upload_attachment(&task_gid, &path)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        // Refresh attachments after uploading
+        self.get_task_attachments(task_gid).await?;
+        info!("Attachment uploaded successfully.");
+        Ok(())
+    }
+
+    /// Mark a task as blocked by another.
+    ///
+    async fn add_dependency(&mut self, task_gid: String, dependency_gid: String) -> AppResult<()> {
+        info!(
+            "Marking task {} as blocked by {}...",
+            task_gid, dependency_gid
+        );
+        self.asana
+            .add_dependencies(&task_gid, std::slice::from_ref(&dependency_gid))
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.refresh_linked_tasks(&[task_gid, dependency_gid])
+            .await?;
+        info!("Dependency added successfully.");
+        Ok(())
+    }
+
+    /// Stop a task from being blocked by another.
+    ///
+    async fn remove_dependency(
+        &mut self,
+        task_gid: String,
+        dependency_gid: String,
+    ) -> AppResult<()> {
+        info!("Unblocking task {} from {}...", task_gid, dependency_gid);
+        self.asana
+            .remove_dependencies(&task_gid, std::slice::from_ref(&dependency_gid))
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        self.refresh_linked_tasks(&[task_gid, dependency_gid])
+            .await?;
+        info!("Dependency removed successfully.");
+        Ok(())
+    }
+
+    /// Refetch both ends of a changed blocking relationship where they're shown.
+    ///
+    async fn refresh_linked_tasks(&mut self, gids: &[String]) -> AppResult<()> {
+        for gid in gids {
+            let (on_board, open) = {
+                let state = self.state.read().await;
+                (
+                    state.get_tasks().iter().any(|t| &t.gid == gid),
+                    state.get_task_detail().map(|t| &t.gid) == Some(gid),
+                )
+            };
+            if open {
+                self.get_task_detail(gid.clone()).await?;
+            }
+            if on_board {
+                let task = self
+                    .asana
+                    .get_task(gid)
+                    .await
+                    .map_err(|e| AppError::Asana(e.into()))?;
+                let mut state = self.state.write().await;
+                state.upsert_task(task);
+            }
+        }
+        Ok(())
+    }
+
     /// Get workspace users.
     ///
     async fn get_workspace_users(&mut self, workspace_gid: String) -> AppResult<()> {
         info!("Fetching users for workspace {}...", workspace_gid);
-        let users = self
+        let result = self
             .asana
             .get_workspace_users(&workspace_gid)
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()));
+        let mut state = self.state.write().await;
+        // Users of a workspace switched away from are dropped
+        if state.get_active_workspace().map(|w| w.gid.as_str()) != Some(&workspace_gid) {
+            return Ok(());
+        }
+        match result {
+            Ok(users) => {
+                state.set_workspace_users(users);
+                info!("Users loaded successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                state.release_typeahead_fallback(TypeaheadKind::User);
+                Err(e)
+            }
+        }
+    }
+
+    /// Get workspace tags.
+    ///
+    async fn get_workspace_tags(&mut self, workspace_gid: String) -> AppResult<()> {
+        info!("Fetching tags for workspace {}...", workspace_gid);
+        let result = self
+            .asana
+            .get_tags(&workspace_gid)
+            .await
+            .map_err(|e| AppError::Asana(e.into()));
         let mut state = self.state.write().await;
-        state.set_workspace_users(users);
-        info!("Users loaded successfully.");
+        // Tags of a workspace switched away from are dropped
+        if state.get_active_workspace().map(|w| w.gid.as_str()) != Some(&workspace_gid) {
+            return Ok(());
+        }
+        match result {
+            Ok(tags) => {
+                state.set_workspace_tags(tags);
+                info!("Tags loaded successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                state.expire_workspace_tags();
+                state.release_typeahead_fallback(TypeaheadKind::Tag);
+                Err(e)
+            }
+        }
+    }
+
+    /// Look up users, projects or tags matching a picker search. Users and
+    /// tags fall back to loading the whole list if the lookup fails.
+    ///
+    async fn typeahead(
+        &mut self,
+        workspace_gid: String,
+        kind: TypeaheadKind,
+        query: String,
+    ) -> AppResult<()> {
+        debug!("Looking up {:?} matches for '{}'...", kind, query);
+        let result = match kind {
+            TypeaheadKind::User => self
+                .asana
+                .typeahead_users(&workspace_gid, &query)
+                .await
+                .map(|users| (users, vec![], vec![])),
+            TypeaheadKind::Project => self
+                .asana
+                .typeahead_projects(&workspace_gid, &query)
+                .await
+                .map(|projects| (vec![], projects, vec![])),
+            TypeaheadKind::Tag => self
+                .asana
+                .typeahead_tags(&workspace_gid, &query)
+                .await
+                .map(|tags| (vec![], vec![], tags)),
+        };
+        {
+            let mut state = self.state.write().await;
+            // Results for a workspace switched away from are dropped
+            if state.get_active_workspace().map(|w| w.gid.as_str()) != Some(&workspace_gid) {
+                return Ok(());
+            }
+            match result {
+                Ok((users, projects, tags)) => {
+                    match kind {
+                        TypeaheadKind::User => state.set_typeahead_users(&query, users),
+                        TypeaheadKind::Project => state.set_typeahead_projects(&query, projects),
+                        TypeaheadKind::Tag => state.set_typeahead_tags(&query, tags),
+                    };
+                    return Ok(());
+                }
+                Err(e) => {
+                    warn!("{:?} typeahead for '{}' failed: {}", kind, query, e);
+                    state.fail_typeahead(kind, &query);
+                    // Only one full load per workspace, however many lookups fail
+                    if kind == TypeaheadKind::Project || !state.claim_typeahead_fallback(kind) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        match kind {
+            TypeaheadKind::User => self.get_workspace_users(workspace_gid).await,
+            TypeaheadKind::Tag => self.get_workspace_tags(workspace_gid).await,
+            TypeaheadKind::Project => Ok(()),
+        }
+    }
+
+    /// Create a workspace tag and select it in the open task form.
+    ///
+    async fn create_tag(&mut self, workspace_gid: String, name: String) -> AppResult<()> {
+        info!("Creating tag '{}'...", name);
+        let tag = self
+            .asana
+            .create_tag(&workspace_gid, &name)
+            .await
+            .map_err(|e| AppError::Asana(e.into()))?;
+        let mut state = self.state.write().await;
+        state.add_created_tag(tag);
+        info!("Tag '{}' created successfully.", name);
+        Ok(())
+    }
+
+    /// Add and remove tags on a task, then refresh what shows it.
+    ///
+    async fn set_task_tags(
+        &mut self,
+        gid: String,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> AppResult<()> {
+        info!("Updating tags on task {}...", gid);
+        for tag_gid in &add {
+            self.asana
+                .add_tag(&gid, tag_gid)
+                .await
+                .map_err(|e| AppError::Asana(e.into()))?;
+        }
+        for tag_gid in &remove {
+            self.asana
+                .remove_tag(&gid, tag_gid)
+                .await
+                .map_err(|e| AppError::Asana(e.into()))?;
+        }
+        info!("Tags updated successfully.");
+        let detail_gid = {
+            let state = self.state.read().await;
+            match state.current_view() {
+                crate::state::View::TaskDetail => state.get_task_detail().map(|t| t.gid.clone()),
+                _ => None,
+            }
+        };
+        match detail_gid {
+            Some(detail_gid) => self.get_task_detail(detail_gid).await?,
+            None => self.refresh_current_view().await?,
+        }
         Ok(())
     }
 
@@ -530,8 +2093,9 @@ impl<'a> Handler<'a> {
         name: String,
         notes: Option<String>,
         assignee: Option<String>,
-        due_on: Option<String>,
+        dates: TaskDates,
         section: Option<String>,
+        tags: Vec<String>,
         custom_fields: HashMap<String, crate::state::CustomFieldValue>,
     ) -> AppResult<()> {
         info!("Creating new task '{}' in project {}...", name, project_gid);
@@ -542,12 +2106,17 @@ impl<'a> Handler<'a> {
                 &name,
                 notes.as_deref(),
                 assignee.as_deref(),
-                due_on.as_deref(),
+                &dates,
                 section.as_deref(),
+                &tags,
                 &custom_fields,
             )
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
+        {
+            let mut state = self.state.write().await;
+            state.clear_custom_field_errors();
+        }
 
         info!(
             "Task '{}' created successfully with GID {}",
@@ -587,7 +2156,7 @@ impl<'a> Handler<'a> {
         name: Option<String>,
         notes: Option<String>,
         assignee: Option<String>,
-        due_on: Option<String>,
+        dates: TaskDates,
         section: Option<String>,
         completed: Option<bool>,
         custom_fields: HashMap<String, crate::state::CustomFieldValue>,
@@ -598,13 +2167,17 @@ impl<'a> Handler<'a> {
                 name.as_deref(),
                 notes.as_deref(),
                 assignee.as_deref(),
-                due_on.as_deref(),
+                &dates,
                 section.as_deref(),
                 completed,
                 &custom_fields,
             )
             .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
+            .map_err(|e| AppError::Asana(e.into()))?;
+        {
+            let mut state = self.state.write().await;
+            state.clear_custom_field_errors();
+        }
         // Refresh task detail if we're viewing it
         let (view, task_gid_to_refresh) = {
             let state = self.state.read().await;
@@ -627,21 +2200,4 @@ impl<'a> Handler<'a> {
         info!("Task updated successfully.");
         Ok(())
     }
-
-    /// Move task to a different section.
-    ///
-    async fn move_task_to_section(
-        &mut self,
-        task_gid: String,
-        section_gid: String,
-    ) -> AppResult<()> {
-        self.asana
-            .add_task_to_section(&task_gid, &section_gid)
-            .await
-            .map_err(|e| AppError::Asana(crate::asana::AsanaError::Other(e.to_string())))?;
-        // Refresh tasks after moving
-        self.project_tasks().await?;
-        info!("Task moved successfully.");
-        Ok(())
-    }
 }
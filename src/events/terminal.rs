@@ -3,10 +3,11 @@
 //! This module handles all terminal input events, including keyboard input, mouse events,
 //! and user interactions. It processes these events and updates the application state accordingly.
 
+use crate::asana::TypeaheadKind;
 use crate::config::{
     get_action_for_special_mode, hotkeys::get_action_for_event, HotkeyAction, SpecialMode,
 };
-use crate::state::{Focus, Menu, State};
+use crate::state::{Focus, Menu, ProjectPickerAction, State};
 use anyhow::Result;
 use clipboard::{ClipboardContext, ClipboardProvider};
 use crossterm::{
@@ -39,6 +40,7 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
     if state.is_search_mode()
         || state.is_debug_mode()
         || (state.is_field_editing_mode() && !is_submit_form)
+        || state.has_date_picker()
         || state.is_comment_input_mode()
         || state.has_delete_confirmation()
         || state.has_theme_selector()
@@ -69,8 +71,10 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                             state.set_delete_confirmation(task.gid.clone());
                         }
                     } else if matches!(state.current_view(), crate::state::View::ProjectTasks) {
-                        // Delete task from kanban view
-                        if let Some(task) = state.get_kanban_selected_task() {
+                        // Delete the marked tasks, or the selected one, from kanban view
+                        if let Some(gid) = state.get_marked_tasks().first().cloned() {
+                            state.set_delete_confirmation(gid);
+                        } else if let Some(task) = state.get_kanban_selected_task() {
                             state.set_delete_confirmation(task.gid.clone());
                         }
                     } else {
@@ -90,12 +94,9 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                         let task_clone = task.clone();
                         state.init_edit_form(&task_clone);
 
-                        // Load workspace users and sections for dropdowns
-                        if let Some(workspace) = state.get_active_workspace() {
-                            state.dispatch(crate::events::network::Event::GetWorkspaceUsers {
-                                workspace_gid: workspace.gid.clone(),
-                            });
-                        }
+                        // Suggest users and tags, and load sections for dropdowns
+                        state.request_typeahead(TypeaheadKind::User, "");
+                        state.request_typeahead(TypeaheadKind::Tag, "");
                         if let Some(project) = state.get_project() {
                             state.dispatch(crate::events::network::Event::GetProjectSections {
                                 project_gid: project.gid.clone(),
@@ -109,6 +110,18 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                 }
             }
             HotkeyAction::AddComment => {
+                if matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::ProjectTasks)
+                    && !state.get_marked_tasks().is_empty()
+                {
+                    // Comment on every marked task from kanban view
+                    debug!(
+                        "Processing add comment to marked tasks event '{:?}'...",
+                        event
+                    );
+                    state.enter_marked_comment_input_mode();
+                    return Ok(Some(true));
+                }
                 if matches!(state.current_focus(), Focus::View)
                     && matches!(state.current_view(), crate::state::View::TaskDetail)
                 {
@@ -119,6 +132,100 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                     return Ok(Some(true));
                 }
             }
+            HotkeyAction::AddDependency => {
+                let in_detail = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail);
+                if in_detail {
+                    debug!("Processing add dependency event '{:?}'...", event);
+                    state.enter_dependency_input_mode(crate::state::DependencyKind::BlockedBy);
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::RemoveDependency => {
+                let on_panel = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail)
+                    && state.get_current_task_panel()
+                        == crate::state::TaskDetailPanel::Dependencies;
+                if on_panel {
+                    debug!("Processing remove dependency event '{:?}'...", event);
+                    state.remove_selected_relation();
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::UploadAttachment => {
+                let in_detail = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail);
+                if in_detail {
+                    debug!("Processing upload attachment event '{:?}'...", event);
+                    state.enter_attachment_input_mode();
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::DownloadAttachment => {
+                let on_panel = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail)
+                    && state.get_current_task_panel() == crate::state::TaskDetailPanel::Attachments;
+                if on_panel {
+                    debug!("Processing download attachment event '{:?}'...", event);
+                    state.download_selected_attachment();
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::AddToProject
+            | HotkeyAction::MoveToProject
+            | HotkeyAction::RemoveFromProject => {
+                let in_detail = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail);
+                if in_detail {
+                    debug!("Processing task project event '{:?}'...", event);
+                    state.open_project_picker(match action {
+                        HotkeyAction::AddToProject => ProjectPickerAction::Add,
+                        HotkeyAction::MoveToProject => ProjectPickerAction::Move,
+                        _ => ProjectPickerAction::Remove,
+                    });
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::EditFollowers | HotkeyAction::ToggleFollow => {
+                let in_detail = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail);
+                if in_detail {
+                    debug!("Processing followers event '{:?}'...", event);
+                    if action == HotkeyAction::EditFollowers {
+                        state.open_follower_picker();
+                    } else {
+                        state.toggle_follow_current_task();
+                    }
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::EditComment
+            | HotkeyAction::DeleteComment
+            | HotkeyAction::LikeComment
+            | HotkeyAction::PinComment => {
+                let on_panel = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail)
+                    && state.get_current_task_panel() == crate::state::TaskDetailPanel::Comments;
+                if on_panel {
+                    debug!("Processing comment event '{:?}'...", event);
+                    match action {
+                        HotkeyAction::EditComment => state.enter_edit_comment_input_mode(),
+                        HotkeyAction::DeleteComment => state.delete_selected_comment(),
+                        HotkeyAction::LikeComment => state.toggle_selected_comment_liked(),
+                        _ => state.toggle_selected_comment_pinned(),
+                    };
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::AddSubtask => {
+                let in_detail = matches!(state.current_focus(), Focus::View)
+                    && matches!(state.current_view(), crate::state::View::TaskDetail);
+                if in_detail {
+                    debug!("Processing add subtask event '{:?}'...", event);
+                    state.enter_subtask_input_mode();
+                    return Ok(Some(true));
+                }
+            }
             HotkeyAction::SubmitForm => {
                 if matches!(
                     state.current_view(),
@@ -139,6 +246,13 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                         warn!("Cannot submit form: task name is required");
                         return Ok(Some(true));
                     }
+                    // Notes must keep the tags the editor can't show; only edited
+                    // tasks have original notes, so other forms always pass
+                    if let Err(error) = state.check_form_notes_saveable() {
+                        warn!("Cannot submit form: {}", error);
+                        state.set_edit_form_state(Some(crate::state::EditFormState::Notes));
+                        return Ok(Some(true));
+                    }
 
                     let notes = state.get_form_notes();
                     let notes_opt = if notes.trim().is_empty() {
@@ -148,16 +262,26 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                     };
 
                     let assignee = state.get_form_assignee().cloned();
-                    let due_on = {
-                        let due = state.get_form_due_on().trim();
-                        if due.is_empty() {
-                            None
-                        } else {
-                            Some(due.to_string())
+                    // Dates must be valid; show the first problem on its field
+                    let editing = matches!(state.current_view(), crate::state::View::EditTask);
+                    let dates = match state.get_form_task_dates(editing) {
+                        Ok(dates) => dates,
+                        Err((field, error)) => {
+                            warn!("Cannot submit form: {}", error);
+                            state.set_edit_form_state(Some(field));
+                            return Ok(Some(true));
                         }
                     };
                     let section = state.get_form_section().cloned();
-                    let custom_fields = state.get_form_custom_field_values().clone();
+                    let tags = state.get_form_tags().to_vec();
+                    let custom_fields = match state.get_submit_custom_field_values() {
+                        Ok(custom_fields) => custom_fields,
+                        Err((field, error)) => {
+                            warn!("Cannot submit form: {}", error);
+                            state.set_edit_form_state(Some(field));
+                            return Ok(Some(true));
+                        }
+                    };
 
                     // Dispatch appropriate network event
                     match state.current_view() {
@@ -168,8 +292,9 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                                     name,
                                     notes: notes_opt,
                                     assignee,
-                                    due_on,
+                                    dates,
                                     section,
+                                    tags,
                                     custom_fields,
                                 });
                                 // Navigate back after creating
@@ -197,13 +322,6 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                                     } else {
                                         None
                                     };
-                                let due_on_opt = if due_on.as_deref()
-                                    != Some(state.get_original_form_due_on())
-                                {
-                                    due_on
-                                } else {
-                                    None
-                                };
                                 let section_opt = if section != *state.get_original_form_section() {
                                     section
                                 } else {
@@ -215,11 +333,29 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                                     name: name_opt,
                                     notes: notes_opt,
                                     assignee: assignee_opt,
-                                    due_on: due_on_opt,
+                                    dates,
                                     section: section_opt,
                                     completed: None,
                                     custom_fields,
                                 });
+                                let original_tags = state.get_original_form_tags();
+                                let add: Vec<String> = tags
+                                    .iter()
+                                    .filter(|gid| !original_tags.contains(gid))
+                                    .cloned()
+                                    .collect();
+                                let remove: Vec<String> = original_tags
+                                    .iter()
+                                    .filter(|gid| !tags.contains(gid))
+                                    .cloned()
+                                    .collect();
+                                if !add.is_empty() || !remove.is_empty() {
+                                    state.dispatch(crate::events::network::Event::SetTaskTags {
+                                        gid: task.gid.clone(),
+                                        add,
+                                        remove,
+                                    });
+                                }
                                 // Navigate back after updating
                                 state.pop_view();
                             }
@@ -230,7 +366,9 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                 }
             }
             HotkeyAction::CreateTask => {
+                // New tasks are created in a project, which My Tasks isn't
                 if !state.is_debug_mode()
+                    && !state.is_my_tasks()
                     && state.current_focus() == &Focus::View
                     && matches!(
                         state.current_view(),
@@ -241,12 +379,9 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                     debug!("Processing create task event '{:?}'...", event);
                     state.clear_form();
                     state.set_edit_form_state(Some(crate::state::EditFormState::Name));
-                    // Load workspace users and sections if needed
-                    if let Some(workspace) = state.get_active_workspace() {
-                        state.dispatch(crate::events::network::Event::GetWorkspaceUsers {
-                            workspace_gid: workspace.gid.clone(),
-                        });
-                    }
+                    // Suggest users and tags, and load sections if needed
+                    state.request_typeahead(TypeaheadKind::User, "");
+                    state.request_typeahead(TypeaheadKind::Tag, "");
                     if let Some(project) = state.get_project() {
                         state.dispatch(crate::events::network::Event::GetProjectSections {
                             project_gid: project.gid.clone(),
@@ -273,6 +408,19 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                     return Ok(Some(true));
                 }
             }
+            HotkeyAction::MarkTask => {
+                let on_board = !state.is_debug_mode()
+                    && state.current_focus() == &Focus::View
+                    && matches!(state.current_view(), crate::state::View::ProjectTasks);
+                if on_board {
+                    // Mark or unmark the selected task for bulk actions
+                    if let Some(task) = state.get_kanban_selected_task() {
+                        debug!("Toggling mark on task {}...", task.gid);
+                        state.toggle_task_mark(&task.gid);
+                    }
+                    return Ok(Some(true));
+                }
+            }
             HotkeyAction::FilterByAssignee => {
                 if !state.is_debug_mode()
                     && state.current_focus() == &Focus::View
@@ -281,16 +429,33 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                 {
                     // Open assignee filter modal
                     debug!("Opening assignee filter modal...");
-                    // Ensure workspace users are loaded
-                    if let Some(workspace) = state.get_active_workspace() {
-                        state.dispatch(crate::events::network::Event::GetWorkspaceUsers {
-                            workspace_gid: workspace.gid.clone(),
-                        });
-                    }
+                    // Suggest users, and ensure workspace tags are loaded
+                    state.request_typeahead(TypeaheadKind::User, "");
+                    state.request_workspace_tags();
                     state.open_assignee_filter();
                     return Ok(Some(true));
                 }
             }
+            HotkeyAction::AddSection
+            | HotkeyAction::RenameSection
+            | HotkeyAction::DeleteSection
+            | HotkeyAction::MoveSectionLeft
+            | HotkeyAction::MoveSectionRight => {
+                let on_board = !state.is_debug_mode()
+                    && state.current_focus() == &Focus::View
+                    && matches!(state.current_view(), crate::state::View::ProjectTasks);
+                if on_board {
+                    debug!("Processing section event '{:?}'...", event);
+                    match action {
+                        HotkeyAction::AddSection => state.enter_new_section_input_mode(),
+                        HotkeyAction::RenameSection => state.enter_rename_section_input_mode(),
+                        HotkeyAction::DeleteSection => state.delete_current_section(),
+                        HotkeyAction::MoveSectionLeft => state.move_current_section_left(),
+                        _ => state.move_current_section_right(),
+                    };
+                    return Ok(Some(true));
+                }
+            }
             HotkeyAction::OpenThemeSelector => {
                 if !state.is_debug_mode()
                     && !state.has_theme_selector()
@@ -303,6 +468,70 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                     return Ok(Some(true));
                 }
             }
+            HotkeyAction::CreateProject
+            | HotkeyAction::EditProject
+            | HotkeyAction::ArchiveProject
+            | HotkeyAction::ToggleArchivedProjects
+            | HotkeyAction::ToggleTeamBrowser => {
+                let in_projects_list =
+                    state.current_focus() == &Focus::Menu && state.current_menu() == &Menu::TopList;
+                if !state.has_hotkey_editor()
+                    && matches!(state.current_view(), crate::state::View::Welcome)
+                {
+                    debug!("Processing project event '{:?}'...", event);
+                    match action {
+                        HotkeyAction::CreateProject => {
+                            state.open_create_project_form();
+                        }
+                        HotkeyAction::ToggleArchivedProjects => {
+                            state.toggle_show_archived_projects();
+                        }
+                        HotkeyAction::ToggleTeamBrowser => {
+                            state.toggle_team_browser();
+                        }
+                        HotkeyAction::EditProject if in_projects_list => {
+                            state.open_edit_project_form();
+                        }
+                        HotkeyAction::ArchiveProject if in_projects_list => {
+                            state.toggle_current_project_archived();
+                        }
+                        _ => return Ok(None),
+                    }
+                    return Ok(Some(true));
+                }
+            }
+            HotkeyAction::OpenWorkspaceSelector
+                if !state.is_debug_mode()
+                    && !state.has_theme_selector()
+                    && !state.has_hotkey_editor()
+                    && matches!(state.current_view(), crate::state::View::Welcome) =>
+            {
+                // Open workspace selector modal (only available on welcome screen)
+                debug!("Opening workspace selector modal...");
+                state.open_workspace_selector();
+                return Ok(Some(true));
+            }
+            HotkeyAction::OpenTaskSearch
+                if !state.is_debug_mode()
+                    && !state.has_theme_selector()
+                    && !state.has_hotkey_editor()
+                    && matches!(
+                        state.current_view(),
+                        crate::state::View::Welcome | crate::state::View::TaskSearch
+                    ) =>
+            {
+                debug!("Opening task search filters...");
+                state.open_task_search();
+                return Ok(Some(true));
+            }
+            HotkeyAction::PostStatusUpdate
+                if state.current_focus() == &Focus::View
+                    && matches!(state.current_view(), crate::state::View::Portfolios) =>
+            {
+                debug!("Opening status update form...");
+                state.open_status_update_form();
+                return Ok(Some(true));
+            }
             HotkeyAction::OpenHotkeyEditor => {
                 if !state.is_debug_mode()
                     && !state.has_theme_selector()
@@ -377,7 +606,12 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
                                     state.dispatch(crate::events::network::Event::ProjectTasks);
                                 }
                             }
-                            crate::state::View::ProjectTasks => {
+                            crate::state::View::ProjectTasks
+                                if !matches!(
+                                    state.current_view(),
+                                    crate::state::View::Portfolios
+                                ) =>
+                            {
                                 state.focus_menu();
                             }
                             _ => {}
@@ -401,6 +635,68 @@ fn try_execute_hotkey_action(event: &KeyEvent, state: &mut State) -> Result<Opti
     Ok(None)
 }
 
+/// Moves or closes the due date calendar for a key, resolving it through the
+/// form's hotkeys. Arrows, paging, Enter and Esc always work as fallbacks.
+///
+fn handle_date_picker_key(event: &KeyEvent, state: &mut State) {
+    match get_action_for_event(event, state.current_view(), state.get_hotkeys()) {
+        Some(HotkeyAction::Cancel) => {
+            state.close_date_picker();
+        }
+        Some(HotkeyAction::EditField) => {
+            state.select_date_picker();
+        }
+        Some(HotkeyAction::NavigateLeft) => {
+            state.move_date_picker_days(-1);
+        }
+        Some(HotkeyAction::NavigateRight) => {
+            state.move_date_picker_days(1);
+        }
+        Some(HotkeyAction::NavigatePrev) => {
+            state.move_date_picker_days(-7);
+        }
+        Some(HotkeyAction::NavigateNext) => {
+            state.move_date_picker_days(7);
+        }
+        Some(HotkeyAction::CalendarPrevMonth) => {
+            state.move_date_picker_months(-1);
+        }
+        Some(HotkeyAction::CalendarNextMonth) => {
+            state.move_date_picker_months(1);
+        }
+        Some(HotkeyAction::CalendarToday) => {
+            state.move_date_picker_to_today();
+        }
+        _ => match event.code {
+            KeyCode::Esc => {
+                state.close_date_picker();
+            }
+            KeyCode::Enter => {
+                state.select_date_picker();
+            }
+            KeyCode::Left => {
+                state.move_date_picker_days(-1);
+            }
+            KeyCode::Right => {
+                state.move_date_picker_days(1);
+            }
+            KeyCode::Up => {
+                state.move_date_picker_days(-7);
+            }
+            KeyCode::Down => {
+                state.move_date_picker_days(7);
+            }
+            KeyCode::PageUp => {
+                state.move_date_picker_months(-1);
+            }
+            KeyCode::PageDown => {
+                state.move_date_picker_months(1);
+            }
+            _ => {}
+        },
+    }
+}
+
 /// Specify different terminal event types.
 ///
 #[derive(Debug)]
@@ -453,6 +749,227 @@ impl Handler {
                     return Ok(true);
                 }
 
+                // Handle workspace selector - navigation keys move, Enter switches
+                if state.has_workspace_selector() {
+                    match event.code {
+                        KeyCode::Esc => {
+                            state.close_workspace_selector();
+                        }
+                        KeyCode::Enter => {
+                            state.select_workspace();
+                        }
+                        KeyCode::Down => {
+                            state.next_workspace();
+                        }
+                        KeyCode::Up => {
+                            state.previous_workspace();
+                        }
+                        _ => match get_action_for_event(
+                            &event,
+                            state.current_view(),
+                            state.get_hotkeys(),
+                        ) {
+                            Some(HotkeyAction::NavigateNext) => {
+                                state.next_workspace();
+                            }
+                            Some(HotkeyAction::NavigatePrev) => {
+                                state.previous_workspace();
+                            }
+                            _ => {}
+                        },
+                    }
+                    return Ok(true);
+                }
+
+                // Handle follower picker - arrows navigate, typing searches
+                if state.has_follower_picker() {
+                    match event.code {
+                        KeyCode::Esc => {
+                            state.close_follower_picker();
+                        }
+                        KeyCode::Enter => {
+                            state.toggle_selected_follower();
+                        }
+                        KeyCode::Down => {
+                            state.next_assignee();
+                        }
+                        KeyCode::Up => {
+                            state.previous_assignee();
+                        }
+                        KeyCode::Backspace => {
+                            state.backspace_assignee_search();
+                        }
+                        KeyCode::Char(c) => {
+                            state.add_assignee_search_char(c);
+                        }
+                        _ => {}
+                    }
+                    return Ok(true);
+                }
+
+                // Handle task project picker - arrows navigate, typing searches
+                if state.has_project_picker() {
+                    match event.code {
+                        KeyCode::Esc => {
+                            state.close_project_picker();
+                        }
+                        KeyCode::Enter => {
+                            state.select_project_picker_item();
+                        }
+                        KeyCode::Down => {
+                            state.next_project_picker_item();
+                        }
+                        KeyCode::Up => {
+                            state.previous_project_picker_item();
+                        }
+                        KeyCode::Backspace => {
+                            state.remove_project_picker_char();
+                        }
+                        KeyCode::Char(c) => {
+                            state.add_project_picker_char(c);
+                        }
+                        _ => {}
+                    }
+                    return Ok(true);
+                }
+
+                // Handle project form modal FIRST - it captures all keys while open
+                if state.has_project_form() {
+                    match event.code {
+                        KeyCode::Esc => {
+                            state.close_project_form();
+                        }
+                        KeyCode::Enter => {
+                            state.submit_project_form();
+                        }
+                        KeyCode::Tab | KeyCode::Down => {
+                            state.next_project_form_field();
+                        }
+                        KeyCode::BackTab | KeyCode::Up => {
+                            state.previous_project_form_field();
+                        }
+                        KeyCode::Left => {
+                            state.cycle_project_form_option(false);
+                        }
+                        KeyCode::Right => {
+                            state.cycle_project_form_option(true);
+                        }
+                        KeyCode::Backspace => {
+                            state.remove_project_form_char();
+                        }
+                        KeyCode::Char(c) => {
+                            state.add_project_form_char(c);
+                        }
+                        _ => {}
+                    }
+                    return Ok(true);
+                }
+
+                // Handle date picker calendar - it captures all keys while open
+                if state.has_date_picker() {
+                    handle_date_picker_key(&event, state);
+                    return Ok(true);
+                }
+
+                // Handle status update form - it captures all keys while open and
+                // edits its title and text like the task form's name and notes
+                if matches!(state.current_view(), crate::state::View::CreateStatusUpdate) {
+                    let field = state.get_status_update_form().map(|form| form.field);
+                    if state.is_field_editing_mode() {
+                        match (event.code, field) {
+                            (KeyCode::Esc, _) => {
+                                state.exit_field_editing_mode();
+                            }
+                            (KeyCode::Enter, Some(crate::state::StatusUpdateField::Title)) => {
+                                state.exit_field_editing_mode();
+                            }
+                            (KeyCode::Backspace, Some(crate::state::StatusUpdateField::Title)) => {
+                                state.remove_form_name_char();
+                            }
+                            (KeyCode::Char(c), Some(crate::state::StatusUpdateField::Title)) => {
+                                state.add_form_name_char(c);
+                            }
+                            (_, Some(crate::state::StatusUpdateField::Text)) => {
+                                let input: Input = CrosstermEvent::Key(event).into();
+                                state.get_form_notes_textarea().input(input);
+                            }
+                            _ => {}
+                        }
+                        return Ok(true);
+                    }
+                    match get_action_for_event(&event, state.current_view(), state.get_hotkeys()) {
+                        Some(HotkeyAction::SubmitForm) => {
+                            state.submit_status_update_form();
+                        }
+                        Some(HotkeyAction::Cancel | HotkeyAction::Back) => {
+                            state.close_status_update_form();
+                        }
+                        Some(HotkeyAction::EditField) => {
+                            state.edit_status_update_field();
+                        }
+                        Some(HotkeyAction::NavigateNext) => {
+                            state.next_status_update_field();
+                        }
+                        Some(HotkeyAction::NavigatePrev) => {
+                            state.previous_status_update_field();
+                        }
+                        Some(HotkeyAction::NavigateLeft) => {
+                            state.cycle_status_update_status(false);
+                        }
+                        Some(HotkeyAction::NavigateRight) => {
+                            state.cycle_status_update_status(true);
+                        }
+                        _ => match event.code {
+                            KeyCode::Tab | KeyCode::Down => {
+                                state.next_status_update_field();
+                            }
+                            KeyCode::BackTab | KeyCode::Up => {
+                                state.previous_status_update_field();
+                            }
+                            KeyCode::Left => {
+                                state.cycle_status_update_status(false);
+                            }
+                            KeyCode::Right => {
+                                state.cycle_status_update_status(true);
+                            }
+                            _ => {}
+                        },
+                    }
+                    return Ok(true);
+                }
+
+                // Handle task search filters modal - it captures all keys while open
+                if state.has_task_search_form() {
+                    match event.code {
+                        KeyCode::Esc => {
+                            state.close_task_search_form();
+                        }
+                        KeyCode::Enter => {
+                            state.submit_task_search_form();
+                        }
+                        KeyCode::Tab | KeyCode::Down => {
+                            state.next_task_search_field();
+                        }
+                        KeyCode::BackTab | KeyCode::Up => {
+                            state.previous_task_search_field();
+                        }
+                        KeyCode::Left => {
+                            state.cycle_task_search_completed(false);
+                        }
+                        KeyCode::Right => {
+                            state.cycle_task_search_completed(true);
+                        }
+                        KeyCode::Backspace => {
+                            state.remove_task_search_char();
+                        }
+                        KeyCode::Char(c) => {
+                            state.add_task_search_char(c);
+                        }
+                        _ => {}
+                    }
+                    return Ok(true);
+                }
+
                 // Handle field editing mode - when actively editing a field
                 if matches!(
                     state.current_view(),
@@ -485,15 +1002,22 @@ impl Handler {
                                         state.remove_form_name_char();
                                     }
                                 }
-                                Some(crate::state::EditFormState::DueDate) => {
+                                Some(
+                                    crate::state::EditFormState::StartDate
+                                    | crate::state::EditFormState::DueDate
+                                    | crate::state::EditFormState::DueTime,
+                                ) => {
                                     if let KeyEvent {
                                         code: KeyCode::Char(c),
                                         ..
                                     } = event
                                     {
-                                        state.add_form_due_on_char(c);
+                                        state.add_form_date_char(c);
                                     } else if matches!(event.code, KeyCode::Backspace) {
-                                        state.remove_form_due_on_char();
+                                        state.remove_form_date_char();
+                                    } else if matches!(event.code, KeyCode::Down) {
+                                        // Does nothing on the due time field
+                                        state.open_date_picker();
                                     }
                                 }
                                 Some(crate::state::EditFormState::Assignee) => {
@@ -638,6 +1162,69 @@ impl Handler {
                                         _ => {}
                                     }
                                 }
+                                Some(crate::state::EditFormState::Tags) => {
+                                    // Handle tag multi-select navigation and search
+                                    match event.code {
+                                        KeyCode::Up => {
+                                            state.previous_tag();
+                                            return Ok(true);
+                                        }
+                                        KeyCode::Down => {
+                                            state.next_tag();
+                                            return Ok(true);
+                                        }
+                                        KeyCode::Enter => {
+                                            // Don't close dropdown for multi-select - Escape closes it
+                                            state.toggle_current_tag();
+                                            return Ok(true);
+                                        }
+                                        _ => {
+                                            if let Some(action) = get_action_for_event(
+                                                &event,
+                                                state.current_view(),
+                                                state.get_hotkeys(),
+                                            ) {
+                                                match action {
+                                                    HotkeyAction::NavigateNext => {
+                                                        state.next_tag();
+                                                        return Ok(true);
+                                                    }
+                                                    HotkeyAction::NavigatePrev => {
+                                                        state.previous_tag();
+                                                        return Ok(true);
+                                                    }
+                                                    HotkeyAction::Select
+                                                    | HotkeyAction::EditField => {
+                                                        state.toggle_current_tag();
+                                                        return Ok(true);
+                                                    }
+                                                    _ => {
+                                                        // Not a navigation/select action, continue to text input
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+
+                                    // Handle text input for search (characters and backspace)
+                                    match event {
+                                        KeyEvent {
+                                            code: KeyCode::Char(c),
+                                            ..
+                                        } => {
+                                            state.add_tag_search_char(c);
+                                            return Ok(true);
+                                        }
+                                        KeyEvent {
+                                            code: KeyCode::Backspace,
+                                            ..
+                                        } => {
+                                            state.backspace_tag_search();
+                                            return Ok(true);
+                                        }
+                                        _ => {}
+                                    }
+                                }
                                 Some(crate::state::EditFormState::CustomField(_idx)) => {
                                     // Handle custom field editing based on field type
                                     // Clone custom field data to avoid borrow checker issues
@@ -677,6 +1264,10 @@ impl Handler {
                                                     &cf_gid,
                                                     &cf_subtype,
                                                 );
+                                            } else if matches!(event.code, KeyCode::Down)
+                                                && cf_subtype == "date"
+                                            {
+                                                state.open_date_picker();
                                             }
                                         }
                                         "enum" => {
@@ -1099,164 +1690,54 @@ impl Handler {
                                         }
                                         "people" => {
                                             // Handle people dropdown navigation and search
-                                            let (users, search) = {
-                                                let users = state.get_workspace_users();
-                                                (
-                                                    users.to_vec(),
-                                                    state
-                                                        .get_custom_field_search(&cf_gid)
-                                                        .to_string(),
-                                                )
-                                            };
+                                            let users = state.get_filtered_people(&cf_gid);
+                                            let current_idx = state
+                                                .get_custom_field_dropdown_index(&cf_gid)
+                                                .min(users.len().saturating_sub(1));
 
-                                            // Support arrow keys for navigation (better UX than j/k)
-                                            match event.code {
-                                                KeyCode::Up => {
-                                                    let filtered_count = users
-                                                        .iter()
-                                                        .filter(|u| {
-                                                            search.is_empty()
-                                                                || u.name.to_lowercase().contains(
-                                                                    &search.to_lowercase(),
-                                                                )
-                                                        })
-                                                        .count();
-                                                    state.previous_custom_field_enum(
+                                            // Support arrow keys for navigation (better UX than j/k), falling
+                                            // back to the configured hotkeys
+                                            let action = match event.code {
+                                                KeyCode::Up => Some(HotkeyAction::NavigatePrev),
+                                                KeyCode::Down => Some(HotkeyAction::NavigateNext),
+                                                // In field editing mode, Enter selects in comboboxes, not EditField
+                                                KeyCode::Enter => Some(HotkeyAction::Select),
+                                                _ => get_action_for_event(
+                                                    &event,
+                                                    state.current_view(),
+                                                    state.get_hotkeys(),
+                                                ),
+                                            };
+                                            match action {
+                                                Some(HotkeyAction::NavigateNext) => {
+                                                    state.next_custom_field_enum(
                                                         &cf_gid,
-                                                        filtered_count,
+                                                        users.len(),
                                                     );
                                                     return Ok(true);
                                                 }
-                                                KeyCode::Down => {
-                                                    let filtered_count = users
-                                                        .iter()
-                                                        .filter(|u| {
-                                                            search.is_empty()
-                                                                || u.name.to_lowercase().contains(
-                                                                    &search.to_lowercase(),
-                                                                )
-                                                        })
-                                                        .count();
-                                                    state.next_custom_field_enum(
+                                                Some(HotkeyAction::NavigatePrev) => {
+                                                    state.previous_custom_field_enum(
                                                         &cf_gid,
-                                                        filtered_count,
+                                                        users.len(),
                                                     );
                                                     return Ok(true);
                                                 }
-                                                KeyCode::Enter => {
-                                                    // Enter key: toggle current person and close dropdown
-                                                    // (In field editing mode, Enter selects in comboboxes, not EditField)
-                                                    let filtered: Vec<_> = users
-                                                        .iter()
-                                                        .filter(|u| {
-                                                            search.is_empty()
-                                                                || u.name.to_lowercase().contains(
-                                                                    &search.to_lowercase(),
-                                                                )
-                                                        })
-                                                        .collect();
-                                                    let current_idx = state
-                                                        .get_custom_field_dropdown_index(&cf_gid);
-                                                    if let Some(selected) = filtered.get(
-                                                        current_idx
-                                                            .min(filtered.len().saturating_sub(1)),
-                                                    ) {
+                                                Some(
+                                                    HotkeyAction::Select | HotkeyAction::EditField,
+                                                ) => {
+                                                    // Toggle the current person; the dropdown stays open for
+                                                    // multi-select and closes on Escape
+                                                    if let Some(selected) = users.get(current_idx) {
                                                         state.toggle_custom_field_people(
                                                             &cf_gid,
                                                             selected.gid.clone(),
                                                         );
-                                                        // Don't close dropdown for multi-select - user can select multiple items
-                                                        // Dropdown closes on Escape
                                                     }
                                                     return Ok(true);
                                                 }
                                                 _ => {
-                                                    // Check for configured hotkeys (j/k) as fallback
-                                                    if let Some(action) = get_action_for_event(
-                                                        &event,
-                                                        state.current_view(),
-                                                        state.get_hotkeys(),
-                                                    ) {
-                                                        match action {
-                                                            HotkeyAction::NavigateNext => {
-                                                                let filtered_count = users
-                                                                    .iter()
-                                                                    .filter(|u| {
-                                                                        search.is_empty()
-                                                                            || u.name
-                                                                                .to_lowercase()
-                                                                                .contains(
-                                                                                    &search.to_lowercase(),
-                                                                                )
-                                                                    })
-                                                                    .count();
-                                                                state.next_custom_field_enum(
-                                                                    &cf_gid,
-                                                                    filtered_count,
-                                                                );
-                                                                return Ok(true);
-                                                            }
-                                                            HotkeyAction::NavigatePrev => {
-                                                                let filtered_count = users
-                                                                    .iter()
-                                                                    .filter(|u| {
-                                                                        search.is_empty()
-                                                                            || u.name
-                                                                                .to_lowercase()
-                                                                                .contains(
-                                                                                    &search.to_lowercase(),
-                                                                                )
-                                                                    })
-                                                                    .count();
-                                                                state.previous_custom_field_enum(
-                                                                    &cf_gid,
-                                                                    filtered_count,
-                                                                );
-                                                                return Ok(true);
-                                                            }
-                                                            HotkeyAction::Select
-                                                            | HotkeyAction::EditField => {
-                                                                // In field editing mode with combobox, Enter should select
-                                                                // (EditField is bound to Enter in CreateTask/EditTask, but in comboboxes it means select)
-                                                                let filtered: Vec<_> = users
-                                                                    .iter()
-                                                                    .filter(|u| {
-                                                                        search.is_empty()
-                                                                            || u.name
-                                                                                .to_lowercase()
-                                                                                .contains(
-                                                                                    &search.to_lowercase(),
-                                                                                )
-                                                                    })
-                                                                    .collect();
-                                                                let current_idx = state
-                                                                    .get_custom_field_dropdown_index(
-                                                                        &cf_gid,
-                                                                    );
-                                                                if let Some(selected) = filtered
-                                                                    .get(
-                                                                        current_idx.min(
-                                                                            filtered
-                                                                                .len()
-                                                                                .saturating_sub(1),
-                                                                        ),
-                                                                    )
-                                                                {
-                                                                    state
-                                                                        .toggle_custom_field_people(
-                                                                            &cf_gid,
-                                                                            selected.gid.clone(),
-                                                                        );
-                                                                    // Don't close dropdown for multi-select - user can select multiple items
-                                                                    // Dropdown closes on Escape
-                                                                }
-                                                                return Ok(true);
-                                                            }
-                                                            _ => {
-                                                                // Not a navigation/select action, continue to text input
-                                                            }
-                                                        }
-                                                    }
+                                                    // Not a navigation/select action, continue to text input
                                                 }
                                             }
 
@@ -1270,6 +1751,7 @@ impl Handler {
                                                         cf_gid.clone(),
                                                         c,
                                                     );
+                                                    state.request_people_typeahead(&cf_gid);
                                                     return Ok(true);
                                                 }
                                                 KeyEvent {
@@ -1277,6 +1759,7 @@ impl Handler {
                                                     ..
                                                 } => {
                                                     state.backspace_custom_field_search(&cf_gid);
+                                                    state.request_people_typeahead(&cf_gid);
                                                     return Ok(true);
                                                 }
                                                 _ => {}
@@ -1417,6 +1900,15 @@ impl Handler {
                                     state.add_comment_char(c);
                                     return Ok(true);
                                 }
+                                // Arrow keys pick among the tasks matching a dependency input
+                                if state.is_dependency_input_mode() {
+                                    if action == HotkeyAction::NavigateNext {
+                                        state.next_dependency_candidate();
+                                    } else {
+                                        state.previous_dependency_candidate();
+                                    }
+                                    return Ok(true);
+                                }
                             } else if state.has_assignee_filter() {
                                 // In assignee filter mode, allow typing for search
                                 if let KeyCode::Char(c) = event.code {
@@ -1442,8 +1934,12 @@ impl Handler {
                                         Some(crate::state::EditFormState::Notes) => {
                                             // Already handled above
                                         }
-                                        Some(crate::state::EditFormState::DueDate) => {
-                                            state.add_form_due_on_char(c);
+                                        Some(
+                                            crate::state::EditFormState::StartDate
+                                            | crate::state::EditFormState::DueDate
+                                            | crate::state::EditFormState::DueTime,
+                                        ) => {
+                                            state.add_form_date_char(c);
                                         }
                                         _ => {}
                                     }
@@ -1466,6 +1962,15 @@ impl Handler {
                                             crate::state::View::TaskDetail
                                         ) {
                                             match state.get_current_task_panel() {
+                                                crate::state::TaskDetailPanel::Subtasks => {
+                                                    state.next_subtask();
+                                                }
+                                                crate::state::TaskDetailPanel::Dependencies => {
+                                                    state.next_relation();
+                                                }
+                                                crate::state::TaskDetailPanel::Attachments => {
+                                                    state.next_attachment();
+                                                }
                                                 crate::state::TaskDetailPanel::Comments => {
                                                     state.scroll_comments_down();
                                                 }
@@ -1476,6 +1981,18 @@ impl Handler {
                                                     state.scroll_notes_down();
                                                 }
                                             }
+                                        } else if matches!(
+                                            state.current_view(),
+                                            crate::state::View::Portfolios
+                                        ) && state.current_focus() == &Focus::View
+                                        {
+                                            state.next_portfolio_row();
+                                        } else if matches!(
+                                            state.current_view(),
+                                            crate::state::View::TaskSearch
+                                        ) && state.current_focus() == &Focus::View
+                                        {
+                                            state.next_task_search_result();
                                         } else if matches!(
                                             state.current_view(),
                                             crate::state::View::ProjectTasks
@@ -1497,12 +2014,21 @@ impl Handler {
                                                         crate::state::EditFormState::Assignee
                                                     }
                                                     Some(crate::state::EditFormState::Assignee) => {
-                                                        crate::state::EditFormState::DueDate
+                                                        crate::state::EditFormState::StartDate
                                                     }
+                                                    Some(
+                                                        crate::state::EditFormState::StartDate,
+                                                    ) => crate::state::EditFormState::DueDate,
                                                     Some(crate::state::EditFormState::DueDate) => {
+                                                        crate::state::EditFormState::DueTime
+                                                    }
+                                                    Some(crate::state::EditFormState::DueTime) => {
                                                         crate::state::EditFormState::Section
                                                     }
                                                     Some(crate::state::EditFormState::Section) => {
+                                                        crate::state::EditFormState::Tags
+                                                    }
+                                                    Some(crate::state::EditFormState::Tags) => {
                                                         if !enabled_custom_fields.is_empty() {
                                                             crate::state::EditFormState::CustomField(
                                                                 0,
@@ -1588,6 +2114,15 @@ impl Handler {
                                             crate::state::View::TaskDetail
                                         ) {
                                             match state.get_current_task_panel() {
+                                                crate::state::TaskDetailPanel::Subtasks => {
+                                                    state.previous_subtask();
+                                                }
+                                                crate::state::TaskDetailPanel::Dependencies => {
+                                                    state.previous_relation();
+                                                }
+                                                crate::state::TaskDetailPanel::Attachments => {
+                                                    state.previous_attachment();
+                                                }
                                                 crate::state::TaskDetailPanel::Comments => {
                                                     state.scroll_comments_up();
                                                 }
@@ -1598,6 +2133,18 @@ impl Handler {
                                                     state.scroll_notes_up();
                                                 }
                                             }
+                                        } else if matches!(
+                                            state.current_view(),
+                                            crate::state::View::Portfolios
+                                        ) && state.current_focus() == &Focus::View
+                                        {
+                                            state.previous_portfolio_row();
+                                        } else if matches!(
+                                            state.current_view(),
+                                            crate::state::View::TaskSearch
+                                        ) && state.current_focus() == &Focus::View
+                                        {
+                                            state.previous_task_search_result();
                                         } else if matches!(
                                             state.current_view(),
                                             crate::state::View::ProjectTasks
@@ -1618,7 +2165,7 @@ impl Handler {
                                                                 enabled_custom_fields.len() - 1,
                                                             )
                                                         } else {
-                                                            crate::state::EditFormState::Section
+                                                            crate::state::EditFormState::Tags
                                                         }
                                                     }
                                                     Some(crate::state::EditFormState::Notes) => {
@@ -1627,15 +2174,24 @@ impl Handler {
                                                     Some(crate::state::EditFormState::Assignee) => {
                                                         crate::state::EditFormState::Notes
                                                     }
+                                                    Some(
+                                                        crate::state::EditFormState::StartDate,
+                                                    ) => crate::state::EditFormState::Assignee,
                                                     Some(crate::state::EditFormState::DueDate) => {
-                                                        crate::state::EditFormState::Assignee
+                                                        crate::state::EditFormState::StartDate
                                                     }
-                                                    Some(crate::state::EditFormState::Section) => {
+                                                    Some(crate::state::EditFormState::DueTime) => {
                                                         crate::state::EditFormState::DueDate
                                                     }
+                                                    Some(crate::state::EditFormState::Section) => {
+                                                        crate::state::EditFormState::DueTime
+                                                    }
+                                                    Some(crate::state::EditFormState::Tags) => {
+                                                        crate::state::EditFormState::Section
+                                                    }
                                                     Some(
                                                         crate::state::EditFormState::CustomField(0),
-                                                    ) => crate::state::EditFormState::Section,
+                                                    ) => crate::state::EditFormState::Tags,
                                                     Some(
                                                         crate::state::EditFormState::CustomField(
                                                             idx,
@@ -1734,8 +2290,12 @@ impl Handler {
                                         Some(crate::state::EditFormState::Notes) => {
                                             // Already handled above
                                         }
-                                        Some(crate::state::EditFormState::DueDate) => {
-                                            state.add_form_due_on_char(c);
+                                        Some(
+                                            crate::state::EditFormState::StartDate
+                                            | crate::state::EditFormState::DueDate
+                                            | crate::state::EditFormState::DueTime,
+                                        ) => {
+                                            state.add_form_date_char(c);
                                         }
                                         _ => {}
                                     }
@@ -2203,13 +2763,31 @@ impl Handler {
                                 Some(crate::state::EditFormState::Section) => {
                                     state.backspace_section_search();
                                 }
-                                Some(crate::state::EditFormState::DueDate) => {
-                                    state.remove_form_due_on_char();
+                                Some(
+                                    crate::state::EditFormState::StartDate
+                                    | crate::state::EditFormState::DueDate
+                                    | crate::state::EditFormState::DueTime,
+                                ) => {
+                                    state.remove_form_date_char();
                                 }
                                 _ => {}
                             }
                         }
                     }
+                    KeyEvent {
+                        code: KeyCode::Tab,
+                        modifiers: KeyModifiers::NONE,
+                        ..
+                    } if state.is_dependency_input_mode() => {
+                        state.toggle_dependency_input_kind();
+                    }
+                    KeyEvent {
+                        code: KeyCode::Tab,
+                        modifiers: KeyModifiers::NONE,
+                        ..
+                    } if state.has_assignee_filter() => {
+                        state.toggle_filter_target();
+                    }
                     KeyEvent {
                         code: KeyCode::Tab,
                         modifiers: KeyModifiers::NONE,
@@ -2231,12 +2809,21 @@ impl Handler {
                                     crate::state::EditFormState::Assignee
                                 }
                                 Some(crate::state::EditFormState::Assignee) => {
+                                    crate::state::EditFormState::StartDate
+                                }
+                                Some(crate::state::EditFormState::StartDate) => {
                                     crate::state::EditFormState::DueDate
                                 }
                                 Some(crate::state::EditFormState::DueDate) => {
+                                    crate::state::EditFormState::DueTime
+                                }
+                                Some(crate::state::EditFormState::DueTime) => {
                                     crate::state::EditFormState::Section
                                 }
                                 Some(crate::state::EditFormState::Section) => {
+                                    crate::state::EditFormState::Tags
+                                }
+                                Some(crate::state::EditFormState::Tags) => {
                                     if !custom_fields.is_empty() {
                                         crate::state::EditFormState::CustomField(0)
                                     } else {
@@ -2281,7 +2868,7 @@ impl Handler {
                                             custom_fields.len() - 1,
                                         )
                                     } else {
-                                        crate::state::EditFormState::Section
+                                        crate::state::EditFormState::Tags
                                     }
                                 }
                                 Some(crate::state::EditFormState::Notes) => {
@@ -2290,15 +2877,24 @@ impl Handler {
                                 Some(crate::state::EditFormState::Assignee) => {
                                     crate::state::EditFormState::Notes
                                 }
-                                Some(crate::state::EditFormState::DueDate) => {
+                                Some(crate::state::EditFormState::StartDate) => {
                                     crate::state::EditFormState::Assignee
                                 }
-                                Some(crate::state::EditFormState::Section) => {
+                                Some(crate::state::EditFormState::DueDate) => {
+                                    crate::state::EditFormState::StartDate
+                                }
+                                Some(crate::state::EditFormState::DueTime) => {
                                     crate::state::EditFormState::DueDate
                                 }
-                                Some(crate::state::EditFormState::CustomField(0)) => {
+                                Some(crate::state::EditFormState::Section) => {
+                                    crate::state::EditFormState::DueTime
+                                }
+                                Some(crate::state::EditFormState::Tags) => {
                                     crate::state::EditFormState::Section
                                 }
+                                Some(crate::state::EditFormState::CustomField(0)) => {
+                                    crate::state::EditFormState::Tags
+                                }
                                 Some(crate::state::EditFormState::CustomField(idx)) => {
                                     crate::state::EditFormState::CustomField(idx - 1)
                                 }
@@ -2373,8 +2969,12 @@ impl Handler {
                                 Some(crate::state::EditFormState::Notes) => {
                                     // Already handled above
                                 }
-                                Some(crate::state::EditFormState::DueDate) => {
-                                    state.add_form_due_on_char(c);
+                                Some(
+                                    crate::state::EditFormState::StartDate
+                                    | crate::state::EditFormState::DueDate
+                                    | crate::state::EditFormState::DueTime,
+                                ) => {
+                                    state.add_form_date_char(c);
                                 }
                                 _ => {}
                             }
@@ -2537,44 +3137,23 @@ impl Handler {
                                 state.get_hotkeys(),
                             ) {
                                 // In move task modal, Enter selects the section and moves the task
-                                if let Some(task_gid) = state.get_move_task_gid() {
-                                    let sections = state.get_sections();
-                                    let selected_index = state.get_section_dropdown_index();
-                                    if selected_index < sections.len() {
-                                        let selected_section = &sections[selected_index];
-                                        debug!(
-                                            "Moving task {} to section {}...",
-                                            task_gid, selected_section.gid
-                                        );
-                                        state.dispatch(
-                                            crate::events::network::Event::MoveTaskToSection {
-                                                task_gid: task_gid.clone(),
-                                                section_gid: selected_section.gid.clone(),
-                                            },
-                                        );
-                                        state.clear_move_task();
-                                    }
+                                // (or every marked task)
+                                let selected_section = state
+                                    .get_sections()
+                                    .get(state.get_section_dropdown_index())
+                                    .map(|s| s.gid.clone());
+                                if let Some(section_gid) = selected_section {
+                                    state.move_tasks_to_section(section_gid);
                                 }
                                 return Ok(true);
                             }
                             // Fallback to default behavior
-                            if let Some(task_gid) = state.get_move_task_gid() {
-                                let sections = state.get_sections();
-                                let selected_index = state.get_section_dropdown_index();
-                                if selected_index < sections.len() {
-                                    let selected_section = &sections[selected_index];
-                                    debug!(
-                                        "Moving task {} to section {}...",
-                                        task_gid, selected_section.gid
-                                    );
-                                    state.dispatch(
-                                        crate::events::network::Event::MoveTaskToSection {
-                                            task_gid: task_gid.clone(),
-                                            section_gid: selected_section.gid.clone(),
-                                        },
-                                    );
-                                    state.clear_move_task();
-                                }
+                            let selected_section = state
+                                .get_sections()
+                                .get(state.get_section_dropdown_index())
+                                .map(|s| s.gid.clone());
+                            if let Some(section_gid) = selected_section {
+                                state.move_tasks_to_section(section_gid);
                             }
                         } else if state.has_delete_confirmation() {
                             if let Some(HotkeyAction::DeleteConfirm) = get_action_for_special_mode(
@@ -2589,6 +3168,33 @@ impl Handler {
                             // Fallback to default behavior
                             debug!("Processing confirm delete event '{:?}'...", event);
                             state.confirm_delete_task();
+                        } else if state.is_dependency_input_mode() {
+                            // Link the selected matching task
+                            state.submit_dependency_input();
+                        } else if state.is_attachment_input_mode() {
+                            // Upload the file at the typed path
+                            state.submit_attachment_input();
+                        } else if state.is_section_input_mode() {
+                            // Create or rename the board column
+                            state.submit_section_input();
+                        } else if state.is_marked_comment_input_mode() {
+                            // Comment on every marked task
+                            state.submit_marked_comment();
+                        } else if state.is_edit_comment_input_mode() {
+                            // Save the edited comment
+                            state.submit_comment_edit();
+                        } else if state.is_subtask_input_mode() {
+                            // Submit new subtask name
+                            let task_gid = state.get_task_detail().map(|t| t.gid.clone());
+                            if let Some(gid) = task_gid {
+                                let name = state.submit_comment();
+                                if !name.trim().is_empty() {
+                                    state.dispatch(crate::events::network::Event::CreateSubtask {
+                                        parent_gid: gid,
+                                        name: name.trim().to_string(),
+                                    });
+                                }
+                            }
                         } else if state.is_comment_input_mode() {
                             // Submit comment
                             let task_gid = state.get_task_detail().map(|t| t.gid.clone());
@@ -2621,11 +3227,21 @@ impl Handler {
                                 Some(crate::state::EditFormState::Assignee) => {
                                     // Select the current assignee and move to next field
                                     state.select_current_assignee();
+                                    state.set_edit_form_state(Some(
+                                        crate::state::EditFormState::StartDate,
+                                    ));
+                                }
+                                Some(crate::state::EditFormState::StartDate) => {
                                     state.set_edit_form_state(Some(
                                         crate::state::EditFormState::DueDate,
                                     ));
                                 }
                                 Some(crate::state::EditFormState::DueDate) => {
+                                    state.set_edit_form_state(Some(
+                                        crate::state::EditFormState::DueTime,
+                                    ));
+                                }
+                                Some(crate::state::EditFormState::DueTime) => {
                                     state.set_edit_form_state(Some(
                                         crate::state::EditFormState::Section,
                                     ));
@@ -2636,6 +3252,9 @@ impl Handler {
                                     // User must press 's' to submit or Esc to cancel
                                     state.select_current_section();
                                 }
+                                Some(crate::state::EditFormState::Tags) => {
+                                    state.toggle_current_tag();
+                                }
                                 Some(crate::state::EditFormState::CustomField(_idx)) => {
                                     // TODO: Handle custom field selection
                                 }
@@ -2694,6 +3313,42 @@ impl Handler {
                                                 }
                                             }
                                         }
+                                    } else if matches!(
+                                        state.current_view(),
+                                        crate::state::View::TaskDetail
+                                    ) && state.get_current_task_panel()
+                                        == crate::state::TaskDetailPanel::Subtasks
+                                    {
+                                        // Subtasks panel: drill into the selected subtask
+                                        state.open_selected_subtask();
+                                    } else if matches!(
+                                        state.current_view(),
+                                        crate::state::View::TaskDetail
+                                    ) && state.get_current_task_panel()
+                                        == crate::state::TaskDetailPanel::Dependencies
+                                    {
+                                        // Dependencies panel: jump into the selected task
+                                        state.open_selected_relation();
+                                    } else if matches!(
+                                        state.current_view(),
+                                        crate::state::View::TaskDetail
+                                    ) && state.get_current_task_panel()
+                                        == crate::state::TaskDetailPanel::Attachments
+                                    {
+                                        // Attachments panel: download the selected file
+                                        state.download_selected_attachment();
+                                    } else if matches!(
+                                        state.current_view(),
+                                        crate::state::View::Portfolios
+                                    ) {
+                                        // Portfolios view: expand the portfolio or open the project
+                                        state.select_portfolio_row();
+                                    } else if matches!(
+                                        state.current_view(),
+                                        crate::state::View::TaskSearch
+                                    ) {
+                                        // Task search: open the selected result
+                                        state.open_selected_task_search_result();
                                     } else if matches!(
                                         state.current_view(),
                                         crate::state::View::Welcome
@@ -2722,8 +3377,44 @@ impl Handler {
             }
             Event::Tick => {
                 state.advance_spinner_index();
+                state.dispatch_due_typeahead();
+                if state.sync_due() {
+                    state.dispatch(crate::events::network::Event::SyncProject);
+                }
             }
         }
         Ok(true)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asana::Task;
+    use crate::state::{EditFormState, View};
+    use fake::{Fake, Faker};
+
+    #[test]
+    fn submitting_edited_notes_that_drop_tags_is_rejected() {
+        let mut state = State::default();
+        let mut task = Faker.fake::<Task>();
+        task.name = "Ship".to_string();
+        task.due_on = None;
+        task.due_at = None;
+        task.start_on = None;
+        task.custom_fields = vec![];
+        task.html_notes = Some("<body>See <img data-asana-gid=\"5\"/></body>".to_string());
+        state.push_view(View::EditTask);
+        state.init_edit_form(&task);
+        state.set_form_notes("See below".to_string());
+
+        let submit = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty());
+        assert_eq!(
+            try_execute_hotkey_action(&submit, &mut state).unwrap(),
+            Some(true)
+        );
+        // The form stays open with the notes field focused
+        assert_eq!(state.current_view(), &View::EditTask);
+        assert_eq!(state.get_edit_form_state(), Some(EditFormState::Notes));
+    }
+}
@@ -32,11 +32,21 @@ async fn main() -> AppResult<()> {
                 .long("config")
                 .help("Specify path to configuration file.")
                 .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("api-url")
+                .long("api-url")
+                .value_name("URL")
+                .help("Specify Asana API base URL, e.g. for a proxy or mock server.")
+                .takes_value(true),
         );
 
     let matches = clap_app.get_matches();
     let mut config = Config::new();
     config.load(matches.value_of("config"))?;
+    if let Some(api_url) = matches.value_of("api-url") {
+        config.api_base_url_override = Some(config::normalize_api_base_url(api_url)?);
+    }
 
     App::start(config).await
 }
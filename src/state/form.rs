@@ -3,6 +3,9 @@
 //! This module contains types related to form editing, including form fields,
 //! custom field values, and form state management.
 
+use crate::asana::Section;
+use chrono::{Days, Months, NaiveDate};
+
 /// Custom field value for form editing.
 ///
 #[derive(Clone, Debug, PartialEq)]
@@ -22,8 +25,11 @@ pub enum EditFormState {
     Name,
     Notes,
     Assignee,
+    StartDate,
     DueDate,
+    DueTime,
     Section,
+    Tags,
     CustomField(usize), // Index into custom_fields array
 }
 
@@ -36,12 +42,373 @@ pub enum TaskFilter {
     Completed,
     #[allow(dead_code)]
     Assignee(Option<String>),
+    Tag(String), // GID of the tag tasks must have
+}
+
+/// Asana project colors, in the order the project form cycles through them.
+///
+pub const PROJECT_COLORS: &[&str] = &[
+    "dark-pink",
+    "dark-green",
+    "dark-blue",
+    "dark-red",
+    "dark-teal",
+    "dark-brown",
+    "dark-orange",
+    "dark-purple",
+    "dark-warm-gray",
+    "light-pink",
+    "light-green",
+    "light-blue",
+    "light-red",
+    "light-teal",
+    "light-brown",
+    "light-orange",
+    "light-purple",
+    "light-warm-gray",
+];
+
+/// Specifying project form field state.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProjectFormField {
+    Name,
+    Notes,
+    Color,
+    Team, // Only offered when creating a project
+}
+
+/// Project being created or edited from the projects list.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProjectForm {
+    pub gid: Option<String>, // Project being edited, None when creating
+    pub name: String,
+    pub notes: String,
+    pub color: Option<String>,
+    pub team_gid: Option<String>, // None creates the project in the workspace
+    pub field: ProjectFormField,
+}
+
+impl ProjectForm {
+    /// Return the fields of the form in display order.
+    ///
+    pub fn fields(&self) -> Vec<ProjectFormField> {
+        let mut fields = vec![
+            ProjectFormField::Name,
+            ProjectFormField::Notes,
+            ProjectFormField::Color,
+        ];
+        if self.gid.is_none() {
+            fields.push(ProjectFormField::Team);
+        }
+        fields
+    }
+
+    /// Move to the next field, wrapping around.
+    ///
+    pub fn next_field(&mut self) {
+        let fields = self.fields();
+        let index = fields.iter().position(|f| *f == self.field).unwrap_or(0);
+        self.field = fields[(index + 1) % fields.len()];
+    }
+
+    /// Move to the previous field, wrapping around.
+    ///
+    pub fn previous_field(&mut self) {
+        let fields = self.fields();
+        let index = fields.iter().position(|f| *f == self.field).unwrap_or(0);
+        self.field = fields[(index + fields.len() - 1) % fields.len()];
+    }
+
+    /// Step through the project colors, with no color between the last and
+    /// the first.
+    ///
+    pub fn cycle_color(&mut self, forward: bool) {
+        let options: Vec<Option<&str>> = std::iter::once(None)
+            .chain(PROJECT_COLORS.iter().map(|c| Some(*c)))
+            .collect();
+        let index = options
+            .iter()
+            .position(|c| *c == self.color.as_deref())
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % options.len()
+        } else {
+            (index + options.len() - 1) % options.len()
+        };
+        self.color = options[next].map(str::to_string);
+    }
+}
+
+/// Specifying what a task's project picker does with the chosen project.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProjectPickerAction {
+    Add,    // Also home the task in another project
+    Move,   // Add to another project, then leave the current one
+    Remove, // Take the task out of one of its projects
+}
+
+/// Searchable project dropdown for adding, moving or removing a task's
+/// projects. Adding and moving pick a section of the target project next.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProjectPicker {
+    pub action: ProjectPickerAction,
+    pub search: String,
+    pub index: usize,
+    pub target: Option<(String, String)>, // GID and name of the chosen project
+    pub sections: Option<Vec<Section>>,   // Target project sections, None while loading
+    pub sections_error: Option<String>,   // Why the target project sections failed to load
+}
+
+/// Specifying the field a date picker fills in.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DateTarget {
+    Form(EditFormState), // Start or due date of the task form
+    CustomField(String), // GID of a date custom field
+}
+
+/// Month calendar popup for choosing a date with the keyboard.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DatePicker {
+    pub target: DateTarget,
+    pub cursor: NaiveDate, // Highlighted day
+}
+
+impl DatePicker {
+    /// Move the highlighted day by a number of days.
+    ///
+    pub fn move_days(&mut self, days: i64) {
+        let moved = if days < 0 {
+            self.cursor.checked_sub_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.cursor.checked_add_days(Days::new(days as u64))
+        };
+        self.cursor = moved.unwrap_or(self.cursor);
+    }
+
+    /// Move the highlighted day by a number of months, keeping the day of
+    /// the month where it exists.
+    ///
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months < 0 {
+            self.cursor
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.cursor.checked_add_months(Months::new(months as u32))
+        };
+        self.cursor = moved.unwrap_or(self.cursor);
+    }
+}
+
+/// Project status types, in the order the status update form cycles
+/// through them.
+///
+pub const STATUS_TYPES: &[&str] = &["on_track", "at_risk", "off_track", "on_hold", "complete"];
+
+/// Specifying status update form field state.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StatusUpdateField {
+    Status,
+    Title,
+    Text,
+}
+
+/// Status update being written for a portfolio project. The title and text
+/// are held in the task form's name and notes.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StatusUpdateForm {
+    pub project_gid: String,
+    pub project_name: String,
+    pub status_type: String,
+    pub field: StatusUpdateField,
+}
+
+impl StatusUpdateForm {
+    /// Move to the next field, wrapping around.
+    ///
+    pub fn next_field(&mut self) {
+        self.field = match self.field {
+            StatusUpdateField::Status => StatusUpdateField::Title,
+            StatusUpdateField::Title => StatusUpdateField::Text,
+            StatusUpdateField::Text => StatusUpdateField::Status,
+        };
+    }
+
+    /// Move to the previous field, wrapping around.
+    ///
+    pub fn previous_field(&mut self) {
+        self.field = match self.field {
+            StatusUpdateField::Status => StatusUpdateField::Text,
+            StatusUpdateField::Title => StatusUpdateField::Status,
+            StatusUpdateField::Text => StatusUpdateField::Title,
+        };
+    }
+
+    /// Step through the status types.
+    ///
+    pub fn cycle_status(&mut self, forward: bool) {
+        let index = STATUS_TYPES
+            .iter()
+            .position(|s| *s == self.status_type)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % STATUS_TYPES.len()
+        } else {
+            (index + STATUS_TYPES.len() - 1) % STATUS_TYPES.len()
+        };
+        self.status_type = STATUS_TYPES[next].to_string();
+    }
+}
+
+/// Specifying task search form field state, in display order.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TaskSearchField {
+    Text,
+    Assignee,
+    Project,
+    Section,
+    Tag,
+    Completion,
+    DueAfter,
+    DueBefore,
+    CustomField,
+    CustomFieldValue,
+}
+
+const TASK_SEARCH_FIELDS: &[TaskSearchField] = &[
+    TaskSearchField::Text,
+    TaskSearchField::Assignee,
+    TaskSearchField::Project,
+    TaskSearchField::Section,
+    TaskSearchField::Tag,
+    TaskSearchField::Completion,
+    TaskSearchField::DueAfter,
+    TaskSearchField::DueBefore,
+    TaskSearchField::CustomField,
+    TaskSearchField::CustomFieldValue,
+];
+
+/// Filters of a workspace-wide task search as typed. Assignee, project,
+/// section, tag and custom field are names matched against known options.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TaskSearchForm {
+    pub text: String,
+    pub assignee: String,
+    pub project: String,
+    pub section: String, // Matched against sections of the chosen project
+    pub tag: String,
+    pub completed: Option<bool>, // None matches both complete and incomplete
+    pub due_after: String,
+    pub due_before: String,
+    pub custom_field: String, // Matched against custom fields of the chosen project
+    pub custom_field_value: String,
+    pub field: TaskSearchField,
+    pub error: Option<String>, // Why the last search could not be run
+}
+
+impl Default for TaskSearchForm {
+    fn default() -> Self {
+        TaskSearchForm {
+            text: String::new(),
+            assignee: String::new(),
+            project: String::new(),
+            section: String::new(),
+            tag: String::new(),
+            completed: None,
+            due_after: String::new(),
+            due_before: String::new(),
+            custom_field: String::new(),
+            custom_field_value: String::new(),
+            field: TaskSearchField::Text,
+            error: None,
+        }
+    }
+}
+
+impl TaskSearchForm {
+    /// Move to the next field, wrapping around.
+    ///
+    pub fn next_field(&mut self) {
+        let index = TASK_SEARCH_FIELDS
+            .iter()
+            .position(|f| *f == self.field)
+            .unwrap_or(0);
+        self.field = TASK_SEARCH_FIELDS[(index + 1) % TASK_SEARCH_FIELDS.len()];
+    }
+
+    /// Move to the previous field, wrapping around.
+    ///
+    pub fn previous_field(&mut self) {
+        let len = TASK_SEARCH_FIELDS.len();
+        let index = TASK_SEARCH_FIELDS
+            .iter()
+            .position(|f| *f == self.field)
+            .unwrap_or(0);
+        self.field = TASK_SEARCH_FIELDS[(index + len - 1) % len];
+    }
+
+    /// Step through any, incomplete and completed tasks.
+    ///
+    pub fn cycle_completed(&mut self, forward: bool) {
+        self.completed = match (self.completed, forward) {
+            (None, true) | (Some(true), false) => Some(false),
+            (Some(false), true) | (None, false) => Some(true),
+            _ => None,
+        };
+    }
+
+    /// Return the text of the current field, if it is typed into.
+    ///
+    pub fn current_text_mut(&mut self) -> Option<&mut String> {
+        match self.field {
+            TaskSearchField::Text => Some(&mut self.text),
+            TaskSearchField::Assignee => Some(&mut self.assignee),
+            TaskSearchField::Project => Some(&mut self.project),
+            TaskSearchField::Section => Some(&mut self.section),
+            TaskSearchField::Tag => Some(&mut self.tag),
+            TaskSearchField::Completion => None,
+            TaskSearchField::DueAfter => Some(&mut self.due_after),
+            TaskSearchField::DueBefore => Some(&mut self.due_before),
+            TaskSearchField::CustomField => Some(&mut self.custom_field),
+            TaskSearchField::CustomFieldValue => Some(&mut self.custom_field_value),
+        }
+    }
+}
+
+/// Specifying an entry of the shortcuts list.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Shortcut {
+    Project { gid: String, name: String }, // A starred project
+    MyTasks,                               // The current user's My Tasks list
+    Portfolios,                            // The current user's portfolios
+}
+
+impl Shortcut {
+    /// Return the name shown in the shortcuts list.
+    ///
+    pub fn name(&self) -> &str {
+        match self {
+            Shortcut::Project { name, .. } => name,
+            Shortcut::MyTasks => "My Tasks",
+            Shortcut::Portfolios => "Portfolios",
+        }
+    }
 }
 
 /// Get the base shortcuts list.
 ///
-pub fn base_shortcuts() -> Vec<String> {
-    vec![]
+pub fn base_shortcuts() -> Vec<Shortcut> {
+    vec![Shortcut::MyTasks, Shortcut::Portfolios]
 }
 
 #[cfg(test)]
@@ -115,6 +482,7 @@ mod tests {
         assert_eq!(EditFormState::Notes, EditFormState::Notes);
         assert_eq!(EditFormState::Assignee, EditFormState::Assignee);
         assert_eq!(EditFormState::DueDate, EditFormState::DueDate);
+        assert_ne!(EditFormState::StartDate, EditFormState::DueTime);
         assert_eq!(EditFormState::Section, EditFormState::Section);
         assert_eq!(EditFormState::CustomField(0), EditFormState::CustomField(0));
         assert_ne!(EditFormState::CustomField(0), EditFormState::CustomField(1));
@@ -132,9 +500,92 @@ mod tests {
         assert_eq!(TaskFilter::Assignee(None), TaskFilter::Assignee(None));
     }
 
+    #[test]
+    fn test_project_form_cycles_fields_and_colors() {
+        let mut form = ProjectForm {
+            gid: Some("1".to_string()),
+            name: String::new(),
+            notes: String::new(),
+            color: None,
+            team_gid: None,
+            field: ProjectFormField::Color,
+        };
+        // Editing skips the team field
+        form.next_field();
+        assert_eq!(form.field, ProjectFormField::Name);
+        form.previous_field();
+        assert_eq!(form.field, ProjectFormField::Color);
+
+        form.cycle_color(true);
+        assert_eq!(form.color.as_deref(), Some("dark-pink"));
+        form.cycle_color(false);
+        assert_eq!(form.color, None);
+        form.cycle_color(false);
+        assert_eq!(form.color.as_deref(), Some("light-warm-gray"));
+    }
+
+    #[test]
+    fn test_status_update_form_cycles_status() {
+        let mut form = StatusUpdateForm {
+            project_gid: "1".to_string(),
+            project_name: "Launch".to_string(),
+            status_type: "on_track".to_string(),
+            field: StatusUpdateField::Status,
+        };
+        form.cycle_status(false);
+        assert_eq!(form.status_type, "complete");
+        form.cycle_status(true);
+        assert_eq!(form.status_type, "on_track");
+        form.previous_field();
+        assert_eq!(form.field, StatusUpdateField::Text);
+    }
+
+    #[test]
+    fn test_task_search_form_fields() {
+        let mut form = TaskSearchForm::default();
+        form.previous_field();
+        assert_eq!(form.field, TaskSearchField::CustomFieldValue);
+        form.next_field();
+        form.current_text_mut().unwrap().push('x');
+        assert_eq!(form.text, "x");
+
+        form.field = TaskSearchField::Completion;
+        assert!(form.current_text_mut().is_none());
+        form.cycle_completed(true);
+        assert_eq!(form.completed, Some(false));
+        form.cycle_completed(true);
+        assert_eq!(form.completed, Some(true));
+        form.cycle_completed(true);
+        assert_eq!(form.completed, None);
+        form.cycle_completed(false);
+        assert_eq!(form.completed, Some(true));
+    }
+
+    #[test]
+    fn test_date_picker_moves_by_days_and_months() {
+        let mut picker = DatePicker {
+            target: DateTarget::Form(EditFormState::DueDate),
+            cursor: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        };
+        picker.move_days(1);
+        assert_eq!(picker.cursor, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        picker.move_days(-7);
+        assert_eq!(picker.cursor, NaiveDate::from_ymd_opt(2024, 1, 25).unwrap());
+        picker.move_days(6);
+        picker.move_months(1);
+        // The 31st doesn't exist in February
+        assert_eq!(picker.cursor, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        picker.move_months(-2);
+        assert_eq!(
+            picker.cursor,
+            NaiveDate::from_ymd_opt(2023, 12, 29).unwrap()
+        );
+    }
+
     #[test]
     fn test_base_shortcuts() {
         let shortcuts = base_shortcuts();
-        assert!(shortcuts.is_empty());
+        assert_eq!(shortcuts, vec![Shortcut::MyTasks, Shortcut::Portfolios]);
+        assert_eq!(shortcuts[0].name(), "My Tasks");
     }
 }
@@ -11,8 +11,14 @@ mod form;
 mod navigation;
 
 pub use error::StateError;
-pub use form::{CustomFieldValue, EditFormState};
-pub use navigation::{Focus, Menu, SearchTarget, TaskDetailPanel, View, ViewMode};
+pub use form::{
+    CustomFieldValue, DatePicker, EditFormState, ProjectFormField, ProjectPickerAction,
+    StatusUpdateField, TaskSearchField,
+};
+pub use navigation::{
+    DependencyKind, FilterTarget, Focus, Menu, PortfolioRow, SearchTarget, TaskDetailPanel,
+    TeamListRow, View, ViewMode,
+};
 
 // Re-export implementation from state_impl.rs
 // State struct, methods and Default impl are in state_impl.rs
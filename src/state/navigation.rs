@@ -2,6 +2,8 @@
 //!
 //! This module contains enums and types related to navigation, views, menus, and focus.
 
+use crate::asana::{Portfolio, PortfolioProject, Project, Team};
+
 /// Specifying the different foci.
 ///
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -28,6 +30,9 @@ pub enum View {
     TaskDetail,
     CreateTask,
     EditTask,
+    Portfolios,
+    CreateStatusUpdate,
+    TaskSearch,
 }
 
 /// Specifying view mode (list or kanban).
@@ -46,13 +51,66 @@ pub enum SearchTarget {
     Tasks,
 }
 
+/// Specifies what the filter modal lists.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FilterTarget {
+    Assignee,
+    Tag,
+}
+
 /// Defines different panels within task detail view.
 ///
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TaskDetailPanel {
-    Details,  // Properties, assignee, dates, etc.
-    Comments, // Comments/stories
-    Notes,    // Task notes/description
+    Details,      // Properties, assignee, dates, etc.
+    Subtasks,     // Child tasks
+    Dependencies, // Blocked by / Blocking
+    Attachments,  // Files attached to the task
+    Comments,     // Comments/stories
+    Notes,        // Task notes/description
+}
+
+/// Defines which side of a blocking relationship a task is on.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DependencyKind {
+    BlockedBy, // The other task blocks the current one
+    Blocking,  // The current task blocks the other one
+}
+
+/// Defines what the comment input box is collecting, either in task detail
+/// or on the kanban board.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DetailInput {
+    Comment,
+    Subtask,
+    Dependency(DependencyKind),
+    AttachmentPath,
+    NewSection,    // Name of a column to add to the board
+    RenameSection, // New name of the focused column
+    EditComment,   // New text of the selected comment
+    MarkedComment, // Comment to add to every marked task
+}
+
+/// Defines a row of the projects list while it is grouped by team.
+///
+#[derive(Debug, PartialEq, Clone)]
+pub enum TeamListRow {
+    Team(Team, bool), // Team and whether it is expanded
+    Project(Project), // Project of the expanded team above it
+    Loading,          // Projects of the expanded team above are loading
+}
+
+/// Defines a row of the portfolios view.
+///
+#[derive(Debug, PartialEq, Clone)]
+pub enum PortfolioRow {
+    Portfolio(Portfolio, bool),     // Portfolio and whether it is expanded
+    Project(Box<PortfolioProject>), // Project of the expanded portfolio above it
+    Loading,                        // Projects of the expanded portfolio above are loading
+    Failed(String),                 // Why the projects of the portfolio above failed to load
 }
 
 #[cfg(test)]
@@ -99,6 +157,9 @@ mod tests {
     #[test]
     fn test_task_detail_panel() {
         assert_eq!(TaskDetailPanel::Details, TaskDetailPanel::Details);
+        assert_eq!(TaskDetailPanel::Subtasks, TaskDetailPanel::Subtasks);
+        assert_eq!(TaskDetailPanel::Dependencies, TaskDetailPanel::Dependencies);
+        assert_eq!(TaskDetailPanel::Attachments, TaskDetailPanel::Attachments);
         assert_eq!(TaskDetailPanel::Comments, TaskDetailPanel::Comments);
         assert_eq!(TaskDetailPanel::Notes, TaskDetailPanel::Notes);
     }
@@ -1,17 +1,49 @@
 use crate::app::NetworkEventSender;
-use crate::asana::{CustomField, Project, Section, Story, Task, User, Workspace};
+use crate::asana::{
+    custom_field_filter, html_to_markdown, unsupported_tags, Attachment, CustomField, Portfolio,
+    PortfolioProject, Project, RetryStatus, Section, StatusUpdate, Story, Tag, Task, TaskCompact,
+    TaskDates, TaskSearch, Team, TypeaheadKind, User, Workspace,
+};
 use crate::config::{HotkeyAction, ViewHotkeys};
 use crate::events::network::Event as NetworkEvent;
 use crate::ui::SPINNER_FRAME_COUNT;
+use crate::utils::{dates, thumbnails};
+use image::DynamicImage;
 use log::*;
 use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
+use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tui_textarea::TextArea;
 
 // Import types from new modules - enums are now in separate modules
-use super::form::{base_shortcuts, CustomFieldValue, EditFormState, TaskFilter};
-use super::navigation::{Focus, Menu, SearchTarget, TaskDetailPanel, View, ViewMode};
+use super::form::{
+    base_shortcuts, CustomFieldValue, DatePicker, DateTarget, EditFormState, ProjectForm,
+    ProjectFormField, ProjectPicker, ProjectPickerAction, Shortcut, StatusUpdateField,
+    StatusUpdateForm, TaskFilter, TaskSearchField, TaskSearchForm,
+};
+use super::navigation::{
+    DependencyKind, DetailInput, FilterTarget, Focus, Menu, PortfolioRow, SearchTarget,
+    TaskDetailPanel, TeamListRow, View, ViewMode,
+};
+
+/// How often the open project is polled for changes made elsewhere.
+///
+const SYNC_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long a row changed by a sync stays highlighted.
+///
+const HIGHLIGHT_DURATION: Duration = Duration::from_secs(3);
+
+/// How long typing must pause before a typeahead lookup is sent.
+///
+const TYPEAHEAD_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// How long loaded workspace tags are used before they are requested again.
+///
+const WORKSPACE_TAGS_MAX_AGE: Duration = Duration::from_secs(300);
 
 /// Houses data representative of application state.
 ///
@@ -34,11 +66,13 @@ pub struct State {
     tasks: Vec<Task>,
     projects: Vec<Project>,
     project: Option<Project>,
+    my_tasks: bool, // Whether `project` is the current user's My Tasks list
     projects_list_state: ListState,
     tasks_list_state: ListState,
     comments_list_state: ListState,
-    starred_projects: HashSet<String>,              // GIDs
-    starred_project_names: HashMap<String, String>, // GID -> Name
+    starred_projects: HashSet<String>,                   // GIDs
+    starred_project_names: HashMap<String, String>,      // GID -> Name
+    starred_project_workspaces: HashMap<String, String>, // GID -> Workspace GID
     search_query: String,
     search_mode: bool,
     search_target: Option<SearchTarget>,
@@ -49,15 +83,20 @@ pub struct State {
     debug_entries: Vec<String>, // Store log entries for navigation and copying
     task_filter: TaskFilter,
     delete_confirmation: Option<String>, // GID of task pending deletion confirmation
+    comment_delete_confirmation: Option<String>, // GID of comment pending deletion confirmation
     move_task_gid: Option<String>,       // GID of task being moved (for section selection modal)
     theme_selector_open: bool,           // Whether theme selector modal is open
     theme_dropdown_index: usize,         // Selected index in theme selector
+    workspace_selector_open: bool,       // Whether workspace selector modal is open
+    workspace_dropdown_index: usize,     // Selected index in workspace selector
     assignee_filter_open: bool,          // Whether assignee filter modal is open
     assignee_filter_dropdown_index: usize, // Selected index in assignee filter dropdown
     assignee_filter_search: String,      // Search text for assignee filter
+    filter_target: FilterTarget,         // Whether the filter modal lists assignees or tags
     current_task_detail: Option<Task>,   // Currently viewed task with full details
     sections: Vec<Section>,              // Project sections for kanban
     workspace_users: Vec<User>,          // Users for assignment dropdowns
+    workspace_tags: Vec<Tag>,            // Tags for tag dropdowns and filters
     task_stories: Vec<Story>,            // Comments for current task
     view_mode: ViewMode,                 // List or Kanban view
     #[allow(dead_code)]
@@ -75,28 +114,57 @@ pub struct State {
     details_scroll_offset: usize,        // Scroll offset for details panel
     notes_scroll_offset: usize,          // Scroll offset for notes panel
     current_task_panel: TaskDetailPanel, // Current panel in task detail view
+    task_subtasks: Vec<Task>,            // Subtasks of current task
+    subtasks_list_state: ListState,
+    parent_task_details: Vec<Task>, // Parents of the open subtask, innermost last
+    detail_input: DetailInput,      // What the task detail input box is collecting
+    dependencies_list_state: ListState, // Selection across Blocked by, then Blocking
+    dependency_candidate_index: usize, // Selected task among dependency input matches
+    task_attachments: Vec<Attachment>, // Attachments of current task
+    attachments_list_state: ListState,
+    download_directory: PathBuf,  // Where attachments are saved
+    image_picker: Option<Picker>, // Terminal graphics protocol, if images can be shown
+    attachment_previews: HashMap<String, Box<dyn StatefulProtocol>>, // Thumbnails by attachment GID
+    requested_previews: HashSet<String>, // Attachment GIDs with a preview load in flight or done
+    preview_errors: HashMap<String, String>, // Why previews failed to load, by attachment GID
+    typeahead_cache: HashMap<(TypeaheadKind, String), Vec<String>>, // Query -> ranked GIDs, empty while in flight
+    workspace_tags_requested: Option<Instant>, // When every workspace tag was last requested
+    typeahead_fallbacks: HashSet<TypeaheadKind>, // Kinds fully loaded, or loading, after a failed lookup
+    typeahead_pending: HashMap<TypeaheadKind, (String, Instant)>, // Latest query per kind and when it is due
+    typeahead_projects: Vec<Project>, // Projects found through typeahead
+    all_workspace_users: bool,        // Whether every workspace user was loaded as a fallback
     // Form input fields
     form_name: String,
     form_notes_textarea: TextArea<'static>, // TextArea for multi-line notes editing
     form_assignee: Option<String>,          // GID of selected assignee
     form_assignee_search: String,           // Search text for filtering assignees
+    form_start_on: String,                  // Date string
     form_due_on: String,                    // Date string
+    form_due_time: String,                  // Local time of day, empty for a due date only
     form_section: Option<String>,           // GID of selected section
     form_section_search: String,            // Search text for filtering sections
+    form_tags: Vec<String>,                 // GIDs of selected tags
+    form_tag_search: String,                // Search text for filtering tags
     // Original form values (for tracking changes)
     original_form_name: String,
     original_form_notes: String,
     original_form_assignee: Option<String>,
+    original_form_start_on: String,
     original_form_due_on: String,
+    original_form_due_time: String,
     original_form_section: Option<String>,
+    original_form_tags: Vec<String>,
+    original_notes_unsupported_tags: Vec<String>, // Tags in the original notes that saving drops
     // Dropdown selection indices
     assignee_dropdown_index: usize,
     section_dropdown_index: usize,
+    tag_dropdown_index: usize,
     // Custom fields
     project_custom_fields: Vec<CustomField>, // Custom fields available for the current project
     form_custom_field_values: HashMap<String, CustomFieldValue>, // GID -> value for form
     custom_field_search: HashMap<String, String>, // GID -> search text for enum/people fields
     custom_field_dropdown_index: HashMap<String, usize>, // GID -> dropdown index
+    date_picker: Option<DatePicker>,         // Calendar open over a date field of the form
     form_scroll_offset: usize, // Scroll offset for form fields (how many fields to skip)
     access_token_input: String, // Input field for welcome screen
     has_access_token: bool,    // Whether access token exists (user is logged in)
@@ -107,6 +175,37 @@ pub struct State {
     hotkey_editor_view: Option<View>, // Which view is being edited
     hotkey_editor_selected_action: Option<HotkeyAction>, // Action being edited
     hotkey_editor_dropdown_index: usize, // Selected index in hotkey editor
+    retry_status: Option<RetryStatus>, // Pending retry of a rate-limited or failed request
+    custom_field_errors: HashMap<String, String>, // GID -> API validation message
+    next_tasks_offset: Option<String>, // Offset of the next page of project tasks still to fetch
+    tasks_load_generation: u64, // Count of project task loads started, to drop stale pages
+    sync_tokens: HashMap<String, String>, // Project GID -> Events API sync token
+    last_sync: Option<Instant>, // When the open project was last polled for events
+    highlighted_tasks: HashMap<String, Instant>, // Task GID -> when its highlight ends
+    marked_tasks: Vec<String>, // GIDs of tasks marked for bulk actions, in marking order
+    teams: Vec<Team>,          // Teams of the current user, for project forms
+    project_form: Option<ProjectForm>, // Project being created or edited
+    show_archived_projects: bool, // Whether the projects list includes archived projects
+    project_picker: Option<ProjectPicker>, // Adding, moving or removing the open task's projects
+    follower_picker_open: bool, // Whether the open task's followers are being edited
+    team_browser: bool,        // Whether the projects list is grouped by the user's teams
+    expanded_teams: HashSet<String>, // GIDs of teams expanded in the team browser
+    team_projects: HashMap<String, Vec<Project>>, // Team GID -> its loaded projects
+    team_list_state: ListState, // Selected row of the team browser
+    portfolios: Vec<Portfolio>, // Portfolios of the current user
+    portfolio_projects: HashMap<String, Vec<PortfolioProject>>, // Portfolio GID -> its projects
+    portfolio_errors: HashMap<String, String>, // Portfolio GID -> why its projects failed to load
+    expanded_portfolios: HashSet<String>, // GIDs of portfolios expanded in the portfolios view
+    portfolios_list_state: ListState, // Selected row of the portfolios view
+    status_update_form: Option<StatusUpdateForm>, // Status update being written
+    task_search_form: TaskSearchForm, // Filters of the workspace-wide task search
+    task_search_form_open: bool, // Whether the task search filters are being edited
+    task_search: Option<TaskSearch>, // Search whose results are listed
+    task_search_results: Vec<Task>, // Tasks found so far, newest first
+    task_search_next: Option<String>, // Creation time to fetch the next page before
+    task_search_loading: bool, // A page of search results is being fetched
+    task_search_list_state: ListState,
+    search_project_fields: HashMap<String, (Vec<Section>, Vec<CustomField>)>, // Project GID -> filterable fields
 }
 
 // SearchTarget and TaskDetailPanel are now in navigation.rs
@@ -132,11 +231,13 @@ impl Default for State {
             tasks: vec![],
             projects: vec![],
             project: None,
+            my_tasks: false,
             projects_list_state: ListState::default(),
             tasks_list_state: ListState::default(),
             comments_list_state: ListState::default(),
             starred_projects: HashSet::new(),
             starred_project_names: HashMap::new(),
+            starred_project_workspaces: HashMap::new(),
             search_query: String::new(),
             search_mode: false,
             search_target: None,
@@ -147,16 +248,40 @@ impl Default for State {
             debug_entries: vec![],
             task_filter: TaskFilter::All,
             delete_confirmation: None,
+            comment_delete_confirmation: None,
             move_task_gid: None,
             theme_selector_open: false,
             theme_dropdown_index: 0,
+            workspace_selector_open: false,
+            workspace_dropdown_index: 0,
             assignee_filter_open: false,
             assignee_filter_dropdown_index: 0,
             assignee_filter_search: String::new(),
+            filter_target: FilterTarget::Assignee,
             current_task_detail: None,
             sections: vec![],
             workspace_users: vec![],
+            workspace_tags: vec![],
+            typeahead_cache: HashMap::new(),
+            typeahead_pending: HashMap::new(),
+            workspace_tags_requested: None,
+            typeahead_fallbacks: HashSet::new(),
+            typeahead_projects: vec![],
+            all_workspace_users: false,
             task_stories: vec![],
+            task_subtasks: vec![],
+            subtasks_list_state: ListState::default(),
+            parent_task_details: vec![],
+            detail_input: DetailInput::Comment,
+            dependencies_list_state: ListState::default(),
+            dependency_candidate_index: 0,
+            task_attachments: vec![],
+            attachments_list_state: ListState::default(),
+            download_directory: PathBuf::from("."),
+            image_picker: None,
+            attachment_previews: HashMap::new(),
+            requested_previews: HashSet::new(),
+            preview_errors: HashMap::new(),
             view_mode: ViewMode::Kanban,
             edit_mode: false,
             edit_form_state: None,
@@ -174,20 +299,30 @@ impl Default for State {
             form_notes_textarea: TextArea::default(),
             form_assignee: None,
             form_assignee_search: String::new(),
+            form_start_on: String::new(),
             form_due_on: String::new(),
+            form_due_time: String::new(),
             form_section: None,
             form_section_search: String::new(),
+            form_tags: vec![],
+            form_tag_search: String::new(),
             original_form_name: String::new(),
             original_form_notes: String::new(),
+            original_notes_unsupported_tags: vec![],
             original_form_assignee: None,
+            original_form_start_on: String::new(),
             original_form_due_on: String::new(),
+            original_form_due_time: String::new(),
             original_form_section: None,
+            original_form_tags: vec![],
             assignee_dropdown_index: 0,
             section_dropdown_index: 0,
+            tag_dropdown_index: 0,
             project_custom_fields: vec![],
             form_custom_field_values: HashMap::new(),
             custom_field_search: HashMap::new(),
             custom_field_dropdown_index: HashMap::new(),
+            date_picker: None,
             form_scroll_offset: 0,
             access_token_input: String::new(),
             has_access_token: false, // Default to false, will be set when token is loaded
@@ -198,6 +333,37 @@ impl Default for State {
             hotkey_editor_view: None,
             hotkey_editor_selected_action: None,
             hotkey_editor_dropdown_index: 0,
+            retry_status: None,
+            next_tasks_offset: None,
+            tasks_load_generation: 0,
+            sync_tokens: HashMap::new(),
+            last_sync: None,
+            highlighted_tasks: HashMap::new(),
+            marked_tasks: vec![],
+            teams: vec![],
+            project_form: None,
+            show_archived_projects: false,
+            project_picker: None,
+            follower_picker_open: false,
+            team_browser: false,
+            expanded_teams: HashSet::new(),
+            team_projects: HashMap::new(),
+            team_list_state: ListState::default(),
+            portfolios: vec![],
+            portfolio_projects: HashMap::new(),
+            portfolio_errors: HashMap::new(),
+            expanded_portfolios: HashSet::new(),
+            portfolios_list_state: ListState::default(),
+            status_update_form: None,
+            task_search_form: TaskSearchForm::default(),
+            task_search_form_open: false,
+            task_search: None,
+            task_search_results: vec![],
+            task_search_next: None,
+            task_search_loading: false,
+            task_search_list_state: ListState::default(),
+            search_project_fields: HashMap::new(),
+            custom_field_errors: HashMap::new(),
         }
     }
 }
@@ -256,6 +422,13 @@ impl State {
         }
     }
 
+    /// Returns the GID of the active workspace, which may be set from config
+    /// before workspaces are loaded.
+    ///
+    pub fn get_active_workspace_gid(&self) -> Option<&str> {
+        self.active_workspace_gid.as_deref()
+    }
+
     /// Sets the active workspace by the given workspace GID.
     ///
     pub fn set_active_workspace(&mut self, workspace_gid: String) -> &mut Self {
@@ -263,6 +436,129 @@ impl State {
         self
     }
 
+    /// Returns the workspaces available to the current user.
+    ///
+    pub fn get_workspaces(&self) -> &[Workspace] {
+        &self.workspaces
+    }
+
+    /// Switch to another workspace, dropping everything loaded for the
+    /// previous one and reloading its projects and users. The choice is
+    /// saved to config.
+    ///
+    pub fn switch_workspace(&mut self, workspace_gid: String) -> &mut Self {
+        if self.active_workspace_gid.as_deref() == Some(workspace_gid.as_str()) {
+            return self;
+        }
+        self.active_workspace_gid = Some(workspace_gid.clone());
+        self.view_stack = vec![View::Welcome];
+        self.project = None;
+        self.my_tasks = false;
+        self.tasks.clear();
+        self.marked_tasks.clear();
+        self.sections.clear();
+        self.workspace_users.clear();
+        self.workspace_tags.clear();
+        self.workspace_tags_requested = None;
+        self.typeahead_cache.clear();
+        self.typeahead_pending.clear();
+        self.typeahead_projects.clear();
+        self.all_workspace_users = false;
+        self.typeahead_fallbacks.clear();
+        self.teams.clear();
+        self.expanded_teams.clear();
+        self.team_projects.clear();
+        self.team_list_state.select(None);
+        self.portfolios.clear();
+        self.portfolio_projects.clear();
+        self.portfolio_errors.clear();
+        self.expanded_portfolios.clear();
+        self.task_search_form = TaskSearchForm::default();
+        self.task_search = None;
+        self.task_search_results.clear();
+        self.search_project_fields.clear();
+        self.current_top_list_index = 0;
+        // Starred shortcuts of the new workspace show once its projects load
+        self.set_projects(vec![]);
+        self.dispatch(NetworkEvent::GetProjects);
+        if self.team_browser {
+            self.dispatch(NetworkEvent::GetTeams {
+                workspace_gid: workspace_gid.clone(),
+            });
+        }
+        self.request_typeahead(TypeaheadKind::User, "");
+        if let Some(sender) = &self.config_save_sender {
+            let _ = sender.send(());
+        }
+        self
+    }
+
+    /// Open workspace selector modal, starting from the active workspace.
+    ///
+    pub fn open_workspace_selector(&mut self) -> &mut Self {
+        self.workspace_selector_open = true;
+        self.workspace_dropdown_index = self
+            .workspaces
+            .iter()
+            .position(|w| Some(w.gid.as_str()) == self.active_workspace_gid.as_deref())
+            .unwrap_or(0);
+        self
+    }
+
+    /// Close workspace selector modal.
+    ///
+    pub fn close_workspace_selector(&mut self) -> &mut Self {
+        self.workspace_selector_open = false;
+        self
+    }
+
+    /// Check if workspace selector modal is open.
+    ///
+    pub fn has_workspace_selector(&self) -> bool {
+        self.workspace_selector_open
+    }
+
+    /// Get workspace dropdown index.
+    ///
+    pub fn get_workspace_dropdown_index(&self) -> usize {
+        self.workspace_dropdown_index
+    }
+
+    /// Highlight the next workspace in the selector, wrapping around.
+    ///
+    pub fn next_workspace(&mut self) -> &mut Self {
+        if !self.workspaces.is_empty() {
+            self.workspace_dropdown_index =
+                (self.workspace_dropdown_index + 1) % self.workspaces.len();
+        }
+        self
+    }
+
+    /// Highlight the previous workspace in the selector, wrapping around.
+    ///
+    pub fn previous_workspace(&mut self) -> &mut Self {
+        if !self.workspaces.is_empty() {
+            self.workspace_dropdown_index = self
+                .workspace_dropdown_index
+                .checked_sub(1)
+                .unwrap_or(self.workspaces.len() - 1);
+        }
+        self
+    }
+
+    /// Switch to the highlighted workspace and close the selector.
+    ///
+    pub fn select_workspace(&mut self) -> &mut Self {
+        self.workspace_selector_open = false;
+        match self.workspaces.get(self.workspace_dropdown_index) {
+            Some(workspace) => {
+                let workspace_gid = workspace.gid.clone();
+                self.switch_workspace(workspace_gid)
+            }
+            None => self,
+        }
+    }
+
     /// Sets workspaces available to current user, initializing the active
     /// workspace GID if unset and at least one workspace is available.
     ///
@@ -431,14 +727,39 @@ impl State {
             return self;
         }
 
-        let shortcut = &all_shortcuts[selected_index];
-
-        // It's a starred project
-        if let Some(project) = self.projects.iter().find(|p| p.name == shortcut.as_str()) {
-            self.project = Some(project.to_owned());
-            self.tasks.clear();
-            self.dispatch(NetworkEvent::ProjectTasks);
-            self.view_stack.push(View::ProjectTasks);
+        match all_shortcuts[selected_index].clone() {
+            Shortcut::MyTasks => {
+                // The list is resolved for the active workspace before its tasks load
+                self.my_tasks = true;
+                self.project = None;
+                self.marked_tasks.clear();
+                self.tasks.clear();
+                self.dispatch(NetworkEvent::MyTasks);
+                self.view_stack.push(View::ProjectTasks);
+            }
+            Shortcut::Portfolios => {
+                self.dispatch(NetworkEvent::GetPortfolios);
+                self.view_stack.push(View::Portfolios);
+            }
+            Shortcut::Project { gid, name } => {
+                // Starred projects needn't be in the loaded list, e.g. when archived
+                let project = match self.projects.iter().find(|p| p.gid == gid) {
+                    Some(project) => project.to_owned(),
+                    None => Project {
+                        gid,
+                        name,
+                        archived: false,
+                        color: String::new(),
+                        notes: String::new(),
+                    },
+                };
+                self.my_tasks = false;
+                self.project = Some(project);
+                self.marked_tasks.clear();
+                self.tasks.clear();
+                self.dispatch(NetworkEvent::ProjectTasks);
+                self.view_stack.push(View::ProjectTasks);
+            }
         }
         self.focus_view();
         self
@@ -447,6 +768,14 @@ impl State {
     /// Activate the next top list item.
     ///
     pub fn next_top_list_index(&mut self) -> &mut Self {
+        if self.team_browser {
+            let len = self.get_team_list_rows().len();
+            if len > 0 {
+                let current = self.team_list_state.selected().unwrap_or(0);
+                self.team_list_state.select(Some((current + 1) % len));
+            }
+            return self;
+        }
         let filtered = self.get_filtered_projects();
         if filtered.is_empty() {
             return self;
@@ -465,6 +794,15 @@ impl State {
     /// Activate the previous top list item.
     ///
     pub fn previous_top_list_index(&mut self) -> &mut Self {
+        if self.team_browser {
+            let len = self.get_team_list_rows().len();
+            if len > 0 {
+                let current = self.team_list_state.selected().unwrap_or(0);
+                self.team_list_state
+                    .select(Some(if current > 0 { current - 1 } else { len - 1 }));
+            }
+            return self;
+        }
         let filtered = self.get_filtered_projects();
         if filtered.is_empty() {
             return self;
@@ -493,24 +831,29 @@ impl State {
     /// Select the current top list item.
     ///
     pub fn select_current_top_list_index(&mut self) -> &mut Self {
-        let filtered = self.get_filtered_projects();
-        if filtered.is_empty() {
-            return self;
+        if self.team_browser {
+            return self.select_team_list_row();
         }
-        if let Some(selected_index) = self.projects_list_state.selected() {
-            if selected_index < filtered.len() {
-                self.project = Some(filtered[selected_index].to_owned());
-                self.view_stack.clear();
-                self.tasks.clear();
-                self.dispatch(NetworkEvent::ProjectTasks);
-                self.view_stack.push(View::ProjectTasks);
-                self.focus_view();
-                self.exit_search_mode();
-            }
+        if let Some(project) = self.get_selected_top_list_project() {
+            self.view_stack.clear();
+            self.open_project(project);
         }
         self
     }
 
+    /// Open a project's tasks on top of the current view.
+    ///
+    fn open_project(&mut self, project: Project) {
+        self.project = Some(project);
+        self.my_tasks = false;
+        self.marked_tasks.clear();
+        self.tasks.clear();
+        self.dispatch(NetworkEvent::ProjectTasks);
+        self.view_stack.push(View::ProjectTasks);
+        self.focus_view();
+        self.exit_search_mode();
+    }
+
     /// Return the current view.
     ///
     pub fn current_view(&self) -> &View {
@@ -541,7 +884,21 @@ impl State {
     pub fn pop_view(&mut self) -> Option<View> {
         // Don't pop if we're at the base view (Welcome)
         if self.view_stack.len() > 1 {
-            self.view_stack.pop()
+            let popped = self.view_stack.pop();
+            if popped == Some(View::TaskDetail) {
+                if matches!(self.current_view(), View::TaskDetail) {
+                    // Back from a subtask: restore its parent and refresh it
+                    if let Some(parent) = self.parent_task_details.pop() {
+                        let gid = parent.gid.clone();
+                        self.clear_task_detail();
+                        self.current_task_detail = Some(parent);
+                        self.dispatch(NetworkEvent::GetTaskDetail { gid });
+                    }
+                } else {
+                    self.parent_task_details.clear();
+                }
+            }
+            popped
         } else {
             None
         }
@@ -556,6 +913,8 @@ impl State {
     /// Set the list of tasks.
     ///
     pub fn set_tasks(&mut self, tasks: Vec<Task>) -> &mut Self {
+        // Assignees on the board are offered by the assignee filter
+        self.merge_workspace_users(tasks.iter().filter_map(|t| t.assignee.clone()));
         self.tasks = tasks;
         self.update_search_filters();
         if !self.tasks.is_empty() {
@@ -566,6 +925,223 @@ impl State {
         self
     }
 
+    /// Return the list of tasks loaded for the current project.
+    ///
+    pub fn get_tasks(&self) -> &Vec<Task> {
+        &self.tasks
+    }
+
+    /// Append a further page of tasks, keeping the current selection.
+    ///
+    pub fn append_tasks(&mut self, tasks: Vec<Task>) -> &mut Self {
+        self.tasks.extend(tasks);
+        self.update_search_filters();
+        if self.tasks_list_state.selected().is_none() && !self.tasks.is_empty() {
+            self.tasks_list_state.select(Some(0));
+        }
+        self
+    }
+
+    /// Set the offset of the next page of project tasks still to fetch.
+    ///
+    pub fn set_next_tasks_offset(&mut self, offset: Option<String>) -> &mut Self {
+        self.next_tasks_offset = offset;
+        self
+    }
+
+    /// Start a new load of project tasks, returning the generation its pages
+    /// are tagged with. Pages of earlier loads are dropped.
+    ///
+    pub fn start_tasks_load(&mut self) -> u64 {
+        self.tasks_load_generation += 1;
+        self.tasks_load_generation
+    }
+
+    /// Return whether pages tagged with a generation belong to the latest load
+    /// of project tasks.
+    ///
+    pub fn is_current_tasks_load(&self, generation: u64) -> bool {
+        self.tasks_load_generation == generation
+    }
+
+    /// Return whether more pages of project tasks are still being fetched.
+    ///
+    pub fn is_loading_more_tasks(&self) -> bool {
+        self.next_tasks_offset.is_some()
+    }
+
+    /// Insert or replace a task changed elsewhere on the board.
+    ///
+    pub fn upsert_task(&mut self, task: Task) -> &mut Self {
+        match self.tasks.iter_mut().find(|t| t.gid == task.gid) {
+            Some(existing) => *existing = task,
+            None => self.tasks.push(task),
+        }
+        self.update_search_filters();
+        if self.tasks_list_state.selected().is_none() {
+            self.tasks_list_state.select(Some(0));
+        }
+        self
+    }
+
+    /// Replace the open task detail with its full task changed elsewhere,
+    /// keeping the current view.
+    ///
+    pub fn refresh_task_detail(&mut self, task: Task) -> &mut Self {
+        if self.current_task_detail.as_ref().map(|t| t.gid.as_str()) == Some(task.gid.as_str()) {
+            self.current_task_detail = Some(task);
+            self.clamp_relation_selection();
+        }
+        self
+    }
+
+    /// Remove a section deleted elsewhere.
+    ///
+    pub fn remove_section(&mut self, gid: &str) -> &mut Self {
+        let sections = self
+            .sections
+            .iter()
+            .filter(|s| s.gid != gid)
+            .cloned()
+            .collect();
+        self.set_sections(sections)
+    }
+
+    /// Return the Events API sync token stored for a project.
+    ///
+    pub fn get_sync_token(&self, project_gid: &str) -> Option<&String> {
+        self.sync_tokens.get(project_gid)
+    }
+
+    /// Store the Events API sync token for a project.
+    ///
+    pub fn set_sync_token(&mut self, project_gid: String, token: String) -> &mut Self {
+        self.sync_tokens.insert(project_gid, token);
+        self
+    }
+
+    /// Return whether the open project should be polled for changes now,
+    /// recording the poll if so.
+    ///
+    pub fn sync_due(&mut self) -> bool {
+        let board_open = matches!(self.current_view(), View::ProjectTasks | View::TaskDetail);
+        if !self.has_access_token
+            || !board_open
+            || self.project.is_none()
+            || self.is_loading_more_tasks()
+        {
+            return false;
+        }
+        let now = Instant::now();
+        match self.last_sync {
+            Some(last) if now.duration_since(last) < SYNC_INTERVAL => false,
+            _ => {
+                self.last_sync = Some(now);
+                true
+            }
+        }
+    }
+
+    /// Briefly highlight a task that was changed elsewhere.
+    ///
+    pub fn highlight_task(&mut self, gid: String) -> &mut Self {
+        let now = Instant::now();
+        self.highlighted_tasks.retain(|_, until| *until > now);
+        self.highlighted_tasks.insert(gid, now + HIGHLIGHT_DURATION);
+        self
+    }
+
+    /// Return whether a task is currently highlighted after a change.
+    ///
+    pub fn is_task_highlighted(&self, gid: &str) -> bool {
+        self.highlighted_tasks
+            .get(gid)
+            .map(|until| *until > Instant::now())
+            .unwrap_or(false)
+    }
+
+    /// Mark a task for bulk actions, or unmark it if already marked.
+    ///
+    pub fn toggle_task_mark(&mut self, gid: &str) -> &mut Self {
+        if self.is_task_marked(gid) {
+            self.marked_tasks.retain(|marked| marked != gid);
+        } else {
+            self.marked_tasks.push(gid.to_string());
+        }
+        self
+    }
+
+    /// Return whether a task is marked for bulk actions.
+    ///
+    pub fn is_task_marked(&self, gid: &str) -> bool {
+        self.marked_tasks.iter().any(|marked| marked == gid)
+    }
+
+    /// Return the GIDs of tasks marked for bulk actions.
+    ///
+    pub fn get_marked_tasks(&self) -> &[String] {
+        &self.marked_tasks
+    }
+
+    /// Move the task being moved, or all marked tasks, to the given section.
+    ///
+    pub fn move_tasks_to_section(&mut self, section_gid: String) -> &mut Self {
+        if !self.marked_tasks.is_empty() {
+            let task_gids = std::mem::take(&mut self.marked_tasks);
+            debug!(
+                "Moving {} marked tasks to section {}...",
+                task_gids.len(),
+                section_gid
+            );
+            self.dispatch(NetworkEvent::MoveTasksToSection {
+                task_gids,
+                section_gid,
+            });
+        } else if let Some(task_gid) = self.move_task_gid.clone() {
+            debug!("Moving task {} to section {}...", task_gid, section_gid);
+            self.dispatch(NetworkEvent::MoveTasksToSection {
+                task_gids: vec![task_gid],
+                section_gid,
+            });
+        }
+        self.clear_move_task()
+    }
+
+    /// Remove a task that no longer exists, leaving its detail views if open.
+    ///
+    pub fn remove_task(&mut self, gid: &str) -> &mut Self {
+        self.remove_board_task(gid);
+        if self.current_task_detail.as_ref().map(|t| t.gid.as_str()) == Some(gid) {
+            self.clear_task_detail();
+            while matches!(
+                self.current_view(),
+                View::TaskDetail | View::EditTask | View::CreateTask
+            ) {
+                if self.pop_view().is_none() {
+                    break;
+                }
+            }
+        }
+        self
+    }
+
+    /// Remove a task from the open project's tasks, keeping any open detail
+    /// view of it.
+    ///
+    pub fn remove_board_task(&mut self, gid: &str) -> &mut Self {
+        self.tasks.retain(|t| t.gid != gid);
+        self.marked_tasks.retain(|marked| marked != gid);
+        self.update_search_filters();
+        match self.tasks_list_state.selected() {
+            Some(_) if self.tasks.is_empty() => self.tasks_list_state.select(None),
+            Some(i) if i >= self.tasks.len() => {
+                self.tasks_list_state.select(Some(self.tasks.len() - 1))
+            }
+            _ => {}
+        }
+        self
+    }
+
     /// Return the list of projects.
     ///
     pub fn get_projects(&self) -> &Vec<Project> {
@@ -576,11 +1152,17 @@ impl State {
     ///
     pub fn set_projects(&mut self, projects: Vec<Project>) -> &mut Self {
         self.projects = projects;
-        // Update starred project names when projects load
+        // Update starred project names when projects load, and record the
+        // workspace of stars saved before workspaces were
         for project in &self.projects {
             if self.starred_projects.contains(&project.gid) {
                 self.starred_project_names
                     .insert(project.gid.to_owned(), project.name.to_owned());
+                if let Some(workspace_gid) = &self.active_workspace_gid {
+                    self.starred_project_workspaces
+                        .entry(project.gid.to_owned())
+                        .or_insert_with(|| workspace_gid.clone());
+                }
             }
         }
         // Update shortcuts list state when projects load (so starred projects appear)
@@ -604,6 +1186,22 @@ impl State {
         self.project.as_ref()
     }
 
+    /// Check if the open task list is the current user's My Tasks.
+    ///
+    pub fn is_my_tasks(&self) -> bool {
+        self.my_tasks
+    }
+
+    /// Open the resolved My Tasks list, unless another project was opened
+    /// while it was being resolved.
+    ///
+    pub fn set_my_tasks_list(&mut self, list: Project) -> &mut Self {
+        if self.my_tasks {
+            self.project = Some(list);
+        }
+        self
+    }
+
     /// Return the projects list state.
     ///
     pub fn get_projects_list_state(&mut self) -> &mut ListState {
@@ -684,6 +1282,26 @@ impl State {
     /// Toggle completion status of the selected task.
     ///
     pub fn toggle_task_completion(&mut self) -> &mut Self {
+        if matches!(self.current_view(), View::TaskDetail) {
+            if self.current_task_panel == TaskDetailPanel::Subtasks {
+                self.toggle_selected_subtask();
+            }
+            return self;
+        }
+        if !self.marked_tasks.is_empty() {
+            // Complete all marked tasks, or reopen them if they're all complete already
+            let all_completed = self
+                .tasks
+                .iter()
+                .filter(|t| self.marked_tasks.contains(&t.gid))
+                .all(|t| t.completed);
+            let gids = std::mem::take(&mut self.marked_tasks);
+            self.dispatch(NetworkEvent::UpdateTasks {
+                gids,
+                completed: !all_completed,
+            });
+            return self;
+        }
         let filtered = self.get_filtered_tasks();
         if let Some(selected_index) = self.tasks_list_state.selected() {
             if selected_index < filtered.len() {
@@ -728,13 +1346,21 @@ impl State {
     ///
     pub fn cancel_delete_confirmation(&mut self) -> &mut Self {
         self.delete_confirmation = None;
+        self.comment_delete_confirmation = None;
         self
     }
 
     /// Check if there's a pending delete confirmation.
     ///
     pub fn has_delete_confirmation(&self) -> bool {
-        self.delete_confirmation.is_some()
+        self.delete_confirmation.is_some() || self.comment_delete_confirmation.is_some()
+    }
+
+    /// Get the comment pending deletion confirmation, if any.
+    ///
+    pub fn get_comment_delete_confirmation(&self) -> Option<&Story> {
+        let gid = self.comment_delete_confirmation.as_ref()?;
+        self.task_stories.iter().find(|s| &s.gid == gid)
     }
 
     /// Set delete confirmation for a task GID (used from detail view and kanban view).
@@ -747,10 +1373,15 @@ impl State {
     /// Confirm and delete the task with pending confirmation (works for all views).
     ///
     pub fn confirm_delete_task(&mut self) -> &mut Self {
-        if let Some(task_gid) = &self.delete_confirmation {
-            let gid = task_gid.clone();
-            self.delete_confirmation = None;
-            self.dispatch(NetworkEvent::DeleteTask { gid });
+        if let Some(story_gid) = self.comment_delete_confirmation.take() {
+            self.delete_comment(story_gid);
+        } else if let Some(gid) = self.delete_confirmation.take() {
+            if matches!(self.current_view(), View::ProjectTasks) && !self.marked_tasks.is_empty() {
+                let gids = std::mem::take(&mut self.marked_tasks);
+                self.dispatch(NetworkEvent::DeleteTasks { gids });
+            } else {
+                self.dispatch(NetworkEvent::DeleteTask { gid });
+            }
         }
         self
     }
@@ -782,6 +1413,7 @@ impl State {
             TaskFilter::Incomplete => TaskFilter::Completed,
             TaskFilter::Completed => TaskFilter::All,
             TaskFilter::Assignee(_) => TaskFilter::All, // Reset assignee filter to All
+            TaskFilter::Tag(_) => TaskFilter::All,
         };
 
         // If switching to All or Completed, we need to refetch tasks to get completed ones
@@ -804,10 +1436,32 @@ impl State {
     /// Set the current task detail.
     ///
     pub fn set_task_detail(&mut self, task: Task) -> &mut Self {
+        // Keep the users on the task displayable without the full user list
+        let people = task.custom_fields.iter().flat_map(|cf| &cf.people_value);
+        self.merge_workspace_users(
+            task.assignee
+                .iter()
+                .chain(&task.followers)
+                .chain(people)
+                .cloned(),
+        );
         self.current_task_detail = Some(task);
+        self.clamp_relation_selection();
         self
     }
 
+    /// Keep the blocking relationship selection within the current task's lists.
+    ///
+    fn clamp_relation_selection(&mut self) {
+        let total = self.relations_len();
+        let selected = match self.dependencies_list_state.selected() {
+            _ if total == 0 => None,
+            Some(index) => Some(index.min(total - 1)),
+            None => Some(0),
+        };
+        self.dependencies_list_state.select(selected);
+    }
+
     /// Get the current task detail.
     ///
     pub fn get_task_detail(&self) -> Option<&Task> {
@@ -820,2607 +1474,7006 @@ impl State {
     pub fn clear_task_detail(&mut self) -> &mut Self {
         self.current_task_detail = None;
         self.task_stories = vec![];
+        self.task_subtasks = vec![];
+        self.subtasks_list_state.select(None);
+        self.dependencies_list_state.select(None);
+        self.task_attachments = vec![];
+        self.attachments_list_state.select(None);
+        self.attachment_previews.clear();
+        self.requested_previews.clear();
+        self.preview_errors.clear();
         self
     }
 
-    /// Set sections for kanban board.
+    /// Set the attachments of the current task.
     ///
-    pub fn set_sections(&mut self, sections: Vec<Section>) -> &mut Self {
-        self.sections = sections;
-        // Validate and adjust column index if needed
-        let visible_indices = self.get_visible_section_indices();
-        if !visible_indices.is_empty() {
-            // If current column index is not in visible indices, reset to first visible
-            if !visible_indices.contains(&self.kanban_column_index) {
-                self.kanban_column_index = visible_indices[0];
-                self.kanban_task_index = 0;
-            }
-        } else if !self.sections.is_empty() {
-            // If no visible sections but we have sections, reset to first section
-            self.kanban_column_index = 0;
-            self.kanban_task_index = 0;
-        }
+    pub fn set_task_attachments(&mut self, attachments: Vec<Attachment>) -> &mut Self {
+        self.task_attachments = attachments;
+        let selected = match self.attachments_list_state.selected() {
+            _ if self.task_attachments.is_empty() => None,
+            Some(index) => Some(index.min(self.task_attachments.len() - 1)),
+            None => Some(0),
+        };
+        self.attachments_list_state.select(selected);
+        self.request_selected_attachment_preview();
         self
     }
 
-    /// Get sections.
+    /// Get the attachments of the current task.
     ///
-    pub fn get_sections(&self) -> &[Section] {
-        &self.sections
+    pub fn get_task_attachments(&self) -> &[Attachment] {
+        &self.task_attachments
     }
 
-    /// Set workspace users.
+    /// Get attachments list state.
     ///
-    pub fn set_workspace_users(&mut self, users: Vec<User>) -> &mut Self {
-        self.workspace_users = users;
-        self
+    pub fn get_attachments_list_state(&mut self) -> &mut ListState {
+        &mut self.attachments_list_state
     }
 
-    /// Get workspace users.
+    /// Select the next attachment, wrapping to the top.
     ///
-    pub fn get_workspace_users(&self) -> &[User] {
-        &self.workspace_users
+    pub fn next_attachment(&mut self) -> &mut Self {
+        if !self.task_attachments.is_empty() {
+            let next = match self.attachments_list_state.selected() {
+                Some(index) if index + 1 < self.task_attachments.len() => index + 1,
+                _ => 0,
+            };
+            self.attachments_list_state.select(Some(next));
+            self.request_selected_attachment_preview();
+        }
+        self
     }
 
-    /// Set task stories/comments.
+    /// Select the previous attachment, wrapping to the bottom.
     ///
-    pub fn set_task_stories(&mut self, stories: Vec<Story>) -> &mut Self {
-        self.task_stories = stories;
-        // Initialize comments list state - select first comment (oldest)
-        let comments: Vec<&Story> = self
-            .task_stories
-            .iter()
-            .filter(|s| match &s.resource_subtype {
-                Some(subtype) => subtype == "comment_added",
-                None => s.created_by.is_some(),
-            })
-            .collect();
-        if !comments.is_empty() {
-            self.comments_list_state.select(Some(0));
-        } else {
-            self.comments_list_state.select(None);
+    pub fn previous_attachment(&mut self) -> &mut Self {
+        if !self.task_attachments.is_empty() {
+            let previous = match self.attachments_list_state.selected() {
+                Some(index) if index > 0 => index - 1,
+                _ => self.task_attachments.len() - 1,
+            };
+            self.attachments_list_state.select(Some(previous));
+            self.request_selected_attachment_preview();
         }
         self
     }
 
-    /// Get task stories/comments.
+    /// Get the selected attachment.
     ///
-    pub fn get_task_stories(&self) -> &[Story] {
-        &self.task_stories
+    pub fn get_selected_attachment(&self) -> Option<&Attachment> {
+        self.attachments_list_state
+            .selected()
+            .and_then(|index| self.task_attachments.get(index))
     }
 
-    #[allow(dead_code)]
-    pub fn get_comments_scroll_offset(&self) -> usize {
-        self.comments_scroll_offset
+    /// Set the picker used to render images, or none if the terminal can't
+    /// show them.
+    ///
+    pub fn set_image_picker(&mut self, picker: Option<Picker>) -> &mut Self {
+        self.image_picker = picker;
+        self
     }
 
-    pub fn scroll_comments_down(&mut self) -> &mut Self {
-        // Use ListState for proper navigation
-        let comments: Vec<&Story> = self
-            .task_stories
-            .iter()
-            .filter(|s| match &s.resource_subtype {
-                Some(subtype) => subtype == "comment_added",
-                None => s.created_by.is_some(),
-            })
-            .collect();
+    /// Load the thumbnail of the selected attachment if it is an image that
+    /// hasn't been requested yet, retrying a load that failed.
+    ///
+    fn request_selected_attachment_preview(&mut self) {
+        let gid = match self.get_selected_attachment() {
+            Some(attachment)
+                if self.image_picker.is_some()
+                    && attachment.host == "asana"
+                    && thumbnails::is_image(&attachment.name)
+                    && !self.requested_previews.contains(&attachment.gid) =>
+            {
+                attachment.gid.clone()
+            }
+            _ => return,
+        };
+        self.preview_errors.remove(&gid);
+        self.requested_previews.insert(gid.clone());
+        self.dispatch(NetworkEvent::LoadAttachmentPreview { gid });
+    }
 
-        let total_comments = comments.len();
-        if total_comments > 0 {
-            let current = self.comments_list_state.selected().unwrap_or(0);
-            let next = if current >= total_comments.saturating_sub(1) {
-                0 // Wrap to top
-            } else {
-                current + 1
-            };
-            self.comments_list_state.select(Some(next));
-        } else {
-            self.comments_list_state.select(None);
+    /// Set the thumbnail of an attachment of the current task.
+    ///
+    pub fn set_attachment_preview(&mut self, gid: String, thumbnail: DynamicImage) -> &mut Self {
+        // The task may have been closed while the thumbnail loaded
+        if !self.task_attachments.iter().any(|a| a.gid == gid) {
+            return self;
+        }
+        if let Some(picker) = self.image_picker.as_mut() {
+            let protocol = picker.new_resize_protocol(thumbnail);
+            self.attachment_previews.insert(gid, protocol);
         }
         self
     }
 
-    pub fn scroll_comments_up(&mut self) -> &mut Self {
-        // Use ListState for proper navigation
-        let comments: Vec<&Story> = self
-            .task_stories
-            .iter()
-            .filter(|s| match &s.resource_subtype {
-                Some(subtype) => subtype == "comment_added",
-                None => s.created_by.is_some(),
-            })
-            .collect();
-
-        let total_comments = comments.len();
-        if total_comments > 0 {
-            let current = self.comments_list_state.selected().unwrap_or(0);
-            let prev = if current == 0 {
-                total_comments.saturating_sub(1) // Wrap to bottom
-            } else {
-                current - 1
-            };
-            self.comments_list_state.select(Some(prev));
-        } else {
-            self.comments_list_state.select(None);
+    /// Record that the thumbnail of an attachment failed to load, allowing
+    /// it to be requested again when the attachment is next selected.
+    ///
+    pub fn set_attachment_preview_error(&mut self, gid: String, error: String) -> &mut Self {
+        self.requested_previews.remove(&gid);
+        if self.task_attachments.iter().any(|a| a.gid == gid) {
+            self.preview_errors.insert(gid, error);
         }
         self
     }
 
-    // Details panel scrolling
+    /// Get why the thumbnail of the selected attachment failed to load, if it
+    /// did.
+    ///
+    pub fn get_selected_attachment_preview_error(&self) -> Option<&str> {
+        let gid = &self.get_selected_attachment()?.gid;
+        self.preview_errors.get(gid).map(String::as_str)
+    }
 
-    #[allow(dead_code)]
-    pub fn get_details_scroll_offset(&self) -> usize {
-        self.details_scroll_offset
+    /// Check if the selected attachment can be previewed.
+    ///
+    pub fn has_selected_attachment_preview(&self) -> bool {
+        self.image_picker.is_some()
+            && self
+                .get_selected_attachment()
+                .is_some_and(|a| a.host == "asana" && thumbnails::is_image(&a.name))
     }
 
-    pub fn scroll_details_up(&mut self) -> &mut Self {
-        if self.details_scroll_offset > 0 {
-            self.details_scroll_offset -= 1;
-        }
-        self
+    /// Get the thumbnail of the selected attachment, if it has loaded.
+    ///
+    pub fn get_selected_attachment_preview(&mut self) -> Option<&mut Box<dyn StatefulProtocol>> {
+        let gid = self.get_selected_attachment()?.gid.clone();
+        self.attachment_previews.get_mut(&gid)
     }
 
-    pub fn scroll_details_down(&mut self) -> &mut Self {
-        // Details panel has a fixed number of properties, so we can scroll through them
-        // For now, just allow scrolling up to a reasonable limit
-        if self.details_scroll_offset < 20 {
-            self.details_scroll_offset += 1;
+    /// Download the selected attachment into the download directory.
+    ///
+    pub fn download_selected_attachment(&mut self) -> &mut Self {
+        if let Some(attachment) = self.get_selected_attachment() {
+            let event = NetworkEvent::DownloadAttachment {
+                gid: attachment.gid.clone(),
+            };
+            self.dispatch(event);
         }
         self
     }
 
-    #[allow(dead_code)]
-    pub fn reset_details_scroll(&mut self) -> &mut Self {
-        self.details_scroll_offset = 0;
+    /// Set the directory attachments are downloaded to.
+    ///
+    pub fn set_download_directory(&mut self, directory: PathBuf) -> &mut Self {
+        self.download_directory = directory;
         self
     }
 
-    // Notes panel scrolling
-
-    #[allow(dead_code)]
-    pub fn get_notes_scroll_offset(&self) -> usize {
-        self.notes_scroll_offset
+    /// Get the directory attachments are downloaded to.
+    ///
+    pub fn get_download_directory(&self) -> &PathBuf {
+        &self.download_directory
     }
 
-    pub fn scroll_notes_up(&mut self) -> &mut Self {
-        if self.notes_scroll_offset > 0 {
-            self.notes_scroll_offset -= 1;
-        }
+    /// Start typing the path of a local file to attach to the current task.
+    ///
+    pub fn enter_attachment_input_mode(&mut self) -> &mut Self {
+        self.current_task_panel = TaskDetailPanel::Attachments;
+        self.enter_comment_input_mode();
+        self.detail_input = DetailInput::AttachmentPath;
         self
     }
 
-    pub fn scroll_notes_down(&mut self) -> &mut Self {
-        // Notes can be long, allow scrolling
-        if self.notes_scroll_offset < 100 {
-            self.notes_scroll_offset += 1;
+    /// Check if the comment input is collecting a file path to attach.
+    ///
+    pub fn is_attachment_input_mode(&self) -> bool {
+        self.comment_input_mode && self.detail_input == DetailInput::AttachmentPath
+    }
+
+    /// Upload the file at the typed path to the current task.
+    ///
+    pub fn submit_attachment_input(&mut self) -> &mut Self {
+        let task_gid = self.current_task_detail.as_ref().map(|t| t.gid.clone());
+        let input = self.submit_comment();
+        let input = input.trim();
+        if let (Some(task_gid), false) = (task_gid, input.is_empty()) {
+            // Expand a leading ~ the way a shell would
+            let path = match (input.strip_prefix("~/"), dirs::home_dir()) {
+                (Some(rest), Some(home)) => home.join(rest),
+                _ => PathBuf::from(input),
+            };
+            self.dispatch(NetworkEvent::UploadAttachment { task_gid, path });
         }
         self
     }
 
-    #[allow(dead_code)]
-    pub fn reset_notes_scroll(&mut self) -> &mut Self {
-        self.notes_scroll_offset = 0;
+    /// Set the subtasks of the current task.
+    ///
+    pub fn set_task_subtasks(&mut self, subtasks: Vec<Task>) -> &mut Self {
+        if let Some(task) = self.current_task_detail.as_mut() {
+            task.num_subtasks = subtasks.len();
+        }
+        self.task_subtasks = subtasks;
+        let selected = match self.subtasks_list_state.selected() {
+            _ if self.task_subtasks.is_empty() => None,
+            Some(index) => Some(index.min(self.task_subtasks.len() - 1)),
+            None => Some(0),
+        };
+        self.subtasks_list_state.select(selected);
         self
     }
 
-    // Task detail panel navigation
-
-    pub fn get_current_task_panel(&self) -> TaskDetailPanel {
-        self.current_task_panel
+    /// Get the subtasks of the current task.
+    ///
+    pub fn get_task_subtasks(&self) -> &[Task] {
+        &self.task_subtasks
     }
 
-    pub fn next_task_panel(&mut self) -> &mut Self {
-        self.current_task_panel = match self.current_task_panel {
-            TaskDetailPanel::Details => TaskDetailPanel::Comments,
-            TaskDetailPanel::Comments => TaskDetailPanel::Notes,
-            TaskDetailPanel::Notes => TaskDetailPanel::Details, // Wrap around
-        };
-        self
+    /// Get subtasks list state.
+    ///
+    pub fn get_subtasks_list_state(&mut self) -> &mut ListState {
+        &mut self.subtasks_list_state
     }
 
-    pub fn previous_task_panel(&mut self) -> &mut Self {
-        self.current_task_panel = match self.current_task_panel {
-            TaskDetailPanel::Details => TaskDetailPanel::Notes, // Wrap around
-            TaskDetailPanel::Comments => TaskDetailPanel::Details,
-            TaskDetailPanel::Notes => TaskDetailPanel::Comments,
-        };
-        self
+    /// Get the selected subtask, if any.
+    ///
+    pub fn get_selected_subtask(&self) -> Option<&Task> {
+        self.subtasks_list_state
+            .selected()
+            .and_then(|index| self.task_subtasks.get(index))
     }
 
-    #[allow(dead_code)]
-    pub fn reset_task_panel(&mut self) -> &mut Self {
-        self.current_task_panel = TaskDetailPanel::Details;
+    /// Select the next subtask, wrapping to the top.
+    ///
+    pub fn next_subtask(&mut self) -> &mut Self {
+        if !self.task_subtasks.is_empty() {
+            let next = match self.subtasks_list_state.selected() {
+                Some(index) if index + 1 < self.task_subtasks.len() => index + 1,
+                _ => 0,
+            };
+            self.subtasks_list_state.select(Some(next));
+        }
         self
     }
 
-    pub fn set_current_task_panel(&mut self, panel: TaskDetailPanel) -> &mut Self {
-        self.current_task_panel = panel;
+    /// Select the previous subtask, wrapping to the bottom.
+    ///
+    pub fn previous_subtask(&mut self) -> &mut Self {
+        if !self.task_subtasks.is_empty() {
+            let previous = match self.subtasks_list_state.selected() {
+                Some(index) if index > 0 => index - 1,
+                _ => self.task_subtasks.len() - 1,
+            };
+            self.subtasks_list_state.select(Some(previous));
+        }
         self
     }
 
-    /// Toggle view mode (List/Kanban).
+    /// Toggle completion of the selected subtask.
     ///
-    #[allow(dead_code)]
-    pub fn toggle_view_mode(&mut self) -> &mut Self {
-        self.view_mode = match self.view_mode {
-            ViewMode::List => ViewMode::Kanban,
-            ViewMode::Kanban => ViewMode::List,
+    pub fn toggle_selected_subtask(&mut self) -> &mut Self {
+        let index = match self.subtasks_list_state.selected() {
+            Some(index) if index < self.task_subtasks.len() => index,
+            _ => return self,
         };
+        let subtask = &mut self.task_subtasks[index];
+        subtask.completed = !subtask.completed;
+        let event = NetworkEvent::UpdateTask {
+            gid: subtask.gid.clone(),
+            completed: Some(subtask.completed),
+        };
+        self.dispatch(event);
         self
     }
 
-    /// Get current view mode.
+    /// Open the selected subtask on top of the current task detail.
     ///
-    pub fn get_view_mode(&self) -> ViewMode {
-        self.view_mode
+    pub fn open_selected_subtask(&mut self) -> &mut Self {
+        if let Some(gid) = self.get_selected_subtask().map(|t| t.gid.clone()) {
+            self.open_related_task(gid);
+        }
+        self
     }
 
-    /// Set kanban column index.
+    /// Open a task on top of the current task detail, returning to it on back.
     ///
-    #[allow(dead_code)]
-    pub fn set_kanban_column_index(&mut self, index: usize) -> &mut Self {
-        // Validate against visible sections to prevent crashes when filtering
-        let visible_indices = self.get_visible_section_indices();
-        if !visible_indices.is_empty() {
-            // If index is in visible sections, use it; otherwise use first visible
-            if visible_indices.contains(&index) {
-                self.kanban_column_index = index;
-            } else {
-                self.kanban_column_index = visible_indices[0];
-            }
-        } else {
-            // No visible sections, clamp to sections length
-            self.kanban_column_index = index.min(self.sections.len().saturating_sub(1));
+    fn open_related_task(&mut self, gid: String) {
+        if let Some(parent) = self.current_task_detail.take() {
+            self.parent_task_details.push(parent);
         }
+        self.clear_task_detail();
+        self.current_task_panel = TaskDetailPanel::Details;
+        self.dispatch(NetworkEvent::GetTaskDetail { gid });
+        self.push_view(View::TaskDetail);
+    }
+
+    /// Start naming a new subtask in the comment input box.
+    ///
+    pub fn enter_subtask_input_mode(&mut self) -> &mut Self {
+        self.current_task_panel = TaskDetailPanel::Subtasks;
+        self.enter_comment_input_mode();
+        self.detail_input = DetailInput::Subtask;
         self
     }
 
-    /// Get kanban column index.
+    /// Check if the comment input is naming a new subtask.
     ///
-    pub fn get_kanban_column_index(&self) -> usize {
-        self.kanban_column_index
+    pub fn is_subtask_input_mode(&self) -> bool {
+        self.comment_input_mode && self.detail_input == DetailInput::Subtask
     }
 
-    /// Get visible sections (sections that have tasks after filtering).
-    /// Returns a vector of section indices that should be shown.
+    /// Get the number of blocking relationships of the current task.
     ///
-    pub fn get_visible_section_indices(&self) -> Vec<usize> {
-        let sections = &self.sections;
-        let tasks = self.get_filtered_tasks();
+    fn relations_len(&self) -> usize {
+        self.current_task_detail
+            .as_ref()
+            .map(|t| t.dependencies.len() + t.dependents.len())
+            .unwrap_or(0)
+    }
 
-        // Get all sections that have tasks after filtering
-        let sections_with_tasks: Vec<usize> = sections
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, section)| {
-                // Check if this section has any filtered tasks
-                if tasks.iter().any(|t| {
-                    t.section
-                        .as_ref()
-                        .map(|s| s.gid == section.gid)
-                        .unwrap_or(false)
-                }) {
-                    Some(idx)
-                } else {
-                    None
-                }
-            })
-            .collect();
+    /// Get dependencies list state.
+    ///
+    pub fn get_dependencies_list_state(&mut self) -> &mut ListState {
+        &mut self.dependencies_list_state
+    }
 
-        // If we have a search/filter active and some sections have no tasks, only show sections with tasks
-        if !self.search_query.is_empty() && sections_with_tasks.len() < sections.len() {
-            // Filtering is active - only show sections with tasks
-            sections_with_tasks
-        } else {
-            // No filtering or all sections have tasks - show all sections
-            (0..sections.len()).collect::<Vec<_>>()
+    /// Get the selected blocking relationship of the current task, if any.
+    ///
+    pub fn get_selected_relation(&self) -> Option<(DependencyKind, &TaskCompact)> {
+        let task = self.current_task_detail.as_ref()?;
+        let index = self.dependencies_list_state.selected()?;
+        match task.dependencies.get(index) {
+            Some(dependency) => Some((DependencyKind::BlockedBy, dependency)),
+            None => task
+                .dependents
+                .get(index - task.dependencies.len())
+                .map(|dependent| (DependencyKind::Blocking, dependent)),
         }
     }
 
-    /// Navigate to next kanban column.
+    /// Select the next blocking relationship, wrapping to the top.
     ///
-    pub fn next_kanban_column(&mut self) -> &mut Self {
-        let visible_indices = self.get_visible_section_indices();
-
-        if visible_indices.is_empty() {
-            return self;
+    pub fn next_relation(&mut self) -> &mut Self {
+        let total = self.relations_len();
+        if total > 0 {
+            let next = match self.dependencies_list_state.selected() {
+                Some(index) if index + 1 < total => index + 1,
+                _ => 0,
+            };
+            self.dependencies_list_state.select(Some(next));
         }
-
-        // Find current position in visible indices
-        let current_pos = visible_indices
-            .iter()
-            .position(|&idx| idx == self.kanban_column_index)
-            .unwrap_or(0);
-
-        // Move to next position, wrapping around
-        let next_pos = (current_pos + 1) % visible_indices.len();
-        self.kanban_column_index = visible_indices[next_pos];
-
-        // Reset task index when changing columns
-        self.kanban_task_index = 0;
-        // Auto-scroll to keep selected column visible
-        self.auto_scroll_to_column();
-
         self
     }
 
-    /// Navigate to previous kanban column.
+    /// Select the previous blocking relationship, wrapping to the bottom.
     ///
-    pub fn previous_kanban_column(&mut self) -> &mut Self {
-        let visible_indices = self.get_visible_section_indices();
-
-        if visible_indices.is_empty() {
-            return self;
+    pub fn previous_relation(&mut self) -> &mut Self {
+        let total = self.relations_len();
+        if total > 0 {
+            let previous = match self.dependencies_list_state.selected() {
+                Some(index) if index > 0 => index - 1,
+                _ => total - 1,
+            };
+            self.dependencies_list_state.select(Some(previous));
         }
+        self
+    }
 
-        // Find current position in visible indices
-        let current_pos = visible_indices
-            .iter()
-            .position(|&idx| idx == self.kanban_column_index)
-            .unwrap_or(0);
+    /// Open the selected blocking or blocked task on top of the current task detail.
+    ///
+    pub fn open_selected_relation(&mut self) -> &mut Self {
+        if let Some(gid) = self.get_selected_relation().map(|(_, t)| t.gid.clone()) {
+            self.open_related_task(gid);
+        }
+        self
+    }
 
-        // Move to previous position, wrapping around
-        let prev_pos = if current_pos > 0 {
-            current_pos - 1
-        } else {
-            visible_indices.len() - 1
+    /// Remove the selected blocking relationship from the current task.
+    ///
+    pub fn remove_selected_relation(&mut self) -> &mut Self {
+        let current_gid = match self.current_task_detail.as_ref() {
+            Some(task) => task.gid.clone(),
+            None => return self,
         };
-        self.kanban_column_index = visible_indices[prev_pos];
-
-        // Reset task index when changing columns
-        self.kanban_task_index = 0;
-        // Auto-scroll to keep selected column visible
-        self.auto_scroll_to_column();
-
+        let (kind, other_gid) = match self.get_selected_relation() {
+            Some((kind, other)) => (kind, other.gid.clone()),
+            None => return self,
+        };
+        let (task_gid, dependency_gid) = match kind {
+            DependencyKind::BlockedBy => (current_gid, other_gid),
+            DependencyKind::Blocking => (other_gid, current_gid),
+        };
+        self.dispatch(NetworkEvent::RemoveDependency {
+            task_gid,
+            dependency_gid,
+        });
         self
     }
 
-    /// Auto-scroll to keep the selected column visible.
-    /// With fixed 3-column layout, this is now a no-op but kept for compatibility.
+    /// Start picking a task to link as blocking or blocked by the current task.
     ///
-    fn auto_scroll_to_column(&mut self) {
-        // With fixed 3-column layout, we always show 3 columns centered around selection
-        // No horizontal scrolling needed, but we keep this function for compatibility
-        // The rendering code handles centering the current column in the 3 visible columns
+    pub fn enter_dependency_input_mode(&mut self, kind: DependencyKind) -> &mut Self {
+        self.current_task_panel = TaskDetailPanel::Dependencies;
+        self.enter_comment_input_mode();
+        self.detail_input = DetailInput::Dependency(kind);
+        self.dependency_candidate_index = 0;
+        self
     }
 
-    /// Set task GID for moving (opens section selection modal).
+    /// Get the kind of relationship being added, if picking a task for one.
     ///
-    pub fn set_move_task_gid(&mut self, task_gid: Option<String>) -> &mut Self {
-        let should_init = task_gid.is_some();
-        self.move_task_gid = task_gid;
-        // Initialize section dropdown index when opening modal
-        if should_init {
-            self.init_section_dropdown_index();
+    pub fn get_dependency_input_kind(&self) -> Option<DependencyKind> {
+        match self.detail_input {
+            DetailInput::Dependency(kind) if self.comment_input_mode => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// Check if the comment input is picking a task for a relationship.
+    ///
+    pub fn is_dependency_input_mode(&self) -> bool {
+        self.get_dependency_input_kind().is_some()
+    }
+
+    /// Switch the relationship being added between blocked by and blocking.
+    ///
+    pub fn toggle_dependency_input_kind(&mut self) -> &mut Self {
+        if let Some(kind) = self.get_dependency_input_kind() {
+            self.detail_input = DetailInput::Dependency(match kind {
+                DependencyKind::BlockedBy => DependencyKind::Blocking,
+                DependencyKind::Blocking => DependencyKind::BlockedBy,
+            });
         }
         self
     }
 
-    /// Get task GID being moved.
+    /// Get project tasks matching the dependency input that aren't linked yet.
     ///
-    pub fn get_move_task_gid(&self) -> Option<&String> {
-        self.move_task_gid.as_ref()
+    pub fn get_dependency_candidates(&self) -> Vec<&Task> {
+        let current = match self.current_task_detail.as_ref() {
+            Some(task) => task,
+            None => return vec![],
+        };
+        let query = self.comment_input_text.to_lowercase();
+        self.tasks
+            .iter()
+            .filter(|t| t.gid != current.gid)
+            .filter(|t| {
+                !current
+                    .dependencies
+                    .iter()
+                    .chain(current.dependents.iter())
+                    .any(|linked| linked.gid == t.gid)
+            })
+            .filter(|t| query.is_empty() || t.name.to_lowercase().contains(&query))
+            .collect()
     }
 
-    /// Check if move task modal is open.
+    /// Get the selected index among dependency input matches.
     ///
-    pub fn has_move_task(&self) -> bool {
-        self.move_task_gid.is_some()
+    pub fn get_dependency_candidate_index(&self) -> usize {
+        self.dependency_candidate_index
     }
 
-    /// Clear move task state.
+    /// Select the next dependency input match, wrapping to the top.
     ///
-    pub fn clear_move_task(&mut self) -> &mut Self {
-        self.move_task_gid = None;
+    pub fn next_dependency_candidate(&mut self) -> &mut Self {
+        let total = self.get_dependency_candidates().len();
+        if total > 0 {
+            self.dependency_candidate_index = (self.dependency_candidate_index + 1) % total;
+        }
         self
     }
 
-    /// Open theme selector modal.
+    /// Select the previous dependency input match, wrapping to the bottom.
     ///
-    pub fn open_theme_selector(&mut self) -> &mut Self {
-        self.theme_selector_open = true;
-        // Initialize dropdown index to current theme
-        let available_themes = crate::ui::Theme::available_themes();
-        if let Some(current_index) = available_themes
-            .iter()
-            .position(|name| name == &self.theme.name)
-        {
-            self.theme_dropdown_index = current_index;
-        } else {
-            self.theme_dropdown_index = 0;
+    pub fn previous_dependency_candidate(&mut self) -> &mut Self {
+        let total = self.get_dependency_candidates().len();
+        if total > 0 {
+            self.dependency_candidate_index = if self.dependency_candidate_index == 0 {
+                total - 1
+            } else {
+                self.dependency_candidate_index - 1
+            };
         }
         self
     }
 
-    /// Close theme selector modal.
+    /// Link the selected dependency input match to the current task.
     ///
-    pub fn close_theme_selector(&mut self) -> &mut Self {
-        self.theme_selector_open = false;
+    pub fn submit_dependency_input(&mut self) -> &mut Self {
+        let kind = match self.get_dependency_input_kind() {
+            Some(kind) => kind,
+            None => return self,
+        };
+        let current_gid = self.current_task_detail.as_ref().map(|t| t.gid.clone());
+        let other_gid = self
+            .get_dependency_candidates()
+            .get(self.dependency_candidate_index)
+            .map(|t| t.gid.clone());
+        self.exit_comment_input_mode();
+        if let (Some(current_gid), Some(other_gid)) = (current_gid, other_gid) {
+            let (task_gid, dependency_gid) = match kind {
+                DependencyKind::BlockedBy => (current_gid, other_gid),
+                DependencyKind::Blocking => (other_gid, current_gid),
+            };
+            self.dispatch(NetworkEvent::AddDependency {
+                task_gid,
+                dependency_gid,
+            });
+        }
         self
     }
 
-    /// Check if theme selector modal is open.
+    /// Set sections for kanban board.
     ///
-    pub fn has_theme_selector(&self) -> bool {
-        self.theme_selector_open
+    pub fn set_sections(&mut self, sections: Vec<Section>) -> &mut Self {
+        self.sections = sections;
+        // Validate and adjust column index if needed
+        let visible_indices = self.get_visible_section_indices();
+        if !visible_indices.is_empty() {
+            // If current column index is not in visible indices, reset to first visible
+            if !visible_indices.contains(&self.kanban_column_index) {
+                self.kanban_column_index = visible_indices[0];
+                self.kanban_task_index = 0;
+            }
+        } else if !self.sections.is_empty() {
+            // If no visible sections but we have sections, reset to first section
+            self.kanban_column_index = 0;
+            self.kanban_task_index = 0;
+        }
+        self
     }
 
-    /// Check if in theme selector mode.
+    /// Get sections.
     ///
-    pub fn is_theme_mode(&self) -> bool {
-        self.theme_selector_open
+    pub fn get_sections(&self) -> &[Section] {
+        &self.sections
     }
 
-    /// Get theme dropdown index.
+    /// Get the section of the focused kanban column.
     ///
-    pub fn get_theme_dropdown_index(&self) -> usize {
-        self.theme_dropdown_index
+    pub fn get_current_section(&self) -> Option<&Section> {
+        self.sections.get(self.kanban_column_index)
     }
 
-    /// Check if assignee filter modal is open.
+    /// Check if the board columns of the open list can be changed, warning
+    /// when they can't. My Tasks sections belong to the user task list.
     ///
-    pub fn has_assignee_filter(&self) -> bool {
-        self.assignee_filter_open
+    fn can_edit_sections(&self) -> bool {
+        if self.my_tasks {
+            warn!("My Tasks sections can't be changed here.");
+        }
+        !self.my_tasks
     }
 
-    /// Open assignee filter modal.
+    /// Start typing the name of a new board column.
     ///
-    pub fn open_assignee_filter(&mut self) -> &mut Self {
-        self.assignee_filter_open = true;
-        self.assignee_filter_search.clear();
-        self.assignee_filter_dropdown_index = 0;
-        // Initialize dropdown index to match currently selected assignee filter (if any)
-        match &self.task_filter {
-            TaskFilter::All => {
-                // Index 0 is "All"
-                self.assignee_filter_dropdown_index = 0;
-            }
-            TaskFilter::Assignee(None) => {
-                // Index 1 is "Unassigned"
-                self.assignee_filter_dropdown_index = 1;
-            }
-            TaskFilter::Assignee(Some(selected_gid)) => {
-                let filtered_users = self.get_filtered_assignee_filter_users();
-                if let Some(index) = filtered_users.iter().position(|u| &u.gid == selected_gid) {
-                    // Index 0 is "All", index 1 is "Unassigned", so add 2
-                    self.assignee_filter_dropdown_index = index + 2;
-                }
-            }
-            _ => {
-                // For other filter types, default to "All"
-                self.assignee_filter_dropdown_index = 0;
-            }
+    pub fn enter_new_section_input_mode(&mut self) -> &mut Self {
+        if !self.can_edit_sections() {
+            return self;
         }
+        self.enter_comment_input_mode();
+        self.detail_input = DetailInput::NewSection;
         self
     }
 
-    /// Close assignee filter modal.
+    /// Start renaming the focused board column, starting from its name.
     ///
-    pub fn close_assignee_filter(&mut self) -> &mut Self {
-        self.assignee_filter_open = false;
-        self.assignee_filter_search.clear();
-        self.assignee_filter_dropdown_index = 0;
+    pub fn enter_rename_section_input_mode(&mut self) -> &mut Self {
+        if !self.can_edit_sections() {
+            return self;
+        }
+        if let Some(name) = self.get_current_section().map(|s| s.name.clone()) {
+            self.enter_comment_input_mode();
+            self.detail_input = DetailInput::RenameSection;
+            self.comment_input_text = name;
+        }
         self
     }
 
-    /// Get assignee filter search text.
+    /// Check if the comment input is naming a board column.
     ///
-    pub fn get_assignee_filter_search(&self) -> &str {
-        &self.assignee_filter_search
+    pub fn is_section_input_mode(&self) -> bool {
+        self.comment_input_mode
+            && matches!(
+                self.detail_input,
+                DetailInput::NewSection | DetailInput::RenameSection
+            )
     }
 
-    /// Add a character to assignee filter search.
+    /// Check if the comment input is renaming the focused board column.
     ///
-    pub fn add_assignee_filter_search_char(&mut self, c: char) -> &mut Self {
-        self.assignee_filter_search.push(c);
-        // Reset dropdown index when search changes
-        self.assignee_filter_dropdown_index = 0;
-        self
+    pub fn is_rename_section_input_mode(&self) -> bool {
+        self.comment_input_mode && self.detail_input == DetailInput::RenameSection
     }
 
-    /// Remove last character from assignee filter search.
+    /// Create or rename a board column with the typed name.
     ///
-    pub fn backspace_assignee_filter_search(&mut self) -> &mut Self {
-        self.assignee_filter_search.pop();
-        // Reset dropdown index when search changes
-        self.assignee_filter_dropdown_index = 0;
+    pub fn submit_section_input(&mut self) -> &mut Self {
+        let renaming = self.detail_input == DetailInput::RenameSection;
+        let name = self.submit_comment().trim().to_string();
+        let project_gid = match self.project.as_ref() {
+            Some(project) if !name.is_empty() && !self.my_tasks => project.gid.clone(),
+            _ => return self,
+        };
+        if !renaming {
+            self.dispatch(NetworkEvent::CreateSection { project_gid, name });
+        } else if let Some(section) = self.sections.get_mut(self.kanban_column_index) {
+            if section.name != name {
+                section.name = name.clone();
+                let section_gid = section.gid.clone();
+                self.dispatch(NetworkEvent::RenameSection {
+                    project_gid,
+                    section_gid,
+                    name,
+                });
+            }
+        }
         self
     }
 
-    /// Get assignee filter dropdown index.
+    /// Start typing a comment to add to every marked task.
     ///
-    pub fn get_assignee_filter_dropdown_index(&self) -> usize {
-        self.assignee_filter_dropdown_index
+    pub fn enter_marked_comment_input_mode(&mut self) -> &mut Self {
+        if !self.marked_tasks.is_empty() {
+            self.enter_comment_input_mode();
+            self.detail_input = DetailInput::MarkedComment;
+        }
+        self
     }
 
-    /// Get filtered users for assignee filter (based on search).
+    /// Check if the comment input is for every marked task.
     ///
-    fn get_filtered_assignee_filter_users(&self) -> Vec<&User> {
-        self.workspace_users
-            .iter()
-            .filter(|user| {
-                self.assignee_filter_search.is_empty()
-                    || user
-                        .name
-                        .to_lowercase()
-                        .contains(&self.assignee_filter_search.to_lowercase())
-                    || user
-                        .email
-                        .to_lowercase()
-                        .contains(&self.assignee_filter_search.to_lowercase())
-            })
-            .collect()
+    pub fn is_marked_comment_input_mode(&self) -> bool {
+        self.comment_input_mode && self.detail_input == DetailInput::MarkedComment
     }
 
-    /// Move to next assignee filter option.
+    /// Add the typed comment to every marked task.
     ///
-    pub fn next_assignee_filter_option(&mut self) -> &mut Self {
-        let filtered = self.get_filtered_assignee_filter_users();
-        // Add 2 for "All" and "Unassigned" options at the beginning
-        let total = filtered.len() + 2;
-        if total > 0 {
-            self.assignee_filter_dropdown_index = (self.assignee_filter_dropdown_index + 1) % total;
+    pub fn submit_marked_comment(&mut self) -> &mut Self {
+        let text = self.submit_comment();
+        if !text.trim().is_empty() {
+            let task_gids = std::mem::take(&mut self.marked_tasks);
+            self.dispatch(NetworkEvent::CreateStories { task_gids, text });
         }
         self
     }
 
-    /// Move to previous assignee filter option.
+    /// Delete the focused board column if no loaded task is in it.
     ///
-    pub fn previous_assignee_filter_option(&mut self) -> &mut Self {
-        let filtered = self.get_filtered_assignee_filter_users();
-        // Add 2 for "All" and "Unassigned" options at the beginning
-        let total = filtered.len() + 2;
-        if total > 0 {
-            if self.assignee_filter_dropdown_index == 0 {
-                self.assignee_filter_dropdown_index = total - 1;
-            } else {
-                self.assignee_filter_dropdown_index -= 1;
-            }
+    pub fn delete_current_section(&mut self) -> &mut Self {
+        if !self.can_edit_sections() {
+            return self;
+        }
+        let (project_gid, section_gid) = match (self.project.as_ref(), self.get_current_section()) {
+            (Some(project), Some(section)) => (project.gid.clone(), section.gid.clone()),
+            _ => return self,
+        };
+        let has_tasks = self.tasks.iter().any(|t| {
+            t.section
+                .as_ref()
+                .map(|s| s.gid == section_gid)
+                .unwrap_or(false)
+        });
+        if has_tasks {
+            warn!("Only empty sections can be deleted; move its tasks out first.");
+            return self;
         }
+        self.sections.remove(self.kanban_column_index);
+        self.kanban_column_index = self
+            .kanban_column_index
+            .min(self.sections.len().saturating_sub(1));
+        self.kanban_task_index = 0;
+        self.dispatch(NetworkEvent::DeleteSection {
+            project_gid,
+            section_gid,
+        });
         self
     }
 
-    /// Select current assignee filter option and apply filter.
+    /// Move the focused board column one place to the left.
     ///
-    pub fn select_assignee_filter(&mut self) -> &mut Self {
-        let filtered = self.get_filtered_assignee_filter_users();
-        // Index 0 is "All", index 1 is "Unassigned", indices 2+ are users
-        if self.assignee_filter_dropdown_index == 0 {
-            // Select "All" - clear filter
-            self.set_task_filter(TaskFilter::All);
-        } else if self.assignee_filter_dropdown_index == 1 {
-            // Select "Unassigned"
-            self.set_task_filter(TaskFilter::Assignee(None));
-        } else {
-            let user_index = self.assignee_filter_dropdown_index - 2;
-            if let Some(user) = filtered.get(user_index) {
-                self.set_task_filter(TaskFilter::Assignee(Some(user.gid.clone())));
-            }
+    pub fn move_current_section_left(&mut self) -> &mut Self {
+        if self.kanban_column_index > 0 {
+            let neighbour = self.sections[self.kanban_column_index - 1].gid.clone();
+            self.swap_current_section(self.kanban_column_index - 1, Some(neighbour), None);
         }
-        self.close_assignee_filter();
         self
     }
 
-    /// Set theme dropdown index.
+    /// Move the focused board column one place to the right.
     ///
-    #[allow(dead_code)]
-    pub fn set_theme_dropdown_index(&mut self, index: usize) -> &mut Self {
-        self.theme_dropdown_index = index;
+    pub fn move_current_section_right(&mut self) -> &mut Self {
+        if self.kanban_column_index + 1 < self.sections.len() {
+            let neighbour = self.sections[self.kanban_column_index + 1].gid.clone();
+            self.swap_current_section(self.kanban_column_index + 1, None, Some(neighbour));
+        }
         self
     }
 
-    /// Navigate to next theme in selector.
+    /// Swap the focused column with the one at `target`, keeping it focused,
+    /// and tell Asana which section it now sits before or after.
     ///
-    pub fn next_theme(&mut self) -> &mut Self {
-        let available_themes = crate::ui::Theme::available_themes();
-        if !available_themes.is_empty() {
-            self.theme_dropdown_index = (self.theme_dropdown_index + 1) % available_themes.len();
+    fn swap_current_section(
+        &mut self,
+        target: usize,
+        before_section: Option<String>,
+        after_section: Option<String>,
+    ) {
+        if !self.can_edit_sections() {
+            return;
         }
-        self
+        let project_gid = match self.project.as_ref() {
+            Some(project) => project.gid.clone(),
+            None => return,
+        };
+        let section_gid = self.sections[self.kanban_column_index].gid.clone();
+        self.sections.swap(self.kanban_column_index, target);
+        self.kanban_column_index = target;
+        self.auto_scroll_to_column();
+        self.dispatch(NetworkEvent::MoveSection {
+            project_gid,
+            section_gid,
+            before_section,
+            after_section,
+        });
     }
 
-    /// Navigate to previous theme in selector.
+    /// Set every workspace user, loaded when typeahead lookups fail.
     ///
-    pub fn previous_theme(&mut self) -> &mut Self {
-        let available_themes = crate::ui::Theme::available_themes();
-        if !available_themes.is_empty() {
-            if self.theme_dropdown_index == 0 {
-                self.theme_dropdown_index = available_themes.len() - 1;
-            } else {
-                self.theme_dropdown_index -= 1;
-            }
-        }
+    pub fn set_workspace_users(&mut self, users: Vec<User>) -> &mut Self {
+        self.workspace_users = users;
+        self.all_workspace_users = true;
         self
     }
 
-    /// Get hotkeys.
+    /// Add users not yet known to the user pool, so their names display.
     ///
-    pub fn get_hotkeys(&self) -> &ViewHotkeys {
-        &self.hotkeys
+    fn merge_workspace_users(&mut self, users: impl IntoIterator<Item = User>) {
+        for user in users {
+            if !self.workspace_users.iter().any(|u| u.gid == user.gid) {
+                self.workspace_users.push(user);
+            }
+        }
     }
 
-    /// Set hotkeys.
+    /// Ask for a typeahead lookup once typing pauses. Queries already looked
+    /// up are served from the cache, and users are not looked up once every
+    /// workspace user is loaded.
     ///
-    pub fn set_hotkeys(&mut self, hotkeys: ViewHotkeys) -> &mut Self {
-        self.hotkeys = hotkeys;
+    pub fn request_typeahead(&mut self, kind: TypeaheadKind, query: &str) -> &mut Self {
+        let query = query.trim().to_lowercase();
+        if (kind == TypeaheadKind::User && self.all_workspace_users)
+            || self.typeahead_cache.contains_key(&(kind, query.clone()))
+        {
+            self.typeahead_pending.remove(&kind);
+            return self;
+        }
+        self.typeahead_pending
+            .insert(kind, (query, Instant::now() + TYPEAHEAD_DEBOUNCE));
         self
     }
 
-    /// Open hotkey editor modal (only from Welcome view).
+    /// Send the typeahead lookups whose debounce has elapsed.
     ///
-    pub fn open_hotkey_editor(&mut self) -> &mut Self {
-        // Only allow opening from Welcome view
-        if matches!(self.current_view(), View::Welcome) {
-            self.hotkey_editor_open = true;
-            self.hotkey_editor_view = None; // No specific view - showing all grouped
-            self.hotkey_editor_dropdown_index = 0;
+    pub fn dispatch_due_typeahead(&mut self) -> &mut Self {
+        let now = Instant::now();
+        let due: Vec<TypeaheadKind> = self
+            .typeahead_pending
+            .iter()
+            .filter(|(_, (_, at))| *at <= now)
+            .map(|(kind, _)| *kind)
+            .collect();
+        for kind in due {
+            let (query, _) = match self.typeahead_pending.remove(&kind) {
+                Some(pending) => pending,
+                None => continue,
+            };
+            let workspace_gid = match self.active_workspace_gid.clone() {
+                Some(gid) => gid,
+                None => continue,
+            };
+            // An empty entry marks the lookup as in flight
+            self.typeahead_cache.insert((kind, query.clone()), vec![]);
+            self.dispatch(NetworkEvent::Typeahead {
+                workspace_gid,
+                kind,
+                query,
+            });
         }
         self
     }
 
-    /// Close hotkey editor modal.
+    /// Forget a failed typeahead lookup so it can be retried.
     ///
-    pub fn close_hotkey_editor(&mut self) -> &mut Self {
-        self.hotkey_editor_open = false;
-        self.hotkey_editor_view = None;
-        self.hotkey_editor_selected_action = None;
+    pub fn fail_typeahead(&mut self, kind: TypeaheadKind, query: &str) -> &mut Self {
+        self.typeahead_cache.remove(&(kind, query.to_string()));
         self
     }
 
-    /// Check if hotkey editor modal is open.
+    /// Claim loading every workspace user or tag after a failed typeahead
+    /// lookup. Returns false if that is already loading or loaded for the
+    /// workspace.
     ///
-    pub fn has_hotkey_editor(&self) -> bool {
-        self.hotkey_editor_open
+    pub fn claim_typeahead_fallback(&mut self, kind: TypeaheadKind) -> bool {
+        self.typeahead_fallbacks.insert(kind)
     }
 
-    /// Get hotkey editor view.
+    /// Let a failed load of every workspace user or tag be tried again.
     ///
-    #[allow(dead_code)] // May be used in future or via reflection
-    pub fn get_hotkey_editor_view(&self) -> Option<&View> {
-        self.hotkey_editor_view.as_ref()
+    pub fn release_typeahead_fallback(&mut self, kind: TypeaheadKind) -> &mut Self {
+        self.typeahead_fallbacks.remove(&kind);
+        self
     }
 
-    /// Set hotkey editor view.
+    /// Store the users a typeahead lookup found.
     ///
-    #[allow(dead_code)]
-    pub fn set_hotkey_editor_view(&mut self, view: Option<View>) -> &mut Self {
-        self.hotkey_editor_view = view;
+    pub fn set_typeahead_users(&mut self, query: &str, users: Vec<User>) -> &mut Self {
+        self.typeahead_cache.insert(
+            (TypeaheadKind::User, query.to_string()),
+            users.iter().map(|u| u.gid.clone()).collect(),
+        );
+        self.merge_workspace_users(users);
         self
     }
 
-    /// Get hotkey editor dropdown index.
+    /// Store the projects a typeahead lookup found.
     ///
-    pub fn get_hotkey_editor_dropdown_index(&self) -> usize {
-        self.hotkey_editor_dropdown_index
+    pub fn set_typeahead_projects(&mut self, query: &str, projects: Vec<Project>) -> &mut Self {
+        self.typeahead_cache.insert(
+            (TypeaheadKind::Project, query.to_string()),
+            projects.iter().map(|p| p.gid.clone()).collect(),
+        );
+        for project in projects {
+            if !self.typeahead_projects.iter().any(|p| p.gid == project.gid) {
+                self.typeahead_projects.push(project);
+            }
+        }
+        self
     }
 
-    /// Get hotkey editor selected action.
+    /// Store the tags a typeahead lookup found.
     ///
-    pub fn get_hotkey_editor_selected_action(&self) -> Option<&HotkeyAction> {
-        self.hotkey_editor_selected_action.as_ref()
+    pub fn set_typeahead_tags(&mut self, query: &str, tags: Vec<Tag>) -> &mut Self {
+        self.typeahead_cache.insert(
+            (TypeaheadKind::Tag, query.to_string()),
+            tags.iter().map(|t| t.gid.clone()).collect(),
+        );
+        let mut all_tags = self.workspace_tags.clone();
+        for tag in tags {
+            if !all_tags.iter().any(|t| t.gid == tag.gid) {
+                all_tags.push(tag);
+            }
+        }
+        self.set_workspace_tags(all_tags);
+        self
     }
 
-    /// Set hotkey editor selected action.
+    /// Return the GIDs a typeahead lookup found for a query, best match
+    /// first.
     ///
-    pub fn set_hotkey_editor_selected_action(&mut self, action: Option<HotkeyAction>) -> &mut Self {
-        self.hotkey_editor_selected_action = action;
-        self
+    fn typeahead_hits(&self, kind: TypeaheadKind, query: &str) -> &[String] {
+        self.typeahead_cache
+            .get(&(kind, query.trim().to_lowercase()))
+            .map(|gids| gids.as_slice())
+            .unwrap_or(&[])
     }
 
-    /// Navigate to next hotkey action in editor (grouped view).
+    /// Return the items matching a search by name, or found by the typeahead
+    /// lookup for it, ordered with typeahead matches first as ranked.
     ///
-    pub fn next_hotkey_action(&mut self) -> &mut Self {
-        use crate::config::hotkeys::get_all_hotkeys_grouped;
-
-        // Get all grouped hotkeys
-        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
-        let total_items: usize = grouped.iter().map(|(_, actions)| 1 + actions.len()).sum();
-
-        if total_items > 0 {
-            self.hotkey_editor_dropdown_index =
-                (self.hotkey_editor_dropdown_index + 1) % total_items;
-        }
-        self
+    fn rank_typeahead<T: Clone>(
+        &self,
+        kind: TypeaheadKind,
+        search: &str,
+        items: &[T],
+        text: fn(&T) -> Vec<&str>,
+        gid: fn(&T) -> &str,
+    ) -> Vec<T> {
+        let hits = self.typeahead_hits(kind, search);
+        let search = search.to_lowercase();
+        let mut matches: Vec<T> = items
+            .iter()
+            .filter(|item| {
+                search.is_empty()
+                    || hits.iter().any(|g| g == gid(item))
+                    || text(item)
+                        .iter()
+                        .any(|t| t.to_lowercase().contains(&search))
+            })
+            .cloned()
+            .collect();
+        matches.sort_by_key(|item| {
+            hits.iter()
+                .position(|g| g == gid(item))
+                .unwrap_or(usize::MAX)
+        });
+        matches
     }
 
-    /// Navigate to previous hotkey action in editor (grouped view).
+    /// Get workspace users.
     ///
-    pub fn previous_hotkey_action(&mut self) -> &mut Self {
-        use crate::config::hotkeys::get_all_hotkeys_grouped;
-
-        // Get all grouped hotkeys
-        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
-        let total_items: usize = grouped.iter().map(|(_, actions)| 1 + actions.len()).sum();
-
-        if total_items > 0 {
-            if self.hotkey_editor_dropdown_index == 0 {
-                self.hotkey_editor_dropdown_index = total_items - 1;
-            } else {
-                self.hotkey_editor_dropdown_index -= 1;
-            }
-        }
-        self
+    pub fn get_workspace_users(&self) -> &[User] {
+        &self.workspace_users
     }
 
-    /// Get the action at the current index in the grouped hotkey editor.
+    /// Set workspace tags.
     ///
-    pub fn get_hotkey_action_at_index(&self, index: usize) -> Option<HotkeyAction> {
-        use crate::config::hotkeys::get_all_hotkeys_grouped;
-
-        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
-        let mut current_index = 0;
-
-        for (_, actions) in &grouped {
-            // Skip group header
-            current_index += 1;
-            // Check actions in this group
-            for (action, _) in actions {
-                if current_index == index {
-                    return Some(action.clone());
-                }
-                current_index += 1;
-            }
-        }
-        None
+    pub fn set_workspace_tags(&mut self, mut tags: Vec<Tag>) -> &mut Self {
+        tags.sort_by_key(|t| t.name.to_lowercase());
+        self.workspace_tags = tags;
+        self
     }
 
-    /// Select current theme and apply it.
+    /// Get workspace tags.
     ///
-    pub fn select_theme(&mut self) -> &mut Self {
-        let available_themes = crate::ui::Theme::available_themes();
-        if let Some(theme_name) = available_themes.get(self.theme_dropdown_index) {
-            if let Some(new_theme) = crate::ui::Theme::from_name(theme_name) {
-                self.theme = new_theme;
-                // Trigger config save
-                if let Some(sender) = &self.config_save_sender {
-                    let _ = sender.send(());
-                }
-            }
-        }
-        self.close_theme_selector()
+    pub fn get_workspace_tags(&self) -> &[Tag] {
+        &self.workspace_tags
     }
 
-    /// Set kanban task index.
+    /// Load every tag of the active workspace, unless they were requested
+    /// recently for it.
     ///
-    #[allow(dead_code)]
-    pub fn set_kanban_task_index(&mut self, index: usize) -> &mut Self {
-        self.kanban_task_index = index;
+    pub fn request_workspace_tags(&mut self) -> &mut Self {
+        let fresh = self
+            .workspace_tags_requested
+            .map(|at| at.elapsed() < WORKSPACE_TAGS_MAX_AGE)
+            .unwrap_or(false);
+        if fresh {
+            return self;
+        }
+        if let Some(workspace_gid) = self.active_workspace_gid.clone() {
+            self.workspace_tags_requested = Some(Instant::now());
+            self.dispatch(NetworkEvent::GetWorkspaceTags { workspace_gid });
+        }
         self
     }
 
-    /// Get kanban task index.
+    /// Forget when workspace tags were requested, so they are requested again
+    /// next time.
     ///
-    pub fn get_kanban_task_index(&self) -> usize {
-        self.kanban_task_index
+    pub fn expire_workspace_tags(&mut self) -> &mut Self {
+        self.workspace_tags_requested = None;
+        self
     }
 
-    /// Navigate to next task in current kanban column.
+    /// Add a newly created tag, selecting it when a task form is open.
     ///
-    #[allow(dead_code)]
-    pub fn next_kanban_task(&mut self) -> &mut Self {
-        if !self.sections.is_empty() && self.kanban_column_index < self.sections.len() {
-            let section = &self.sections[self.kanban_column_index];
-            // Use filtered tasks to respect search/filter
-            let filtered_tasks = self.get_filtered_tasks();
-            let section_tasks: Vec<&Task> = filtered_tasks
-                .iter()
-                .filter(|t| {
-                    t.section
-                        .as_ref()
-                        .map(|s| s.gid == section.gid)
-                        .unwrap_or(false)
-                })
-                .collect();
-
-            if !section_tasks.is_empty() {
-                self.kanban_task_index = (self.kanban_task_index + 1) % section_tasks.len();
-            } else {
-                // No tasks in this section after filtering, reset index
-                self.kanban_task_index = 0;
-            }
+    pub fn add_created_tag(&mut self, tag: Tag) -> &mut Self {
+        if matches!(self.current_view(), View::CreateTask | View::EditTask)
+            && !self.form_tags.contains(&tag.gid)
+        {
+            self.form_tags.push(tag.gid.clone());
         }
-        self
+        let mut tags = std::mem::take(&mut self.workspace_tags);
+        tags.push(tag);
+        self.set_workspace_tags(tags)
     }
 
-    /// Navigate to previous task in current kanban column.
+    /// Set task stories/comments.
     ///
-    #[allow(dead_code)]
-    pub fn previous_kanban_task(&mut self) -> &mut Self {
-        if !self.sections.is_empty() && self.kanban_column_index < self.sections.len() {
-            let section = &self.sections[self.kanban_column_index];
-            // Use filtered tasks to respect search/filter
-            let filtered_tasks = self.get_filtered_tasks();
-            let section_tasks: Vec<&Task> = filtered_tasks
-                .iter()
-                .filter(|t| {
-                    t.section
-                        .as_ref()
-                        .map(|s| s.gid == section.gid)
-                        .unwrap_or(false)
-                })
-                .collect();
-
-            if !section_tasks.is_empty() {
-                if self.kanban_task_index > 0 {
-                    self.kanban_task_index -= 1;
-                } else {
-                    self.kanban_task_index = section_tasks.len() - 1;
-                }
-            } else {
-                // No tasks in this section after filtering, reset index
-                self.kanban_task_index = 0;
-            }
-        }
+    pub fn set_task_stories(&mut self, stories: Vec<Story>) -> &mut Self {
+        let selected_gid = self.get_selected_comment().map(|s| s.gid.clone());
+        self.task_stories = stories;
+        // Keep the selected comment across reloads, otherwise select the
+        // first comment (pinned or oldest)
+        let comments = self.get_task_comments();
+        let index = selected_gid
+            .and_then(|gid| comments.iter().position(|s| s.gid == gid))
+            .or(if comments.is_empty() { None } else { Some(0) });
+        self.comments_list_state.select(index);
         self
     }
 
-    /// Get current selected task in kanban view.
+    /// Get the comments of the current task, pinned comments first and
+    /// otherwise in posting order.
     ///
-    pub fn get_kanban_selected_task(&self) -> Option<Task> {
-        if self.sections.is_empty() || self.kanban_column_index >= self.sections.len() {
-            return None;
-        }
-
-        let section = &self.sections[self.kanban_column_index];
-        // Use filtered tasks to respect search/filter
-        let filtered_tasks = self.get_filtered_tasks();
-        let section_tasks: Vec<&Task> = filtered_tasks
+    pub fn get_task_comments(&self) -> Vec<&Story> {
+        let mut comments: Vec<&Story> = self
+            .task_stories
             .iter()
-            .filter(|t| {
-                t.section
-                    .as_ref()
-                    .map(|s| s.gid == section.gid)
-                    .unwrap_or(false)
+            .filter(|s| match &s.resource_subtype {
+                Some(subtype) => subtype == "comment_added",
+                None => s.created_by.is_some(),
             })
             .collect();
-
-        if self.kanban_task_index < section_tasks.len() {
-            Some(section_tasks[self.kanban_task_index].clone())
-        } else {
-            None
-        }
+        comments.sort_by_key(|s| !s.is_pinned);
+        comments
     }
 
-    /// Get edit form state.
+    /// Get the comment selected in the Comments panel.
     ///
-    pub fn get_edit_form_state(&self) -> Option<EditFormState> {
-        self.edit_form_state
+    pub fn get_selected_comment(&self) -> Option<&Story> {
+        let index = self.comments_list_state.selected()?;
+        self.get_task_comments().get(index).copied()
     }
 
-    /// Set edit form state.
+    /// Check if a comment was written by the current user.
     ///
-    pub fn set_edit_form_state(&mut self, state: Option<EditFormState>) -> &mut Self {
-        self.edit_form_state = state;
-        self
+    pub fn is_own_comment(&self, story: &Story) -> bool {
+        match (&self.user, &story.created_by) {
+            (Some(user), Some(author)) => user.gid == author.gid,
+            _ => false,
+        }
     }
 
-    /// Check if actively editing a field (vs navigating between fields).
+    /// Get the GID of the selected comment if the current user wrote it.
     ///
-    pub fn is_field_editing_mode(&self) -> bool {
-        self.field_editing_mode
+    fn get_selected_own_comment_gid(&self) -> Option<String> {
+        self.get_selected_comment()
+            .filter(|s| self.is_own_comment(s))
+            .map(|s| s.gid.clone())
     }
 
-    /// Enter field editing mode (start editing the current field).
+    /// Start editing the selected comment, starting from its text. Only
+    /// comments written by the current user can be edited.
     ///
-    pub fn enter_field_editing_mode(&mut self) -> &mut Self {
-        self.field_editing_mode = true;
+    pub fn enter_edit_comment_input_mode(&mut self) -> &mut Self {
+        let text = self
+            .get_selected_comment()
+            .filter(|s| self.is_own_comment(s))
+            .map(|s| s.text.clone());
+        if let Some(text) = text {
+            self.enter_comment_input_mode();
+            self.detail_input = DetailInput::EditComment;
+            self.comment_input_text = text;
+        }
         self
     }
 
-    /// Exit field editing mode (return to navigation mode).
+    /// Check if the comment input is editing a posted comment.
     ///
-    pub fn exit_field_editing_mode(&mut self) -> &mut Self {
-        self.field_editing_mode = false;
-        self
+    pub fn is_edit_comment_input_mode(&self) -> bool {
+        self.comment_input_mode && self.detail_input == DetailInput::EditComment
     }
 
-    /// Enter comment input mode.
+    /// Save the edited text of the selected comment.
     ///
-    pub fn enter_comment_input_mode(&mut self) -> &mut Self {
-        self.comment_input_mode = true;
-        self.comment_input_text.clear();
+    pub fn submit_comment_edit(&mut self) -> &mut Self {
+        let text = self.submit_comment().trim().to_string();
+        let task_gid = match self.current_task_detail.as_ref() {
+            Some(task) if !text.is_empty() => task.gid.clone(),
+            _ => return self,
+        };
+        let story_gid = match self.get_selected_own_comment_gid() {
+            Some(gid) => gid,
+            None => return self,
+        };
+        if let Some(story) = self.task_stories.iter_mut().find(|s| s.gid == story_gid) {
+            if story.text == text {
+                return self;
+            }
+            story.text = text.clone();
+        }
+        self.dispatch(NetworkEvent::UpdateStory {
+            task_gid,
+            story_gid,
+            text,
+        });
         self
     }
 
-    /// Exit comment input mode.
+    /// Ask for confirmation before deleting the selected comment. Only
+    /// comments written by the current user can be deleted.
     ///
-    pub fn exit_comment_input_mode(&mut self) -> &mut Self {
-        self.comment_input_mode = false;
-        self.comment_input_text.clear();
+    pub fn delete_selected_comment(&mut self) -> &mut Self {
+        if let Some(gid) = self.get_selected_own_comment_gid() {
+            self.comment_delete_confirmation = Some(gid);
+        }
         self
     }
 
-    /// Check if in comment input mode.
+    /// Delete a comment of the task in detail view, removing it before the
+    /// request completes.
     ///
-    pub fn is_comment_input_mode(&self) -> bool {
-        self.comment_input_mode
+    fn delete_comment(&mut self, story_gid: String) {
+        let task_gid = match self.current_task_detail.as_ref() {
+            Some(task) => task.gid.clone(),
+            None => return,
+        };
+        self.task_stories.retain(|s| s.gid != story_gid);
+        let total_comments = self.get_task_comments().len();
+        let selected = self.comments_list_state.selected().unwrap_or(0);
+        if total_comments == 0 {
+            self.comments_list_state.select(None);
+        } else {
+            self.comments_list_state
+                .select(Some(selected.min(total_comments - 1)));
+        }
+        self.dispatch(NetworkEvent::DeleteStory {
+            task_gid,
+            story_gid,
+        });
     }
 
-    /// Get comment input text.
+    /// Like or unlike the selected comment.
     ///
-    pub fn has_access_token(&self) -> bool {
-        self.has_access_token
-    }
-
-    pub fn get_access_token_input(&self) -> &str {
-        &self.access_token_input
-    }
-
-    pub fn set_access_token(&mut self, _token: String) -> &mut Self {
-        self.has_access_token = true;
-        // Token is stored in config file by network handler
+    pub fn toggle_selected_comment_liked(&mut self) -> &mut Self {
+        let task_gid = match self.current_task_detail.as_ref() {
+            Some(task) => task.gid.clone(),
+            None => return self,
+        };
+        let story_gid = match self.get_selected_comment() {
+            Some(story) => story.gid.clone(),
+            None => return self,
+        };
+        if let Some(story) = self.task_stories.iter_mut().find(|s| s.gid == story_gid) {
+            story.liked = !story.liked;
+            let liked = story.liked;
+            self.dispatch(NetworkEvent::SetStoryLiked {
+                task_gid,
+                story_gid,
+                liked,
+            });
+        }
         self
     }
 
-    pub fn add_access_token_char(&mut self, c: char) -> &mut Self {
-        self.access_token_input.push(c);
+    /// Pin or unpin the selected comment, keeping it selected as it moves.
+    ///
+    pub fn toggle_selected_comment_pinned(&mut self) -> &mut Self {
+        let task_gid = match self.current_task_detail.as_ref() {
+            Some(task) => task.gid.clone(),
+            None => return self,
+        };
+        let story_gid = match self.get_selected_comment() {
+            Some(story) => story.gid.clone(),
+            None => return self,
+        };
+        if let Some(story) = self.task_stories.iter_mut().find(|s| s.gid == story_gid) {
+            story.is_pinned = !story.is_pinned;
+            let pinned = story.is_pinned;
+            let index = self
+                .get_task_comments()
+                .iter()
+                .position(|s| s.gid == story_gid);
+            self.comments_list_state.select(index);
+            self.dispatch(NetworkEvent::SetStoryPinned {
+                task_gid,
+                story_gid,
+                pinned,
+            });
+        }
         self
     }
 
-    pub fn backspace_access_token(&mut self) -> &mut Self {
-        self.access_token_input.pop();
-        self
+    #[allow(dead_code)]
+    pub fn get_comments_scroll_offset(&self) -> usize {
+        self.comments_scroll_offset
     }
 
-    pub fn clear_access_token_input(&mut self) -> &mut Self {
-        self.access_token_input.clear();
+    pub fn scroll_comments_down(&mut self) -> &mut Self {
+        // Use ListState for proper navigation
+        let total_comments = self.get_task_comments().len();
+        if total_comments > 0 {
+            let current = self.comments_list_state.selected().unwrap_or(0);
+            let next = if current >= total_comments.saturating_sub(1) {
+                0 // Wrap to top
+            } else {
+                current + 1
+            };
+            self.comments_list_state.select(Some(next));
+        } else {
+            self.comments_list_state.select(None);
+        }
         self
     }
 
-    pub fn set_auth_error(&mut self, error: Option<String>) -> &mut Self {
-        self.auth_error = error;
-        self
-    }
-
-    pub fn get_auth_error(&self) -> Option<&String> {
-        self.auth_error.as_ref()
-    }
-
-    pub fn clear_auth_error(&mut self) -> &mut Self {
-        self.auth_error = None;
+    pub fn scroll_comments_up(&mut self) -> &mut Self {
+        // Use ListState for proper navigation
+        let total_comments = self.get_task_comments().len();
+        if total_comments > 0 {
+            let current = self.comments_list_state.selected().unwrap_or(0);
+            let prev = if current == 0 {
+                total_comments.saturating_sub(1) // Wrap to bottom
+            } else {
+                current - 1
+            };
+            self.comments_list_state.select(Some(prev));
+        } else {
+            self.comments_list_state.select(None);
+        }
         self
     }
 
-    /// Get comment input text.
-    ///
-    pub fn get_comment_input_text(&self) -> &str {
-        &self.comment_input_text
+    // Details panel scrolling
+
+    #[allow(dead_code)]
+    pub fn get_details_scroll_offset(&self) -> usize {
+        self.details_scroll_offset
     }
 
-    /// Add character to comment input.
-    ///
-    pub fn add_comment_char(&mut self, c: char) -> &mut Self {
-        self.comment_input_text.push(c);
+    pub fn scroll_details_up(&mut self) -> &mut Self {
+        if self.details_scroll_offset > 0 {
+            self.details_scroll_offset -= 1;
+        }
         self
     }
 
-    /// Remove last character from comment input.
-    ///
-    pub fn remove_comment_char(&mut self) -> &mut Self {
-        self.comment_input_text.pop();
+    pub fn scroll_details_down(&mut self) -> &mut Self {
+        // Details panel has a fixed number of properties, so we can scroll through them
+        // For now, just allow scrolling up to a reasonable limit
+        if self.details_scroll_offset < 20 {
+            self.details_scroll_offset += 1;
+        }
         self
     }
 
-    /// Submit comment (returns the text and clears input).
-    ///
-    pub fn submit_comment(&mut self) -> String {
-        let text = self.comment_input_text.clone();
-        self.comment_input_text.clear();
-        self.comment_input_mode = false;
-        text
+    #[allow(dead_code)]
+    pub fn reset_details_scroll(&mut self) -> &mut Self {
+        self.details_scroll_offset = 0;
+        self
     }
 
-    /// Get form name.
-    ///
-    pub fn get_form_name(&self) -> &str {
-        &self.form_name
-    }
+    // Notes panel scrolling
 
-    /// Set form name.
-    ///
     #[allow(dead_code)]
-    pub fn set_form_name(&mut self, name: String) -> &mut Self {
-        self.form_name = name;
-        self
+    pub fn get_notes_scroll_offset(&self) -> usize {
+        self.notes_scroll_offset
     }
 
-    /// Add character to form name.
-    ///
-    pub fn add_form_name_char(&mut self, c: char) -> &mut Self {
-        self.form_name.push(c);
+    pub fn scroll_notes_up(&mut self) -> &mut Self {
+        if self.notes_scroll_offset > 0 {
+            self.notes_scroll_offset -= 1;
+        }
         self
     }
 
-    /// Remove last character from form name.
-    ///
-    pub fn remove_form_name_char(&mut self) -> &mut Self {
-        self.form_name.pop();
+    pub fn scroll_notes_down(&mut self) -> &mut Self {
+        // Notes can be long, allow scrolling
+        if self.notes_scroll_offset < 100 {
+            self.notes_scroll_offset += 1;
+        }
         self
     }
 
-    /// Get form notes.
-    ///
-    /// Get form notes as string.
-    ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_form_notes(&self) -> String {
-        self.form_notes_textarea
-            .lines()
-            .iter()
-            .map(|s| s.as_str())
-            .collect::<Vec<_>>()
-            .join("\n")
+    #[allow(dead_code)]
+    pub fn reset_notes_scroll(&mut self) -> &mut Self {
+        self.notes_scroll_offset = 0;
+        self
     }
 
-    /// Get form notes textarea (mutable).
-    ///
-    pub fn get_form_notes_textarea(&mut self) -> &mut TextArea<'static> {
-        &mut self.form_notes_textarea
+    // Task detail panel navigation
+
+    pub fn get_current_task_panel(&self) -> TaskDetailPanel {
+        self.current_task_panel
     }
 
-    /// Set form notes from string.
-    ///
-    pub fn set_form_notes(&mut self, notes: String) -> &mut Self {
-        self.form_notes_textarea = TextArea::from(notes.lines().collect::<Vec<_>>());
+    pub fn next_task_panel(&mut self) -> &mut Self {
+        self.current_task_panel = match self.current_task_panel {
+            TaskDetailPanel::Details => TaskDetailPanel::Subtasks,
+            TaskDetailPanel::Subtasks => TaskDetailPanel::Dependencies,
+            TaskDetailPanel::Dependencies => TaskDetailPanel::Attachments,
+            TaskDetailPanel::Attachments => TaskDetailPanel::Comments,
+            TaskDetailPanel::Comments => TaskDetailPanel::Notes,
+            TaskDetailPanel::Notes => TaskDetailPanel::Details, // Wrap around
+        };
         self
     }
 
-    /// Get form assignee.
-    ///
-    pub fn get_form_assignee(&self) -> Option<&String> {
-        self.form_assignee.as_ref()
+    pub fn previous_task_panel(&mut self) -> &mut Self {
+        self.current_task_panel = match self.current_task_panel {
+            TaskDetailPanel::Details => TaskDetailPanel::Notes, // Wrap around
+            TaskDetailPanel::Subtasks => TaskDetailPanel::Details,
+            TaskDetailPanel::Dependencies => TaskDetailPanel::Subtasks,
+            TaskDetailPanel::Attachments => TaskDetailPanel::Dependencies,
+            TaskDetailPanel::Comments => TaskDetailPanel::Attachments,
+            TaskDetailPanel::Notes => TaskDetailPanel::Comments,
+        };
+        self
     }
 
-    /// Set form assignee.
-    ///
     #[allow(dead_code)]
-    pub fn set_form_assignee(&mut self, assignee: Option<String>) -> &mut Self {
-        self.form_assignee = assignee;
+    pub fn reset_task_panel(&mut self) -> &mut Self {
+        self.current_task_panel = TaskDetailPanel::Details;
         self
     }
 
-    /// Get form due date.
-    ///
-    pub fn get_form_due_on(&self) -> &str {
-        &self.form_due_on
+    pub fn set_current_task_panel(&mut self, panel: TaskDetailPanel) -> &mut Self {
+        self.current_task_panel = panel;
+        self
     }
 
-    /// Set form due date.
+    /// Toggle view mode (List/Kanban).
     ///
     #[allow(dead_code)]
-    pub fn set_form_due_on(&mut self, due_on: String) -> &mut Self {
-        self.form_due_on = due_on;
+    pub fn toggle_view_mode(&mut self) -> &mut Self {
+        self.view_mode = match self.view_mode {
+            ViewMode::List => ViewMode::Kanban,
+            ViewMode::Kanban => ViewMode::List,
+        };
         self
     }
 
-    /// Add character to form due date.
+    /// Get current view mode.
     ///
-    pub fn add_form_due_on_char(&mut self, c: char) -> &mut Self {
-        self.form_due_on.push(c);
-        self
+    pub fn get_view_mode(&self) -> ViewMode {
+        self.view_mode
     }
 
-    /// Remove last character from form due date.
+    /// Set kanban column index.
     ///
-    pub fn remove_form_due_on_char(&mut self) -> &mut Self {
-        self.form_due_on.pop();
+    #[allow(dead_code)]
+    pub fn set_kanban_column_index(&mut self, index: usize) -> &mut Self {
+        // Validate against visible sections to prevent crashes when filtering
+        let visible_indices = self.get_visible_section_indices();
+        if !visible_indices.is_empty() {
+            // If index is in visible sections, use it; otherwise use first visible
+            if visible_indices.contains(&index) {
+                self.kanban_column_index = index;
+            } else {
+                self.kanban_column_index = visible_indices[0];
+            }
+        } else {
+            // No visible sections, clamp to sections length
+            self.kanban_column_index = index.min(self.sections.len().saturating_sub(1));
+        }
         self
     }
 
-    /// Get form section.
-    ///
-    pub fn get_form_section(&self) -> Option<&String> {
-        self.form_section.as_ref()
-    }
-
-    /// Get original form name (for change detection).
+    /// Get kanban column index.
     ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_original_form_name(&self) -> &str {
-        &self.original_form_name
+    pub fn get_kanban_column_index(&self) -> usize {
+        self.kanban_column_index
     }
 
-    /// Get original form notes (for change detection).
+    /// Get visible sections (sections that have tasks after filtering).
+    /// Returns a vector of section indices that should be shown.
     ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_original_form_notes(&self) -> &str {
-        &self.original_form_notes
-    }
+    pub fn get_visible_section_indices(&self) -> Vec<usize> {
+        let sections = &self.sections;
+        let tasks = self.get_filtered_tasks();
 
-    /// Get original form assignee (for change detection).
-    ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_original_form_assignee(&self) -> &Option<String> {
-        &self.original_form_assignee
-    }
+        // Get all sections that have tasks after filtering
+        let sections_with_tasks: Vec<usize> = sections
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, section)| {
+                // Check if this section has any filtered tasks
+                if tasks.iter().any(|t| {
+                    t.section
+                        .as_ref()
+                        .map(|s| s.gid == section.gid)
+                        .unwrap_or(false)
+                }) {
+                    Some(idx)
+                } else {
+                    None
+                }
+            })
+            .collect();
 
-    /// Get original form due date (for change detection).
-    ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_original_form_due_on(&self) -> &str {
-        &self.original_form_due_on
+        // If we have a search/filter active and some sections have no tasks, only show sections with tasks
+        if !self.search_query.is_empty() && sections_with_tasks.len() < sections.len() {
+            // Filtering is active - only show sections with tasks
+            sections_with_tasks
+        } else {
+            // No filtering or all sections have tasks - show all sections
+            (0..sections.len()).collect::<Vec<_>>()
+        }
     }
 
-    /// Get original form section (for change detection).
+    /// Navigate to next kanban column.
     ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_original_form_section(&self) -> &Option<String> {
-        &self.original_form_section
-    }
+    pub fn next_kanban_column(&mut self) -> &mut Self {
+        let visible_indices = self.get_visible_section_indices();
 
-    /// Set form section.
-    ///
-    #[allow(dead_code)]
-    pub fn set_form_section(&mut self, section: Option<String>) -> &mut Self {
-        self.form_section = section;
-        self
-    }
+        if visible_indices.is_empty() {
+            return self;
+        }
 
-    pub fn get_assignee_dropdown_index(&self) -> usize {
-        self.assignee_dropdown_index
-    }
+        // Find current position in visible indices
+        let current_pos = visible_indices
+            .iter()
+            .position(|&idx| idx == self.kanban_column_index)
+            .unwrap_or(0);
 
-    #[allow(dead_code)]
-    pub fn set_assignee_dropdown_index(&mut self, index: usize) -> &mut Self {
-        self.assignee_dropdown_index = index;
-        self
-    }
+        // Move to next position, wrapping around
+        let next_pos = (current_pos + 1) % visible_indices.len();
+        self.kanban_column_index = visible_indices[next_pos];
 
-    /// Initialize assignee dropdown index to match currently selected assignee (if any).
-    /// This ensures the selected assignee is shown when entering the dropdown.
-    pub fn init_assignee_dropdown_index(&mut self) -> &mut Self {
-        if let Some(selected_gid) = &self.form_assignee {
-            let filtered_users = self.get_filtered_assignees();
-            if let Some(index) = filtered_users.iter().position(|u| &u.gid == selected_gid) {
-                self.assignee_dropdown_index = index;
-            } else {
-                // Selected assignee not in filtered list, reset to 0
-                self.assignee_dropdown_index = 0;
-            }
-        } else {
-            // No assignee selected, start at 0
-            self.assignee_dropdown_index = 0;
-        }
-        self
-    }
+        // Reset task index when changing columns
+        self.kanban_task_index = 0;
+        // Auto-scroll to keep selected column visible
+        self.auto_scroll_to_column();
 
-    pub fn next_assignee(&mut self) -> &mut Self {
-        let users = self.get_filtered_assignees();
-        if !users.is_empty() {
-            self.assignee_dropdown_index = (self.assignee_dropdown_index + 1) % users.len();
-        }
         self
     }
 
-    pub fn previous_assignee(&mut self) -> &mut Self {
-        let users = self.get_filtered_assignees();
-        if !users.is_empty() {
-            if self.assignee_dropdown_index == 0 {
-                self.assignee_dropdown_index = users.len() - 1;
-            } else {
-                self.assignee_dropdown_index -= 1;
-            }
-        }
-        self
-    }
+    /// Navigate to previous kanban column.
+    ///
+    pub fn previous_kanban_column(&mut self) -> &mut Self {
+        let visible_indices = self.get_visible_section_indices();
 
-    pub fn select_current_assignee(&mut self) -> &mut Self {
-        let users = self.get_filtered_assignees();
-        if !users.is_empty() && self.assignee_dropdown_index < users.len() {
-            self.form_assignee = Some(users[self.assignee_dropdown_index].gid.clone());
+        if visible_indices.is_empty() {
+            return self;
         }
-        self
-    }
 
-    /// Get filtered assignees based on search text
-    pub fn get_filtered_assignees(&self) -> Vec<User> {
-        let search = self.form_assignee_search.to_lowercase();
-        if search.is_empty() {
-            self.workspace_users.clone()
+        // Find current position in visible indices
+        let current_pos = visible_indices
+            .iter()
+            .position(|&idx| idx == self.kanban_column_index)
+            .unwrap_or(0);
+
+        // Move to previous position, wrapping around
+        let prev_pos = if current_pos > 0 {
+            current_pos - 1
         } else {
-            self.workspace_users
-                .iter()
-                .filter(|u| {
-                    u.name.to_lowercase().contains(&search)
-                        || u.email.to_lowercase().contains(&search)
-                })
-                .cloned()
-                .collect()
-        }
-    }
+            visible_indices.len() - 1
+        };
+        self.kanban_column_index = visible_indices[prev_pos];
 
-    pub fn add_assignee_search_char(&mut self, c: char) -> &mut Self {
-        self.form_assignee_search.push(c);
-        // Reset dropdown index when search changes
-        self.assignee_dropdown_index = 0;
-        self
-    }
+        // Reset task index when changing columns
+        self.kanban_task_index = 0;
+        // Auto-scroll to keep selected column visible
+        self.auto_scroll_to_column();
 
-    pub fn backspace_assignee_search(&mut self) -> &mut Self {
-        self.form_assignee_search.pop();
-        // Reset dropdown index when search changes
-        self.assignee_dropdown_index = 0;
         self
     }
 
-    pub fn get_assignee_search(&self) -> &str {
-        &self.form_assignee_search
+    /// Auto-scroll to keep the selected column visible.
+    /// With fixed 3-column layout, this is now a no-op but kept for compatibility.
+    ///
+    fn auto_scroll_to_column(&mut self) {
+        // With fixed 3-column layout, we always show 3 columns centered around selection
+        // No horizontal scrolling needed, but we keep this function for compatibility
+        // The rendering code handles centering the current column in the 3 visible columns
     }
 
-    #[allow(dead_code)]
-    pub fn clear_assignee_search(&mut self) -> &mut Self {
-        self.form_assignee_search.clear();
-        self.assignee_dropdown_index = 0;
+    /// Set task GID for moving (opens section selection modal).
+    ///
+    pub fn set_move_task_gid(&mut self, task_gid: Option<String>) -> &mut Self {
+        let should_init = task_gid.is_some();
+        self.move_task_gid = task_gid;
+        // Initialize section dropdown index when opening modal
+        if should_init {
+            self.init_section_dropdown_index();
+        }
         self
     }
 
-    pub fn get_section_dropdown_index(&self) -> usize {
-        self.section_dropdown_index
+    /// Check if move task modal is open.
+    ///
+    pub fn has_move_task(&self) -> bool {
+        self.move_task_gid.is_some()
     }
 
-    #[allow(dead_code)]
-    pub fn set_section_dropdown_index(&mut self, index: usize) -> &mut Self {
-        self.section_dropdown_index = index;
+    /// Clear move task state.
+    ///
+    pub fn clear_move_task(&mut self) -> &mut Self {
+        self.move_task_gid = None;
         self
     }
 
-    /// Get filtered sections based on search text
-    pub fn get_filtered_sections(&self) -> Vec<Section> {
-        let search = self.form_section_search.to_lowercase();
-        if search.is_empty() {
-            self.sections.clone()
+    /// Open theme selector modal.
+    ///
+    pub fn open_theme_selector(&mut self) -> &mut Self {
+        self.theme_selector_open = true;
+        // Initialize dropdown index to current theme
+        let available_themes = crate::ui::Theme::available_themes();
+        if let Some(current_index) = available_themes
+            .iter()
+            .position(|name| name == &self.theme.name)
+        {
+            self.theme_dropdown_index = current_index;
         } else {
-            self.sections
-                .iter()
-                .filter(|s| s.name.to_lowercase().contains(&search))
-                .cloned()
-                .collect()
+            self.theme_dropdown_index = 0;
         }
-    }
-
-    pub fn add_section_search_char(&mut self, c: char) -> &mut Self {
-        self.form_section_search.push(c);
-        // Reset dropdown index when search changes
-        self.section_dropdown_index = 0;
         self
     }
 
-    pub fn backspace_section_search(&mut self) -> &mut Self {
-        self.form_section_search.pop();
-        // Reset dropdown index when search changes
-        self.section_dropdown_index = 0;
+    /// Close theme selector modal.
+    ///
+    pub fn close_theme_selector(&mut self) -> &mut Self {
+        self.theme_selector_open = false;
         self
     }
 
-    pub fn get_section_search(&self) -> &str {
-        &self.form_section_search
+    /// Check if theme selector modal is open.
+    ///
+    pub fn has_theme_selector(&self) -> bool {
+        self.theme_selector_open
     }
 
-    #[allow(dead_code)]
-    pub fn clear_section_search(&mut self) -> &mut Self {
-        self.form_section_search.clear();
-        self.section_dropdown_index = 0;
-        self
+    /// Check if in theme selector mode.
+    ///
+    pub fn is_theme_mode(&self) -> bool {
+        self.theme_selector_open
     }
 
-    /// Initialize section dropdown index to match currently selected section (if any).
-    /// This ensures the selected section is shown when entering the dropdown.
-    pub fn init_section_dropdown_index(&mut self) -> &mut Self {
-        if let Some(selected_gid) = &self.form_section {
-            let filtered_sections = self.get_filtered_sections();
-            if let Some(index) = filtered_sections
-                .iter()
-                .position(|s| &s.gid == selected_gid)
-            {
-                self.section_dropdown_index = index;
-            } else {
-                // Selected section not in filtered list, reset to 0
-                self.section_dropdown_index = 0;
-            }
-        } else {
-            // No section selected, start at 0
-            self.section_dropdown_index = 0;
-        }
-        self
+    /// Get theme dropdown index.
+    ///
+    pub fn get_theme_dropdown_index(&self) -> usize {
+        self.theme_dropdown_index
     }
 
-    pub fn next_section(&mut self) -> &mut Self {
-        let sections = self.get_filtered_sections();
-        if !sections.is_empty() {
-            self.section_dropdown_index = (self.section_dropdown_index + 1) % sections.len();
-        }
-        self
+    /// Check if the filter modal is open, listing assignees or tags.
+    ///
+    pub fn has_assignee_filter(&self) -> bool {
+        self.assignee_filter_open
     }
 
-    pub fn previous_section(&mut self) -> &mut Self {
-        let sections = self.get_filtered_sections();
-        if !sections.is_empty() {
-            if self.section_dropdown_index == 0 {
-                self.section_dropdown_index = sections.len() - 1;
-            } else {
-                self.section_dropdown_index -= 1;
-            }
-        }
-        self
+    /// Get what the filter modal lists.
+    ///
+    pub fn get_filter_target(&self) -> FilterTarget {
+        self.filter_target
     }
 
-    pub fn select_current_section(&mut self) -> &mut Self {
-        let sections = self.get_filtered_sections();
-        if !sections.is_empty() && self.section_dropdown_index < sections.len() {
-            self.form_section = Some(sections[self.section_dropdown_index].gid.clone());
-        } else {
-            warn!("No section found at index {}", self.section_dropdown_index);
+    /// Switch the filter modal between assignees and tags.
+    ///
+    pub fn toggle_filter_target(&mut self) -> &mut Self {
+        match self.filter_target {
+            FilterTarget::Assignee => self.open_tag_filter(),
+            FilterTarget::Tag => self.open_assignee_filter(),
         }
-        self
     }
 
-    /// Clear form fields.
+    /// Open the filter modal listing tags.
     ///
-    pub fn clear_form(&mut self) -> &mut Self {
-        self.form_name.clear();
-        self.form_notes_textarea = TextArea::default();
-        self.form_assignee = None;
-        self.form_assignee_search.clear();
-        self.form_due_on.clear();
-        self.form_section = None;
-        self.form_section_search.clear();
-        self.form_custom_field_values.clear();
-        self.custom_field_search.clear();
-        self.custom_field_dropdown_index.clear();
-        self.edit_form_state = None;
-        self.field_editing_mode = false;
-        self.assignee_dropdown_index = 0;
-        self.section_dropdown_index = 0;
-        self.form_scroll_offset = 0;
+    pub fn open_tag_filter(&mut self) -> &mut Self {
+        self.assignee_filter_open = true;
+        self.filter_target = FilterTarget::Tag;
+        self.assignee_filter_search.clear();
+        // Index 0 is "All", so the selected tag is offset by one
+        self.assignee_filter_dropdown_index = match &self.task_filter {
+            TaskFilter::Tag(selected_gid) => self
+                .get_filtered_tag_filter_tags()
+                .iter()
+                .position(|t| &t.gid == selected_gid)
+                .map_or(0, |index| index + 1),
+            _ => 0,
+        };
         self
     }
 
-    /// Get available custom fields for the current project.
+    /// Open assignee filter modal.
     ///
-    pub fn get_project_custom_fields(&self) -> &[CustomField] {
-        &self.project_custom_fields
-    }
-
-    /// Get enabled custom fields (excluding custom_id and disabled fields).
-    ///
-    pub fn get_enabled_custom_fields(&self) -> Vec<&CustomField> {
-        self.project_custom_fields
-            .iter()
-            .filter(|cf| {
-                // Skip custom_id fields
-                let is_custom_id = cf
-                    .representation_type
-                    .as_ref()
-                    .map(|s| s == "custom_id")
-                    .unwrap_or(false)
-                    || cf.id_prefix.is_some();
-                // Only include enabled fields
-                !is_custom_id && cf.enabled
-            })
-            .collect()
+    pub fn open_assignee_filter(&mut self) -> &mut Self {
+        self.assignee_filter_open = true;
+        self.filter_target = FilterTarget::Assignee;
+        self.assignee_filter_search.clear();
+        self.assignee_filter_dropdown_index = 0;
+        // Initialize dropdown index to match currently selected assignee filter (if any)
+        match &self.task_filter {
+            TaskFilter::All => {
+                // Index 0 is "All"
+                self.assignee_filter_dropdown_index = 0;
+            }
+            TaskFilter::Assignee(None) => {
+                // Index 1 is "Unassigned"
+                self.assignee_filter_dropdown_index = 1;
+            }
+            TaskFilter::Assignee(Some(selected_gid)) => {
+                let filtered_users = self.get_filtered_assignee_filter_users();
+                if let Some(index) = filtered_users.iter().position(|u| &u.gid == selected_gid) {
+                    // Index 0 is "All", index 1 is "Unassigned", so add 2
+                    self.assignee_filter_dropdown_index = index + 2;
+                }
+            }
+            _ => {
+                // For other filter types, default to "All"
+                self.assignee_filter_dropdown_index = 0;
+            }
+        }
+        self
     }
 
-    /// Set project custom fields.
+    /// Close assignee filter modal.
     ///
-    pub fn set_project_custom_fields(&mut self, fields: Vec<CustomField>) -> &mut Self {
-        self.project_custom_fields = fields;
+    pub fn close_assignee_filter(&mut self) -> &mut Self {
+        self.assignee_filter_open = false;
+        self.assignee_filter_search.clear();
+        self.assignee_filter_dropdown_index = 0;
         self
     }
 
-    /// Get custom field value for a given GID.
+    /// Get assignee filter search text.
     ///
-    pub fn get_custom_field_value(&self, gid: &str) -> Option<&CustomFieldValue> {
-        self.form_custom_field_values.get(gid)
+    pub fn get_assignee_filter_search(&self) -> &str {
+        &self.assignee_filter_search
     }
 
-    /// Get all custom field values (for API calls).
+    /// Add a character to assignee filter search.
     ///
-    #[allow(dead_code)] // Used via dispatch system
-    pub fn get_form_custom_field_values(&self) -> &HashMap<String, CustomFieldValue> {
-        &self.form_custom_field_values
+    pub fn add_assignee_filter_search_char(&mut self, c: char) -> &mut Self {
+        self.assignee_filter_search.push(c);
+        // Reset dropdown index when search changes
+        self.assignee_filter_dropdown_index = 0;
+        self.request_assignee_filter_typeahead();
+        self
     }
 
-    /// Set custom field value for a given GID.
+    /// Remove last character from assignee filter search.
     ///
-    #[allow(dead_code)]
-    pub fn set_custom_field_value(&mut self, gid: String, value: CustomFieldValue) -> &mut Self {
-        self.form_custom_field_values.insert(gid, value);
+    pub fn backspace_assignee_filter_search(&mut self) -> &mut Self {
+        self.assignee_filter_search.pop();
+        // Reset dropdown index when search changes
+        self.assignee_filter_dropdown_index = 0;
+        self.request_assignee_filter_typeahead();
         self
     }
 
-    /// Get custom field search text.
+    /// Look up users matching the assignee filter search. Tags are all
+    /// loaded when the filter opens.
     ///
-    pub fn get_custom_field_search(&self, gid: &str) -> &str {
-        self.custom_field_search
-            .get(gid)
-            .map(|s| s.as_str())
-            .unwrap_or("")
+    fn request_assignee_filter_typeahead(&mut self) {
+        if self.filter_target == FilterTarget::Assignee {
+            let search = self.assignee_filter_search.clone();
+            self.request_typeahead(TypeaheadKind::User, &search);
+        }
     }
 
-    /// Add character to custom field search.
+    /// Get assignee filter dropdown index.
     ///
-    pub fn add_custom_field_search_char(&mut self, gid: String, c: char) -> &mut Self {
-        self.custom_field_search.entry(gid).or_default().push(c);
-        self
+    pub fn get_assignee_filter_dropdown_index(&self) -> usize {
+        self.assignee_filter_dropdown_index
     }
 
-    /// Backspace custom field search.
+    /// Get filtered users for assignee filter (based on search).
     ///
-    pub fn backspace_custom_field_search(&mut self, gid: &str) -> &mut Self {
-        if let Some(search) = self.custom_field_search.get_mut(gid) {
-            search.pop();
-        }
-        self
+    pub fn get_filtered_assignee_filter_users(&self) -> Vec<User> {
+        self.rank_typeahead(
+            TypeaheadKind::User,
+            &self.assignee_filter_search,
+            &self.workspace_users,
+            |u| vec![&u.name, &u.email],
+            |u| &u.gid,
+        )
     }
 
-    /// Get custom field dropdown index.
+    /// Get filtered tags for the tag filter (based on search).
     ///
-    pub fn get_custom_field_dropdown_index(&self, gid: &str) -> usize {
-        *self.custom_field_dropdown_index.get(gid).unwrap_or(&0)
+    pub fn get_filtered_tag_filter_tags(&self) -> Vec<&Tag> {
+        let search = self.assignee_filter_search.to_lowercase();
+        self.workspace_tags
+            .iter()
+            .filter(|t| search.is_empty() || t.name.to_lowercase().contains(&search))
+            .collect()
     }
 
-    /// Set custom field dropdown index.
+    /// Number of options in the filter modal, including the fixed ones.
     ///
-    pub fn set_custom_field_dropdown_index(&mut self, gid: String, index: usize) -> &mut Self {
-        self.custom_field_dropdown_index.insert(gid, index);
-        self
+    fn filter_option_count(&self) -> usize {
+        match self.filter_target {
+            // "All" and "Unassigned" come before the users
+            FilterTarget::Assignee => self.get_filtered_assignee_filter_users().len() + 2,
+            // "All" comes before the tags
+            FilterTarget::Tag => self.get_filtered_tag_filter_tags().len() + 1,
+        }
     }
 
-    /// Add character to custom field text/number/date value.
+    /// Move to next filter modal option.
     ///
-    pub fn add_custom_field_text_char(
-        &mut self,
-        gid: String,
-        c: char,
-        field_type: &str,
-    ) -> &mut Self {
-        match field_type {
-            "number" => {
-                // For number fields, parse the current value and append the character
-                let current_text = self
-                    .form_custom_field_values
-                    .get(&gid)
-                    .and_then(|v| match v {
-                        CustomFieldValue::Number(Some(n)) => Some(n.to_string()),
-                        CustomFieldValue::Text(s) => Some(s.clone()),
-                        _ => None,
-                    })
-                    .unwrap_or_default();
-
-                let new_text = current_text + &c.to_string();
-                // Try to parse as number
-                if let Ok(num) = new_text.parse::<f64>() {
-                    self.form_custom_field_values
-                        .insert(gid, CustomFieldValue::Number(Some(num)));
-                } else {
-                    // If parsing fails, store as text (will be ignored when sending)
-                    self.form_custom_field_values
-                        .insert(gid, CustomFieldValue::Text(new_text));
-                }
-            }
-            _ => {
-                // For text and date fields, store as text
-                let current = self
-                    .form_custom_field_values
-                    .get(&gid)
-                    .and_then(|v| match v {
-                        CustomFieldValue::Text(s) => Some(s.clone()),
-                        CustomFieldValue::Date(Some(d)) => Some(d.clone()),
-                        _ => None,
-                    })
-                    .unwrap_or_default();
-
-                let new_value = current + &c.to_string();
-                if field_type == "date" {
-                    self.form_custom_field_values
-                        .insert(gid, CustomFieldValue::Date(Some(new_value)));
-                } else {
-                    self.form_custom_field_values
-                        .insert(gid, CustomFieldValue::Text(new_value));
-                }
-            }
+    pub fn next_assignee_filter_option(&mut self) -> &mut Self {
+        let total = self.filter_option_count();
+        if total > 0 {
+            self.assignee_filter_dropdown_index = (self.assignee_filter_dropdown_index + 1) % total;
         }
         self
     }
 
-    /// Remove character from custom field text/number/date value.
+    /// Move to previous filter modal option.
     ///
-    pub fn remove_custom_field_text_char(&mut self, gid: &str, field_type: &str) -> &mut Self {
-        match field_type {
-            "number" => {
-                // For number fields, get current value and remove last character
-                let current_text = self
-                    .form_custom_field_values
-                    .get(gid)
-                    .and_then(|v| match v {
-                        CustomFieldValue::Number(Some(n)) => Some(n.to_string()),
-                        CustomFieldValue::Text(s) => Some(s.clone()),
-                        _ => None,
-                    })
-                    .unwrap_or_default();
-
-                let mut new_text = current_text;
-                new_text.pop();
-
-                if new_text.is_empty() {
-                    self.form_custom_field_values.remove(gid);
-                } else if let Ok(num) = new_text.parse::<f64>() {
-                    self.form_custom_field_values
-                        .insert(gid.to_string(), CustomFieldValue::Number(Some(num)));
-                } else {
-                    self.form_custom_field_values
-                        .insert(gid.to_string(), CustomFieldValue::Text(new_text));
-                }
-            }
-            _ => {
-                // For text and date fields
-                if let Some(value) = self.form_custom_field_values.get(gid) {
-                    let mut new_value = match value {
-                        CustomFieldValue::Text(s) => s.clone(),
-                        CustomFieldValue::Date(Some(d)) => d.clone(),
-                        _ => return self,
-                    };
-                    new_value.pop();
-
-                    if new_value.is_empty() {
-                        self.form_custom_field_values.remove(gid);
-                    } else if field_type == "date" {
-                        self.form_custom_field_values
-                            .insert(gid.to_string(), CustomFieldValue::Date(Some(new_value)));
-                    } else {
-                        self.form_custom_field_values
-                            .insert(gid.to_string(), CustomFieldValue::Text(new_value));
-                    }
-                }
+    pub fn previous_assignee_filter_option(&mut self) -> &mut Self {
+        let total = self.filter_option_count();
+        if total > 0 {
+            if self.assignee_filter_dropdown_index == 0 {
+                self.assignee_filter_dropdown_index = total - 1;
+            } else {
+                self.assignee_filter_dropdown_index -= 1;
             }
         }
         self
     }
 
-    /// Get current custom field being edited.
+    /// Select current filter modal option and apply filter.
     ///
-    pub fn get_current_custom_field(&self) -> Option<(usize, &CustomField)> {
-        if let Some(EditFormState::CustomField(idx)) = self.edit_form_state {
-            self.project_custom_fields.get(idx).map(|cf| (idx, cf))
+    pub fn select_assignee_filter(&mut self) -> &mut Self {
+        if self.filter_target == FilterTarget::Tag {
+            // Index 0 is "All", indices 1+ are tags
+            let tag_gid = match self.assignee_filter_dropdown_index {
+                0 => None,
+                index => self
+                    .get_filtered_tag_filter_tags()
+                    .get(index - 1)
+                    .map(|t| t.gid.clone()),
+            };
+            match tag_gid {
+                Some(gid) => self.set_task_filter(TaskFilter::Tag(gid)),
+                None => self.set_task_filter(TaskFilter::All),
+            };
+            self.close_assignee_filter();
+            return self;
+        }
+        let filtered = self.get_filtered_assignee_filter_users();
+        // Index 0 is "All", index 1 is "Unassigned", indices 2+ are users
+        if self.assignee_filter_dropdown_index == 0 {
+            // Select "All" - clear filter
+            self.set_task_filter(TaskFilter::All);
+        } else if self.assignee_filter_dropdown_index == 1 {
+            // Select "Unassigned"
+            self.set_task_filter(TaskFilter::Assignee(None));
         } else {
-            None
+            let user_index = self.assignee_filter_dropdown_index - 2;
+            if let Some(user) = filtered.get(user_index) {
+                self.set_task_filter(TaskFilter::Assignee(Some(user.gid.clone())));
+            }
         }
+        self.close_assignee_filter();
+        self
     }
 
-    /// Navigate to next enum option in custom field dropdown.
+    /// Set theme dropdown index.
     ///
-    pub fn next_custom_field_enum(&mut self, gid: &str, count: usize) -> &mut Self {
-        let current = self.get_custom_field_dropdown_index(gid);
-        let new_idx = if current + 1 >= count { 0 } else { current + 1 };
-        self.set_custom_field_dropdown_index(gid.to_string(), new_idx);
+    #[allow(dead_code)]
+    pub fn set_theme_dropdown_index(&mut self, index: usize) -> &mut Self {
+        self.theme_dropdown_index = index;
         self
     }
 
-    /// Navigate to previous enum option in custom field dropdown.
+    /// Navigate to next theme in selector.
     ///
-    pub fn previous_custom_field_enum(&mut self, gid: &str, count: usize) -> &mut Self {
-        let current = self.get_custom_field_dropdown_index(gid);
-        let new_idx = if current == 0 {
-            count.saturating_sub(1)
-        } else {
-            current - 1
-        };
-        self.set_custom_field_dropdown_index(gid.to_string(), new_idx);
+    pub fn next_theme(&mut self) -> &mut Self {
+        let available_themes = crate::ui::Theme::available_themes();
+        if !available_themes.is_empty() {
+            self.theme_dropdown_index = (self.theme_dropdown_index + 1) % available_themes.len();
+        }
         self
     }
 
-    /// Toggle multi-enum option for custom field.
+    /// Navigate to previous theme in selector.
     ///
-    pub fn toggle_custom_field_multi_enum(&mut self, gid: &str, enum_gid: String) -> &mut Self {
-        let current = self
-            .form_custom_field_values
-            .get(gid)
-            .and_then(|v| match v {
-                CustomFieldValue::MultiEnum(gids) => Some(gids.clone()),
-                _ => None,
-            })
-            .unwrap_or_default();
-
-        let mut new_gids = current;
-        if new_gids.contains(&enum_gid) {
-            new_gids.retain(|g| g != &enum_gid);
-        } else {
-            new_gids.push(enum_gid);
+    pub fn previous_theme(&mut self) -> &mut Self {
+        let available_themes = crate::ui::Theme::available_themes();
+        if !available_themes.is_empty() {
+            if self.theme_dropdown_index == 0 {
+                self.theme_dropdown_index = available_themes.len() - 1;
+            } else {
+                self.theme_dropdown_index -= 1;
+            }
         }
-        self.form_custom_field_values
-            .insert(gid.to_string(), CustomFieldValue::MultiEnum(new_gids));
         self
     }
 
-    /// Toggle people option for custom field.
+    /// Get hotkeys.
     ///
-    pub fn toggle_custom_field_people(&mut self, gid: &str, user_gid: String) -> &mut Self {
-        let current = self
-            .form_custom_field_values
-            .get(gid)
-            .and_then(|v| match v {
-                CustomFieldValue::People(gids) => Some(gids.clone()),
-                _ => None,
-            })
-            .unwrap_or_default();
+    pub fn get_hotkeys(&self) -> &ViewHotkeys {
+        &self.hotkeys
+    }
 
-        let mut new_gids = current;
-        if new_gids.contains(&user_gid) {
-            new_gids.retain(|g| g != &user_gid);
-        } else {
-            new_gids.push(user_gid);
-        }
-        self.form_custom_field_values
-            .insert(gid.to_string(), CustomFieldValue::People(new_gids));
+    /// Set hotkeys.
+    ///
+    pub fn set_hotkeys(&mut self, hotkeys: ViewHotkeys) -> &mut Self {
+        self.hotkeys = hotkeys;
         self
     }
 
-    /// Get form scroll offset.
+    /// Open hotkey editor modal (only from Welcome view).
     ///
-    #[allow(dead_code)]
-    pub fn get_form_scroll_offset(&self) -> usize {
-        self.form_scroll_offset
+    pub fn open_hotkey_editor(&mut self) -> &mut Self {
+        // Only allow opening from Welcome view
+        if matches!(self.current_view(), View::Welcome) {
+            self.hotkey_editor_open = true;
+            self.hotkey_editor_view = None; // No specific view - showing all grouped
+            self.hotkey_editor_dropdown_index = 0;
+        }
+        self
     }
 
-    /// Scroll form up (show earlier fields).
+    /// Close hotkey editor modal.
     ///
-    #[allow(dead_code)]
-    pub fn scroll_form_up(&mut self) -> &mut Self {
-        if self.form_scroll_offset > 0 {
-            self.form_scroll_offset -= 1;
-        }
+    pub fn close_hotkey_editor(&mut self) -> &mut Self {
+        self.hotkey_editor_open = false;
+        self.hotkey_editor_view = None;
+        self.hotkey_editor_selected_action = None;
         self
     }
 
-    /// Scroll form down (show later fields).
+    /// Check if hotkey editor modal is open.
+    ///
+    pub fn has_hotkey_editor(&self) -> bool {
+        self.hotkey_editor_open
+    }
+
+    /// Get hotkey editor view.
+    ///
+    #[allow(dead_code)] // May be used in future or via reflection
+    pub fn get_hotkey_editor_view(&self) -> Option<&View> {
+        self.hotkey_editor_view.as_ref()
+    }
+
+    /// Set hotkey editor view.
     ///
     #[allow(dead_code)]
-    pub fn scroll_form_down(&mut self) -> &mut Self {
-        // Don't scroll past the last field - limit is handled in rendering
-        self.form_scroll_offset += 1;
+    pub fn set_hotkey_editor_view(&mut self, view: Option<View>) -> &mut Self {
+        self.hotkey_editor_view = view;
         self
     }
 
-    /// Get total number of form fields (standard + custom).
+    /// Get hotkey editor dropdown index.
     ///
-    #[allow(dead_code)]
-    pub fn get_total_form_field_count(&self) -> usize {
-        // Standard fields: Name, Notes, Assignee, DueDate, Section = 5
-        5 + self.project_custom_fields.len()
+    pub fn get_hotkey_editor_dropdown_index(&self) -> usize {
+        self.hotkey_editor_dropdown_index
     }
 
-    /// Initialize edit form with task data.
+    /// Get hotkey editor selected action.
     ///
-    #[allow(dead_code)]
-    pub fn init_edit_form(&mut self, task: &Task) -> &mut Self {
-        // Set current form values
-        self.form_name = task.name.clone();
-        self.set_form_notes(task.notes.clone().unwrap_or_default());
-        self.form_assignee = task.assignee.as_ref().map(|u| u.gid.clone());
-        self.form_due_on = task.due_on.clone().unwrap_or_default();
-        self.form_section = task.section.as_ref().map(|s| s.gid.clone());
-        // Store original values for change detection
-        self.original_form_name = task.name.clone();
-        self.original_form_notes = task.notes.clone().unwrap_or_default();
-        self.original_form_assignee = task.assignee.as_ref().map(|u| u.gid.clone());
-        self.original_form_due_on = task.due_on.clone().unwrap_or_default();
-        self.original_form_section = task.section.as_ref().map(|s| s.gid.clone());
-        // Initialize custom field values from task
-        self.form_custom_field_values.clear();
-        self.custom_field_dropdown_index.clear();
-        for cf in &task.custom_fields {
-            let value = match cf.resource_subtype.as_str() {
-                "text" => CustomFieldValue::Text(cf.text_value.clone().unwrap_or_default()),
-                "number" => CustomFieldValue::Number(cf.number_value),
-                "date" => CustomFieldValue::Date(cf.date_value.clone()),
-                "enum" => CustomFieldValue::Enum(cf.enum_value.as_ref().map(|e| e.gid.clone())),
-                "multi_enum" => CustomFieldValue::MultiEnum(
-                    cf.multi_enum_values.iter().map(|e| e.gid.clone()).collect(),
-                ),
-                "people" => CustomFieldValue::People(
-                    cf.people_value.iter().map(|u| u.gid.clone()).collect(),
-                ),
-                _ => continue,
-            };
-            self.form_custom_field_values.insert(cf.gid.clone(), value);
+    pub fn get_hotkey_editor_selected_action(&self) -> Option<&HotkeyAction> {
+        self.hotkey_editor_selected_action.as_ref()
+    }
 
-            // Initialize dropdown index for enum fields with selected values
-            if cf.resource_subtype == "enum" {
-                if let Some(selected_enum_gid) = cf.enum_value.as_ref().map(|e| &e.gid) {
-                    // Find the project custom field definition to get enum options
-                    if let Some(project_cf) = self
-                        .project_custom_fields
-                        .iter()
-                        .find(|pcf| pcf.gid == cf.gid)
-                    {
-                        if let Some(index) = project_cf
-                            .enum_options
-                            .iter()
-                            .position(|eo| eo.gid == *selected_enum_gid)
-                        {
-                            self.custom_field_dropdown_index
-                                .insert(cf.gid.clone(), index);
-                        }
-                    }
-                }
-            }
+    /// Set hotkey editor selected action.
+    ///
+    pub fn set_hotkey_editor_selected_action(&mut self, action: Option<HotkeyAction>) -> &mut Self {
+        self.hotkey_editor_selected_action = action;
+        self
+    }
+
+    /// Navigate to next hotkey action in editor (grouped view).
+    ///
+    pub fn next_hotkey_action(&mut self) -> &mut Self {
+        use crate::config::hotkeys::get_all_hotkeys_grouped;
+
+        // Get all grouped hotkeys
+        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
+        let total_items: usize = grouped.iter().map(|(_, actions)| 1 + actions.len()).sum();
+
+        if total_items > 0 {
+            self.hotkey_editor_dropdown_index =
+                (self.hotkey_editor_dropdown_index + 1) % total_items;
         }
-        // Initialize assignee and section dropdown indices
-        self.init_assignee_dropdown_index();
-        self.init_section_dropdown_index();
-        self.edit_form_state = Some(EditFormState::Name);
         self
     }
 
-    /// Toggle star status of the currently selected project (from Projects list).
+    /// Navigate to previous hotkey action in editor (grouped view).
     ///
-    #[allow(dead_code)] // Used via hotkey system
-    pub fn toggle_star_current_project(&mut self) -> &mut Self {
-        let project_info = {
-            let filtered = self.get_filtered_projects();
-            if let Some(selected_index) = self.projects_list_state.selected() {
-                if selected_index < filtered.len() {
-                    let project = &filtered[selected_index];
-                    Some((project.gid.to_owned(), project.name.to_owned()))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        };
+    pub fn previous_hotkey_action(&mut self) -> &mut Self {
+        use crate::config::hotkeys::get_all_hotkeys_grouped;
 
-        if let Some((gid, name)) = project_info {
-            if self.starred_projects.contains(&gid) {
-                self.starred_projects.remove(&gid);
-                self.starred_project_names.remove(&gid);
+        // Get all grouped hotkeys
+        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
+        let total_items: usize = grouped.iter().map(|(_, actions)| 1 + actions.len()).sum();
+
+        if total_items > 0 {
+            if self.hotkey_editor_dropdown_index == 0 {
+                self.hotkey_editor_dropdown_index = total_items - 1;
             } else {
-                self.starred_projects.insert(gid.clone());
-                self.starred_project_names.insert(gid, name);
-            }
-            // Update shortcuts list state when starring/unstarring
-            self.update_shortcuts_list_state();
-            // Trigger config save
-            if let Some(sender) = &self.config_save_sender {
-                let _ = sender.send(());
+                self.hotkey_editor_dropdown_index -= 1;
             }
         }
         self
     }
 
-    /// Unstar the currently selected shortcut (only works for starred projects, not base shortcuts).
+    /// Get the action at the current index in the grouped hotkey editor.
     ///
-    #[allow(dead_code)] // Used via hotkey system
-    pub fn unstar_current_shortcut(&mut self) -> &mut Self {
-        let all_shortcuts = self.get_all_shortcuts();
-        let selected_index = self.shortcuts_list_state.selected();
-
-        if let Some(index) = selected_index {
-            if index < all_shortcuts.len() {
-                let shortcut_name = &all_shortcuts[index];
+    pub fn get_hotkey_action_at_index(&self, index: usize) -> Option<HotkeyAction> {
+        use crate::config::hotkeys::get_all_hotkeys_grouped;
 
-                // It's a starred project - find it and unstar it
-                // First find the GID, then remove it (to avoid borrowing conflicts)
-                let gid_to_remove = self
-                    .starred_project_names
-                    .iter()
-                    .find(|(_, name)| name.as_str() == shortcut_name.as_str())
-                    .map(|(gid, _)| gid.clone());
+        let grouped = get_all_hotkeys_grouped(&self.hotkeys);
+        let mut current_index = 0;
 
-                if let Some(gid) = gid_to_remove {
-                    self.starred_projects.remove(&gid);
-                    self.starred_project_names.remove(&gid);
-                    // Update shortcuts list state
-                    self.update_shortcuts_list_state();
-                    // Trigger config save
-                    if let Some(sender) = &self.config_save_sender {
-                        let _ = sender.send(());
-                    }
+        for (_, actions) in &grouped {
+            // Skip group header
+            current_index += 1;
+            // Check actions in this group
+            for (action, _) in actions {
+                if current_index == index {
+                    return Some(action.clone());
                 }
+                current_index += 1;
             }
         }
-        self
+        None
     }
 
-    /// Check if a project is starred.
+    /// Select current theme and apply it.
     ///
-    pub fn is_project_starred(&self, project_gid: &str) -> bool {
-        self.starred_projects.contains(project_gid)
-    }
-
-    /// Get all starred projects.
-    ///
-    #[allow(dead_code)]
-    pub fn get_starred_projects(&self) -> Vec<&Project> {
-        self.projects
-            .iter()
-            .filter(|p| self.starred_projects.contains(&p.gid))
-            .collect()
-    }
-
-    /// Get all shortcuts (starred projects first, then static shortcuts).
-    ///
-    pub fn get_all_shortcuts(&self) -> Vec<String> {
-        // Get starred project names - prioritize stored names, then loaded projects
-        let mut starred: Vec<String> = Vec::new();
-
-        for gid in &self.starred_projects {
-            // Try to get name from stored names first (most reliable)
-            if let Some(name) = self.starred_project_names.get(gid) {
-                starred.push(name.clone());
-            } else {
-                // Fallback: try to get from loaded projects
-                if let Some(project) = self.projects.iter().find(|p| &p.gid == gid) {
-                    starred.push(project.name.to_owned());
+    pub fn select_theme(&mut self) -> &mut Self {
+        let available_themes = crate::ui::Theme::available_themes();
+        if let Some(theme_name) = available_themes.get(self.theme_dropdown_index) {
+            if let Some(new_theme) = crate::ui::Theme::from_name(theme_name) {
+                self.theme = new_theme;
+                // Trigger config save
+                if let Some(sender) = &self.config_save_sender {
+                    let _ = sender.send(());
                 }
             }
         }
-
-        starred.sort(); // Sort for consistent ordering
-
-        // Put starred projects first, then base shortcuts
-        let mut shortcuts = starred;
-        shortcuts.extend(base_shortcuts());
-        shortcuts
-    }
-
-    /// Get all shortcuts and update list state.
-    ///
-    pub fn get_all_shortcuts_with_update(&mut self) -> Vec<String> {
-        let shortcuts = self.get_all_shortcuts();
-        self.update_shortcuts_list_state();
-        shortcuts
+        self.close_theme_selector()
     }
 
-    /// Get starred project GIDs for saving to config.
+    /// Set kanban task index.
     ///
-    pub fn get_starred_project_gids(&self) -> Vec<String> {
-        self.starred_projects.iter().cloned().collect()
+    #[allow(dead_code)]
+    pub fn set_kanban_task_index(&mut self, index: usize) -> &mut Self {
+        self.kanban_task_index = index;
+        self
     }
 
-    /// Get starred project names map for saving to config.
+    /// Get kanban task index.
     ///
-    pub fn get_starred_project_names(&self) -> HashMap<String, String> {
-        self.starred_project_names.clone()
+    pub fn get_kanban_task_index(&self) -> usize {
+        self.kanban_task_index
     }
 
-    /// Enter search mode. Only works when in Projects list or ProjectTasks view.
+    /// Navigate to next task in current kanban column.
     ///
-    pub fn enter_search_mode(&mut self) -> &mut Self {
-        // If in Menu focus but not on TopList, switch to TopList first
-        if *self.current_focus() == Focus::Menu && *self.current_menu() != Menu::TopList {
-            self.current_menu = Menu::TopList;
-        }
-
-        // Only allow search in Projects list (TopList menu) or ProjectTasks view
-        let can_search = match self.current_focus() {
-            Focus::Menu => *self.current_menu() == Menu::TopList,
-            Focus::View => {
-                matches!(self.current_view(), View::ProjectTasks)
-            }
-        };
-
-        if can_search {
-            let new_target = match self.current_focus() {
-                Focus::Menu => SearchTarget::Projects,
-                Focus::View => SearchTarget::Tasks,
-            };
-
-            // If switching targets, clear the query
-            if self.search_target != Some(new_target.clone()) {
-                self.search_query.clear();
-            }
-            // If query is empty, we're starting fresh
-            // If query is not empty and same target, we're re-entering to edit
+    #[allow(dead_code)]
+    pub fn next_kanban_task(&mut self) -> &mut Self {
+        if !self.sections.is_empty() && self.kanban_column_index < self.sections.len() {
+            let section = &self.sections[self.kanban_column_index];
+            // Use filtered tasks to respect search/filter
+            let filtered_tasks = self.get_filtered_tasks();
+            let section_tasks: Vec<&Task> = filtered_tasks
+                .iter()
+                .filter(|t| {
+                    t.section
+                        .as_ref()
+                        .map(|s| s.gid == section.gid)
+                        .unwrap_or(false)
+                })
+                .collect();
 
-            self.search_mode = true;
-            self.search_target = Some(new_target);
-            // Initialize filtered lists if needed
-            if self.filtered_projects.is_empty() && !self.projects.is_empty() {
-                self.filtered_projects = self.projects.clone();
-            }
-            if self.filtered_tasks.is_empty() && !self.tasks.is_empty() {
-                self.filtered_tasks = self.tasks.clone();
+            if !section_tasks.is_empty() {
+                self.kanban_task_index = (self.kanban_task_index + 1) % section_tasks.len();
+            } else {
+                // No tasks in this section after filtering, reset index
+                self.kanban_task_index = 0;
             }
-            self.update_search_filters();
         }
         self
     }
 
-    /// Exit search mode. Keeps filtered results visible for navigation.
+    /// Navigate to previous task in current kanban column.
     ///
-    pub fn exit_search_mode(&mut self) -> &mut Self {
-        self.search_mode = false;
-        // Don't clear search_query or search_target - keep filters active
-        // Don't repopulate filtered lists - keep filtered results
+    #[allow(dead_code)]
+    pub fn previous_kanban_task(&mut self) -> &mut Self {
+        if !self.sections.is_empty() && self.kanban_column_index < self.sections.len() {
+            let section = &self.sections[self.kanban_column_index];
+            // Use filtered tasks to respect search/filter
+            let filtered_tasks = self.get_filtered_tasks();
+            let section_tasks: Vec<&Task> = filtered_tasks
+                .iter()
+                .filter(|t| {
+                    t.section
+                        .as_ref()
+                        .map(|s| s.gid == section.gid)
+                        .unwrap_or(false)
+                })
+                .collect();
 
-        // Ensure selections are valid for the filtered lists
-        if let Some(target) = &self.search_target {
-            match target {
-                SearchTarget::Projects => {
-                    if !self.filtered_projects.is_empty() {
-                        let current = self.projects_list_state.selected().unwrap_or(0);
-                        if current >= self.filtered_projects.len() {
-                            self.projects_list_state.select(Some(0));
-                        }
-                    } else {
-                        self.projects_list_state.select(None);
-                    }
-                }
-                SearchTarget::Tasks => {
-                    if !self.filtered_tasks.is_empty() {
-                        let current = self.tasks_list_state.selected().unwrap_or(0);
-                        if current >= self.filtered_tasks.len() {
-                            self.tasks_list_state.select(Some(0));
-                        }
-                    } else {
-                        self.tasks_list_state.select(None);
-                    }
+            if !section_tasks.is_empty() {
+                if self.kanban_task_index > 0 {
+                    self.kanban_task_index -= 1;
+                } else {
+                    self.kanban_task_index = section_tasks.len() - 1;
                 }
+            } else {
+                // No tasks in this section after filtering, reset index
+                self.kanban_task_index = 0;
             }
         }
-
         self
     }
 
-    /// Clear search completely (clears query and filters).
+    /// Get current selected task in kanban view.
     ///
-    #[allow(dead_code)]
-    pub fn clear_search(&mut self) -> &mut Self {
-        self.search_mode = false;
-        self.search_query.clear();
-        self.search_target = None;
+    pub fn get_kanban_selected_task(&self) -> Option<Task> {
+        if self.sections.is_empty() || self.kanban_column_index >= self.sections.len() {
+            return None;
+        }
 
-        // Repopulate filtered lists with full lists
-        self.filtered_projects = self.projects.clone();
-        self.filtered_tasks = self.tasks.clone();
+        let section = &self.sections[self.kanban_column_index];
+        // Use filtered tasks to respect search/filter
+        let filtered_tasks = self.get_filtered_tasks();
+        let section_tasks: Vec<&Task> = filtered_tasks
+            .iter()
+            .filter(|t| {
+                t.section
+                    .as_ref()
+                    .map(|s| s.gid == section.gid)
+                    .unwrap_or(false)
+            })
+            .collect();
 
-        // Reset selections to valid indices in the full lists
-        if !self.projects.is_empty() {
-            let current = self.projects_list_state.selected().unwrap_or(0);
-            if current >= self.projects.len() {
-                self.projects_list_state.select(Some(0));
-            } else {
-                self.projects_list_state.select(Some(current));
-            }
+        if self.kanban_task_index < section_tasks.len() {
+            Some(section_tasks[self.kanban_task_index].clone())
+        } else {
+            None
         }
+    }
 
-        if !self.tasks.is_empty() {
-            let current = self.tasks_list_state.selected().unwrap_or(0);
-            if current >= self.tasks.len() {
-                self.tasks_list_state.select(Some(0));
-            } else {
-                self.tasks_list_state.select(Some(current));
-            }
-        }
+    /// Get edit form state.
+    ///
+    pub fn get_edit_form_state(&self) -> Option<EditFormState> {
+        self.edit_form_state
+    }
 
+    /// Set edit form state.
+    ///
+    pub fn set_edit_form_state(&mut self, state: Option<EditFormState>) -> &mut Self {
+        self.edit_form_state = state;
         self
     }
 
-    /// Add a character to the search query.
+    /// Check if actively editing a field (vs navigating between fields).
     ///
-    pub fn add_search_char(&mut self, c: char) -> &mut Self {
-        self.search_query.push(c);
-        self.update_search_filters();
+    pub fn is_field_editing_mode(&self) -> bool {
+        self.field_editing_mode
+    }
+
+    /// Enter field editing mode (start editing the current field).
+    ///
+    pub fn enter_field_editing_mode(&mut self) -> &mut Self {
+        self.field_editing_mode = true;
         self
     }
 
-    /// Remove the last character from the search query.
+    /// Exit field editing mode (return to navigation mode).
     ///
-    pub fn remove_search_char(&mut self) -> &mut Self {
-        self.search_query.pop();
-        self.update_search_filters();
+    pub fn exit_field_editing_mode(&mut self) -> &mut Self {
+        self.field_editing_mode = false;
         self
     }
 
-    /// Update filtered lists based on search query. Only filters the target list.
+    /// Enter comment input mode.
     ///
-    fn update_search_filters(&mut self) {
-        if self.search_query.is_empty() {
-            // If query is empty, show all items for the search target
-            if let Some(target) = &self.search_target {
-                match target {
-                    SearchTarget::Projects => {
-                        self.filtered_projects = self.projects.clone();
-                    }
-                    SearchTarget::Tasks => {
-                        self.filtered_tasks = self.tasks.clone();
-                    }
-                }
-            } else {
-                // No target, reset both
-                self.filtered_projects = self.projects.clone();
-                self.filtered_tasks = self.tasks.clone();
-            }
-        } else {
-            let query_lower = self.search_query.to_lowercase();
+    pub fn enter_comment_input_mode(&mut self) -> &mut Self {
+        self.comment_input_mode = true;
+        self.detail_input = DetailInput::Comment;
+        self.comment_input_text.clear();
+        self
+    }
 
-            // Only filter the target list
-            if let Some(target) = &self.search_target {
-                match target {
-                    SearchTarget::Projects => {
-                        self.filtered_projects = self
-                            .projects
-                            .iter()
-                            .filter(|p| p.name.to_lowercase().contains(&query_lower))
-                            .cloned()
-                            .collect();
-                        // Don't filter tasks when searching projects
-                        self.filtered_tasks = self.tasks.clone();
-                    }
-                    SearchTarget::Tasks => {
-                        self.filtered_tasks = self
-                            .tasks
-                            .iter()
-                            .filter(|t| t.name.to_lowercase().contains(&query_lower))
-                            .cloned()
-                            .collect();
-                        // Don't filter projects when searching tasks
-                        self.filtered_projects = self.projects.clone();
-                    }
-                }
-            }
-        }
+    /// Exit comment input mode.
+    ///
+    pub fn exit_comment_input_mode(&mut self) -> &mut Self {
+        self.comment_input_mode = false;
+        self.detail_input = DetailInput::Comment;
+        self.comment_input_text.clear();
+        self
+    }
 
-        // Reset selection to first item if current selection is out of bounds
-        if let Some(target) = &self.search_target {
-            match target {
-                SearchTarget::Projects => {
-                    if !self.filtered_projects.is_empty() {
-                        let current = self.projects_list_state.selected().unwrap_or(0);
-                        if current >= self.filtered_projects.len() {
-                            self.projects_list_state.select(Some(0));
-                        }
-                    } else {
-                        self.projects_list_state.select(None);
-                    }
-                }
-                SearchTarget::Tasks => {
-                    if !self.filtered_tasks.is_empty() {
-                        let current = self.tasks_list_state.selected().unwrap_or(0);
-                        if current >= self.filtered_tasks.len() {
-                            self.tasks_list_state.select(Some(0));
-                        }
-                    } else {
-                        self.tasks_list_state.select(None);
-                    }
-                    // Also validate kanban column index when filtering tasks
-                    // This prevents crashes when sections become hidden due to filtering
-                    let visible_indices = self.get_visible_section_indices();
-                    if !visible_indices.is_empty()
-                        && !visible_indices.contains(&self.kanban_column_index)
-                    {
-                        // Current column is not visible, move to first visible
-                        self.kanban_column_index = visible_indices[0];
-                        self.kanban_task_index = 0;
-                    }
-                }
-            }
-        }
+    /// Check if in comment input mode.
+    ///
+    pub fn is_comment_input_mode(&self) -> bool {
+        self.comment_input_mode
     }
 
-    /// Get search query.
+    /// Get comment input text.
     ///
-    pub fn get_search_query(&self) -> &str {
-        &self.search_query
+    pub fn has_access_token(&self) -> bool {
+        self.has_access_token
     }
 
-    /// Check if in search mode.
+    pub fn get_access_token_input(&self) -> &str {
+        &self.access_token_input
+    }
+
+    pub fn set_access_token(&mut self, _token: String) -> &mut Self {
+        self.has_access_token = true;
+        // Token is stored in config file by network handler
+        self
+    }
+
+    pub fn add_access_token_char(&mut self, c: char) -> &mut Self {
+        self.access_token_input.push(c);
+        self
+    }
+
+    pub fn backspace_access_token(&mut self) -> &mut Self {
+        self.access_token_input.pop();
+        self
+    }
+
+    pub fn clear_access_token_input(&mut self) -> &mut Self {
+        self.access_token_input.clear();
+        self
+    }
+
+    pub fn set_auth_error(&mut self, error: Option<String>) -> &mut Self {
+        self.auth_error = error;
+        self
+    }
+
+    pub fn get_auth_error(&self) -> Option<&String> {
+        self.auth_error.as_ref()
+    }
+
+    pub fn clear_auth_error(&mut self) -> &mut Self {
+        self.auth_error = None;
+        self
+    }
+
+    /// Forget the rejected access token and return to the token prompt.
     ///
-    pub fn is_search_mode(&self) -> bool {
-        self.search_mode
+    pub fn require_access_token(&mut self, error: String) -> &mut Self {
+        self.has_access_token = false;
+        self.auth_error = Some(error);
+        self.access_token_input.clear();
+        self.view_stack = vec![View::Welcome];
+        self.current_focus = Focus::View;
+        self
     }
 
-    /// Get the current search target.
+    /// Set the pending request retry, or clear it with `None`.
     ///
-    pub fn get_search_target(&self) -> Option<&SearchTarget> {
-        self.search_target.as_ref()
+    pub fn set_retry_status(&mut self, status: Option<RetryStatus>) -> &mut Self {
+        self.retry_status = status;
+        self
     }
 
-    /// Get filtered projects (or all if not searching).
+    /// Get the pending request retry, if any.
     ///
-    /// Enter debug mode for navigating and copying logs.
+    pub fn get_retry_status(&self) -> Option<&RetryStatus> {
+        self.retry_status.as_ref()
+    }
+
+    /// Get comment input text.
     ///
-    pub fn enter_debug_mode(&mut self) -> &mut Self {
-        self.debug_mode = true;
-        // Set debug_index to the most recent log (last in the list)
-        if !self.debug_entries.is_empty() {
-            self.debug_index = self.debug_entries.len() - 1;
-        } else {
-            self.debug_index = 0;
-        }
+    pub fn get_comment_input_text(&self) -> &str {
+        &self.comment_input_text
+    }
+
+    /// Add character to comment input.
+    ///
+    pub fn add_comment_char(&mut self, c: char) -> &mut Self {
+        self.comment_input_text.push(c);
+        self.dependency_candidate_index = 0;
+        self
+    }
+
+    /// Remove last character from comment input.
+    ///
+    pub fn remove_comment_char(&mut self) -> &mut Self {
+        self.comment_input_text.pop();
+        self.dependency_candidate_index = 0;
+        self
+    }
+
+    /// Submit comment (returns the text and clears input).
+    ///
+    pub fn submit_comment(&mut self) -> String {
+        let text = self.comment_input_text.clone();
+        self.comment_input_text.clear();
+        self.comment_input_mode = false;
+        self.detail_input = DetailInput::Comment;
+        text
+    }
+
+    /// Get form name.
+    ///
+    pub fn get_form_name(&self) -> &str {
+        &self.form_name
+    }
+
+    /// Set form name.
+    ///
+    #[allow(dead_code)]
+    pub fn set_form_name(&mut self, name: String) -> &mut Self {
+        self.form_name = name;
+        self
+    }
+
+    /// Add character to form name.
+    ///
+    pub fn add_form_name_char(&mut self, c: char) -> &mut Self {
+        self.form_name.push(c);
+        self
+    }
+
+    /// Remove last character from form name.
+    ///
+    pub fn remove_form_name_char(&mut self) -> &mut Self {
+        self.form_name.pop();
+        self
+    }
+
+    /// Get form notes.
+    ///
+    /// Get form notes as string.
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_form_notes(&self) -> String {
+        self.form_notes_textarea
+            .lines()
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Get form notes textarea (mutable).
+    ///
+    pub fn get_form_notes_textarea(&mut self) -> &mut TextArea<'static> {
+        &mut self.form_notes_textarea
+    }
+
+    /// Set form notes from string.
+    ///
+    pub fn set_form_notes(&mut self, notes: String) -> &mut Self {
+        self.form_notes_textarea = TextArea::from(notes.lines().collect::<Vec<_>>());
+        self
+    }
+
+    /// Get form assignee.
+    ///
+    pub fn get_form_assignee(&self) -> Option<&String> {
+        self.form_assignee.as_ref()
+    }
+
+    /// Set form assignee.
+    ///
+    #[allow(dead_code)]
+    pub fn set_form_assignee(&mut self, assignee: Option<String>) -> &mut Self {
+        self.form_assignee = assignee;
+        self
+    }
+
+    /// Get form due date.
+    ///
+    pub fn get_form_due_on(&self) -> &str {
+        &self.form_due_on
+    }
+
+    /// Set form due date.
+    ///
+    #[allow(dead_code)]
+    pub fn set_form_due_on(&mut self, due_on: String) -> &mut Self {
+        self.form_due_on = due_on;
         self
     }
 
-    /// Exit debug mode.
-    ///
-    pub fn exit_debug_mode(&mut self) -> &mut Self {
-        self.debug_mode = false;
-        self
+    /// Get form start date.
+    ///
+    pub fn get_form_start_on(&self) -> &str {
+        &self.form_start_on
+    }
+
+    /// Get form due time.
+    ///
+    pub fn get_form_due_time(&self) -> &str {
+        &self.form_due_time
+    }
+
+    /// Return the text of the date or time field being edited.
+    ///
+    fn form_date_text_mut(&mut self) -> Option<&mut String> {
+        match self.edit_form_state {
+            Some(EditFormState::StartDate) => Some(&mut self.form_start_on),
+            Some(EditFormState::DueDate) => Some(&mut self.form_due_on),
+            Some(EditFormState::DueTime) => Some(&mut self.form_due_time),
+            _ => None,
+        }
+    }
+
+    /// Add character to the date or time field being edited.
+    ///
+    pub fn add_form_date_char(&mut self, c: char) -> &mut Self {
+        if let Some(text) = self.form_date_text_mut() {
+            text.push(c);
+        }
+        self
+    }
+
+    /// Remove last character from the date or time field being edited.
+    ///
+    pub fn remove_form_date_char(&mut self) -> &mut Self {
+        if let Some(text) = self.form_date_text_mut() {
+            text.pop();
+        }
+        self
+    }
+
+    /// Return the problems with the typed dates and times, each with the
+    /// field to show it on.
+    ///
+    fn form_date_errors(&self) -> Vec<(EditFormState, String)> {
+        let typed = |text: &str| (!text.trim().is_empty()).then(|| text.trim().to_string());
+        let start_on = typed(&self.form_start_on).map(|d| dates::parse_date(&d));
+        let due_on = typed(&self.form_due_on).map(|d| dates::parse_date(&d));
+        let due_time = typed(&self.form_due_time).map(|t| dates::parse_time(&t));
+
+        let mut errors = vec![];
+        if let Some(Err(e)) = &start_on {
+            errors.push((EditFormState::StartDate, e.clone()));
+        }
+        if let Some(Err(e)) = &due_on {
+            errors.push((EditFormState::DueDate, e.clone()));
+        }
+        if let Some(Err(e)) = &due_time {
+            errors.push((EditFormState::DueTime, e.clone()));
+        }
+        match (&start_on, &due_on) {
+            (Some(_), None) => errors.push((
+                EditFormState::StartDate,
+                "A start date needs a due date".to_string(),
+            )),
+            (Some(Ok(start)), Some(Ok(due))) if start > due => errors.push((
+                EditFormState::StartDate,
+                "Start date must not be after the due date".to_string(),
+            )),
+            _ => {}
+        }
+        match (&due_on, &due_time) {
+            (None, Some(_)) => errors.push((
+                EditFormState::DueTime,
+                "A due time needs a due date".to_string(),
+            )),
+            (Some(Ok(date)), Some(Ok(time))) => {
+                if let Err(e) = dates::local_to_utc(*date, *time) {
+                    errors.push((EditFormState::DueTime, e));
+                }
+            }
+            _ => {}
+        }
+        errors
+    }
+
+    /// Return the problem with the typed value of a date or time field.
+    ///
+    pub fn get_form_date_error(&self, field: EditFormState) -> Option<String> {
+        self.form_date_errors()
+            .into_iter()
+            .find(|(f, _)| *f == field)
+            .map(|(_, e)| e)
+    }
+
+    /// Return the dates to send for the form, or the first field with a
+    /// problem and what it is. When editing, only changed dates are sent and
+    /// emptied ones are cleared.
+    ///
+    pub fn get_form_task_dates(
+        &self,
+        changed_only: bool,
+    ) -> Result<TaskDates, (EditFormState, String)> {
+        if let Some(error) = self.form_date_errors().into_iter().next() {
+            return Err(error);
+        }
+        // Typed dates are sent as the ISO dates they parse to
+        let iso = |text: &str| match dates::parse_date(text) {
+            Ok(date) => date.format(dates::ISO_DATE).to_string(),
+            Err(_) => String::new(),
+        };
+        let start_on = iso(&self.form_start_on);
+        let due_on = iso(&self.form_due_on);
+        let due_time = self.form_due_time.trim();
+        let send = |value: &str, original: &str| {
+            let send = if changed_only {
+                value != original
+            } else {
+                !value.is_empty()
+            };
+            send.then(|| value.to_string())
+        };
+
+        let mut task_dates = TaskDates {
+            start_on: send(&start_on, &self.original_form_start_on),
+            ..TaskDates::default()
+        };
+        let due_changed =
+            due_on != self.original_form_due_on || due_time != self.original_form_due_time;
+        if due_time.is_empty() {
+            task_dates.due_on = send(&due_on, &self.original_form_due_on)
+                .or_else(|| (changed_only && due_changed).then(|| due_on.clone()));
+        } else if !changed_only || due_changed {
+            // Both parse, as validated above
+            if let (Ok(date), Ok(time)) = (dates::parse_date(&due_on), dates::parse_time(due_time))
+            {
+                task_dates.due_at = dates::local_to_utc(date, time).ok();
+            }
+        }
+        Ok(task_dates)
+    }
+
+    /// Return the date field of the form being edited, if any.
+    ///
+    fn current_date_target(&self) -> Option<DateTarget> {
+        match self.edit_form_state {
+            Some(field @ (EditFormState::StartDate | EditFormState::DueDate)) => {
+                Some(DateTarget::Form(field))
+            }
+            Some(EditFormState::CustomField(_)) => self
+                .get_current_custom_field()
+                .filter(|(_, cf)| cf.resource_subtype == "date")
+                .map(|(_, cf)| DateTarget::CustomField(cf.gid.clone())),
+            _ => None,
+        }
+    }
+
+    /// Return the typed text of a date field.
+    ///
+    fn date_target_text(&self, target: &DateTarget) -> &str {
+        match target {
+            DateTarget::Form(EditFormState::StartDate) => &self.form_start_on,
+            DateTarget::Form(_) => &self.form_due_on,
+            DateTarget::CustomField(gid) => match self.form_custom_field_values.get(gid) {
+                Some(CustomFieldValue::Date(Some(text))) => text,
+                _ => "",
+            },
+        }
+    }
+
+    /// Open the calendar over the date field being edited, on the typed date
+    /// or today.
+    ///
+    pub fn open_date_picker(&mut self) -> &mut Self {
+        if let Some(target) = self.current_date_target() {
+            let cursor = dates::parse_date(self.date_target_text(&target))
+                .unwrap_or_else(|_| chrono::Local::now().date_naive());
+            self.date_picker = Some(DatePicker { target, cursor });
+        }
+        self
+    }
+
+    /// Return whether the calendar is open.
+    ///
+    pub fn has_date_picker(&self) -> bool {
+        self.date_picker.is_some()
+    }
+
+    /// Get the open calendar.
+    ///
+    pub fn get_date_picker(&self) -> Option<&DatePicker> {
+        self.date_picker.as_ref()
+    }
+
+    /// Move the calendar's highlighted day by a number of days.
+    ///
+    pub fn move_date_picker_days(&mut self, days: i64) -> &mut Self {
+        if let Some(picker) = &mut self.date_picker {
+            picker.move_days(days);
+        }
+        self
+    }
+
+    /// Move the calendar's highlighted day by a number of months.
+    ///
+    pub fn move_date_picker_months(&mut self, months: i32) -> &mut Self {
+        if let Some(picker) = &mut self.date_picker {
+            picker.move_months(months);
+        }
+        self
+    }
+
+    /// Highlight today in the calendar.
+    ///
+    pub fn move_date_picker_to_today(&mut self) -> &mut Self {
+        if let Some(picker) = &mut self.date_picker {
+            picker.cursor = chrono::Local::now().date_naive();
+        }
+        self
+    }
+
+    /// Fill the date field with the highlighted day and close the calendar.
+    ///
+    pub fn select_date_picker(&mut self) -> &mut Self {
+        let picker = match self.date_picker.take() {
+            Some(picker) => picker,
+            None => return self,
+        };
+        let date = picker.cursor.format(dates::ISO_DATE).to_string();
+        match picker.target {
+            DateTarget::Form(EditFormState::StartDate) => self.form_start_on = date,
+            DateTarget::Form(_) => self.form_due_on = date,
+            DateTarget::CustomField(gid) => {
+                self.form_custom_field_values
+                    .insert(gid, CustomFieldValue::Date(Some(date)));
+            }
+        }
+        self
+    }
+
+    /// Close the calendar without changing the date field.
+    ///
+    pub fn close_date_picker(&mut self) -> &mut Self {
+        self.date_picker = None;
+        self
+    }
+
+    /// Get form section.
+    ///
+    pub fn get_form_section(&self) -> Option<&String> {
+        self.form_section.as_ref()
+    }
+
+    /// Get original form name (for change detection).
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_original_form_name(&self) -> &str {
+        &self.original_form_name
+    }
+
+    /// Get original form notes (for change detection).
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_original_form_notes(&self) -> &str {
+        &self.original_form_notes
+    }
+
+    /// Check that the notes can be saved: notes holding tags the editor
+    /// can't keep, like inline images, may only be left as they were.
+    ///
+    pub fn check_form_notes_saveable(&self) -> Result<(), String> {
+        if self.original_notes_unsupported_tags.is_empty()
+            || self.get_form_notes().trim() == self.original_form_notes.trim()
+        {
+            return Ok(());
+        }
+        Err(format!(
+            "notes contain {} that saving would drop, undo the notes changes to save",
+            self.format_unsupported_notes_tags()
+        ))
+    }
+
+    /// Return the tags of the original notes that saving drops, like `<img>`.
+    ///
+    fn format_unsupported_notes_tags(&self) -> String {
+        self.original_notes_unsupported_tags
+            .iter()
+            .map(|tag| format!("<{}>", tag))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Get original form assignee (for change detection).
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_original_form_assignee(&self) -> &Option<String> {
+        &self.original_form_assignee
+    }
+
+    /// Get original form section (for change detection).
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_original_form_section(&self) -> &Option<String> {
+        &self.original_form_section
+    }
+
+    /// Set form section.
+    ///
+    #[allow(dead_code)]
+    pub fn set_form_section(&mut self, section: Option<String>) -> &mut Self {
+        self.form_section = section;
+        self
+    }
+
+    pub fn get_assignee_dropdown_index(&self) -> usize {
+        self.assignee_dropdown_index
+    }
+
+    #[allow(dead_code)]
+    pub fn set_assignee_dropdown_index(&mut self, index: usize) -> &mut Self {
+        self.assignee_dropdown_index = index;
+        self
+    }
+
+    /// Initialize assignee dropdown index to match currently selected assignee (if any).
+    /// This ensures the selected assignee is shown when entering the dropdown.
+    pub fn init_assignee_dropdown_index(&mut self) -> &mut Self {
+        if let Some(selected_gid) = &self.form_assignee {
+            let filtered_users = self.get_filtered_assignees();
+            if let Some(index) = filtered_users.iter().position(|u| &u.gid == selected_gid) {
+                self.assignee_dropdown_index = index;
+            } else {
+                // Selected assignee not in filtered list, reset to 0
+                self.assignee_dropdown_index = 0;
+            }
+        } else {
+            // No assignee selected, start at 0
+            self.assignee_dropdown_index = 0;
+        }
+        self
+    }
+
+    pub fn next_assignee(&mut self) -> &mut Self {
+        let users = self.get_filtered_assignees();
+        if !users.is_empty() {
+            self.assignee_dropdown_index = (self.assignee_dropdown_index + 1) % users.len();
+        }
+        self
+    }
+
+    pub fn previous_assignee(&mut self) -> &mut Self {
+        let users = self.get_filtered_assignees();
+        if !users.is_empty() {
+            if self.assignee_dropdown_index == 0 {
+                self.assignee_dropdown_index = users.len() - 1;
+            } else {
+                self.assignee_dropdown_index -= 1;
+            }
+        }
+        self
+    }
+
+    pub fn select_current_assignee(&mut self) -> &mut Self {
+        let users = self.get_filtered_assignees();
+        if !users.is_empty() && self.assignee_dropdown_index < users.len() {
+            self.form_assignee = Some(users[self.assignee_dropdown_index].gid.clone());
+        }
+        self
+    }
+
+    /// Get filtered assignees based on search text
+    pub fn get_filtered_assignees(&self) -> Vec<User> {
+        self.rank_typeahead(
+            TypeaheadKind::User,
+            &self.form_assignee_search,
+            &self.workspace_users,
+            |u| vec![&u.name, &u.email],
+            |u| &u.gid,
+        )
+    }
+
+    pub fn add_assignee_search_char(&mut self, c: char) -> &mut Self {
+        self.form_assignee_search.push(c);
+        // Reset dropdown index when search changes
+        self.assignee_dropdown_index = 0;
+        let search = self.form_assignee_search.clone();
+        self.request_typeahead(TypeaheadKind::User, &search)
+    }
+
+    pub fn backspace_assignee_search(&mut self) -> &mut Self {
+        self.form_assignee_search.pop();
+        // Reset dropdown index when search changes
+        self.assignee_dropdown_index = 0;
+        let search = self.form_assignee_search.clone();
+        self.request_typeahead(TypeaheadKind::User, &search)
+    }
+
+    pub fn get_assignee_search(&self) -> &str {
+        &self.form_assignee_search
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_assignee_search(&mut self) -> &mut Self {
+        self.form_assignee_search.clear();
+        self.assignee_dropdown_index = 0;
+        self
+    }
+
+    pub fn get_section_dropdown_index(&self) -> usize {
+        self.section_dropdown_index
+    }
+
+    #[allow(dead_code)]
+    pub fn set_section_dropdown_index(&mut self, index: usize) -> &mut Self {
+        self.section_dropdown_index = index;
+        self
+    }
+
+    /// Get filtered sections based on search text
+    pub fn get_filtered_sections(&self) -> Vec<Section> {
+        let search = self.form_section_search.to_lowercase();
+        if search.is_empty() {
+            self.sections.clone()
+        } else {
+            self.sections
+                .iter()
+                .filter(|s| s.name.to_lowercase().contains(&search))
+                .cloned()
+                .collect()
+        }
+    }
+
+    pub fn add_section_search_char(&mut self, c: char) -> &mut Self {
+        self.form_section_search.push(c);
+        // Reset dropdown index when search changes
+        self.section_dropdown_index = 0;
+        self
+    }
+
+    pub fn backspace_section_search(&mut self) -> &mut Self {
+        self.form_section_search.pop();
+        // Reset dropdown index when search changes
+        self.section_dropdown_index = 0;
+        self
+    }
+
+    pub fn get_section_search(&self) -> &str {
+        &self.form_section_search
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_section_search(&mut self) -> &mut Self {
+        self.form_section_search.clear();
+        self.section_dropdown_index = 0;
+        self
+    }
+
+    /// Initialize section dropdown index to match currently selected section (if any).
+    /// This ensures the selected section is shown when entering the dropdown.
+    pub fn init_section_dropdown_index(&mut self) -> &mut Self {
+        if let Some(selected_gid) = &self.form_section {
+            let filtered_sections = self.get_filtered_sections();
+            if let Some(index) = filtered_sections
+                .iter()
+                .position(|s| &s.gid == selected_gid)
+            {
+                self.section_dropdown_index = index;
+            } else {
+                // Selected section not in filtered list, reset to 0
+                self.section_dropdown_index = 0;
+            }
+        } else {
+            // No section selected, start at 0
+            self.section_dropdown_index = 0;
+        }
+        self
+    }
+
+    pub fn next_section(&mut self) -> &mut Self {
+        let sections = self.get_filtered_sections();
+        if !sections.is_empty() {
+            self.section_dropdown_index = (self.section_dropdown_index + 1) % sections.len();
+        }
+        self
+    }
+
+    pub fn previous_section(&mut self) -> &mut Self {
+        let sections = self.get_filtered_sections();
+        if !sections.is_empty() {
+            if self.section_dropdown_index == 0 {
+                self.section_dropdown_index = sections.len() - 1;
+            } else {
+                self.section_dropdown_index -= 1;
+            }
+        }
+        self
+    }
+
+    pub fn select_current_section(&mut self) -> &mut Self {
+        let sections = self.get_filtered_sections();
+        if !sections.is_empty() && self.section_dropdown_index < sections.len() {
+            self.form_section = Some(sections[self.section_dropdown_index].gid.clone());
+        } else {
+            warn!("No section found at index {}", self.section_dropdown_index);
+        }
+        self
+    }
+
+    /// Get the GIDs of the tags selected in the form.
+    ///
+    pub fn get_form_tags(&self) -> &[String] {
+        &self.form_tags
+    }
+
+    /// Get original form tags (for change detection).
+    ///
+    pub fn get_original_form_tags(&self) -> &[String] {
+        &self.original_form_tags
+    }
+
+    /// Get filtered tags based on search text
+    pub fn get_filtered_tags(&self) -> Vec<Tag> {
+        self.rank_typeahead(
+            TypeaheadKind::Tag,
+            &self.form_tag_search,
+            &self.workspace_tags,
+            |t| vec![&t.name],
+            |t| &t.gid,
+        )
+    }
+
+    /// Get the name of the tag to create from the search text, if no tag is
+    /// named that yet.
+    ///
+    pub fn get_new_tag_name(&self) -> Option<&str> {
+        let name = self.form_tag_search.trim();
+        let exists = self
+            .workspace_tags
+            .iter()
+            .any(|t| t.name.eq_ignore_ascii_case(name));
+        (!name.is_empty() && !exists).then_some(name)
+    }
+
+    /// Number of options in the tag dropdown, including the create option.
+    ///
+    fn tag_option_count(&self) -> usize {
+        self.get_filtered_tags().len() + usize::from(self.get_new_tag_name().is_some())
+    }
+
+    pub fn get_tag_dropdown_index(&self) -> usize {
+        self.tag_dropdown_index
+    }
+
+    pub fn next_tag(&mut self) -> &mut Self {
+        let count = self.tag_option_count();
+        if count > 0 {
+            self.tag_dropdown_index = (self.tag_dropdown_index + 1) % count;
+        }
+        self
+    }
+
+    pub fn previous_tag(&mut self) -> &mut Self {
+        let count = self.tag_option_count();
+        if count > 0 {
+            if self.tag_dropdown_index == 0 {
+                self.tag_dropdown_index = count - 1;
+            } else {
+                self.tag_dropdown_index -= 1;
+            }
+        }
+        self
+    }
+
+    /// Toggle the highlighted tag in the form, or create a tag named after
+    /// the search text when the create option is highlighted.
+    ///
+    pub fn toggle_current_tag(&mut self) -> &mut Self {
+        let tags = self.get_filtered_tags();
+        if let Some(tag) = tags.get(self.tag_dropdown_index) {
+            match self.form_tags.iter().position(|gid| gid == &tag.gid) {
+                Some(index) => {
+                    self.form_tags.remove(index);
+                }
+                None => self.form_tags.push(tag.gid.clone()),
+            }
+        } else if let (Some(name), Some(workspace_gid)) = (
+            self.get_new_tag_name().map(str::to_string),
+            self.active_workspace_gid.clone(),
+        ) {
+            self.dispatch(NetworkEvent::CreateTag {
+                workspace_gid,
+                name,
+            });
+            self.form_tag_search.clear();
+            self.tag_dropdown_index = 0;
+        }
+        self
+    }
+
+    pub fn add_tag_search_char(&mut self, c: char) -> &mut Self {
+        self.form_tag_search.push(c);
+        // Reset dropdown index when search changes
+        self.tag_dropdown_index = 0;
+        let search = self.form_tag_search.clone();
+        self.request_typeahead(TypeaheadKind::Tag, &search)
+    }
+
+    pub fn backspace_tag_search(&mut self) -> &mut Self {
+        self.form_tag_search.pop();
+        // Reset dropdown index when search changes
+        self.tag_dropdown_index = 0;
+        let search = self.form_tag_search.clone();
+        self.request_typeahead(TypeaheadKind::Tag, &search)
+    }
+
+    pub fn get_tag_search(&self) -> &str {
+        &self.form_tag_search
+    }
+
+    /// Clear form fields.
+    ///
+    pub fn clear_form(&mut self) -> &mut Self {
+        self.form_name.clear();
+        self.form_notes_textarea = TextArea::default();
+        self.form_assignee = None;
+        self.form_assignee_search.clear();
+        self.form_start_on.clear();
+        self.form_due_on.clear();
+        self.form_due_time.clear();
+        self.form_section = None;
+        self.form_section_search.clear();
+        self.form_tags.clear();
+        self.form_tag_search.clear();
+        self.original_form_tags.clear();
+        self.original_notes_unsupported_tags.clear();
+        self.form_custom_field_values.clear();
+        self.custom_field_search.clear();
+        self.custom_field_dropdown_index.clear();
+        self.date_picker = None;
+        self.edit_form_state = None;
+        self.field_editing_mode = false;
+        self.assignee_dropdown_index = 0;
+        self.section_dropdown_index = 0;
+        self.tag_dropdown_index = 0;
+        self.form_scroll_offset = 0;
+        self
+    }
+
+    /// Get available custom fields for the current project.
+    ///
+    pub fn get_project_custom_fields(&self) -> &[CustomField] {
+        &self.project_custom_fields
+    }
+
+    /// Get enabled custom fields (excluding custom_id and disabled fields).
+    ///
+    pub fn get_enabled_custom_fields(&self) -> Vec<&CustomField> {
+        self.project_custom_fields
+            .iter()
+            .filter(|cf| {
+                // Skip custom_id fields
+                let is_custom_id = cf
+                    .representation_type
+                    .as_ref()
+                    .map(|s| s == "custom_id")
+                    .unwrap_or(false)
+                    || cf.id_prefix.is_some();
+                // Only include enabled fields
+                !is_custom_id && cf.enabled
+            })
+            .collect()
+    }
+
+    /// Set project custom fields.
+    ///
+    pub fn set_project_custom_fields(&mut self, fields: Vec<CustomField>) -> &mut Self {
+        self.project_custom_fields = fields;
+        self
+    }
+
+    /// Mark the project custom field named in an API validation message.
+    /// Returns false if no known field could be matched.
+    ///
+    pub fn mark_custom_field_error(&mut self, message: &str) -> bool {
+        let lower = message.to_lowercase();
+        let matched = self
+            .project_custom_fields
+            .iter()
+            .find(|cf| message.contains(&cf.gid))
+            .or_else(|| {
+                self.project_custom_fields
+                    .iter()
+                    .find(|cf| !cf.name.is_empty() && lower.contains(&cf.name.to_lowercase()))
+            })
+            .map(|cf| cf.gid.clone());
+        match matched {
+            Some(gid) => {
+                self.custom_field_errors.insert(gid, message.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the API validation message for a custom field, if any.
+    ///
+    pub fn get_custom_field_error(&self, gid: &str) -> Option<&String> {
+        self.custom_field_errors.get(gid)
+    }
+
+    /// Clear all custom field validation messages.
+    ///
+    pub fn clear_custom_field_errors(&mut self) -> &mut Self {
+        self.custom_field_errors.clear();
+        self
+    }
+
+    /// Get custom field value for a given GID.
+    ///
+    pub fn get_custom_field_value(&self, gid: &str) -> Option<&CustomFieldValue> {
+        self.form_custom_field_values.get(gid)
+    }
+
+    /// Get all custom field values (for API calls).
+    ///
+    #[allow(dead_code)] // Used via dispatch system
+    pub fn get_form_custom_field_values(&self) -> &HashMap<String, CustomFieldValue> {
+        &self.form_custom_field_values
+    }
+
+    /// Return the custom field values to send, with typed dates as the ISO
+    /// dates they parse to, or the first date field that doesn't parse and
+    /// why.
+    ///
+    pub fn get_submit_custom_field_values(
+        &self,
+    ) -> Result<HashMap<String, CustomFieldValue>, (EditFormState, String)> {
+        let mut values = self.form_custom_field_values.clone();
+        for (idx, cf) in self.project_custom_fields.iter().enumerate() {
+            let text = match values.get_mut(&cf.gid) {
+                Some(CustomFieldValue::Date(Some(text))) => text,
+                _ => continue,
+            };
+            if text.trim().is_empty() {
+                values.insert(cf.gid.clone(), CustomFieldValue::Date(None));
+                continue;
+            }
+            match dates::parse_date(text) {
+                Ok(date) => *text = date.format(dates::ISO_DATE).to_string(),
+                Err(e) => {
+                    return Err((
+                        EditFormState::CustomField(idx),
+                        format!("{}: {}", cf.name, e),
+                    ))
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Set custom field value for a given GID.
+    ///
+    #[allow(dead_code)]
+    pub fn set_custom_field_value(&mut self, gid: String, value: CustomFieldValue) -> &mut Self {
+        self.form_custom_field_values.insert(gid, value);
+        self
+    }
+
+    /// Get custom field search text.
+    ///
+    pub fn get_custom_field_search(&self, gid: &str) -> &str {
+        self.custom_field_search
+            .get(gid)
+            .map(|s| s.as_str())
+            .unwrap_or("")
+    }
+
+    /// Add character to custom field search.
+    ///
+    pub fn add_custom_field_search_char(&mut self, gid: String, c: char) -> &mut Self {
+        self.custom_field_search.entry(gid).or_default().push(c);
+        self
+    }
+
+    /// Backspace custom field search.
+    ///
+    pub fn backspace_custom_field_search(&mut self, gid: &str) -> &mut Self {
+        if let Some(search) = self.custom_field_search.get_mut(gid) {
+            search.pop();
+        }
+        self
+    }
+
+    /// Look up users matching the search of a people custom field.
+    ///
+    pub fn request_people_typeahead(&mut self, gid: &str) -> &mut Self {
+        let search = self.get_custom_field_search(gid).to_string();
+        self.request_typeahead(TypeaheadKind::User, &search)
+    }
+
+    /// Get the users a people custom field offers, filtered by its search.
+    ///
+    pub fn get_filtered_people(&self, gid: &str) -> Vec<User> {
+        self.rank_typeahead(
+            TypeaheadKind::User,
+            self.get_custom_field_search(gid),
+            &self.workspace_users,
+            |u| vec![&u.name],
+            |u| &u.gid,
+        )
+    }
+
+    /// Get custom field dropdown index.
+    ///
+    pub fn get_custom_field_dropdown_index(&self, gid: &str) -> usize {
+        *self.custom_field_dropdown_index.get(gid).unwrap_or(&0)
+    }
+
+    /// Set custom field dropdown index.
+    ///
+    pub fn set_custom_field_dropdown_index(&mut self, gid: String, index: usize) -> &mut Self {
+        self.custom_field_dropdown_index.insert(gid, index);
+        self
+    }
+
+    /// Add character to custom field text/number/date value.
+    ///
+    pub fn add_custom_field_text_char(
+        &mut self,
+        gid: String,
+        c: char,
+        field_type: &str,
+    ) -> &mut Self {
+        match field_type {
+            "number" => {
+                // For number fields, parse the current value and append the character
+                let current_text = self
+                    .form_custom_field_values
+                    .get(&gid)
+                    .and_then(|v| match v {
+                        CustomFieldValue::Number(Some(n)) => Some(n.to_string()),
+                        CustomFieldValue::Text(s) => Some(s.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                let new_text = current_text + &c.to_string();
+                // Try to parse as number
+                if let Ok(num) = new_text.parse::<f64>() {
+                    self.form_custom_field_values
+                        .insert(gid, CustomFieldValue::Number(Some(num)));
+                } else {
+                    // If parsing fails, store as text (will be ignored when sending)
+                    self.form_custom_field_values
+                        .insert(gid, CustomFieldValue::Text(new_text));
+                }
+            }
+            _ => {
+                // For text and date fields, store as text
+                let current = self
+                    .form_custom_field_values
+                    .get(&gid)
+                    .and_then(|v| match v {
+                        CustomFieldValue::Text(s) => Some(s.clone()),
+                        CustomFieldValue::Date(Some(d)) => Some(d.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                let new_value = current + &c.to_string();
+                if field_type == "date" {
+                    self.form_custom_field_values
+                        .insert(gid, CustomFieldValue::Date(Some(new_value)));
+                } else {
+                    self.form_custom_field_values
+                        .insert(gid, CustomFieldValue::Text(new_value));
+                }
+            }
+        }
+        self
+    }
+
+    /// Remove character from custom field text/number/date value.
+    ///
+    pub fn remove_custom_field_text_char(&mut self, gid: &str, field_type: &str) -> &mut Self {
+        match field_type {
+            "number" => {
+                // For number fields, get current value and remove last character
+                let current_text = self
+                    .form_custom_field_values
+                    .get(gid)
+                    .and_then(|v| match v {
+                        CustomFieldValue::Number(Some(n)) => Some(n.to_string()),
+                        CustomFieldValue::Text(s) => Some(s.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                let mut new_text = current_text;
+                new_text.pop();
+
+                if new_text.is_empty() {
+                    self.form_custom_field_values.remove(gid);
+                } else if let Ok(num) = new_text.parse::<f64>() {
+                    self.form_custom_field_values
+                        .insert(gid.to_string(), CustomFieldValue::Number(Some(num)));
+                } else {
+                    self.form_custom_field_values
+                        .insert(gid.to_string(), CustomFieldValue::Text(new_text));
+                }
+            }
+            _ => {
+                // For text and date fields
+                if let Some(value) = self.form_custom_field_values.get(gid) {
+                    let mut new_value = match value {
+                        CustomFieldValue::Text(s) => s.clone(),
+                        CustomFieldValue::Date(Some(d)) => d.clone(),
+                        _ => return self,
+                    };
+                    new_value.pop();
+
+                    if new_value.is_empty() {
+                        self.form_custom_field_values.remove(gid);
+                    } else if field_type == "date" {
+                        self.form_custom_field_values
+                            .insert(gid.to_string(), CustomFieldValue::Date(Some(new_value)));
+                    } else {
+                        self.form_custom_field_values
+                            .insert(gid.to_string(), CustomFieldValue::Text(new_value));
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Get current custom field being edited.
+    ///
+    pub fn get_current_custom_field(&self) -> Option<(usize, &CustomField)> {
+        if let Some(EditFormState::CustomField(idx)) = self.edit_form_state {
+            self.project_custom_fields.get(idx).map(|cf| (idx, cf))
+        } else {
+            None
+        }
+    }
+
+    /// Navigate to next enum option in custom field dropdown.
+    ///
+    pub fn next_custom_field_enum(&mut self, gid: &str, count: usize) -> &mut Self {
+        let current = self.get_custom_field_dropdown_index(gid);
+        let new_idx = if current + 1 >= count { 0 } else { current + 1 };
+        self.set_custom_field_dropdown_index(gid.to_string(), new_idx);
+        self
+    }
+
+    /// Navigate to previous enum option in custom field dropdown.
+    ///
+    pub fn previous_custom_field_enum(&mut self, gid: &str, count: usize) -> &mut Self {
+        let current = self.get_custom_field_dropdown_index(gid);
+        let new_idx = if current == 0 {
+            count.saturating_sub(1)
+        } else {
+            current - 1
+        };
+        self.set_custom_field_dropdown_index(gid.to_string(), new_idx);
+        self
+    }
+
+    /// Toggle multi-enum option for custom field.
+    ///
+    pub fn toggle_custom_field_multi_enum(&mut self, gid: &str, enum_gid: String) -> &mut Self {
+        let current = self
+            .form_custom_field_values
+            .get(gid)
+            .and_then(|v| match v {
+                CustomFieldValue::MultiEnum(gids) => Some(gids.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let mut new_gids = current;
+        if new_gids.contains(&enum_gid) {
+            new_gids.retain(|g| g != &enum_gid);
+        } else {
+            new_gids.push(enum_gid);
+        }
+        self.form_custom_field_values
+            .insert(gid.to_string(), CustomFieldValue::MultiEnum(new_gids));
+        self
+    }
+
+    /// Toggle people option for custom field.
+    ///
+    pub fn toggle_custom_field_people(&mut self, gid: &str, user_gid: String) -> &mut Self {
+        let current = self
+            .form_custom_field_values
+            .get(gid)
+            .and_then(|v| match v {
+                CustomFieldValue::People(gids) => Some(gids.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let mut new_gids = current;
+        if new_gids.contains(&user_gid) {
+            new_gids.retain(|g| g != &user_gid);
+        } else {
+            new_gids.push(user_gid);
+        }
+        self.form_custom_field_values
+            .insert(gid.to_string(), CustomFieldValue::People(new_gids));
+        self
+    }
+
+    /// Get form scroll offset.
+    ///
+    #[allow(dead_code)]
+    pub fn get_form_scroll_offset(&self) -> usize {
+        self.form_scroll_offset
+    }
+
+    /// Scroll form up (show earlier fields).
+    ///
+    #[allow(dead_code)]
+    pub fn scroll_form_up(&mut self) -> &mut Self {
+        if self.form_scroll_offset > 0 {
+            self.form_scroll_offset -= 1;
+        }
+        self
+    }
+
+    /// Scroll form down (show later fields).
+    ///
+    #[allow(dead_code)]
+    pub fn scroll_form_down(&mut self) -> &mut Self {
+        // Don't scroll past the last field - limit is handled in rendering
+        self.form_scroll_offset += 1;
+        self
+    }
+
+    /// Get total number of form fields (standard + custom).
+    ///
+    #[allow(dead_code)]
+    pub fn get_total_form_field_count(&self) -> usize {
+        // Standard fields: Name, Notes, Assignee, StartDate, DueDate, DueTime, Section, Tags = 8
+        8 + self.project_custom_fields.len()
+    }
+
+    /// Initialize edit form with task data.
+    ///
+    #[allow(dead_code)]
+    pub fn init_edit_form(&mut self, task: &Task) -> &mut Self {
+        // Set current form values
+        self.form_name = task.name.clone();
+        // Rich text notes are edited as Markdown so saving keeps the formatting
+        let notes = match &task.html_notes {
+            Some(html) => html_to_markdown(html),
+            None => task.notes.clone().unwrap_or_default(),
+        };
+        self.original_notes_unsupported_tags = task
+            .html_notes
+            .as_deref()
+            .map(unsupported_tags)
+            .unwrap_or_default();
+        if !self.original_notes_unsupported_tags.is_empty() {
+            warn!(
+                "Notes contain {} that can't be edited here, so changes to them won't be saved.",
+                self.format_unsupported_notes_tags()
+            );
+        }
+        self.set_form_notes(notes.clone());
+        self.form_assignee = task.assignee.as_ref().map(|u| u.gid.clone());
+        self.form_start_on = task.start_on.clone().unwrap_or_default();
+        // A due time is shown in the local timezone, on its local date
+        let (due_on, due_time) = task
+            .due_at
+            .as_deref()
+            .and_then(dates::utc_to_local)
+            .unwrap_or_else(|| (task.due_on.clone().unwrap_or_default(), String::new()));
+        self.form_due_on = due_on;
+        self.form_due_time = due_time;
+        self.form_section = task.section.as_ref().map(|s| s.gid.clone());
+        self.form_tags = task.tags.iter().map(|t| t.gid.clone()).collect();
+        self.form_tag_search.clear();
+        self.tag_dropdown_index = 0;
+        // Store original values for change detection
+        self.original_form_name = task.name.clone();
+        self.original_form_notes = notes;
+        self.original_form_assignee = task.assignee.as_ref().map(|u| u.gid.clone());
+        self.original_form_start_on = self.form_start_on.clone();
+        self.original_form_due_on = self.form_due_on.clone();
+        self.original_form_due_time = self.form_due_time.clone();
+        self.original_form_section = task.section.as_ref().map(|s| s.gid.clone());
+        self.original_form_tags = self.form_tags.clone();
+        // Initialize custom field values from task
+        self.form_custom_field_values.clear();
+        self.custom_field_dropdown_index.clear();
+        self.date_picker = None;
+        for cf in &task.custom_fields {
+            let value = match cf.resource_subtype.as_str() {
+                "text" => CustomFieldValue::Text(cf.text_value.clone().unwrap_or_default()),
+                "number" => CustomFieldValue::Number(cf.number_value),
+                "date" => CustomFieldValue::Date(cf.date_value.clone()),
+                "enum" => CustomFieldValue::Enum(cf.enum_value.as_ref().map(|e| e.gid.clone())),
+                "multi_enum" => CustomFieldValue::MultiEnum(
+                    cf.multi_enum_values.iter().map(|e| e.gid.clone()).collect(),
+                ),
+                "people" => CustomFieldValue::People(
+                    cf.people_value.iter().map(|u| u.gid.clone()).collect(),
+                ),
+                _ => continue,
+            };
+            self.form_custom_field_values.insert(cf.gid.clone(), value);
+
+            // Initialize dropdown index for enum fields with selected values
+            if cf.resource_subtype == "enum" {
+                if let Some(selected_enum_gid) = cf.enum_value.as_ref().map(|e| &e.gid) {
+                    // Find the project custom field definition to get enum options
+                    if let Some(project_cf) = self
+                        .project_custom_fields
+                        .iter()
+                        .find(|pcf| pcf.gid == cf.gid)
+                    {
+                        if let Some(index) = project_cf
+                            .enum_options
+                            .iter()
+                            .position(|eo| eo.gid == *selected_enum_gid)
+                        {
+                            self.custom_field_dropdown_index
+                                .insert(cf.gid.clone(), index);
+                        }
+                    }
+                }
+            }
+        }
+        // Initialize assignee and section dropdown indices
+        self.init_assignee_dropdown_index();
+        self.init_section_dropdown_index();
+        self.edit_form_state = Some(EditFormState::Name);
+        self
+    }
+
+    /// Toggle star status of the currently selected project (from Projects list).
+    ///
+    #[allow(dead_code)] // Used via hotkey system
+    pub fn toggle_star_current_project(&mut self) -> &mut Self {
+        if let Some(Project { gid, name, .. }) = self.get_selected_top_list_project() {
+            if self.starred_projects.contains(&gid) {
+                self.starred_projects.remove(&gid);
+                self.starred_project_names.remove(&gid);
+                self.starred_project_workspaces.remove(&gid);
+            } else {
+                self.starred_projects.insert(gid.clone());
+                if let Some(workspace_gid) = &self.active_workspace_gid {
+                    self.starred_project_workspaces
+                        .insert(gid.clone(), workspace_gid.clone());
+                }
+                self.starred_project_names.insert(gid, name);
+            }
+            // Update shortcuts list state when starring/unstarring
+            self.update_shortcuts_list_state();
+            // Trigger config save
+            if let Some(sender) = &self.config_save_sender {
+                let _ = sender.send(());
+            }
+        }
+        self
+    }
+
+    /// Unstar the currently selected shortcut (only works for starred projects, not base shortcuts).
+    ///
+    #[allow(dead_code)] // Used via hotkey system
+    pub fn unstar_current_shortcut(&mut self) -> &mut Self {
+        let all_shortcuts = self.get_all_shortcuts();
+        let selected_index = self.shortcuts_list_state.selected();
+
+        if let Some(index) = selected_index {
+            if index < all_shortcuts.len() {
+                // Only starred projects can be unstarred
+                if let Shortcut::Project { gid, .. } = &all_shortcuts[index] {
+                    self.starred_projects.remove(gid);
+                    self.starred_project_names.remove(gid);
+                    self.starred_project_workspaces.remove(gid);
+                    // Update shortcuts list state
+                    self.update_shortcuts_list_state();
+                    // Trigger config save
+                    if let Some(sender) = &self.config_save_sender {
+                        let _ = sender.send(());
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Check if a project is starred.
+    ///
+    pub fn is_project_starred(&self, project_gid: &str) -> bool {
+        self.starred_projects.contains(project_gid)
+    }
+
+    /// Get all starred projects.
+    ///
+    #[allow(dead_code)]
+    pub fn get_starred_projects(&self) -> Vec<&Project> {
+        self.projects
+            .iter()
+            .filter(|p| self.starred_projects.contains(&p.gid))
+            .collect()
+    }
+
+    /// Return the project selected in the projects list.
+    ///
+    fn get_selected_top_list_project(&self) -> Option<Project> {
+        if self.team_browser {
+            let index = self.team_list_state.selected()?;
+            return match self.get_team_list_rows().into_iter().nth(index) {
+                Some(TeamListRow::Project(project)) => Some(project),
+                _ => None,
+            };
+        }
+        let index = self.projects_list_state.selected()?;
+        self.get_filtered_projects().get(index).cloned()
+    }
+
+    /// Return the teams of the current user.
+    ///
+    pub fn get_teams(&self) -> &[Team] {
+        &self.teams
+    }
+
+    /// Set the teams of the current user.
+    ///
+    pub fn set_teams(&mut self, teams: Vec<Team>) -> &mut Self {
+        self.teams = teams;
+        self.clamp_team_list_selection();
+        self
+    }
+
+    /// Check whether the projects list is grouped by the user's teams.
+    ///
+    pub fn is_team_browser(&self) -> bool {
+        self.team_browser
+    }
+
+    /// Switch the projects list between all projects of the workspace and
+    /// the projects of the current user's teams.
+    ///
+    pub fn toggle_team_browser(&mut self) -> &mut Self {
+        self.team_browser = !self.team_browser;
+        if self.team_browser && self.teams.is_empty() {
+            if let Some(workspace_gid) = self.active_workspace_gid.clone() {
+                self.dispatch(NetworkEvent::GetTeams { workspace_gid });
+            }
+        }
+        // The flat list isn't loaded while teams are shown
+        if !self.team_browser && self.projects.is_empty() {
+            self.dispatch(NetworkEvent::GetProjects);
+        }
+        self.clamp_team_list_selection();
+        self
+    }
+
+    /// Return the rows of the team browser: each team, followed by its
+    /// projects when expanded. Projects are filtered by the projects search.
+    ///
+    pub fn get_team_list_rows(&self) -> Vec<TeamListRow> {
+        let query = if matches!(self.search_target, Some(SearchTarget::Projects)) {
+            self.search_query.to_lowercase()
+        } else {
+            String::new()
+        };
+        let mut rows = vec![];
+        for team in &self.teams {
+            let expanded = self.expanded_teams.contains(&team.gid);
+            rows.push(TeamListRow::Team(team.clone(), expanded));
+            if !expanded {
+                continue;
+            }
+            match self.team_projects.get(&team.gid) {
+                Some(projects) => rows.extend(
+                    projects
+                        .iter()
+                        .filter(|p| p.name.to_lowercase().contains(&query))
+                        .cloned()
+                        .map(TeamListRow::Project),
+                ),
+                None => rows.push(TeamListRow::Loading),
+            }
+        }
+        rows
+    }
+
+    /// Return mutable team browser list state for rendering.
+    ///
+    pub fn get_team_list_state(&mut self) -> &mut ListState {
+        &mut self.team_list_state
+    }
+
+    /// Reload the projects of the expanded teams, and forget those of
+    /// collapsed teams so they load again when next expanded.
+    ///
+    pub fn refresh_team_projects(&mut self) -> &mut Self {
+        let expanded_teams = &self.expanded_teams;
+        self.team_projects
+            .retain(|team_gid, _| expanded_teams.contains(team_gid));
+        let mut team_gids: Vec<String> = self.expanded_teams.iter().cloned().collect();
+        team_gids.sort();
+        for team_gid in team_gids {
+            self.dispatch(NetworkEvent::GetTeamProjects { team_gid });
+        }
+        self
+    }
+
+    /// Set the loaded projects of a team.
+    ///
+    pub fn set_team_projects(&mut self, team_gid: String, projects: Vec<Project>) -> &mut Self {
+        self.team_projects.insert(team_gid, projects);
+        self.clamp_team_list_selection();
+        self
+    }
+
+    /// Expand or collapse the selected team, loading its projects the first
+    /// time, or open the selected project.
+    ///
+    fn select_team_list_row(&mut self) -> &mut Self {
+        let index = match self.team_list_state.selected() {
+            Some(index) => index,
+            None => return self,
+        };
+        match self.get_team_list_rows().into_iter().nth(index) {
+            Some(TeamListRow::Team(team, true)) => {
+                self.expanded_teams.remove(&team.gid);
+            }
+            Some(TeamListRow::Team(team, false)) => {
+                if !self.team_projects.contains_key(&team.gid) {
+                    self.dispatch(NetworkEvent::GetTeamProjects {
+                        team_gid: team.gid.clone(),
+                    });
+                }
+                self.expanded_teams.insert(team.gid);
+            }
+            Some(TeamListRow::Project(project)) => {
+                self.view_stack.clear();
+                self.open_project(project);
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Keep the team browser selection within its rows.
+    ///
+    fn clamp_team_list_selection(&mut self) {
+        let len = self.get_team_list_rows().len();
+        match self.team_list_state.selected() {
+            _ if len == 0 => self.team_list_state.select(None),
+            None => self.team_list_state.select(Some(0)),
+            Some(index) if index >= len => self.team_list_state.select(Some(len - 1)),
+            _ => {}
+        }
+    }
+
+    /// Set the portfolios of the current user, reloading the projects of
+    /// those still expanded.
+    ///
+    pub fn set_portfolios(&mut self, portfolios: Vec<Portfolio>) -> &mut Self {
+        self.portfolios = portfolios;
+        self.portfolio_projects.clear();
+        self.portfolio_errors.clear();
+        let portfolio_gids: Vec<String> = self
+            .portfolios
+            .iter()
+            .map(|p| p.gid.clone())
+            .filter(|gid| self.expanded_portfolios.contains(gid))
+            .collect();
+        self.expanded_portfolios = portfolio_gids.iter().cloned().collect();
+        for portfolio_gid in portfolio_gids {
+            self.dispatch(NetworkEvent::GetPortfolioProjects { portfolio_gid });
+        }
+        self.clamp_portfolios_selection();
+        self
+    }
+
+    /// Set the projects of a portfolio.
+    ///
+    pub fn set_portfolio_projects(
+        &mut self,
+        portfolio_gid: String,
+        projects: Vec<PortfolioProject>,
+    ) -> &mut Self {
+        self.portfolio_errors.remove(&portfolio_gid);
+        self.portfolio_projects.insert(portfolio_gid, projects);
+        self.clamp_portfolios_selection();
+        self
+    }
+
+    /// Record why the projects of a portfolio failed to load. Selecting the
+    /// portfolio's error row loads them again.
+    ///
+    pub fn set_portfolio_projects_error(
+        &mut self,
+        portfolio_gid: String,
+        error: String,
+    ) -> &mut Self {
+        self.portfolio_projects.remove(&portfolio_gid);
+        self.portfolio_errors.insert(portfolio_gid, error);
+        self.clamp_portfolios_selection();
+        self
+    }
+
+    /// Record a project's latest status update wherever the project shows.
+    ///
+    pub fn set_project_status_update(
+        &mut self,
+        project_gid: &str,
+        status_update: StatusUpdate,
+    ) -> &mut Self {
+        for project in self
+            .portfolio_projects
+            .values_mut()
+            .flatten()
+            .filter(|p| p.project.gid == project_gid)
+        {
+            project.status_update = Some(status_update.clone());
+        }
+        self
+    }
+
+    /// Return the rows of the portfolios view: each portfolio followed by
+    /// its projects when expanded.
+    ///
+    pub fn get_portfolio_rows(&self) -> Vec<PortfolioRow> {
+        let mut rows = vec![];
+        for portfolio in &self.portfolios {
+            let expanded = self.expanded_portfolios.contains(&portfolio.gid);
+            rows.push(PortfolioRow::Portfolio(portfolio.clone(), expanded));
+            if !expanded {
+                continue;
+            }
+            match (
+                self.portfolio_projects.get(&portfolio.gid),
+                self.portfolio_errors.get(&portfolio.gid),
+            ) {
+                (Some(projects), _) => rows.extend(
+                    projects
+                        .iter()
+                        .map(|p| PortfolioRow::Project(Box::new(p.clone()))),
+                ),
+                (None, Some(error)) => rows.push(PortfolioRow::Failed(error.clone())),
+                (None, None) => rows.push(PortfolioRow::Loading),
+            }
+        }
+        rows
+    }
+
+    /// Return mutable portfolios list state for rendering.
+    ///
+    pub fn get_portfolios_list_state(&mut self) -> &mut ListState {
+        &mut self.portfolios_list_state
+    }
+
+    /// Select the next row of the portfolios view.
+    ///
+    pub fn next_portfolio_row(&mut self) -> &mut Self {
+        let len = self.get_portfolio_rows().len();
+        if len > 0 {
+            let current = self.portfolios_list_state.selected().unwrap_or(0);
+            self.portfolios_list_state.select(Some((current + 1) % len));
+        }
+        self
+    }
+
+    /// Select the previous row of the portfolios view.
+    ///
+    pub fn previous_portfolio_row(&mut self) -> &mut Self {
+        let len = self.get_portfolio_rows().len();
+        if len > 0 {
+            let current = self.portfolios_list_state.selected().unwrap_or(0);
+            self.portfolios_list_state.select(Some(if current > 0 {
+                current - 1
+            } else {
+                len - 1
+            }));
+        }
+        self
+    }
+
+    /// Return the project selected in the portfolios view.
+    ///
+    pub fn get_selected_portfolio_project(&self) -> Option<PortfolioProject> {
+        let index = self.portfolios_list_state.selected()?;
+        match self.get_portfolio_rows().into_iter().nth(index) {
+            Some(PortfolioRow::Project(project)) => Some(*project),
+            _ => None,
+        }
+    }
+
+    /// Expand or collapse the selected portfolio, loading its projects the
+    /// first time, retry projects that failed to load, or open the tasks of
+    /// the selected project.
+    ///
+    pub fn select_portfolio_row(&mut self) -> &mut Self {
+        let index = match self.portfolios_list_state.selected() {
+            Some(index) => index,
+            None => return self,
+        };
+        let rows = self.get_portfolio_rows();
+        // Failed rows belong to the closest portfolio above them
+        let portfolio_gid = rows[..=index.min(rows.len().saturating_sub(1))]
+            .iter()
+            .rev()
+            .find_map(|row| match row {
+                PortfolioRow::Portfolio(portfolio, _) => Some(portfolio.gid.clone()),
+                _ => None,
+            });
+        match (rows.into_iter().nth(index), portfolio_gid) {
+            (Some(PortfolioRow::Portfolio(portfolio, true)), _) => {
+                self.expanded_portfolios.remove(&portfolio.gid);
+                self.clamp_portfolios_selection();
+            }
+            (Some(PortfolioRow::Portfolio(portfolio, false)), _) => {
+                if !self.portfolio_projects.contains_key(&portfolio.gid) {
+                    self.portfolio_errors.remove(&portfolio.gid);
+                    self.dispatch(NetworkEvent::GetPortfolioProjects {
+                        portfolio_gid: portfolio.gid.clone(),
+                    });
+                }
+                self.expanded_portfolios.insert(portfolio.gid);
+            }
+            (Some(PortfolioRow::Failed(_)), Some(portfolio_gid)) => {
+                self.portfolio_errors.remove(&portfolio_gid);
+                self.dispatch(NetworkEvent::GetPortfolioProjects { portfolio_gid });
+            }
+            (Some(PortfolioRow::Project(project)), _) => {
+                self.open_project(project.project);
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Keep the portfolios view selection within its rows.
+    ///
+    fn clamp_portfolios_selection(&mut self) {
+        let len = self.get_portfolio_rows().len();
+        match self.portfolios_list_state.selected() {
+            _ if len == 0 => self.portfolios_list_state.select(None),
+            None => self.portfolios_list_state.select(Some(0)),
+            Some(index) if index >= len => self.portfolios_list_state.select(Some(len - 1)),
+            _ => {}
+        }
+    }
+
+    /// Open the status update form for the project selected in the
+    /// portfolios view, starting from its current status. The title and text
+    /// are written in the task form's name and notes fields.
+    ///
+    pub fn open_status_update_form(&mut self) -> &mut Self {
+        if let Some(selected) = self.get_selected_portfolio_project() {
+            self.status_update_form = Some(StatusUpdateForm {
+                project_gid: selected.project.gid,
+                project_name: selected.project.name,
+                status_type: selected
+                    .status_update
+                    .map(|s| s.status_type)
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or_else(|| "on_track".to_string()),
+                field: StatusUpdateField::Status,
+            });
+            self.clear_form();
+            self.exit_field_editing_mode();
+            self.push_view(View::CreateStatusUpdate);
+        }
+        self
+    }
+
+    /// Check if the status update form is open.
+    ///
+    #[allow(dead_code)]
+    pub fn has_status_update_form(&self) -> bool {
+        self.status_update_form.is_some()
+    }
+
+    /// Return the open status update form.
+    ///
+    pub fn get_status_update_form(&self) -> Option<&StatusUpdateForm> {
+        self.status_update_form.as_ref()
+    }
+
+    /// Close the status update form without posting.
+    ///
+    pub fn close_status_update_form(&mut self) -> &mut Self {
+        if self.status_update_form.take().is_some() {
+            self.exit_field_editing_mode();
+            self.clear_form();
+            if matches!(self.current_view(), View::CreateStatusUpdate) {
+                self.pop_view();
+            }
+        }
+        self
+    }
+
+    /// Move to the next status update form field.
+    ///
+    pub fn next_status_update_field(&mut self) -> &mut Self {
+        if let Some(form) = &mut self.status_update_form {
+            form.next_field();
+        }
+        self
+    }
+
+    /// Move to the previous status update form field.
+    ///
+    pub fn previous_status_update_field(&mut self) -> &mut Self {
+        if let Some(form) = &mut self.status_update_form {
+            form.previous_field();
+        }
+        self
+    }
+
+    /// Step the status of the status update form forward or back.
+    ///
+    pub fn cycle_status_update_status(&mut self, forward: bool) -> &mut Self {
+        if let Some(form) = &mut self.status_update_form {
+            if form.field == StatusUpdateField::Status {
+                form.cycle_status(forward);
+            }
+        }
+        self
+    }
+
+    /// Start editing the focused status update field. The status is changed
+    /// in place, so only the title and text can be edited.
+    ///
+    pub fn edit_status_update_field(&mut self) -> &mut Self {
+        if let Some(form) = &self.status_update_form {
+            if form.field != StatusUpdateField::Status {
+                self.enter_field_editing_mode();
+            }
+        }
+        self
+    }
+
+    /// Post the status update. The form stays open while the text is empty,
+    /// since Asana requires it.
+    ///
+    pub fn submit_status_update_form(&mut self) -> &mut Self {
+        let form = match self.status_update_form.clone() {
+            Some(form) => form,
+            None => return self,
+        };
+        let text = self.get_form_notes().trim().to_string();
+        if text.is_empty() {
+            warn!("Status update text is required.");
+            return self;
+        }
+        self.dispatch(NetworkEvent::CreateStatusUpdate {
+            project_gid: form.project_gid,
+            status_type: form.status_type,
+            title: self.form_name.trim().to_string(),
+            text,
+        });
+        self.close_status_update_form();
+        self
+    }
+
+    /// Open the workspace-wide task search with its filters form, loading
+    /// the workspace tags the form matches against if needed.
+    ///
+    pub fn open_task_search(&mut self) -> &mut Self {
+        if !matches!(self.current_view(), View::TaskSearch) {
+            self.push_view(View::TaskSearch);
+        }
+        self.focus_view();
+        self.task_search_form.error = None;
+        self.task_search_form_open = true;
+        self.request_workspace_tags()
+    }
+
+    /// Check whether the task search filters form is open.
+    ///
+    pub fn has_task_search_form(&self) -> bool {
+        self.task_search_form_open
+    }
+
+    /// Return the task search filters form.
+    ///
+    pub fn get_task_search_form(&self) -> &TaskSearchForm {
+        &self.task_search_form
+    }
+
+    /// Close the task search filters form, leaving the search view if no
+    /// search was run yet.
+    ///
+    pub fn close_task_search_form(&mut self) -> &mut Self {
+        self.task_search_form_open = false;
+        if self.task_search.is_none() && matches!(self.current_view(), View::TaskSearch) {
+            self.pop_view();
+            if matches!(self.current_view(), View::Welcome) {
+                self.focus_menu();
+            }
+        }
+        self
+    }
+
+    /// Move to the next task search field.
+    ///
+    pub fn next_task_search_field(&mut self) -> &mut Self {
+        self.task_search_form.next_field();
+        self.load_search_project_fields();
+        self
+    }
+
+    /// Move to the previous task search field.
+    ///
+    pub fn previous_task_search_field(&mut self) -> &mut Self {
+        self.task_search_form.previous_field();
+        self.load_search_project_fields();
+        self
+    }
+
+    /// Step through the completion filter when it is the current field.
+    ///
+    pub fn cycle_task_search_completed(&mut self, forward: bool) -> &mut Self {
+        if self.task_search_form.field == TaskSearchField::Completion {
+            self.task_search_form.cycle_completed(forward);
+        }
+        self
+    }
+
+    /// Type a character into the current task search field.
+    ///
+    pub fn add_task_search_char(&mut self, c: char) -> &mut Self {
+        if let Some(text) = self.task_search_form.current_text_mut() {
+            text.push(c);
+        }
+        self.request_task_search_typeahead();
+        self
+    }
+
+    /// Remove the last character of the current task search field.
+    ///
+    pub fn remove_task_search_char(&mut self) -> &mut Self {
+        if let Some(text) = self.task_search_form.current_text_mut() {
+            text.pop();
+        }
+        self.request_task_search_typeahead();
+        self
+    }
+
+    /// Look up users, projects or tags matching the typed task search field.
+    ///
+    fn request_task_search_typeahead(&mut self) {
+        let form = &self.task_search_form;
+        let (kind, query) = match form.field {
+            TaskSearchField::Assignee => (TypeaheadKind::User, form.assignee.clone()),
+            TaskSearchField::Project => (TypeaheadKind::Project, form.project.clone()),
+            TaskSearchField::Tag => (TypeaheadKind::Tag, form.tag.clone()),
+            _ => return,
+        };
+        if !query.trim().is_empty() && !query.trim().eq_ignore_ascii_case("me") {
+            self.request_typeahead(kind, &query);
+        }
+    }
+
+    /// Load the sections and custom fields of the chosen search project
+    /// once the section or custom field filter is reached.
+    ///
+    fn load_search_project_fields(&mut self) {
+        let needs_fields = matches!(
+            self.task_search_form.field,
+            TaskSearchField::Section
+                | TaskSearchField::CustomField
+                | TaskSearchField::CustomFieldValue
+        );
+        if !needs_fields {
+            return;
+        }
+        let project_gid = match self.search_project() {
+            Some(project) => project.gid.clone(),
+            None => return,
+        };
+        if !self.search_project_fields.contains_key(&project_gid) {
+            self.dispatch(NetworkEvent::GetSearchProjectFields { project_gid });
+        }
+    }
+
+    /// Store the sections and custom fields of a project the task search
+    /// can filter by.
+    ///
+    pub fn set_search_project_fields(
+        &mut self,
+        project_gid: String,
+        sections: Vec<Section>,
+        custom_fields: Vec<CustomField>,
+    ) -> &mut Self {
+        self.search_project_fields
+            .insert(project_gid, (sections, custom_fields));
+        self
+    }
+
+    /// Return the item whose name matches the typed query, preferring an
+    /// exact match over the first partial one.
+    ///
+    fn find_by_name<'a, T>(items: &'a [T], query: &str, name: fn(&T) -> &str) -> Option<&'a T> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+        items
+            .iter()
+            .find(|item| name(item).to_lowercase() == query)
+            .or_else(|| {
+                items
+                    .iter()
+                    .find(|item| name(item).to_lowercase().contains(&query))
+            })
+    }
+
+    /// Return the user matching the typed assignee, where "me" is the
+    /// current user.
+    ///
+    fn search_assignee(&self) -> Option<&User> {
+        if self
+            .task_search_form
+            .assignee
+            .trim()
+            .eq_ignore_ascii_case("me")
+        {
+            return self.user.as_ref();
+        }
+        Self::find_by_name(
+            &self.workspace_users,
+            &self.task_search_form.assignee,
+            |u| &u.name,
+        )
+    }
+
+    /// Return the project matching the typed search project.
+    ///
+    fn search_project(&self) -> Option<&Project> {
+        Self::find_by_name(&self.projects, &self.task_search_form.project, |p| &p.name).or_else(
+            || {
+                Self::find_by_name(
+                    &self.typeahead_projects,
+                    &self.task_search_form.project,
+                    |p| &p.name,
+                )
+            },
+        )
+    }
+
+    /// Return the custom field matching the typed search custom field.
+    ///
+    fn search_custom_field(&self) -> Option<&CustomField> {
+        let (_, custom_fields) = self
+            .search_project()
+            .and_then(|p| self.search_project_fields.get(&p.gid))?;
+        Self::find_by_name(custom_fields, &self.task_search_form.custom_field, |f| {
+            &f.name
+        })
+    }
+
+    /// Return the name of the option a typed task search field matches,
+    /// for showing next to what was typed.
+    ///
+    pub fn get_task_search_match(&self, field: TaskSearchField) -> Option<String> {
+        match field {
+            TaskSearchField::Assignee => self.search_assignee().map(|u| u.name.clone()),
+            TaskSearchField::Project => self.search_project().map(|p| p.name.clone()),
+            TaskSearchField::Section => {
+                let (sections, _) = self
+                    .search_project()
+                    .and_then(|p| self.search_project_fields.get(&p.gid))?;
+                Self::find_by_name(sections, &self.task_search_form.section, |s| &s.name)
+                    .map(|s| s.name.clone())
+            }
+            TaskSearchField::Tag => {
+                Self::find_by_name(&self.workspace_tags, &self.task_search_form.tag, |t| {
+                    &t.name
+                })
+                .map(|t| t.name.clone())
+            }
+            TaskSearchField::CustomField => self.search_custom_field().map(|f| f.name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Turn the typed task search filters into a search, or explain which
+    /// filter could not be understood.
+    ///
+    fn build_task_search(&self) -> Result<TaskSearch, String> {
+        let form = &self.task_search_form;
+        let typed = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+
+        let mut search = TaskSearch {
+            text: typed(&form.text),
+            completed: form.completed,
+            ..TaskSearch::default()
+        };
+        if let Some(assignee) = typed(&form.assignee) {
+            let user = self
+                .search_assignee()
+                .ok_or_else(|| format!("No user matches '{}'", assignee))?;
+            search.assignee_gid = Some(user.gid.clone());
+        }
+        if let Some(project) = typed(&form.project) {
+            let project = self
+                .search_project()
+                .ok_or_else(|| format!("No project matches '{}'", project))?;
+            search.project_gid = Some(project.gid.clone());
+        }
+        let project_fields = search
+            .project_gid
+            .as_ref()
+            .and_then(|gid| self.search_project_fields.get(gid));
+        if let Some(section) = typed(&form.section) {
+            let (sections, _) = match (&search.project_gid, project_fields) {
+                (None, _) => return Err("Choose a project to filter by section".to_string()),
+                (Some(_), None) => return Err("Sections are still loading".to_string()),
+                (Some(_), Some(fields)) => fields,
+            };
+            let section = Self::find_by_name(sections, &section, |s| &s.name)
+                .ok_or_else(|| format!("No section matches '{}'", section))?;
+            search.section_gid = Some(section.gid.clone());
+        }
+        if let Some(tag) = typed(&form.tag) {
+            let tag = Self::find_by_name(&self.workspace_tags, &tag, |t| &t.name)
+                .ok_or_else(|| format!("No tag matches '{}'", tag))?;
+            search.tag_gid = Some(tag.gid.clone());
+        }
+        for (label, value, target) in [
+            ("Due after", &form.due_after, &mut search.due_after),
+            ("Due before", &form.due_before, &mut search.due_before),
+        ] {
+            if let Some(date) = typed(value) {
+                let date = dates::parse_date(&date).map_err(|e| format!("{}: {}", label, e))?;
+                *target = Some(date.format(dates::ISO_DATE).to_string());
+            }
+        }
+        if let (Some(after), Some(before)) = (&search.due_after, &search.due_before) {
+            if after > before {
+                return Err("Due after must not be later than due before".to_string());
+            }
+        }
+        if let Some(custom_field) = typed(&form.custom_field) {
+            if search.project_gid.is_none() {
+                return Err("Choose a project to filter by custom field".to_string());
+            }
+            let field = self
+                .search_custom_field()
+                .ok_or_else(|| format!("No custom field matches '{}'", custom_field))?;
+            search.custom_field = Some(custom_field_filter(field, &form.custom_field_value)?);
+        }
+        Ok(search)
+    }
+
+    /// Run the search described by the filters form, replacing any
+    /// previous results.
+    ///
+    pub fn submit_task_search_form(&mut self) -> &mut Self {
+        let search = match self.build_task_search() {
+            Ok(search) => search,
+            Err(message) => {
+                self.task_search_form.error = Some(message);
+                return self;
+            }
+        };
+        self.task_search_form.error = None;
+        self.task_search_form_open = false;
+        self.task_search = Some(search.clone());
+        self.task_search_results.clear();
+        self.task_search_next = None;
+        self.task_search_loading = true;
+        self.task_search_list_state.select(None);
+        self.dispatch(NetworkEvent::SearchTasks {
+            search,
+            created_before: None,
+        });
+        self
+    }
+
+    /// Add a page of results for a search, ignoring results of a search
+    /// that has since been replaced. Pages overlap at the creation time they
+    /// were fetched before, so tasks already listed are dropped.
+    ///
+    pub fn add_task_search_results(
+        &mut self,
+        search: &TaskSearch,
+        tasks: Vec<Task>,
+        next: Option<String>,
+    ) -> &mut Self {
+        if self.task_search.as_ref() != Some(search) {
+            return self;
+        }
+        let listed = self.task_search_results.len();
+        for task in tasks {
+            if !self.task_search_results.iter().any(|t| t.gid == task.gid) {
+                self.task_search_results.push(task);
+            }
+        }
+        // A page of only repeats can't move the creation time bound, so stop
+        self.task_search_next = if self.task_search_results.len() > listed {
+            next
+        } else {
+            None
+        };
+        self.task_search_loading = false;
+        if self.task_search_list_state.selected().is_none() && !self.task_search_results.is_empty()
+        {
+            self.task_search_list_state.select(Some(0));
+        }
+        self
+    }
+
+    /// Stop waiting for search results after a failed request.
+    ///
+    pub fn fail_task_search(&mut self) -> &mut Self {
+        self.task_search_loading = false;
+        self
+    }
+
+    /// Return the tasks found by the current search.
+    ///
+    pub fn get_task_search_results(&self) -> &[Task] {
+        &self.task_search_results
+    }
+
+    /// Return mutable task search results list state for rendering.
+    ///
+    pub fn get_task_search_list_state(&mut self) -> &mut ListState {
+        &mut self.task_search_list_state
+    }
+
+    /// Check whether a page of search results is being fetched.
+    ///
+    pub fn is_task_search_loading(&self) -> bool {
+        self.task_search_loading
+    }
+
+    /// Check whether the current search may have more results.
+    ///
+    pub fn has_more_task_search_results(&self) -> bool {
+        self.task_search_next.is_some()
+    }
+
+    /// Select the next search result, fetching the next page when the last
+    /// loaded result is reached.
+    ///
+    pub fn next_task_search_result(&mut self) -> &mut Self {
+        let len = self.task_search_results.len();
+        if len == 0 {
+            return self;
+        }
+        let next = self
+            .task_search_list_state
+            .selected()
+            .map_or(0, |i| (i + 1).min(len - 1));
+        self.task_search_list_state.select(Some(next));
+        if next == len - 1 && !self.task_search_loading {
+            if let (Some(search), Some(created_before)) =
+                (self.task_search.clone(), self.task_search_next.clone())
+            {
+                self.task_search_loading = true;
+                self.dispatch(NetworkEvent::SearchTasks {
+                    search,
+                    created_before: Some(created_before),
+                });
+            }
+        }
+        self
+    }
+
+    /// Select the previous search result.
+    ///
+    pub fn previous_task_search_result(&mut self) -> &mut Self {
+        if let Some(index) = self.task_search_list_state.selected() {
+            self.task_search_list_state
+                .select(Some(index.saturating_sub(1)));
+        }
+        self
+    }
+
+    /// Open the selected search result in the task detail view.
+    ///
+    pub fn open_selected_task_search_result(&mut self) -> &mut Self {
+        let gid = match self
+            .task_search_list_state
+            .selected()
+            .and_then(|i| self.task_search_results.get(i))
+        {
+            Some(task) => task.gid.clone(),
+            None => return self,
+        };
+        self.clear_task_detail();
+        self.current_task_panel = TaskDetailPanel::Details;
+        self.dispatch(NetworkEvent::GetTaskDetail { gid });
+        self.push_view(View::TaskDetail);
+        self
+    }
+
+    /// Check whether archived projects are listed.
+    ///
+    pub fn shows_archived_projects(&self) -> bool {
+        self.show_archived_projects
+    }
+
+    /// Show or hide archived projects and reload the projects list.
+    ///
+    pub fn toggle_show_archived_projects(&mut self) -> &mut Self {
+        self.show_archived_projects = !self.show_archived_projects;
+        self.dispatch(NetworkEvent::GetProjects);
+        self
+    }
+
+    /// Archive the selected project, or unarchive it if it is archived.
+    /// Newly archived projects leave the list unless archived projects are
+    /// shown.
+    ///
+    pub fn toggle_current_project_archived(&mut self) -> &mut Self {
+        let project = match self.get_selected_top_list_project() {
+            Some(project) => project,
+            None => return self,
+        };
+        let archived = !project.archived;
+        self.dispatch(NetworkEvent::SetProjectArchived {
+            gid: project.gid.clone(),
+            archived,
+        });
+        let lists = [&mut self.projects, &mut self.filtered_projects]
+            .into_iter()
+            .chain(self.team_projects.values_mut());
+        for list in lists {
+            if archived && !self.show_archived_projects {
+                list.retain(|p| p.gid != project.gid);
+            } else if let Some(p) = list.iter_mut().find(|p| p.gid == project.gid) {
+                p.archived = archived;
+            }
+        }
+        let len = self.get_filtered_projects().len();
+        match self.projects_list_state.selected() {
+            _ if len == 0 => self.projects_list_state.select(None),
+            Some(index) if index >= len => self.projects_list_state.select(Some(len - 1)),
+            _ => {}
+        }
+        self.clamp_team_list_selection();
+        self
+    }
+
+    /// Open the project form to create a project in the active workspace.
+    ///
+    pub fn open_create_project_form(&mut self) -> &mut Self {
+        let workspace_gid = match self.active_workspace_gid.clone() {
+            Some(workspace_gid) => workspace_gid,
+            None => return self,
+        };
+        self.dispatch(NetworkEvent::GetTeams { workspace_gid });
+        self.project_form = Some(ProjectForm {
+            gid: None,
+            name: String::new(),
+            notes: String::new(),
+            color: None,
+            team_gid: None,
+            field: ProjectFormField::Name,
+        });
+        self
+    }
+
+    /// Open the project form to edit the selected project.
+    ///
+    pub fn open_edit_project_form(&mut self) -> &mut Self {
+        if let Some(project) = self.get_selected_top_list_project() {
+            self.project_form = Some(ProjectForm {
+                gid: Some(project.gid),
+                name: project.name,
+                notes: project.notes,
+                color: Some(project.color).filter(|c| !c.is_empty() && c != "none"),
+                team_gid: None,
+                field: ProjectFormField::Name,
+            });
+        }
+        self
+    }
+
+    /// Check if the project form is open.
+    ///
+    pub fn has_project_form(&self) -> bool {
+        self.project_form.is_some()
+    }
+
+    /// Return the open project form.
+    ///
+    pub fn get_project_form(&self) -> Option<&ProjectForm> {
+        self.project_form.as_ref()
+    }
+
+    /// Close the project form without saving.
+    ///
+    pub fn close_project_form(&mut self) -> &mut Self {
+        self.project_form = None;
+        self
+    }
+
+    /// Move to the next project form field.
+    ///
+    pub fn next_project_form_field(&mut self) -> &mut Self {
+        if let Some(form) = &mut self.project_form {
+            form.next_field();
+        }
+        self
+    }
+
+    /// Move to the previous project form field.
+    ///
+    pub fn previous_project_form_field(&mut self) -> &mut Self {
+        if let Some(form) = &mut self.project_form {
+            form.previous_field();
+        }
+        self
+    }
+
+    /// Add a character to the focused project form text field.
+    ///
+    pub fn add_project_form_char(&mut self, c: char) -> &mut Self {
+        if let Some(form) = &mut self.project_form {
+            match form.field {
+                ProjectFormField::Name => form.name.push(c),
+                ProjectFormField::Notes => form.notes.push(c),
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Remove the last character from the focused project form text field.
+    ///
+    pub fn remove_project_form_char(&mut self) -> &mut Self {
+        if let Some(form) = &mut self.project_form {
+            match form.field {
+                ProjectFormField::Name => {
+                    form.name.pop();
+                }
+                ProjectFormField::Notes => {
+                    form.notes.pop();
+                }
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Step the focused project form option (color or team) forward or back.
+    ///
+    pub fn cycle_project_form_option(&mut self, forward: bool) -> &mut Self {
+        let team_gids: Vec<Option<String>> = std::iter::once(None)
+            .chain(self.teams.iter().map(|t| Some(t.gid.clone())))
+            .collect();
+        if let Some(form) = &mut self.project_form {
+            match form.field {
+                ProjectFormField::Color => form.cycle_color(forward),
+                ProjectFormField::Team => {
+                    let index = team_gids
+                        .iter()
+                        .position(|gid| *gid == form.team_gid)
+                        .unwrap_or(0);
+                    let next = if forward {
+                        (index + 1) % team_gids.len()
+                    } else {
+                        (index + team_gids.len() - 1) % team_gids.len()
+                    };
+                    form.team_gid = team_gids[next].clone();
+                }
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Save the project form, creating or updating the project. The form
+    /// stays open while the name is empty.
+    ///
+    pub fn submit_project_form(&mut self) -> &mut Self {
+        let form = match self.project_form.clone() {
+            Some(form) => form,
+            None => return self,
+        };
+        let name = form.name.trim().to_string();
+        if name.is_empty() {
+            warn!("Project name is required.");
+            return self;
+        }
+        match form.gid {
+            Some(gid) => self.dispatch(NetworkEvent::UpdateProject {
+                gid,
+                name,
+                notes: form.notes,
+                color: form.color,
+            }),
+            None => {
+                let workspace_gid = match self.active_workspace_gid.clone() {
+                    Some(workspace_gid) => workspace_gid,
+                    None => return self,
+                };
+                self.dispatch(NetworkEvent::CreateProject {
+                    workspace_gid,
+                    team_gid: form.team_gid,
+                    name,
+                    notes: form.notes,
+                    color: form.color,
+                });
+            }
+        }
+        self.project_form = None;
+        self
+    }
+
+    /// Open the project picker for the task in detail view.
+    ///
+    pub fn open_project_picker(&mut self, action: ProjectPickerAction) -> &mut Self {
+        let task = match &self.current_task_detail {
+            Some(task) => task,
+            None => return self,
+        };
+        if action == ProjectPickerAction::Move && task.memberships.is_empty() {
+            warn!("Task is not in a project to move it out of.");
+            return self;
+        }
+        self.project_picker = Some(ProjectPicker {
+            action,
+            search: String::new(),
+            index: 0,
+            target: None,
+            sections: None,
+            sections_error: None,
+        });
+        self
+    }
+
+    /// Check if the project picker is open.
+    ///
+    pub fn has_project_picker(&self) -> bool {
+        self.project_picker.is_some()
+    }
+
+    /// Return the open project picker.
+    ///
+    pub fn get_project_picker(&self) -> Option<&ProjectPicker> {
+        self.project_picker.as_ref()
+    }
+
+    /// Close the project picker.
+    ///
+    pub fn close_project_picker(&mut self) -> &mut Self {
+        self.project_picker = None;
+        self
+    }
+
+    /// Return the GIDs and names the project picker offers, filtered by its
+    /// search: the task's projects when removing, other projects when adding
+    /// or moving, then the sections of the chosen project.
+    ///
+    pub fn get_project_picker_items(&self) -> Vec<(String, String)> {
+        let (picker, task) = match (&self.project_picker, &self.current_task_detail) {
+            (Some(picker), Some(task)) => (picker, task),
+            _ => return vec![],
+        };
+        let items: Vec<(String, String)> = if picker.target.is_some() {
+            picker
+                .sections
+                .iter()
+                .flatten()
+                .map(|s| (s.gid.clone(), s.name.clone()))
+                .collect()
+        } else if picker.action == ProjectPickerAction::Remove {
+            task.memberships
+                .iter()
+                .map(|m| (m.project_gid.clone(), m.project_name.clone()))
+                .collect()
+        } else {
+            // Projects found through typeahead include ones outside the
+            // loaded project list
+            let mut projects = self.projects.clone();
+            for project in &self.typeahead_projects {
+                if !projects.iter().any(|p| p.gid == project.gid) {
+                    projects.push(project.clone());
+                }
+            }
+            return self
+                .rank_typeahead(
+                    TypeaheadKind::Project,
+                    &picker.search,
+                    &projects,
+                    |p| vec![&p.name],
+                    |p| &p.gid,
+                )
+                .into_iter()
+                .filter(|p| !task.memberships.iter().any(|m| m.project_gid == p.gid))
+                .map(|p| (p.gid, p.name))
+                .collect();
+        };
+        let search = picker.search.to_lowercase();
+        items
+            .into_iter()
+            .filter(|(_, name)| search.is_empty() || name.to_lowercase().contains(&search))
+            .collect()
+    }
+
+    /// Add a character to the project picker search.
+    ///
+    pub fn add_project_picker_char(&mut self, c: char) -> &mut Self {
+        if let Some(picker) = &mut self.project_picker {
+            picker.search.push(c);
+            picker.index = 0;
+        }
+        self.request_project_picker_typeahead();
+        self
+    }
+
+    /// Remove the last character from the project picker search.
+    ///
+    pub fn remove_project_picker_char(&mut self) -> &mut Self {
+        if let Some(picker) = &mut self.project_picker {
+            picker.search.pop();
+            picker.index = 0;
+        }
+        self.request_project_picker_typeahead();
+        self
+    }
+
+    /// Look up projects matching the project picker search while a project
+    /// to add to or move into is being chosen.
+    ///
+    fn request_project_picker_typeahead(&mut self) {
+        let search = match &self.project_picker {
+            Some(picker)
+                if picker.target.is_none() && picker.action != ProjectPickerAction::Remove =>
+            {
+                picker.search.clone()
+            }
+            _ => return,
+        };
+        if !search.trim().is_empty() {
+            self.request_typeahead(TypeaheadKind::Project, &search);
+        }
+    }
+
+    /// Select the next project picker item.
+    ///
+    pub fn next_project_picker_item(&mut self) -> &mut Self {
+        let len = self.get_project_picker_items().len();
+        if let Some(picker) = &mut self.project_picker {
+            if len > 0 {
+                picker.index = (picker.index + 1) % len;
+            }
+        }
+        self
+    }
+
+    /// Select the previous project picker item.
+    ///
+    pub fn previous_project_picker_item(&mut self) -> &mut Self {
+        let len = self.get_project_picker_items().len();
+        if let Some(picker) = &mut self.project_picker {
+            if len > 0 {
+                picker.index = (picker.index + len - 1) % len;
+            }
+        }
+        self
+    }
+
+    /// Set the sections offered for the project chosen in the picker.
+    ///
+    pub fn set_project_picker_sections(
+        &mut self,
+        project_gid: &str,
+        sections: Vec<Section>,
+    ) -> &mut Self {
+        if let Some(picker) = &mut self.project_picker {
+            if picker.target.as_ref().map(|(gid, _)| gid.as_str()) == Some(project_gid) {
+                picker.sections = Some(sections);
+            }
+        }
+        self
+    }
+
+    /// Record that the sections of the project picker's target failed to
+    /// load, leaving the task to be added without a section.
+    ///
+    pub fn set_project_picker_sections_error(
+        &mut self,
+        project_gid: &str,
+        error: String,
+    ) -> &mut Self {
+        if let Some(picker) = &mut self.project_picker {
+            if picker.target.as_ref().map(|(gid, _)| gid.as_str()) == Some(project_gid) {
+                picker.sections = Some(vec![]);
+                picker.sections_error = Some(error);
+            }
+        }
+        self
+    }
+
+    /// Use the selected project picker item. Removing happens right away;
+    /// adding and moving first ask for a section of the chosen project.
+    /// Moving leaves the open project, or the task's first project when it
+    /// is not in the open one.
+    ///
+    pub fn select_project_picker_item(&mut self) -> &mut Self {
+        let items = self.get_project_picker_items();
+        let (picker, task) = match (&self.project_picker, &self.current_task_detail) {
+            (Some(picker), Some(task)) => (picker.clone(), task),
+            _ => return self,
+        };
+        let selected = items.get(picker.index).cloned();
+        let task_gid = task.gid.clone();
+        let current_project = self
+            .project
+            .as_ref()
+            .filter(|p| task.memberships.iter().any(|m| m.project_gid == p.gid))
+            .map(|p| p.gid.clone())
+            .or_else(|| task.memberships.first().map(|m| m.project_gid.clone()));
+
+        match (picker.action, &picker.target) {
+            (ProjectPickerAction::Remove, _) => {
+                if let Some((project_gid, _)) = selected {
+                    self.dispatch(NetworkEvent::RemoveTaskFromProject {
+                        task_gid,
+                        project_gid,
+                    });
+                    self.project_picker = None;
+                }
+            }
+            (_, None) => {
+                if let Some((project_gid, name)) = selected {
+                    self.dispatch(NetworkEvent::GetProjectPickerSections {
+                        project_gid: project_gid.clone(),
+                    });
+                    self.project_picker = Some(ProjectPicker {
+                        search: String::new(),
+                        index: 0,
+                        target: Some((project_gid, name)),
+                        sections: None,
+                        sections_error: None,
+                        ..picker
+                    });
+                }
+            }
+            (action, Some((project_gid, _))) => {
+                // Wait for sections, but allow projects without any
+                let no_sections = picker.sections.as_ref().is_some_and(|s| s.is_empty());
+                if selected.is_some() || no_sections {
+                    self.dispatch(NetworkEvent::AddTaskToProject {
+                        task_gid,
+                        project_gid: project_gid.clone(),
+                        section_gid: selected.map(|(gid, _)| gid),
+                        remove_project_gid: if action == ProjectPickerAction::Move {
+                            current_project
+                        } else {
+                            None
+                        },
+                    });
+                    self.project_picker = None;
+                }
+            }
+        }
+        self
+    }
+
+    /// Open the follower picker for the task in detail view. It lists
+    /// workspace users with the same search as the assignee dropdown.
+    ///
+    pub fn open_follower_picker(&mut self) -> &mut Self {
+        if self.current_task_detail.is_some() {
+            self.follower_picker_open = true;
+            self.form_assignee_search.clear();
+            self.assignee_dropdown_index = 0;
+        }
+        self
+    }
+
+    /// Check if the follower picker is open.
+    ///
+    pub fn has_follower_picker(&self) -> bool {
+        self.follower_picker_open
+    }
+
+    /// Close the follower picker.
+    ///
+    pub fn close_follower_picker(&mut self) -> &mut Self {
+        self.follower_picker_open = false;
+        self
+    }
+
+    /// Check if a user follows the task in detail view.
+    ///
+    pub fn is_task_follower(&self, user_gid: &str) -> bool {
+        self.current_task_detail
+            .as_ref()
+            .is_some_and(|task| task.followers.iter().any(|u| u.gid == user_gid))
+    }
+
+    /// Add or remove the user selected in the follower picker.
+    ///
+    pub fn toggle_selected_follower(&mut self) -> &mut Self {
+        let users = self.get_filtered_assignees();
+        if let Some(user) = users.get(self.assignee_dropdown_index).cloned() {
+            self.toggle_task_follower(user);
+        }
+        self
+    }
+
+    /// Follow or unfollow the task in detail view as the current user.
+    ///
+    pub fn toggle_follow_current_task(&mut self) -> &mut Self {
+        if let Some(user) = self.user.clone() {
+            self.toggle_task_follower(user);
+        }
+        self
+    }
+
+    /// Add or remove a follower of the task in detail view, updating it
+    /// before the request completes.
+    ///
+    fn toggle_task_follower(&mut self, user: User) {
+        let following = self.is_task_follower(&user.gid);
+        let task = match &mut self.current_task_detail {
+            Some(task) => task,
+            None => return,
+        };
+        let task_gid = task.gid.clone();
+        let (add, remove) = if following {
+            task.followers.retain(|u| u.gid != user.gid);
+            (vec![], vec![user.gid])
+        } else {
+            let gid = user.gid.clone();
+            task.followers.push(user);
+            (vec![gid], vec![])
+        };
+        self.dispatch(NetworkEvent::SetTaskFollowers {
+            task_gid,
+            add,
+            remove,
+        });
+    }
+
+    /// Get all shortcuts (starred projects first, then static shortcuts).
+    ///
+    pub fn get_all_shortcuts(&self) -> Vec<Shortcut> {
+        // Get starred project names - prioritize stored names, then loaded projects
+        let mut starred: Vec<Shortcut> = Vec::new();
+
+        for gid in &self.starred_projects {
+            // Skip projects starred in other workspaces, keeping archived ones
+            // the projects list leaves out
+            let in_workspace = match self.starred_project_workspaces.get(gid) {
+                Some(workspace_gid) => self.active_workspace_gid.as_ref() == Some(workspace_gid),
+                // Stars saved before their workspace was recorded show once
+                // found in the projects list
+                None => self.projects.is_empty() || self.projects.iter().any(|p| &p.gid == gid),
+            };
+            if !in_workspace {
+                continue;
+            }
+            // Try to get name from stored names first (most reliable)
+            let name = match self.starred_project_names.get(gid) {
+                Some(name) => Some(name.clone()),
+                // Fallback: try to get from loaded projects
+                None => self
+                    .projects
+                    .iter()
+                    .find(|p| &p.gid == gid)
+                    .map(|p| p.name.to_owned()),
+            };
+            if let Some(name) = name {
+                starred.push(Shortcut::Project {
+                    gid: gid.clone(),
+                    name,
+                });
+            }
+        }
+
+        starred.sort_by(|a, b| a.name().cmp(b.name())); // Sort for consistent ordering
+
+        // Put starred projects first, then base shortcuts
+        let mut shortcuts = starred;
+        shortcuts.extend(base_shortcuts());
+        shortcuts
+    }
+
+    /// Get all shortcuts and update list state.
+    ///
+    pub fn get_all_shortcuts_with_update(&mut self) -> Vec<Shortcut> {
+        let shortcuts = self.get_all_shortcuts();
+        self.update_shortcuts_list_state();
+        shortcuts
+    }
+
+    /// Get starred project GIDs for saving to config.
+    ///
+    pub fn get_starred_project_gids(&self) -> Vec<String> {
+        self.starred_projects.iter().cloned().collect()
+    }
+
+    /// Get starred project names map for saving to config.
+    ///
+    pub fn get_starred_project_names(&self) -> HashMap<String, String> {
+        self.starred_project_names.clone()
+    }
+
+    /// Get the workspaces of starred projects for saving to config.
+    ///
+    pub fn get_starred_project_workspaces(&self) -> HashMap<String, String> {
+        self.starred_project_workspaces.clone()
+    }
+
+    /// Set the workspaces of starred projects loaded from config.
+    ///
+    pub fn set_starred_project_workspaces(
+        &mut self,
+        workspaces: HashMap<String, String>,
+    ) -> &mut Self {
+        self.starred_project_workspaces = workspaces;
+        self
+    }
+
+    /// Enter search mode. Only works when in Projects list or ProjectTasks view.
+    ///
+    pub fn enter_search_mode(&mut self) -> &mut Self {
+        // If in Menu focus but not on TopList, switch to TopList first
+        if *self.current_focus() == Focus::Menu && *self.current_menu() != Menu::TopList {
+            self.current_menu = Menu::TopList;
+        }
+
+        // Only allow search in Projects list (TopList menu) or ProjectTasks view
+        let can_search = match self.current_focus() {
+            Focus::Menu => *self.current_menu() == Menu::TopList,
+            Focus::View => {
+                matches!(self.current_view(), View::ProjectTasks)
+            }
+        };
+
+        if can_search {
+            let new_target = match self.current_focus() {
+                Focus::Menu => SearchTarget::Projects,
+                Focus::View => SearchTarget::Tasks,
+            };
+
+            // If switching targets, clear the query
+            if self.search_target != Some(new_target.clone()) {
+                self.search_query.clear();
+            }
+            // If query is empty, we're starting fresh
+            // If query is not empty and same target, we're re-entering to edit
+
+            self.search_mode = true;
+            self.search_target = Some(new_target);
+            // Initialize filtered lists if needed
+            if self.filtered_projects.is_empty() && !self.projects.is_empty() {
+                self.filtered_projects = self.projects.clone();
+            }
+            if self.filtered_tasks.is_empty() && !self.tasks.is_empty() {
+                self.filtered_tasks = self.tasks.clone();
+            }
+            self.update_search_filters();
+        }
+        self
+    }
+
+    /// Exit search mode. Keeps filtered results visible for navigation.
+    ///
+    pub fn exit_search_mode(&mut self) -> &mut Self {
+        self.search_mode = false;
+        // Don't clear search_query or search_target - keep filters active
+        // Don't repopulate filtered lists - keep filtered results
+
+        // Ensure selections are valid for the filtered lists
+        if let Some(target) = &self.search_target {
+            match target {
+                SearchTarget::Projects => {
+                    if !self.filtered_projects.is_empty() {
+                        let current = self.projects_list_state.selected().unwrap_or(0);
+                        if current >= self.filtered_projects.len() {
+                            self.projects_list_state.select(Some(0));
+                        }
+                    } else {
+                        self.projects_list_state.select(None);
+                    }
+                }
+                SearchTarget::Tasks => {
+                    if !self.filtered_tasks.is_empty() {
+                        let current = self.tasks_list_state.selected().unwrap_or(0);
+                        if current >= self.filtered_tasks.len() {
+                            self.tasks_list_state.select(Some(0));
+                        }
+                    } else {
+                        self.tasks_list_state.select(None);
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Clear search completely (clears query and filters).
+    ///
+    #[allow(dead_code)]
+    pub fn clear_search(&mut self) -> &mut Self {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.search_target = None;
+
+        // Repopulate filtered lists with full lists
+        self.filtered_projects = self.projects.clone();
+        self.filtered_tasks = self.tasks.clone();
+
+        // Reset selections to valid indices in the full lists
+        if !self.projects.is_empty() {
+            let current = self.projects_list_state.selected().unwrap_or(0);
+            if current >= self.projects.len() {
+                self.projects_list_state.select(Some(0));
+            } else {
+                self.projects_list_state.select(Some(current));
+            }
+        }
+
+        if !self.tasks.is_empty() {
+            let current = self.tasks_list_state.selected().unwrap_or(0);
+            if current >= self.tasks.len() {
+                self.tasks_list_state.select(Some(0));
+            } else {
+                self.tasks_list_state.select(Some(current));
+            }
+        }
+
+        self
+    }
+
+    /// Add a character to the search query.
+    ///
+    pub fn add_search_char(&mut self, c: char) -> &mut Self {
+        self.search_query.push(c);
+        self.update_search_filters();
+        self
+    }
+
+    /// Remove the last character from the search query.
+    ///
+    pub fn remove_search_char(&mut self) -> &mut Self {
+        self.search_query.pop();
+        self.update_search_filters();
+        self
+    }
+
+    /// Update filtered lists based on search query. Only filters the target list.
+    ///
+    fn update_search_filters(&mut self) {
+        if self.search_query.is_empty() {
+            // If query is empty, show all items for the search target
+            if let Some(target) = &self.search_target {
+                match target {
+                    SearchTarget::Projects => {
+                        self.filtered_projects = self.projects.clone();
+                    }
+                    SearchTarget::Tasks => {
+                        self.filtered_tasks = self.tasks.clone();
+                    }
+                }
+            } else {
+                // No target, reset both
+                self.filtered_projects = self.projects.clone();
+                self.filtered_tasks = self.tasks.clone();
+            }
+        } else {
+            let query_lower = self.search_query.to_lowercase();
+
+            // Only filter the target list
+            if let Some(target) = &self.search_target {
+                match target {
+                    SearchTarget::Projects => {
+                        self.filtered_projects = self
+                            .projects
+                            .iter()
+                            .filter(|p| p.name.to_lowercase().contains(&query_lower))
+                            .cloned()
+                            .collect();
+                        // Don't filter tasks when searching projects
+                        self.filtered_tasks = self.tasks.clone();
+                    }
+                    SearchTarget::Tasks => {
+                        self.filtered_tasks = self
+                            .tasks
+                            .iter()
+                            .filter(|t| t.name.to_lowercase().contains(&query_lower))
+                            .cloned()
+                            .collect();
+                        // Don't filter projects when searching tasks
+                        self.filtered_projects = self.projects.clone();
+                    }
+                }
+            }
+        }
+
+        // Reset selection to first item if current selection is out of bounds
+        if let Some(target) = &self.search_target {
+            match target {
+                SearchTarget::Projects => {
+                    if !self.filtered_projects.is_empty() {
+                        let current = self.projects_list_state.selected().unwrap_or(0);
+                        if current >= self.filtered_projects.len() {
+                            self.projects_list_state.select(Some(0));
+                        }
+                    } else {
+                        self.projects_list_state.select(None);
+                    }
+                }
+                SearchTarget::Tasks => {
+                    if !self.filtered_tasks.is_empty() {
+                        let current = self.tasks_list_state.selected().unwrap_or(0);
+                        if current >= self.filtered_tasks.len() {
+                            self.tasks_list_state.select(Some(0));
+                        }
+                    } else {
+                        self.tasks_list_state.select(None);
+                    }
+                    // Also validate kanban column index when filtering tasks
+                    // This prevents crashes when sections become hidden due to filtering
+                    let visible_indices = self.get_visible_section_indices();
+                    if !visible_indices.is_empty()
+                        && !visible_indices.contains(&self.kanban_column_index)
+                    {
+                        // Current column is not visible, move to first visible
+                        self.kanban_column_index = visible_indices[0];
+                        self.kanban_task_index = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get search query.
+    ///
+    pub fn get_search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    /// Check if in search mode.
+    ///
+    pub fn is_search_mode(&self) -> bool {
+        self.search_mode
+    }
+
+    /// Get the current search target.
+    ///
+    pub fn get_search_target(&self) -> Option<&SearchTarget> {
+        self.search_target.as_ref()
+    }
+
+    /// Get filtered projects (or all if not searching).
+    ///
+    /// Enter debug mode for navigating and copying logs.
+    ///
+    pub fn enter_debug_mode(&mut self) -> &mut Self {
+        self.debug_mode = true;
+        // Set debug_index to the most recent log (last in the list)
+        if !self.debug_entries.is_empty() {
+            self.debug_index = self.debug_entries.len() - 1;
+        } else {
+            self.debug_index = 0;
+        }
+        self
+    }
+
+    /// Exit debug mode.
+    ///
+    pub fn exit_debug_mode(&mut self) -> &mut Self {
+        self.debug_mode = false;
+        self
+    }
+
+    /// Check if in debug mode.
+    ///
+    pub fn is_debug_mode(&self) -> bool {
+        self.debug_mode
+    }
+
+    /// Get current debug index.
+    ///
+    pub fn get_debug_index(&self) -> usize {
+        self.debug_index
+    }
+
+    /// Navigate to next log entry.
+    ///
+    pub fn next_debug(&mut self) -> &mut Self {
+        if !self.debug_entries.is_empty() {
+            self.debug_index = (self.debug_index + 1) % self.debug_entries.len();
+        }
+        self
+    }
+
+    /// Navigate to previous log entry.
+    ///
+    pub fn previous_debug(&mut self) -> &mut Self {
+        if !self.debug_entries.is_empty() {
+            if self.debug_index == 0 {
+                self.debug_index = self.debug_entries.len() - 1;
+            } else {
+                self.debug_index -= 1;
+            }
+        }
+        self
+    }
+
+    /// Get the currently selected log entry.
+    ///
+    pub fn get_current_debug(&self) -> Option<&String> {
+        self.debug_entries.get(self.debug_index)
+    }
+
+    /// Add a log entry to the debug buffer.
+    ///
+    pub fn add_log_entry(&mut self, entry: String) {
+        self.debug_entries.push(entry);
+        // Keep only the last 1000 log entries to prevent memory issues
+        if self.debug_entries.len() > 1000 {
+            self.debug_entries.remove(0);
+            // Adjust debug_index if we removed entries before it
+            if self.debug_index > 0 {
+                self.debug_index -= 1;
+            }
+        }
+        // Always update index to point to the newest log so the list auto-scrolls
+        // This ensures new logs are visible at the bottom
+        if !self.debug_entries.is_empty() {
+            self.debug_index = self.debug_entries.len() - 1;
+        }
+    }
+
+    /// Get debug entries for rendering (read-only access).
+    ///
+    pub fn get_debug_entries(&self) -> &[String] {
+        &self.debug_entries
+    }
+
+    pub fn get_filtered_projects(&self) -> &[Project] {
+        // Show filtered results if we have a search query and target, even if not in search mode
+        if !self.search_query.is_empty()
+            && matches!(self.search_target, Some(SearchTarget::Projects))
+        {
+            &self.filtered_projects
+        } else {
+            &self.projects
+        }
+    }
+
+    /// Get filtered tasks (or all if not searching tasks).
+    ///
+    pub fn get_filtered_tasks(&self) -> Vec<Task> {
+        // Start with search-filtered tasks if applicable
+        let base_tasks = if !self.search_query.is_empty()
+            && matches!(self.search_target, Some(SearchTarget::Tasks))
+        {
+            &self.filtered_tasks
+        } else {
+            &self.tasks
+        };
+
+        // Apply task filter (All, Incomplete, Completed, Assignee, Tag)
+        base_tasks
+            .iter()
+            .filter(|t| self.task_matches_filter(t))
+            .cloned()
+            .collect()
+    }
+
+    /// Return whether a task passes the current task filter.
+    ///
+    pub fn task_matches_filter(&self, task: &Task) -> bool {
+        match &self.task_filter {
+            TaskFilter::All => true,
+            TaskFilter::Incomplete => !task.completed,
+            TaskFilter::Completed => task.completed,
+            TaskFilter::Assignee(assignee_gid) => {
+                match (assignee_gid, &task.assignee) {
+                    (None, None) => true, // Unassigned tasks
+                    (Some(gid), Some(assignee)) => &assignee.gid == gid,
+                    _ => false,
+                }
+            }
+            TaskFilter::Tag(tag_gid) => task.tags.iter().any(|tag| &tag.gid == tag_gid),
+        }
+    }
+
+    /// Dispatches an asynchronous network event.
+    ///
+    pub fn dispatch(&self, event: NetworkEvent) {
+        if let Some(net_sender) = &self.net_sender {
+            if let Err(err) = net_sender.send(event) {
+                error!("Recieved error from network dispatch: {}", err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+    use uuid::Uuid;
+
+    /// Return a default state whose dispatched network events can be received.
+    ///
+    fn state_with_net_receiver() -> (State, std::sync::mpsc::Receiver<NetworkEvent>) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let state = State {
+            net_sender: Some(sender),
+            ..State::default()
+        };
+        (state, receiver)
+    }
+
+    #[test]
+    fn get_user() {
+        let user: User = Faker.fake();
+        let state = State {
+            user: Some(user.to_owned()),
+            ..State::default()
+        };
+        assert_eq!(user, *state.get_user().unwrap());
+    }
+
+    #[test]
+    fn set_user() {
+        let mut state = State::default();
+        let user: User = Faker.fake();
+        state.set_user(user.to_owned());
+        assert_eq!(user, state.user.unwrap());
+    }
+
+    #[test]
+    fn get_active_workspace() {
+        let workspaces = vec![
+            Faker.fake::<Workspace>(),
+            Faker.fake::<Workspace>(),
+            Faker.fake::<Workspace>(),
+        ];
+        let active_workspace = workspaces[0].to_owned();
+        let state = State {
+            active_workspace_gid: Some(active_workspace.gid.to_owned()),
+            workspaces,
+            ..State::default()
+        };
+        assert_eq!(active_workspace, *state.get_active_workspace().unwrap());
+    }
+
+    #[test]
+    fn set_active_workspace() {
+        let workspace_gid: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440100")
+            .expect("Hardcoded test UUID should be valid");
+        let mut state = State::default();
+        state.set_active_workspace(workspace_gid.to_string());
+        assert_eq!(
+            workspace_gid.to_string(),
+            state.active_workspace_gid.unwrap()
+        );
+    }
+
+    #[test]
+    fn set_workspaces() {
+        let workspaces = vec![
+            Faker.fake::<Workspace>(),
+            Faker.fake::<Workspace>(),
+            Faker.fake::<Workspace>(),
+        ];
+        let mut state = State::default();
+        state.set_workspaces(workspaces.to_owned());
+        assert_eq!(workspaces, state.workspaces);
+    }
+
+    #[test]
+    fn set_terminal_size() {
+        let mut state = State::default();
+        let size = Rect::new(Faker.fake(), Faker.fake(), Faker.fake(), Faker.fake());
+        state.set_terminal_size(size);
+        assert_eq!(size, state.terminal_size);
+    }
+
+    #[test]
+    fn advance_spinner_index() {
+        let mut state = State::default();
+        state.advance_spinner_index();
+        assert_eq!(state.spinner_index, 1);
+        for _ in 0..SPINNER_FRAME_COUNT {
+            state.advance_spinner_index();
+        }
+        assert_eq!(state.spinner_index, 1);
+    }
+
+    #[test]
+    fn get_spinner_index() {
+        let state = State {
+            spinner_index: 2,
+            ..State::default()
+        };
+        assert_eq!(*state.get_spinner_index(), 2);
+    }
+
+    #[test]
+    fn current_focus() {
+        let mut state = State {
+            current_focus: Focus::Menu,
+            ..State::default()
+        };
+        assert_eq!(*state.current_focus(), Focus::Menu);
+        state.current_focus = Focus::View;
+        assert_eq!(*state.current_focus(), Focus::View);
+    }
+
+    #[test]
+    fn focus_menu() {
+        let mut state = State {
+            current_focus: Focus::View,
+            ..State::default()
+        };
+        state.focus_menu();
+        assert_eq!(state.current_focus, Focus::Menu);
+    }
+
+    #[test]
+    fn focus_view() {
+        let mut state = State {
+            current_focus: Focus::Menu,
+            ..State::default()
+        };
+        state.focus_view();
+        assert_eq!(state.current_focus, Focus::View);
+    }
+
+    #[test]
+    fn current_menu() {
+        let state = State {
+            current_menu: Menu::Status,
+            ..State::default()
+        };
+        assert_eq!(*state.current_menu(), Menu::Status);
+    }
+
+    #[test]
+    fn next_menu() {
+        let mut state = State {
+            current_menu: Menu::Status,
+            ..State::default()
+        };
+        state.next_menu();
+        assert_eq!(state.current_menu, Menu::Shortcuts);
+        state.next_menu();
+        assert_eq!(state.current_menu, Menu::TopList);
+        state.next_menu();
+        assert_eq!(state.current_menu, Menu::Status);
+    }
+
+    #[test]
+    fn previous_menu() {
+        let mut state = State {
+            current_menu: Menu::Status,
+            ..State::default()
+        };
+        state.previous_menu();
+        assert_eq!(state.current_menu, Menu::TopList);
+        state.previous_menu();
+        assert_eq!(state.current_menu, Menu::Shortcuts);
+        state.previous_menu();
+        assert_eq!(state.current_menu, Menu::Status);
+    }
+
+    #[test]
+    fn select_status_menu() {
+        let mut state = State {
+            view_stack: vec![View::Welcome],
+            ..State::default()
+        };
+        state.select_status_menu();
+        assert_eq!(*state.view_stack.last().unwrap(), View::Welcome);
+    }
+
+    #[test]
+    fn current_shortcut_index() {
+        let state = State {
+            current_shortcut_index: 0,
+            ..State::default()
+        };
+        assert_eq!(*state.current_shortcut_index(), 0);
+    }
+
+    #[test]
+    fn next_shortcut_index() {
+        let mut state = State {
+            current_shortcut_index: 0,
+            ..State::default()
+        };
+        // Add starred projects to make shortcuts list non-empty
+        state.starred_projects.insert("proj1".to_string());
+        state.starred_projects.insert("proj2".to_string());
+        state.starred_projects.insert("proj3".to_string());
+        state
+            .starred_project_names
+            .insert("proj1".to_string(), "Project 1".to_string());
+        state
+            .starred_project_names
+            .insert("proj2".to_string(), "Project 2".to_string());
+        state
+            .starred_project_names
+            .insert("proj3".to_string(), "Project 3".to_string());
+        state.shortcuts_list_state.select(Some(0));
+        state.next_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 1);
+        state.next_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 2);
+        // Then the built-in My Tasks and Portfolios shortcuts
+        state.next_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 3);
+        state.next_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 4);
+        state.next_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 0);
+    }
+
+    #[test]
+    fn previous_shortcut_index() {
+        let mut state = State {
+            current_shortcut_index: 0,
+            ..State::default()
+        };
+        // Add starred projects to make shortcuts list non-empty
+        state.starred_projects.insert("proj1".to_string());
+        state.starred_projects.insert("proj2".to_string());
+        state.starred_projects.insert("proj3".to_string());
+        state
+            .starred_project_names
+            .insert("proj1".to_string(), "Project 1".to_string());
+        state
+            .starred_project_names
+            .insert("proj2".to_string(), "Project 2".to_string());
+        state
+            .starred_project_names
+            .insert("proj3".to_string(), "Project 3".to_string());
+        state.shortcuts_list_state.select(Some(0));
+        // Wraps to the built-in shortcuts after the starred projects
+        state.previous_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 4);
+        state.previous_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 3);
+        state.previous_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 2);
+        state.previous_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 1);
+        state.previous_shortcut_index();
+        assert_eq!(state.current_shortcut_index, 0);
+    }
+
+    #[test]
+    fn select_current_shortcut_index() {
+        let mut state = State {
+            current_shortcut_index: 0,
+            current_focus: Focus::Menu,
+            ..State::default()
+        };
+        // Add starred projects to make shortcuts list non-empty
+        let project1 = Faker.fake::<Project>();
+        state.starred_projects.insert(project1.gid.clone());
+        state
+            .starred_project_names
+            .insert(project1.gid.clone(), project1.name.clone());
+        state.projects.push(project1);
+        state.shortcuts_list_state.select(Some(0));
+        state.select_current_shortcut_index();
+        // select_current_shortcut_index calls focus_view() which switches to View focus
+        assert_eq!(*state.current_focus(), Focus::View);
+    }
+
+    #[test]
+    fn current_top_list_index() {
+        let state = State {
+            current_top_list_index: 2,
+            ..State::default()
+        };
+        assert_eq!(*state.current_top_list_index(), 2);
+    }
+
+    #[test]
+    fn next_top_list_index_when_nonempty() {
+        let mut state = State {
+            current_top_list_index: 0,
+            ..State::default()
+        };
+        let projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
+        state.set_projects(projects);
+        state.projects_list_state.select(Some(0));
+        state.next_top_list_index();
+        assert_eq!(state.current_top_list_index, 1);
+        state.next_top_list_index();
+        assert_eq!(state.current_top_list_index, 0);
+    }
+
+    #[test]
+    fn next_top_list_index_when_empty() {
+        let mut state = State {
+            current_top_list_index: 0,
+            projects: vec![],
+            ..State::default()
+        };
+        state.next_top_list_index();
+        assert_eq!(state.current_top_list_index, 0);
+    }
+
+    #[test]
+    fn previous_top_list_index_when_nonempty() {
+        let mut state = State {
+            current_top_list_index: 0,
+            ..State::default()
+        };
+        let projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
+        state.set_projects(projects);
+        state.projects_list_state.select(Some(0));
+        state.previous_top_list_index();
+        assert_eq!(state.current_top_list_index, 1);
+        state.previous_top_list_index();
+        assert_eq!(state.current_top_list_index, 0);
+    }
+
+    #[test]
+    fn previous_top_list_index_when_empty() {
+        let mut state = State {
+            current_top_list_index: 0,
+            projects: vec![],
+            ..State::default()
+        };
+        state.previous_top_list_index();
+        assert_eq!(state.current_top_list_index, 0);
+    }
+
+    #[test]
+    fn current_view() {
+        let state = State {
+            view_stack: vec![View::Welcome],
+            ..State::default()
+        };
+        assert_eq!(*state.current_view(), View::Welcome);
+    }
+
+    #[test]
+    fn set_tasks() {
+        let mut state = State::default();
+        let tasks = vec![
+            Faker.fake::<Task>(),
+            Faker.fake::<Task>(),
+            Faker.fake::<Task>(),
+        ];
+        state.set_tasks(tasks.to_owned());
+        assert_eq!(tasks, state.tasks);
+    }
+
+    #[test]
+    fn append_tasks_keeps_selection() {
+        let mut state = State::default();
+        let first = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        let second = vec![Faker.fake::<Task>()];
+        state.set_tasks(first.to_owned());
+        state.tasks_list_state.select(Some(1));
+        state.append_tasks(second.to_owned());
+        assert_eq!([first, second].concat(), state.tasks);
+        assert_eq!(state.tasks_list_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn reloading_tasks_makes_earlier_pages_stale() {
+        let mut state = State::default();
+        let first = state.start_tasks_load();
+        assert!(state.is_current_tasks_load(first));
+        let second = state.start_tasks_load();
+        assert!(!state.is_current_tasks_load(first));
+        assert!(state.is_current_tasks_load(second));
+    }
+
+    #[test]
+    fn upsert_task_replaces_or_appends() {
+        let mut state = State::default();
+        let tasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        state.set_tasks(tasks.to_owned());
+        let mut changed = tasks[1].clone();
+        changed.name = String::from("Renamed");
+        state.upsert_task(changed.to_owned());
+        let added: Task = Faker.fake();
+        state.upsert_task(added.to_owned());
+        assert_eq!(vec![tasks[0].clone(), changed, added], state.tasks);
+    }
+
+    #[test]
+    fn task_matches_filter() {
+        let mut state = State::default();
+        let mut task: Task = Faker.fake();
+        task.completed = true;
+        assert!(state.task_matches_filter(&task));
+        state.set_task_filter(TaskFilter::Incomplete);
+        assert!(!state.task_matches_filter(&task));
+        state.set_task_filter(TaskFilter::Completed);
+        assert!(state.task_matches_filter(&task));
+        task.assignee = None;
+        state.set_task_filter(TaskFilter::Assignee(None));
+        assert!(state.task_matches_filter(&task));
+        state.set_task_filter(TaskFilter::Assignee(Some(String::from("1"))));
+        assert!(!state.task_matches_filter(&task));
+    }
+
+    #[test]
+    fn sync_due_waits_for_interval() {
+        let mut state = State {
+            has_access_token: true,
+            project: Some(Faker.fake::<Project>()),
+            view_stack: vec![View::Welcome, View::ProjectTasks],
+            ..State::default()
+        };
+        assert!(state.sync_due());
+        assert!(!state.sync_due());
+        state.last_sync = Some(Instant::now() - SYNC_INTERVAL);
+        assert!(state.sync_due());
+        state.push_view(View::EditTask);
+        state.last_sync = None;
+        assert!(!state.sync_due());
+    }
+
+    #[test]
+    fn highlight_task() {
+        let mut state = State::default();
+        state.highlight_task(String::from("1"));
+        assert!(state.is_task_highlighted("1"));
+        assert!(!state.is_task_highlighted("2"));
+        state
+            .highlighted_tasks
+            .insert(String::from("1"), Instant::now() - Duration::from_millis(1));
+        assert!(!state.is_task_highlighted("1"));
     }
 
-    /// Check if in debug mode.
-    ///
-    pub fn is_debug_mode(&self) -> bool {
-        self.debug_mode
+    #[test]
+    fn toggle_task_mark() {
+        let tasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        let mut state = State::default();
+        state.set_tasks(tasks.to_owned());
+        state.toggle_task_mark(&tasks[1].gid);
+        state.toggle_task_mark(&tasks[0].gid);
+        assert_eq!(
+            state.get_marked_tasks(),
+            [tasks[1].gid.clone(), tasks[0].gid.clone()]
+        );
+        state.toggle_task_mark(&tasks[1].gid);
+        assert!(!state.is_task_marked(&tasks[1].gid));
+        // Marks on tasks removed elsewhere are dropped
+        state.remove_task(&tasks[0].gid);
+        assert!(state.get_marked_tasks().is_empty());
     }
 
-    /// Get current debug index.
-    ///
-    pub fn get_debug_index(&self) -> usize {
-        self.debug_index
+    #[test]
+    fn workspace_tags_are_requested_when_stale_or_switched() {
+        let (mut state, receiver) = state_with_net_receiver();
+        state.active_workspace_gid = Some(String::from("1"));
+        state.request_workspace_tags();
+        state.request_workspace_tags();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetWorkspaceTags { workspace_gid }) if workspace_gid == "1"
+        ));
+        assert!(receiver.try_recv().is_err());
+
+        state.workspace_tags_requested = Some(Instant::now() - WORKSPACE_TAGS_MAX_AGE);
+        state.request_workspace_tags();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetWorkspaceTags { .. })
+        ));
+
+        state.switch_workspace(String::from("2"));
+        while receiver.try_recv().is_ok() {}
+        state.request_workspace_tags();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetWorkspaceTags { workspace_gid }) if workspace_gid == "2"
+        ));
     }
 
-    /// Navigate to next log entry.
-    ///
-    pub fn next_debug(&mut self) -> &mut Self {
-        if !self.debug_entries.is_empty() {
-            self.debug_index = (self.debug_index + 1) % self.debug_entries.len();
-        }
-        self
+    #[test]
+    fn single_task_moves_are_batched() {
+        let (mut state, receiver) = state_with_net_receiver();
+        state.set_move_task_gid(Some(String::from("1")));
+        state.move_tasks_to_section(String::from("9"));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::MoveTasksToSection { task_gids, section_gid })
+                if task_gids == ["1"] && section_gid == "9"
+        ));
     }
 
-    /// Navigate to previous log entry.
-    ///
-    pub fn previous_debug(&mut self) -> &mut Self {
-        if !self.debug_entries.is_empty() {
-            if self.debug_index == 0 {
-                self.debug_index = self.debug_entries.len() - 1;
-            } else {
-                self.debug_index -= 1;
-            }
+    #[test]
+    fn marked_tasks_are_deleted_and_commented_on_in_batches() {
+        let (mut state, receiver) = state_with_net_receiver();
+        state.view_stack = vec![View::Welcome, View::ProjectTasks];
+        state.toggle_task_mark("1");
+        state.toggle_task_mark("2");
+        state.enter_marked_comment_input_mode();
+        assert!(state.is_marked_comment_input_mode());
+        for c in "Done".chars() {
+            state.add_comment_char(c);
         }
-        self
+        state.submit_marked_comment();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::CreateStories { task_gids, text })
+                if task_gids == ["1", "2"] && text == "Done"
+        ));
+        assert!(state.get_marked_tasks().is_empty());
+
+        state.toggle_task_mark("3");
+        state.toggle_task_mark("4");
+        state.set_delete_confirmation("3".to_string());
+        state.confirm_delete_task();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::DeleteTasks { gids }) if gids == ["3", "4"]
+        ));
     }
 
-    /// Get the currently selected log entry.
-    ///
-    pub fn get_current_debug(&self) -> Option<&String> {
-        self.debug_entries.get(self.debug_index)
+    #[test]
+    fn toggle_selected_subtask() {
+        let mut parent = Faker.fake::<Task>();
+        let subtasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        let mut state = State {
+            current_task_detail: Some(parent.clone()),
+            ..State::default()
+        };
+        state.set_task_subtasks(subtasks.to_owned());
+        parent.num_subtasks = 2;
+        assert_eq!(state.get_task_detail(), Some(&parent));
+        state.previous_subtask();
+        assert_eq!(state.get_selected_subtask(), Some(&subtasks[1]));
+        state.toggle_selected_subtask();
+        assert_eq!(state.task_subtasks[1].completed, !subtasks[1].completed);
     }
 
-    /// Add a log entry to the debug buffer.
-    ///
-    pub fn add_log_entry(&mut self, entry: String) {
-        self.debug_entries.push(entry);
-        // Keep only the last 1000 log entries to prevent memory issues
-        if self.debug_entries.len() > 1000 {
-            self.debug_entries.remove(0);
-            // Adjust debug_index if we removed entries before it
-            if self.debug_index > 0 {
-                self.debug_index -= 1;
-            }
-        }
-        // Always update index to point to the newest log so the list auto-scrolls
-        // This ensures new logs are visible at the bottom
-        if !self.debug_entries.is_empty() {
-            self.debug_index = self.debug_entries.len() - 1;
-        }
+    #[test]
+    fn subtask_drill_in_restores_parent() {
+        let parent = Faker.fake::<Task>();
+        let subtasks = vec![Faker.fake::<Task>()];
+        let mut state = State {
+            view_stack: vec![View::Welcome, View::ProjectTasks, View::TaskDetail],
+            current_task_detail: Some(parent.clone()),
+            ..State::default()
+        };
+        state.set_task_subtasks(subtasks.to_owned());
+        state.open_selected_subtask();
+        assert_eq!(state.view_stack_len(), 4);
+        assert!(state.get_task_detail().is_none());
+        assert!(state.get_task_subtasks().is_empty());
+        state.pop_view();
+        assert_eq!(*state.current_view(), View::TaskDetail);
+        assert_eq!(state.get_task_detail().map(|t| &t.gid), Some(&parent.gid));
+        state.pop_view();
+        assert_eq!(*state.current_view(), View::ProjectTasks);
+        assert!(state.parent_task_details.is_empty());
     }
 
-    /// Get debug entries for rendering (read-only access).
-    ///
-    pub fn get_debug_entries(&self) -> &[String] {
-        &self.debug_entries
+    #[test]
+    fn dependency_candidates_skip_linked_tasks() {
+        let tasks = vec![
+            Faker.fake::<Task>(),
+            Faker.fake::<Task>(),
+            Faker.fake::<Task>(),
+        ];
+        let mut current = tasks[0].clone();
+        current.dependencies = vec![TaskCompact {
+            gid: tasks[1].gid.clone(),
+            name: tasks[1].name.clone(),
+            completed: false,
+        }];
+        current.dependents = vec![];
+        let mut state = State {
+            current_task_detail: Some(current),
+            ..State::default()
+        };
+        state.set_tasks(tasks.to_owned());
+        state.enter_dependency_input_mode(DependencyKind::Blocking);
+        assert_eq!(state.get_dependency_candidates(), vec![&tasks[2]]);
+        state.toggle_dependency_input_kind();
+        assert_eq!(
+            state.get_dependency_input_kind(),
+            Some(DependencyKind::BlockedBy)
+        );
+        state.submit_dependency_input();
+        assert!(!state.is_dependency_input_mode());
     }
 
-    pub fn get_filtered_projects(&self) -> &[Project] {
-        // Show filtered results if we have a search query and target, even if not in search mode
-        if !self.search_query.is_empty()
-            && matches!(self.search_target, Some(SearchTarget::Projects))
-        {
-            &self.filtered_projects
-        } else {
-            &self.projects
-        }
+    #[test]
+    fn selected_relation_spans_both_lists() {
+        let mut task = Faker.fake::<Task>();
+        task.dependencies = vec![Faker.fake::<TaskCompact>()];
+        task.dependents = vec![Faker.fake::<TaskCompact>(), Faker.fake::<TaskCompact>()];
+        let mut state = State::default();
+        state.set_task_detail(task.clone());
+        assert_eq!(
+            state.get_selected_relation(),
+            Some((DependencyKind::BlockedBy, &task.dependencies[0]))
+        );
+        state.previous_relation();
+        assert_eq!(
+            state.get_selected_relation(),
+            Some((DependencyKind::Blocking, &task.dependents[1]))
+        );
+        state.next_relation();
+        assert_eq!(
+            state.get_selected_relation(),
+            Some((DependencyKind::BlockedBy, &task.dependencies[0]))
+        );
     }
 
-    /// Get filtered tasks (or all if not searching tasks).
-    ///
-    pub fn get_filtered_tasks(&self) -> Vec<Task> {
-        // Start with search-filtered tasks if applicable
-        let base_tasks = if !self.search_query.is_empty()
-            && matches!(self.search_target, Some(SearchTarget::Tasks))
-        {
-            &self.filtered_tasks
-        } else {
-            &self.tasks
+    #[test]
+    fn tag_filter_keeps_tagged_tasks() {
+        let bug = Tag {
+            gid: String::from("7"),
+            name: String::from("bug"),
         };
-
-        // Apply task filter (All, Incomplete, Completed, Assignee)
-        match &self.task_filter {
-            TaskFilter::All => base_tasks.to_vec(),
-            TaskFilter::Incomplete => base_tasks
-                .iter()
-                .filter(|t| !t.completed)
-                .cloned()
-                .collect(),
-            TaskFilter::Completed => base_tasks.iter().filter(|t| t.completed).cloned().collect(),
-            TaskFilter::Assignee(assignee_gid) => base_tasks
-                .iter()
-                .filter(|t| {
-                    match (assignee_gid, &t.assignee) {
-                        (None, None) => true, // Unassigned tasks
-                        (Some(gid), Some(assignee)) => &assignee.gid == gid,
-                        _ => false,
-                    }
-                })
-                .cloned()
-                .collect(),
-        }
+        let mut tasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        tasks[0].tags = vec![bug.clone()];
+        tasks[1].tags = vec![];
+        let mut state = State::default();
+        state.set_tasks(tasks.to_owned());
+        state.set_workspace_tags(vec![bug]);
+        state.open_tag_filter();
+        state.next_assignee_filter_option();
+        state.select_assignee_filter();
+        assert!(!state.has_assignee_filter());
+        assert_eq!(state.get_filtered_tasks(), vec![tasks[0].clone()]);
     }
 
-    /// Dispatches an asynchronous network event.
-    ///
-    pub fn dispatch(&self, event: NetworkEvent) {
-        if let Some(net_sender) = &self.net_sender {
-            if let Err(err) = net_sender.send(event) {
-                error!("Recieved error from network dispatch: {}", err);
-            }
+    #[test]
+    fn toggle_current_tag_selects_and_offers_create() {
+        let mut state = State::default();
+        state.set_workspace_tags(vec![Tag {
+            gid: String::from("7"),
+            name: String::from("bug"),
+        }]);
+        state.toggle_current_tag();
+        assert_eq!(state.get_form_tags(), &[String::from("7")]);
+        state.toggle_current_tag();
+        assert!(state.get_form_tags().is_empty());
+        for c in "needs-repro".chars() {
+            state.add_tag_search_char(c);
         }
+        assert_eq!(state.get_new_tag_name(), Some("needs-repro"));
+        state.add_created_tag(Tag {
+            gid: String::from("8"),
+            name: String::from("needs-repro"),
+        });
+        assert_eq!(state.get_new_tag_name(), None);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use fake::{Fake, Faker};
-    use uuid::Uuid;
 
     #[test]
-    fn get_user() {
-        let user: User = Faker.fake();
-        let state = State {
-            user: Some(user.to_owned()),
+    fn move_current_section_keeps_focus() {
+        let sections = vec![
+            Faker.fake::<Section>(),
+            Faker.fake::<Section>(),
+            Faker.fake::<Section>(),
+        ];
+        let mut state = State {
+            project: Some(Faker.fake::<Project>()),
             ..State::default()
         };
-        assert_eq!(user, *state.get_user().unwrap());
+        state.set_sections(sections.to_owned());
+        state.move_current_section_left();
+        assert_eq!(state.get_kanban_column_index(), 0);
+        state.move_current_section_right();
+        assert_eq!(state.get_kanban_column_index(), 1);
+        assert_eq!(state.get_current_section(), Some(&sections[0]));
+        assert_eq!(state.get_sections()[0], sections[1]);
     }
 
     #[test]
-    fn set_user() {
-        let mut state = State::default();
-        let user: User = Faker.fake();
-        state.set_user(user.to_owned());
-        assert_eq!(user, state.user.unwrap());
+    fn my_tasks_sections_are_not_edited() {
+        let sections = vec![Faker.fake::<Section>(), Faker.fake::<Section>()];
+        let (mut state, receiver) = state_with_net_receiver();
+        state.project = Some(Faker.fake::<Project>());
+        state.my_tasks = true;
+        state.set_sections(sections.to_owned());
+        state.move_current_section_right();
+        state.delete_current_section();
+        state.enter_new_section_input_mode();
+        assert!(!state.is_section_input_mode());
+        state.enter_rename_section_input_mode();
+        assert!(!state.is_section_input_mode());
+        assert_eq!(state.get_sections(), &sections[..]);
+        assert!(receiver.try_recv().is_err());
     }
 
     #[test]
-    fn get_active_workspace() {
-        let workspaces = vec![
-            Faker.fake::<Workspace>(),
-            Faker.fake::<Workspace>(),
-            Faker.fake::<Workspace>(),
-        ];
-        let active_workspace = workspaces[0].to_owned();
-        let state = State {
-            active_workspace_gid: Some(active_workspace.gid.to_owned()),
-            workspaces,
+    fn delete_current_section_skips_columns_with_tasks() {
+        let sections = vec![Faker.fake::<Section>(), Faker.fake::<Section>()];
+        let mut task = Faker.fake::<Task>();
+        task.section = Some(sections[0].clone());
+        let mut state = State {
+            project: Some(Faker.fake::<Project>()),
             ..State::default()
         };
-        assert_eq!(active_workspace, *state.get_active_workspace().unwrap());
+        state.set_tasks(vec![task]);
+        state.set_sections(sections.to_owned());
+        state.delete_current_section();
+        assert_eq!(state.get_sections().len(), 2);
+        state.next_kanban_column();
+        state.delete_current_section();
+        assert_eq!(state.get_sections(), &sections[..1]);
+        assert_eq!(state.get_kanban_column_index(), 0);
     }
 
     #[test]
-    fn set_active_workspace() {
-        let workspace_gid: Uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440100")
-            .expect("Hardcoded test UUID should be valid");
-        let mut state = State::default();
-        state.set_active_workspace(workspace_gid.to_string());
-        assert_eq!(
-            workspace_gid.to_string(),
-            state.active_workspace_gid.unwrap()
-        );
+    fn rename_section_input_starts_from_name() {
+        let section = Faker.fake::<Section>();
+        let mut state = State {
+            project: Some(Faker.fake::<Project>()),
+            ..State::default()
+        };
+        state.set_sections(vec![section.clone()]);
+        state.enter_rename_section_input_mode();
+        assert!(state.is_rename_section_input_mode());
+        assert_eq!(state.get_comment_input_text(), section.name);
+        state.add_comment_char('!');
+        state.submit_section_input();
+        assert!(!state.is_section_input_mode());
+        assert_eq!(state.get_sections()[0].name, format!("{}!", section.name));
     }
 
     #[test]
-    fn set_workspaces() {
-        let workspaces = vec![
-            Faker.fake::<Workspace>(),
-            Faker.fake::<Workspace>(),
-            Faker.fake::<Workspace>(),
-        ];
+    fn archiving_project_hides_it_unless_archived_shown() {
+        let mut projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
+        projects[0].archived = false;
+        projects[1].archived = false;
         let mut state = State::default();
-        state.set_workspaces(workspaces.to_owned());
-        assert_eq!(workspaces, state.workspaces);
+        state.set_projects(projects.to_owned());
+        state.projects_list_state.select(Some(1));
+        state.toggle_current_project_archived();
+        assert_eq!(state.get_projects(), &projects[..1]);
+        assert_eq!(state.projects_list_state.selected(), Some(0));
+
+        state.show_archived_projects = true;
+        state.toggle_current_project_archived();
+        assert!(state.get_projects()[0].archived);
     }
 
     #[test]
-    fn set_terminal_size() {
+    fn edit_project_form_starts_from_selected_project() {
+        let mut project = Faker.fake::<Project>();
+        project.color = "none".to_string();
         let mut state = State::default();
-        let size = Rect::new(Faker.fake(), Faker.fake(), Faker.fake(), Faker.fake());
-        state.set_terminal_size(size);
-        assert_eq!(size, state.terminal_size);
+        state.set_projects(vec![project.clone()]);
+        state.projects_list_state.select(Some(0));
+        state.open_edit_project_form();
+        let form = state.get_project_form().unwrap();
+        assert_eq!(form.name, project.name);
+        assert_eq!(form.color, None);
+
+        state.remove_project_form_char();
+        state.add_project_form_char('!');
+        state.cycle_project_form_option(true);
+        let mut expected = project.name.clone();
+        expected.pop();
+        expected.push('!');
+        assert_eq!(state.get_project_form().unwrap().name, expected);
+        assert_eq!(state.get_project_form().unwrap().color, None);
+
+        // A blank name keeps the form open
+        state.project_form.as_mut().unwrap().name = "  ".to_string();
+        state.submit_project_form();
+        assert!(state.has_project_form());
     }
 
     #[test]
-    fn advance_spinner_index() {
-        let mut state = State::default();
-        state.advance_spinner_index();
-        assert_eq!(state.spinner_index, 1);
-        for _ in 0..SPINNER_FRAME_COUNT {
-            state.advance_spinner_index();
+    fn project_picker_adds_without_section_when_sections_fail() {
+        let project = Faker.fake::<Project>();
+        let task = Faker.fake::<Task>();
+        let (mut state, receiver) = state_with_net_receiver();
+        state.current_task_detail = Some(Task {
+            memberships: vec![],
+            ..task.clone()
+        });
+        state.set_projects(vec![project.clone()]);
+
+        state.open_project_picker(ProjectPickerAction::Add);
+        state.select_project_picker_item();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetProjectPickerSections { .. })
+        ));
+        state.set_project_picker_sections_error(&project.gid, "Forbidden".to_string());
+        assert_eq!(
+            state
+                .get_project_picker()
+                .unwrap()
+                .sections_error
+                .as_deref(),
+            Some("Forbidden")
+        );
+
+        state.select_project_picker_item();
+        assert!(!state.has_project_picker());
+        match receiver.try_recv() {
+            Ok(NetworkEvent::AddTaskToProject {
+                project_gid,
+                section_gid,
+                ..
+            }) => {
+                assert_eq!(project_gid, project.gid);
+                assert_eq!(section_gid, None);
+            }
+            other => panic!("unexpected event {:?}", other),
         }
-        assert_eq!(state.spinner_index, 1);
     }
 
     #[test]
-    fn get_spinner_index() {
-        let state = State {
-            spinner_index: 2,
-            ..State::default()
-        };
-        assert_eq!(*state.get_spinner_index(), 2);
+    fn project_picker_moves_task_out_of_open_project() {
+        let projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
+        let mut task = Faker.fake::<Task>();
+        task.memberships = vec![crate::asana::Membership {
+            project_gid: projects[0].gid.clone(),
+            project_name: projects[0].name.clone(),
+            section: None,
+        }];
+        let (mut state, receiver) = state_with_net_receiver();
+        state.project = Some(projects[0].clone());
+        state.current_task_detail = Some(task.clone());
+        state.set_projects(projects.to_owned());
+
+        state.open_project_picker(ProjectPickerAction::Move);
+        // Projects the task is already in are not offered
+        assert_eq!(
+            state.get_project_picker_items(),
+            vec![(projects[1].gid.clone(), projects[1].name.clone())]
+        );
+        state.select_project_picker_item();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetProjectPickerSections { .. })
+        ));
+
+        // Enter waits for the sections to load
+        state.select_project_picker_item();
+        assert!(state.has_project_picker());
+        let section = Faker.fake::<Section>();
+        state.set_project_picker_sections(&projects[1].gid, vec![section.clone()]);
+        state.select_project_picker_item();
+        assert!(!state.has_project_picker());
+        match receiver.try_recv() {
+            Ok(NetworkEvent::AddTaskToProject {
+                task_gid,
+                project_gid,
+                section_gid,
+                remove_project_gid,
+            }) => {
+                assert_eq!(task_gid, task.gid);
+                assert_eq!(project_gid, projects[1].gid);
+                assert_eq!(section_gid, Some(section.gid));
+                assert_eq!(remove_project_gid, Some(projects[0].gid.clone()));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
     }
 
     #[test]
-    fn current_focus() {
+    fn pinned_comments_come_first_and_only_own_can_be_edited() {
+        let user = Faker.fake::<User>();
+        let stories: Vec<Story> = (0..3)
+            .map(|i| Story {
+                text: format!("comment {}", i),
+                created_by: Some(if i == 2 {
+                    user.clone()
+                } else {
+                    Faker.fake::<User>()
+                }),
+                resource_subtype: Some("comment_added".to_string()),
+                is_pinned: false,
+                ..Faker.fake::<Story>()
+            })
+            .collect();
         let mut state = State {
-            current_focus: Focus::Menu,
+            user: Some(user),
+            current_task_detail: Some(Faker.fake::<Task>()),
             ..State::default()
         };
-        assert_eq!(*state.current_focus(), Focus::Menu);
-        state.current_focus = Focus::View;
-        assert_eq!(*state.current_focus(), Focus::View);
+        state.set_task_stories(stories.clone());
+
+        // Someone else's comment can't be edited or deleted
+        state.enter_edit_comment_input_mode();
+        assert!(!state.is_edit_comment_input_mode());
+        state.delete_selected_comment();
+        assert!(!state.has_delete_confirmation());
+
+        // Pinning the last comment moves it to the top and keeps it selected
+        state.scroll_comments_up();
+        state.toggle_selected_comment_pinned();
+        assert_eq!(state.get_task_comments()[0].gid, stories[2].gid);
+        assert_eq!(state.get_selected_comment().unwrap().gid, stories[2].gid);
+
+        state.enter_edit_comment_input_mode();
+        assert!(state.is_edit_comment_input_mode());
+        assert_eq!(state.get_comment_input_text(), "comment 2");
+        state.add_comment_char('!');
+        state.submit_comment_edit();
+        assert_eq!(state.get_task_comments()[0].text, "comment 2!");
+
+        state.delete_selected_comment();
+        assert!(state.has_delete_confirmation());
+        state.confirm_delete_task();
+        assert_eq!(state.get_task_comments().len(), 2);
+        assert!(!state.has_delete_confirmation());
     }
 
     #[test]
-    fn focus_menu() {
+    fn toggle_follow_current_task_adds_then_removes_user() {
+        let user = Faker.fake::<User>();
+        let mut task = Faker.fake::<Task>();
+        task.followers = vec![];
         let mut state = State {
-            current_focus: Focus::View,
+            user: Some(user.clone()),
+            current_task_detail: Some(task),
             ..State::default()
         };
-        state.focus_menu();
-        assert_eq!(state.current_focus, Focus::Menu);
+        state.toggle_follow_current_task();
+        assert!(state.is_task_follower(&user.gid));
+        state.toggle_follow_current_task();
+        assert!(!state.is_task_follower(&user.gid));
     }
 
     #[test]
-    fn focus_view() {
+    fn follower_picker_toggles_filtered_user() {
+        let users = vec![Faker.fake::<User>(), Faker.fake::<User>()];
+        let mut task = Faker.fake::<Task>();
+        task.followers = vec![users[0].clone()];
         let mut state = State {
-            current_focus: Focus::Menu,
+            current_task_detail: Some(task),
             ..State::default()
         };
-        state.focus_view();
-        assert_eq!(state.current_focus, Focus::View);
+        state.set_workspace_users(users.to_owned());
+        state.open_follower_picker();
+        state.next_assignee();
+        state.toggle_selected_follower();
+        assert!(state.is_task_follower(&users[1].gid));
+        state.previous_assignee();
+        state.toggle_selected_follower();
+        assert!(!state.is_task_follower(&users[0].gid));
     }
 
     #[test]
-    fn current_menu() {
+    fn get_projects() {
+        let projects = vec![
+            Faker.fake::<Project>(),
+            Faker.fake::<Project>(),
+            Faker.fake::<Project>(),
+        ];
         let state = State {
-            current_menu: Menu::Status,
+            projects: projects.to_owned(),
             ..State::default()
         };
-        assert_eq!(*state.current_menu(), Menu::Status);
+        assert_eq!(projects, *state.get_projects());
+    }
+
+    #[test]
+    fn set_projects() {
+        let mut state = State::default();
+        let projects = vec![
+            Faker.fake::<Project>(),
+            Faker.fake::<Project>(),
+            Faker.fake::<Project>(),
+        ];
+        state.set_projects(projects.to_owned());
+        assert_eq!(projects, state.projects);
     }
 
     #[test]
-    fn next_menu() {
+    fn remove_task_leaves_detail_view() {
+        let tasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
         let mut state = State {
-            current_menu: Menu::Status,
+            view_stack: vec![View::Welcome, View::ProjectTasks, View::TaskDetail],
+            current_task_detail: Some(tasks[1].clone()),
             ..State::default()
         };
-        state.next_menu();
-        assert_eq!(state.current_menu, Menu::Shortcuts);
-        state.next_menu();
-        assert_eq!(state.current_menu, Menu::TopList);
-        state.next_menu();
-        assert_eq!(state.current_menu, Menu::Status);
+        state.set_tasks(tasks.to_owned());
+        state.remove_task(&tasks[1].gid);
+        assert_eq!(vec![tasks[0].clone()], state.tasks);
+        assert!(state.get_task_detail().is_none());
+        assert_eq!(*state.current_view(), View::ProjectTasks);
     }
 
     #[test]
-    fn previous_menu() {
+    fn mark_custom_field_error() {
+        let field = Faker.fake::<CustomField>();
         let mut state = State {
-            current_menu: Menu::Status,
+            project_custom_fields: vec![field.clone()],
             ..State::default()
         };
-        state.previous_menu();
-        assert_eq!(state.current_menu, Menu::TopList);
-        state.previous_menu();
-        assert_eq!(state.current_menu, Menu::Shortcuts);
-        state.previous_menu();
-        assert_eq!(state.current_menu, Menu::Status);
+        let message = format!("custom_fields: {} is not a valid value", field.gid);
+        assert!(state.mark_custom_field_error(&message));
+        assert_eq!(state.get_custom_field_error(&field.gid), Some(&message));
+        assert!(!state.mark_custom_field_error("custom_fields: unknown"));
+        state.clear_custom_field_errors();
+        assert!(state.get_custom_field_error(&field.gid).is_none());
     }
 
     #[test]
-    fn select_status_menu() {
-        let mut state = State {
-            view_stack: vec![View::Welcome],
-            ..State::default()
-        };
-        state.select_status_menu();
-        assert_eq!(*state.view_stack.last().unwrap(), View::Welcome);
+    fn failed_attachment_preview_shows_error_and_is_retried() {
+        let (mut state, receiver) = state_with_net_receiver();
+        state.set_image_picker(Some(Picker::new((7, 14))));
+        let attachments: Vec<Attachment> = ["a.png", "b.png"]
+            .iter()
+            .map(|name| Attachment {
+                name: name.to_string(),
+                host: "asana".to_string(),
+                ..Faker.fake()
+            })
+            .collect();
+        let gid = attachments[0].gid.clone();
+        state.set_task_attachments(attachments);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::LoadAttachmentPreview { gid: requested }) if requested == gid
+        ));
+
+        state.set_attachment_preview_error(gid.clone(), "Not found".to_string());
+        assert_eq!(
+            state.get_selected_attachment_preview_error(),
+            Some("Not found")
+        );
+
+        // Selecting the attachment again retries the load
+        state.next_attachment();
+        let _ = receiver.try_recv();
+        state.previous_attachment();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::LoadAttachmentPreview { gid: requested }) if requested == gid
+        ));
+        assert_eq!(state.get_selected_attachment_preview_error(), None);
     }
 
     #[test]
-    fn current_shortcut_index() {
-        let state = State {
-            current_shortcut_index: 0,
-            ..State::default()
-        };
-        assert_eq!(*state.current_shortcut_index(), 0);
+    fn select_my_tasks_shortcut_resolves_list_then_opens_it() {
+        let (mut state, receiver) = state_with_net_receiver();
+        // My Tasks is the only shortcut without starred projects
+        state.shortcuts_list_state.select(Some(0));
+        state.select_current_shortcut_index();
+        assert!(state.is_my_tasks());
+        assert_eq!(*state.current_view(), View::ProjectTasks);
+        assert!(matches!(receiver.try_recv(), Ok(NetworkEvent::MyTasks)));
+
+        let list = Faker.fake::<Project>();
+        state.set_my_tasks_list(list.clone());
+        assert_eq!(state.get_project(), Some(&list));
+
+        // A list resolved after switching to a project is ignored
+        state.set_projects(vec![Faker.fake::<Project>()]);
+        state.projects_list_state.select(Some(0));
+        state.select_current_top_list_index();
+        assert!(!state.is_my_tasks());
+        state.set_my_tasks_list(list.clone());
+        assert_ne!(state.get_project(), Some(&list));
     }
 
     #[test]
-    fn next_shortcut_index() {
-        let mut state = State {
-            current_shortcut_index: 0,
-            ..State::default()
+    fn starred_project_named_like_a_base_shortcut_opens_the_project() {
+        let (mut state, receiver) = state_with_net_receiver();
+        let project = Project {
+            name: "My Tasks".to_string(),
+            ..Faker.fake()
         };
-        // Add starred projects to make shortcuts list non-empty
-        state.starred_projects.insert("proj1".to_string());
-        state.starred_projects.insert("proj2".to_string());
-        state.starred_projects.insert("proj3".to_string());
-        state
-            .starred_project_names
-            .insert("proj1".to_string(), "Project 1".to_string());
-        state
-            .starred_project_names
-            .insert("proj2".to_string(), "Project 2".to_string());
-        state
-            .starred_project_names
-            .insert("proj3".to_string(), "Project 3".to_string());
+        state.starred_projects.insert(project.gid.clone());
+        state.set_projects(vec![project.clone()]);
+        assert_eq!(
+            state.get_all_shortcuts(),
+            vec![
+                Shortcut::Project {
+                    gid: project.gid.clone(),
+                    name: project.name.clone()
+                },
+                Shortcut::MyTasks,
+                Shortcut::Portfolios
+            ]
+        );
+
         state.shortcuts_list_state.select(Some(0));
-        state.next_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 1);
-        state.next_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 2);
-        state.next_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 0);
+        state.select_current_shortcut_index();
+        assert!(!state.is_my_tasks());
+        assert_eq!(state.get_project(), Some(&project));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::ProjectTasks)
+        ));
     }
 
     #[test]
-    fn previous_shortcut_index() {
-        let mut state = State {
-            current_shortcut_index: 0,
-            ..State::default()
-        };
-        // Add starred projects to make shortcuts list non-empty
-        state.starred_projects.insert("proj1".to_string());
-        state.starred_projects.insert("proj2".to_string());
-        state.starred_projects.insert("proj3".to_string());
-        state
-            .starred_project_names
-            .insert("proj1".to_string(), "Project 1".to_string());
-        state
-            .starred_project_names
-            .insert("proj2".to_string(), "Project 2".to_string());
-        state
-            .starred_project_names
-            .insert("proj3".to_string(), "Project 3".to_string());
-        state.shortcuts_list_state.select(Some(0));
-        state.previous_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 2);
-        state.previous_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 1);
-        state.previous_shortcut_index();
-        assert_eq!(state.current_shortcut_index, 0);
+    fn switch_workspace_reloads_projects_and_hides_other_starred() {
+        let workspaces = vec![Faker.fake::<Workspace>(), Faker.fake::<Workspace>()];
+        let projects = [Faker.fake::<Project>(), Faker.fake::<Project>()];
+        let (mut state, receiver) = state_with_net_receiver();
+        state.workspaces = workspaces.clone();
+        state.active_workspace_gid = Some(workspaces[0].gid.clone());
+        state.view_stack = vec![View::Welcome, View::ProjectTasks];
+        state.project = Some(projects[0].clone());
+        state.starred_projects.insert(projects[0].gid.clone());
+        state.set_projects(vec![projects[0].clone()]);
+        assert_eq!(state.get_all_shortcuts()[0].name(), projects[0].name);
+
+        state.open_workspace_selector();
+        assert_eq!(state.get_workspace_dropdown_index(), 0);
+        state.next_workspace();
+        state.select_workspace();
+        assert!(!state.has_workspace_selector());
+        assert_eq!(
+            state.get_active_workspace_gid(),
+            Some(workspaces[1].gid.as_str())
+        );
+        assert_eq!(*state.current_view(), View::Welcome);
+        assert!(state.get_project().is_none());
+        assert!(matches!(receiver.try_recv(), Ok(NetworkEvent::GetProjects)));
+
+        // The starred project belongs to the previous workspace
+        state.set_projects(vec![projects[1].clone()]);
+        assert_eq!(state.get_all_shortcuts(), base_shortcuts());
     }
 
     #[test]
-    fn select_current_shortcut_index() {
+    fn starred_archived_project_stays_in_shortcuts() {
+        let workspaces = vec![Faker.fake::<Workspace>(), Faker.fake::<Workspace>()];
+        let project = Project {
+            archived: true,
+            ..Faker.fake()
+        };
         let mut state = State {
-            current_shortcut_index: 0,
-            current_focus: Focus::Menu,
+            workspaces: workspaces.clone(),
+            active_workspace_gid: Some(workspaces[0].gid.clone()),
             ..State::default()
         };
-        // Add starred projects to make shortcuts list non-empty
-        let project1 = Faker.fake::<Project>();
-        state.starred_projects.insert(project1.gid.clone());
+        state.starred_projects.insert(project.gid.clone());
         state
             .starred_project_names
-            .insert(project1.gid.clone(), project1.name.clone());
-        state.projects.push(project1);
-        state.shortcuts_list_state.select(Some(0));
-        state.select_current_shortcut_index();
-        // select_current_shortcut_index calls focus_view() which switches to View focus
-        assert_eq!(*state.current_focus(), Focus::View);
+            .insert(project.gid.clone(), project.name.clone());
+        state.set_starred_project_workspaces(HashMap::from([(
+            project.gid.clone(),
+            workspaces[0].gid.clone(),
+        )]));
+
+        // Archived projects are left out of the projects list
+        state.set_projects(vec![Faker.fake::<Project>()]);
+        assert_eq!(state.get_all_shortcuts()[0].name(), project.name);
+
+        state.active_workspace_gid = Some(workspaces[1].gid.clone());
+        assert_eq!(state.get_all_shortcuts(), base_shortcuts());
     }
 
     #[test]
-    fn current_top_list_index() {
-        let state = State {
-            current_top_list_index: 2,
-            ..State::default()
-        };
-        assert_eq!(*state.current_top_list_index(), 2);
+    fn team_browser_loads_team_projects_when_expanded() {
+        let teams = vec![Faker.fake::<Team>(), Faker.fake::<Team>()];
+        let project = Faker.fake::<Project>();
+        let (mut state, receiver) = state_with_net_receiver();
+        state.active_workspace_gid = Some("1".to_string());
+        state.toggle_team_browser();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetTeams { workspace_gid }) if workspace_gid == "1"
+        ));
+        state.set_teams(teams.clone());
+        assert_eq!(state.get_team_list_rows().len(), 2);
+
+        // Expanding a team loads its projects once
+        state.select_current_top_list_index();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetTeamProjects { team_gid }) if team_gid == teams[0].gid
+        ));
+        assert_eq!(state.get_team_list_rows()[1], TeamListRow::Loading);
+        state.set_team_projects(teams[0].gid.clone(), vec![project.clone()]);
+        assert_eq!(
+            state.get_team_list_rows()[1],
+            TeamListRow::Project(project.clone())
+        );
+
+        state.next_top_list_index();
+        state.select_current_top_list_index();
+        assert_eq!(state.get_project(), Some(&project));
+        assert_eq!(*state.current_view(), View::ProjectTasks);
+
+        // Collapsing and expanding again reuses the loaded projects
+        state.team_list_state.select(Some(0));
+        state.select_current_top_list_index();
+        assert_eq!(state.get_team_list_rows().len(), 2);
+        state.select_current_top_list_index();
+        assert_eq!(state.get_team_list_rows().len(), 3);
+        assert!(!receiver
+            .try_iter()
+            .any(|e| matches!(e, NetworkEvent::GetTeamProjects { .. })));
     }
 
     #[test]
-    fn next_top_list_index_when_nonempty() {
-        let mut state = State {
-            current_top_list_index: 0,
-            ..State::default()
-        };
-        let projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
-        state.set_projects(projects);
-        state.projects_list_state.select(Some(0));
-        state.next_top_list_index();
-        assert_eq!(state.current_top_list_index, 1);
-        state.next_top_list_index();
-        assert_eq!(state.current_top_list_index, 0);
+    fn team_browser_refreshes_only_expanded_teams() {
+        let teams = vec![Faker.fake::<Team>(), Faker.fake::<Team>()];
+        let (mut state, receiver) = state_with_net_receiver();
+        state.active_workspace_gid = Some("1".to_string());
+        state.toggle_team_browser();
+        state.set_teams(teams.clone());
+        state.set_team_projects(teams[0].gid.clone(), vec![Faker.fake::<Project>()]);
+        state.set_team_projects(teams[1].gid.clone(), vec![Faker.fake::<Project>()]);
+        state.expanded_teams.insert(teams[0].gid.clone());
+        let _ = receiver.try_iter().count();
+
+        state.refresh_team_projects();
+        let events: Vec<NetworkEvent> = receiver.try_iter().collect();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            NetworkEvent::GetTeamProjects { team_gid } if *team_gid == teams[0].gid
+        ));
+        // The collapsed team loads again when next expanded
+        assert!(!state.team_projects.contains_key(&teams[1].gid));
+
+        // Leaving the team browser loads the flat list it skipped
+        state.toggle_team_browser();
+        assert!(matches!(receiver.try_recv(), Ok(NetworkEvent::GetProjects)));
     }
 
     #[test]
-    fn next_top_list_index_when_empty() {
-        let mut state = State {
-            current_top_list_index: 0,
-            projects: vec![],
-            ..State::default()
+    fn portfolios_shortcut_lists_projects_and_posts_status_update() {
+        let portfolio = Faker.fake::<Portfolio>();
+        let project = PortfolioProject {
+            project: Faker.fake::<Project>(),
+            status_update: None,
         };
-        state.next_top_list_index();
-        assert_eq!(state.current_top_list_index, 0);
+        let (mut state, receiver) = state_with_net_receiver();
+        state.shortcuts_list_state.select(Some(1));
+        state.select_current_shortcut_index();
+        assert_eq!(*state.current_view(), View::Portfolios);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetPortfolios)
+        ));
+
+        state.set_portfolios(vec![portfolio.clone()]);
+        assert_eq!(state.get_portfolio_rows().len(), 1);
+        state.select_portfolio_row();
+        assert_eq!(state.get_portfolio_rows()[1], PortfolioRow::Loading);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetPortfolioProjects { portfolio_gid }) if portfolio_gid == portfolio.gid
+        ));
+        state.set_portfolio_projects(portfolio.gid.clone(), vec![project.clone()]);
+
+        // Portfolio headers cannot take a status update
+        state.open_status_update_form();
+        assert!(!state.has_status_update_form());
+        state.next_portfolio_row();
+        state.open_status_update_form();
+        assert_eq!(*state.current_view(), View::CreateStatusUpdate);
+        assert_eq!(
+            state.get_status_update_form().unwrap().status_type,
+            "on_track"
+        );
+
+        // Text is required, and the status isn't edited as text
+        state.submit_status_update_form();
+        assert!(state.has_status_update_form());
+        state.cycle_status_update_status(true);
+        state.edit_status_update_field();
+        assert!(!state.is_field_editing_mode());
+        state.next_status_update_field();
+        state.next_status_update_field();
+        state.edit_status_update_field();
+        assert!(state.is_field_editing_mode());
+        state.set_form_notes("Ok".to_string());
+        state.submit_status_update_form();
+        assert!(!state.has_status_update_form());
+        assert!(!state.is_field_editing_mode());
+        assert_eq!(*state.current_view(), View::Portfolios);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::CreateStatusUpdate { project_gid, status_type, text, .. })
+                if project_gid == project.project.gid && status_type == "at_risk" && text == "Ok"
+        ));
+
+        let update = Faker.fake::<StatusUpdate>();
+        state.set_project_status_update(&project.project.gid, update.clone());
+        assert_eq!(
+            state
+                .get_selected_portfolio_project()
+                .unwrap()
+                .status_update,
+            Some(update)
+        );
+
+        // Opening a project keeps the portfolios view to go back to
+        state.select_portfolio_row();
+        assert_eq!(*state.current_view(), View::ProjectTasks);
+        assert_eq!(state.pop_view(), Some(View::ProjectTasks));
+        assert_eq!(*state.current_view(), View::Portfolios);
     }
 
     #[test]
-    fn previous_top_list_index_when_nonempty() {
-        let mut state = State {
-            current_top_list_index: 0,
-            ..State::default()
+    fn failed_portfolio_projects_show_error_and_are_retried() {
+        let portfolios = vec![Faker.fake::<Portfolio>(), Faker.fake::<Portfolio>()];
+        let (mut state, receiver) = state_with_net_receiver();
+        state.set_portfolios(portfolios.clone());
+        state.select_portfolio_row();
+        state.set_portfolio_projects_error(portfolios[0].gid.clone(), "boom".to_string());
+        assert_eq!(
+            state.get_portfolio_rows(),
+            vec![
+                PortfolioRow::Portfolio(portfolios[0].clone(), true),
+                PortfolioRow::Failed("boom".to_string()),
+                PortfolioRow::Portfolio(portfolios[1].clone(), false),
+            ]
+        );
+
+        // Other portfolios still load
+        state.portfolios_list_state.select(Some(2));
+        state.select_portfolio_row();
+        state.set_portfolio_projects(portfolios[1].gid.clone(), vec![]);
+        assert_eq!(state.get_portfolio_rows().len(), 3);
+
+        state.portfolios_list_state.select(Some(1));
+        state.select_portfolio_row();
+        assert_eq!(state.get_portfolio_rows()[1], PortfolioRow::Loading);
+        let requested: Vec<String> = receiver
+            .try_iter()
+            .filter_map(|event| match event {
+                NetworkEvent::GetPortfolioProjects { portfolio_gid } => Some(portfolio_gid),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            requested,
+            vec![
+                portfolios[0].gid.clone(),
+                portfolios[1].gid.clone(),
+                portfolios[0].gid.clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn task_search_resolves_filters_and_pages_results() {
+        let mut project = Faker.fake::<Project>();
+        project.name = "Website".to_string();
+        let section = Section {
+            gid: "11".to_string(),
+            name: "Triage".to_string(),
         };
-        let projects = vec![Faker.fake::<Project>(), Faker.fake::<Project>()];
-        state.set_projects(projects);
-        state.projects_list_state.select(Some(0));
-        state.previous_top_list_index();
-        assert_eq!(state.current_top_list_index, 1);
-        state.previous_top_list_index();
-        assert_eq!(state.current_top_list_index, 0);
+        let (mut state, receiver) = state_with_net_receiver();
+        state.active_workspace_gid = Some("1".to_string());
+        state.projects = vec![project.clone()];
+        state.workspace_tags = vec![Faker.fake::<Tag>()];
+        state.workspace_tags_requested = Some(Instant::now());
+        state.open_task_search();
+        assert_eq!(*state.current_view(), View::TaskSearch);
+        assert!(state.has_task_search_form());
+
+        for c in "bug".chars() {
+            state.add_task_search_char(c);
+        }
+        state.next_task_search_field();
+        state.next_task_search_field();
+        for c in "web".chars() {
+            state.add_task_search_char(c);
+        }
+        assert_eq!(
+            state.get_task_search_match(TaskSearchField::Project),
+            Some("Website".to_string())
+        );
+        // Reaching the section filter loads the sections of the project
+        state.next_task_search_field();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetSearchProjectFields { project_gid }) if project_gid == project.gid
+        ));
+        for c in "tri".chars() {
+            state.add_task_search_char(c);
+        }
+        state.submit_task_search_form();
+        assert_eq!(
+            state.get_task_search_form().error.as_deref(),
+            Some("Sections are still loading")
+        );
+        state.set_search_project_fields(project.gid.clone(), vec![section], vec![]);
+
+        state.task_search_form.due_after = "2024-13-01".to_string();
+        state.submit_task_search_form();
+        assert!(state.has_task_search_form());
+        state.task_search_form.due_after = "2024-03-01".to_string();
+        state.submit_task_search_form();
+        assert!(!state.has_task_search_form());
+        let search = match receiver.try_recv() {
+            Ok(NetworkEvent::SearchTasks {
+                search,
+                created_before: None,
+            }) => search,
+            other => panic!("unexpected event {:?}", other),
+        };
+        assert_eq!(search.text.as_deref(), Some("bug"));
+        assert_eq!(search.project_gid, Some(project.gid.clone()));
+        assert_eq!(search.section_gid.as_deref(), Some("11"));
+        assert_eq!(search.due_after.as_deref(), Some("2024-03-01"));
+
+        // Reaching the last result fetches the next page
+        let tasks = vec![Faker.fake::<Task>(), Faker.fake::<Task>()];
+        state.add_task_search_results(&search, tasks.clone(), Some("2024-02-01".to_string()));
+        assert!(state.has_more_task_search_results());
+        state.next_task_search_result();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::SearchTasks { created_before: Some(before), .. }) if before == "2024-02-01"
+        ));
+
+        state.open_selected_task_search_result();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::GetTaskDetail { gid }) if gid == tasks[1].gid
+        ));
+        assert_eq!(*state.current_view(), View::TaskDetail);
+        state.pop_view();
+        assert_eq!(*state.current_view(), View::TaskSearch);
+
+        // The next page repeats the tasks sharing its boundary time
+        let newer = Faker.fake::<Task>();
+        let page = vec![tasks[1].clone(), newer.clone()];
+        state.add_task_search_results(&search, page, Some("2024-01-01".to_string()));
+        let gids: Vec<&str> = state
+            .get_task_search_results()
+            .iter()
+            .map(|t| t.gid.as_str())
+            .collect();
+        assert_eq!(gids, vec![&tasks[0].gid, &tasks[1].gid, &newer.gid]);
+        assert!(state.has_more_task_search_results());
+        state.add_task_search_results(&search, vec![newer], Some("2024-01-01".to_string()));
+        assert!(!state.has_more_task_search_results());
     }
 
     #[test]
-    fn previous_top_list_index_when_empty() {
-        let mut state = State {
-            current_top_list_index: 0,
-            projects: vec![],
-            ..State::default()
+    fn typeahead_lookups_are_debounced_cached_and_ranked() {
+        let (mut state, receiver) = state_with_net_receiver();
+        state.active_workspace_gid = Some("1".to_string());
+        for c in "Ali".chars() {
+            state.add_assignee_search_char(c);
+        }
+        // Nothing is sent until typing pauses, then only the latest query
+        state.dispatch_due_typeahead();
+        assert!(receiver.try_recv().is_err());
+        state
+            .typeahead_pending
+            .get_mut(&TypeaheadKind::User)
+            .unwrap()
+            .1 = Instant::now();
+        state.dispatch_due_typeahead();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(NetworkEvent::Typeahead { kind: TypeaheadKind::User, query, .. }) if query == "ali"
+        ));
+
+        let user = |gid: &str, name: &str| User {
+            gid: gid.to_string(),
+            name: name.to_string(),
+            email: String::new(),
         };
-        state.previous_top_list_index();
-        assert_eq!(state.current_top_list_index, 0);
+        state.merge_workspace_users(vec![user("2", "Alison")]);
+        state.set_typeahead_users("ali", vec![user("3", "Bob Smith"), user("2", "Alison")]);
+        let names: Vec<String> = state
+            .get_filtered_assignees()
+            .into_iter()
+            .map(|u| u.name)
+            .collect();
+        assert_eq!(names, vec!["Bob Smith", "Alison"]);
+
+        // Cached queries are not looked up again
+        state.backspace_assignee_search();
+        state.add_assignee_search_char('i');
+        assert!(!state.typeahead_pending.contains_key(&TypeaheadKind::User));
+
+        // Users are not looked up once every user is loaded
+        state.set_workspace_users(vec![user("2", "Alison")]);
+        state.add_assignee_search_char('s');
+        assert!(!state.typeahead_pending.contains_key(&TypeaheadKind::User));
     }
 
     #[test]
-    fn current_view() {
-        let state = State {
-            view_stack: vec![View::Welcome],
+    fn typeahead_fallback_runs_once_per_workspace() {
+        let mut state = State {
+            active_workspace_gid: Some("1".to_string()),
             ..State::default()
         };
-        assert_eq!(*state.current_view(), View::Welcome);
+        assert!(state.claim_typeahead_fallback(TypeaheadKind::User));
+        assert!(!state.claim_typeahead_fallback(TypeaheadKind::User));
+        assert!(state.claim_typeahead_fallback(TypeaheadKind::Tag));
+        // A failed load can be tried again
+        state.release_typeahead_fallback(TypeaheadKind::Tag);
+        assert!(state.claim_typeahead_fallback(TypeaheadKind::Tag));
+        state.switch_workspace("2".to_string());
+        assert!(state.claim_typeahead_fallback(TypeaheadKind::User));
     }
 
     #[test]
-    fn set_tasks() {
+    fn form_dates_are_validated_and_only_changes_sent() {
         let mut state = State::default();
-        let tasks = vec![
-            Faker.fake::<Task>(),
-            Faker.fake::<Task>(),
-            Faker.fake::<Task>(),
-        ];
-        state.set_tasks(tasks.to_owned());
-        assert_eq!(tasks, state.tasks);
+        let type_into = |state: &mut State, field, text: &str| {
+            state.set_edit_form_state(Some(field));
+            while !state.form_date_text_mut().unwrap().is_empty() {
+                state.remove_form_date_char();
+            }
+            for c in text.chars() {
+                state.add_form_date_char(c);
+            }
+        };
+        type_into(&mut state, EditFormState::StartDate, "2024-05-03");
+        type_into(&mut state, EditFormState::DueTime, "9:5");
+        assert_eq!(
+            state.get_form_date_error(EditFormState::StartDate),
+            Some("A start date needs a due date".to_string())
+        );
+        assert!(state.get_form_date_error(EditFormState::DueTime).is_some());
+        assert_eq!(
+            state.get_form_task_dates(false).map_err(|(field, _)| field),
+            Err(EditFormState::StartDate)
+        );
+
+        type_into(&mut state, EditFormState::DueDate, "2024-05-01");
+        assert_eq!(
+            state.get_form_date_error(EditFormState::StartDate),
+            Some("Start date must not be after the due date".to_string())
+        );
+        type_into(&mut state, EditFormState::StartDate, "2024-04-29");
+        type_into(&mut state, EditFormState::DueTime, "");
+        assert_eq!(
+            state.get_form_task_dates(false),
+            Ok(TaskDates {
+                start_on: Some("2024-04-29".to_string()),
+                due_on: Some("2024-05-01".to_string()),
+                due_at: None,
+            })
+        );
+
+        // Editing sends only what changed, clearing emptied dates
+        let mut task = Faker.fake::<Task>();
+        task.start_on = Some("2024-04-29".to_string());
+        task.due_on = Some("2024-05-01".to_string());
+        task.due_at = None;
+        state.init_edit_form(&task);
+        assert_eq!(state.get_form_task_dates(true), Ok(TaskDates::default()));
+        type_into(&mut state, EditFormState::StartDate, "");
+        type_into(&mut state, EditFormState::DueTime, "17:30");
+        let changed = state.get_form_task_dates(true).unwrap();
+        assert_eq!(changed.start_on, Some(String::new()));
+        assert_eq!(changed.due_on, None);
+        let due_at = changed.due_at.unwrap();
+        assert_eq!(
+            dates::utc_to_local(&due_at),
+            Some(("2024-05-01".to_string(), "17:30".to_string()))
+        );
     }
 
     #[test]
-    fn get_projects() {
-        let projects = vec![
-            Faker.fake::<Project>(),
-            Faker.fake::<Project>(),
-            Faker.fake::<Project>(),
-        ];
-        let state = State {
-            projects: projects.to_owned(),
-            ..State::default()
-        };
-        assert_eq!(projects, *state.get_projects());
+    fn edit_form_notes_are_markdown_of_rich_text() {
+        let mut state = State::default();
+        let mut task = Faker.fake::<Task>();
+        task.notes = Some("Ship it\ntoday".to_string());
+        task.html_notes =
+            Some("<body><em>Ship</em> it\n<ul><li>today</li></ul></body>".to_string());
+        state.init_edit_form(&task);
+        assert_eq!(state.get_form_notes(), "*Ship* it\n- today");
+        assert_eq!(state.get_original_form_notes(), state.get_form_notes());
+
+        task.html_notes = None;
+        state.init_edit_form(&task);
+        assert_eq!(state.get_form_notes(), "Ship it\ntoday");
+        assert_eq!(state.check_form_notes_saveable(), Ok(()));
+
+        // Notes with an inline image save only when left unchanged
+        task.html_notes = Some("<body>See <img data-asana-gid=\"5\"/></body>".to_string());
+        state.init_edit_form(&task);
+        assert_eq!(state.check_form_notes_saveable(), Ok(()));
+        state.set_form_notes("See below".to_string());
+        assert!(state
+            .check_form_notes_saveable()
+            .is_err_and(|e| e.contains("<img>")));
     }
 
     #[test]
-    fn set_projects() {
+    fn typed_and_picked_dates_are_sent_as_iso_dates() {
         let mut state = State::default();
-        let projects = vec![
-            Faker.fake::<Project>(),
-            Faker.fake::<Project>(),
-            Faker.fake::<Project>(),
-        ];
-        state.set_projects(projects.to_owned());
-        assert_eq!(projects, state.projects);
+        let today = chrono::Local::now().date_naive();
+        let iso = |date: chrono::NaiveDate| date.format(dates::ISO_DATE).to_string();
+        state.set_edit_form_state(Some(EditFormState::DueDate));
+        for c in "tomorrow".chars() {
+            state.add_form_date_char(c);
+        }
+        let tomorrow = today + chrono::Days::new(1);
+        assert_eq!(
+            state.get_form_task_dates(false).unwrap().due_on,
+            Some(iso(tomorrow))
+        );
+
+        // The calendar opens on the typed date and fills in the chosen one
+        state.open_date_picker();
+        assert_eq!(state.get_date_picker().unwrap().cursor, tomorrow);
+        state.move_date_picker_days(7).move_date_picker_days(-1);
+        state.select_date_picker();
+        assert!(!state.has_date_picker());
+        assert_eq!(
+            state.get_form_due_on(),
+            iso(tomorrow + chrono::Days::new(6))
+        );
+        state.set_edit_form_state(Some(EditFormState::DueTime));
+        state.open_date_picker();
+        assert!(!state.has_date_picker());
+
+        // Date custom fields that don't parse are not sent
+        let mut field = Faker.fake::<CustomField>();
+        field.gid = "9".to_string();
+        field.resource_subtype = "date".to_string();
+        state.project_custom_fields = vec![field];
+        state.set_edit_form_state(Some(EditFormState::CustomField(0)));
+        for c in "soon".chars() {
+            state.add_custom_field_text_char("9".to_string(), c, "date");
+        }
+        assert_eq!(
+            state
+                .get_submit_custom_field_values()
+                .map_err(|(field, _)| field),
+            Err(EditFormState::CustomField(0))
+        );
+        state.open_date_picker();
+        assert_eq!(state.get_date_picker().unwrap().cursor, today);
+        state.close_date_picker();
+        for _ in 0.."soon".len() {
+            state.remove_custom_field_text_char("9", "date");
+        }
+        for c in "+3d".chars() {
+            state.add_custom_field_text_char("9".to_string(), c, "date");
+        }
+        assert_eq!(
+            state.get_submit_custom_field_values().unwrap().get("9"),
+            Some(&CustomFieldValue::Date(Some(iso(
+                today + chrono::Days::new(3)
+            ))))
+        );
     }
 }
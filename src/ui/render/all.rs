@@ -24,10 +24,16 @@ pub fn all(frame: &mut Frame, state: &mut State) {
 /// Render left widgets to terminal frame according to state.
 ///
 fn left(frame: &mut Frame, size: Rect, state: &mut State) {
+    // Grow the status block while a request retry is pending
+    let status_height = if state.get_retry_status().is_some() {
+        6
+    } else {
+        5
+    };
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(5),
+            Constraint::Length(status_height),
             Constraint::Length(5),
             Constraint::Min(1),
         ])
@@ -0,0 +1,95 @@
+use super::create_task::{render_field, render_notes_field};
+use super::portfolios;
+use super::Frame;
+use crate::state::{State, StatusUpdateField};
+use crate::ui::widgets::styling;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Render the status update form for a portfolio project.
+///
+pub fn create_status_update(frame: &mut Frame, size: Rect, state: &mut State) {
+    let form = match state.get_status_update_form() {
+        Some(form) => form.clone(),
+        None => return,
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Status
+            Constraint::Length(3), // Title field
+            Constraint::Min(5),    // Text
+        ])
+        .split(size);
+
+    let title_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Post Status Update");
+    let title = Paragraph::new(form.project_name.as_str())
+        .block(title_block)
+        .alignment(Alignment::Center);
+    frame.render_widget(title, chunks[0]);
+
+    let is_editing = state.is_field_editing_mode();
+    render_status_field(
+        frame,
+        chunks[1],
+        &form.status_type,
+        form.field == StatusUpdateField::Status,
+        state,
+    );
+    render_field(
+        frame,
+        chunks[2],
+        "Title",
+        state.get_form_name(),
+        form.field == StatusUpdateField::Title,
+        is_editing && form.field == StatusUpdateField::Title,
+        state,
+    );
+    render_notes_field(
+        frame,
+        chunks[3],
+        state,
+        "Text",
+        form.field == StatusUpdateField::Text,
+        is_editing && form.field == StatusUpdateField::Text,
+    );
+}
+
+/// Render the status picked with left and right, in its status color.
+///
+fn render_status_field(
+    frame: &mut Frame,
+    size: Rect,
+    status_type: &str,
+    is_selected: bool,
+    state: &State,
+) {
+    let theme = state.get_theme();
+    let (status, status_color) = portfolios::status_label(theme, status_type);
+    let (border_style, title) = if is_selected {
+        (
+            styling::active_block_border_style(theme),
+            "Status [←/→ to change]",
+        )
+    } else {
+        (styling::normal_block_border_style(theme), "Status")
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+    let text = Line::from(Span::styled(
+        format!("< {} >", status),
+        Style::default()
+            .fg(status_color)
+            .add_modifier(Modifier::BOLD),
+    ));
+    frame.render_widget(Paragraph::new(text).block(block), size);
+}
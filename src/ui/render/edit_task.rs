@@ -2,7 +2,7 @@ use super::form_dropdowns;
 use super::Frame;
 use crate::asana::CustomField;
 use crate::state::{CustomFieldValue, EditFormState, State};
-use crate::ui::widgets::styling;
+use crate::ui::widgets::{date_input, styling};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -50,7 +50,7 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
         .collect();
 
     // Calculate which fields to show based on available height
-    // Fields have different heights: Name/Assignee/DueDate/Section = 3, Notes = 5, Custom dropdowns = 7+
+    // Fields have different heights: Name/Assignee/dates/Section/Tags = 3, Notes = 5, Custom dropdowns = 7+
     let available_height = chunks[1].height;
     let is_editing = state.is_field_editing_mode();
 
@@ -66,12 +66,20 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
         } else {
             3
         };
+        let tags_height = if is_editing && matches!(form_state, EditFormState::Tags) {
+            10 // Search (3) + dropdown (7)
+        } else {
+            3
+        };
         let mut fields = vec![
             (0, "Name", None, 3),
             (1, "Notes", None, 5),
             (2, "Assignee", None, assignee_height),
-            (3, "DueDate", None, 3),
-            (4, "Section", None, section_height),
+            (3, "StartDate", None, 3),
+            (4, "DueDate", None, 3),
+            (5, "DueTime", None, 3),
+            (6, "Section", None, section_height),
+            (7, "Tags", None, tags_height),
         ];
         for (idx, cf) in custom_fields.iter().enumerate() {
             let height = match cf.resource_subtype.as_str() {
@@ -86,7 +94,7 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
                 }
                 _ => 3,
             };
-            fields.push((5 + idx, "CustomField", Some(idx), height));
+            fields.push((8 + idx, "CustomField", Some(idx), height));
         }
         fields
     };
@@ -96,9 +104,12 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
         EditFormState::Name => 0,
         EditFormState::Notes => 1,
         EditFormState::Assignee => 2,
-        EditFormState::DueDate => 3,
-        EditFormState::Section => 4,
-        EditFormState::CustomField(cf_idx) => 5 + cf_idx,
+        EditFormState::StartDate => 3,
+        EditFormState::DueDate => 4,
+        EditFormState::DueTime => 5,
+        EditFormState::Section => 6,
+        EditFormState::Tags => 7,
+        EditFormState::CustomField(cf_idx) => 8 + cf_idx,
     };
 
     // Calculate visible range centered around current field (like kanban board)
@@ -240,16 +251,49 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
                     );
                 }
             }
-            "DueDate" => {
+            "StartDate" | "DueDate" | "DueTime" => {
+                let (field, label, value) = match *field_type {
+                    "StartDate" => (
+                        EditFormState::StartDate,
+                        "Start Date",
+                        state.get_form_start_on(),
+                    ),
+                    "DueDate" => (EditFormState::DueDate, "Due Date", state.get_form_due_on()),
+                    _ => (
+                        EditFormState::DueTime,
+                        "Due Time (HH:MM, local time)",
+                        state.get_form_due_time(),
+                    ),
+                };
                 render_field(
                     frame,
                     form_chunks[chunk_idx],
-                    "Due Date (YYYY-MM-DD)",
-                    state.get_form_due_on(),
-                    form_state == EditFormState::DueDate,
-                    is_editing && form_state == EditFormState::DueDate,
+                    label,
+                    value,
+                    form_state == field,
+                    is_editing && form_state == field,
                     state,
                 );
+                let error = state.get_form_date_error(field);
+                if field == EditFormState::DueTime {
+                    if let Some(error) = error {
+                        form_dropdowns::render_field_error(
+                            frame,
+                            form_chunks[chunk_idx],
+                            &error,
+                            state,
+                        );
+                    }
+                } else {
+                    date_input::render_preview(
+                        frame,
+                        form_chunks[chunk_idx],
+                        value,
+                        error,
+                        is_editing && form_state == field,
+                        state,
+                    );
+                }
             }
             "Section" => {
                 // Show dropdown only when in editing mode, like custom fields
@@ -278,6 +322,34 @@ pub fn edit_task(frame: &mut Frame, size: Rect, state: &mut State) {
                     );
                 }
             }
+            "Tags" => {
+                // Show multi-select dropdown only when in editing mode
+                if is_editing && form_state == EditFormState::Tags {
+                    form_dropdowns::render_tag_dropdown(frame, form_chunks[chunk_idx], state);
+                } else {
+                    let selected = state.get_form_tags();
+                    let tags_text = state
+                        .get_workspace_tags()
+                        .iter()
+                        .filter(|t| selected.contains(&t.gid))
+                        .map(|t| t.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    render_field(
+                        frame,
+                        form_chunks[chunk_idx],
+                        "Tags (multi-select)",
+                        if tags_text.is_empty() {
+                            "None"
+                        } else {
+                            &tags_text
+                        },
+                        form_state == EditFormState::Tags,
+                        false,
+                        state,
+                    );
+                }
+            }
             "CustomField" => {
                 if let Some(cf_idx) = custom_idx {
                     if let Some(cf) = custom_fields.get(*cf_idx) {
@@ -392,17 +464,20 @@ fn render_notes_field(
             Style::default()
                 .fg(theme.warning.to_color())
                 .add_modifier(Modifier::BOLD),
-            "Notes [EDITING - Esc to exit]",
+            "Notes (Markdown) [EDITING - Esc to exit]",
         )
     } else if is_selected {
         // SELECTED (Navigation mode): Cyan border
         (
             styling::active_block_border_style(theme),
-            "Notes [Press Enter to edit]",
+            "Notes (Markdown) [Press Enter to edit]",
         )
     } else {
         // Not selected: Normal border
-        (styling::normal_block_border_style(theme), "Notes")
+        (
+            styling::normal_block_border_style(theme),
+            "Notes (Markdown)",
+        )
     };
 
     let block = Block::default()
@@ -429,6 +504,11 @@ fn render_custom_field_inner(
     is_selected: bool,
     is_editing: bool,
 ) {
+    // Flag fields rejected by the API on the last save
+    let name = match state.get_custom_field_error(&cf.gid) {
+        Some(message) => format!("{} ⚠ {}", cf.name, message),
+        None => cf.name.clone(),
+    };
     match cf.resource_subtype.as_str() {
         "text" => {
             let text_value = match &value {
@@ -438,7 +518,7 @@ fn render_custom_field_inner(
             render_field(
                 frame,
                 size,
-                &cf.name,
+                &name,
                 &text_value,
                 is_selected,
                 is_editing,
@@ -454,7 +534,7 @@ fn render_custom_field_inner(
             render_field(
                 frame,
                 size,
-                &format!("{} (number)", cf.name),
+                &format!("{} (number)", name),
                 &num_value,
                 is_selected,
                 is_editing,
@@ -470,12 +550,13 @@ fn render_custom_field_inner(
             render_field(
                 frame,
                 size,
-                &format!("{} (YYYY-MM-DD)", cf.name),
+                &name,
                 &date_value,
                 is_selected,
                 is_editing,
                 state,
             );
+            date_input::render_preview(frame, size, &date_value, None, is_editing, state);
         }
         "enum" => {
             if is_editing {
@@ -494,7 +575,7 @@ fn render_custom_field_inner(
                 render_field(
                     frame,
                     size,
-                    &format!("{} (dropdown)", cf.name),
+                    &format!("{} (dropdown)", name),
                     &selected_text,
                     is_selected,
                     false,
@@ -519,7 +600,7 @@ fn render_custom_field_inner(
                 render_field(
                     frame,
                     size,
-                    &format!("{} (multi-select)", cf.name),
+                    &format!("{} (multi-select)", name),
                     if selected.is_empty() {
                         "None"
                     } else {
@@ -548,7 +629,7 @@ fn render_custom_field_inner(
                 render_field(
                     frame,
                     size,
-                    &format!("{} (people)", cf.name),
+                    &format!("{} (people)", name),
                     &selected_text,
                     is_selected,
                     false,
@@ -560,7 +641,7 @@ fn render_custom_field_inner(
             render_field(
                 frame,
                 size,
-                &cf.name,
+                &name,
                 "Unsupported type",
                 is_selected,
                 false,
@@ -698,13 +779,7 @@ fn render_people_dropdown(
     frame.render_widget(search_para, chunks[0]);
 
     // Filtered users list - limit to max 5 visible items
-    let users = state.get_workspace_users();
-    let filtered: Vec<_> = users
-        .iter()
-        .filter(|u| {
-            search_text.is_empty() || u.name.to_lowercase().contains(&search_text.to_lowercase())
-        })
-        .collect();
+    let filtered = state.get_filtered_people(cf_gid);
     let selected_index = state.get_custom_field_dropdown_index(cf_gid);
 
     let selected_gids: Vec<String> = match state.get_custom_field_value(cf_gid) {
@@ -16,8 +16,12 @@ fn format_hotkeys_for_view(view: &crate::state::View, state: &State) -> String {
         crate::state::View::Welcome => &hotkeys.welcome,
         crate::state::View::ProjectTasks => &hotkeys.project_tasks,
         crate::state::View::TaskDetail => &hotkeys.task_detail,
-        crate::state::View::CreateTask => &hotkeys.create_task,
+        crate::state::View::CreateTask | crate::state::View::CreateStatusUpdate => {
+            &hotkeys.create_task
+        }
         crate::state::View::EditTask => &hotkeys.edit_task,
+        crate::state::View::Portfolios => &hotkeys.portfolios,
+        crate::state::View::TaskSearch => &hotkeys.task_search,
     };
 
     match view {
@@ -37,6 +41,21 @@ fn format_hotkeys_for_view(view: &crate::state::View, state: &State) -> String {
                 (HotkeyAction::EditTask, "edit", None),
                 (HotkeyAction::DeleteTask, "delete", None),
                 (HotkeyAction::AddComment, "comment", None),
+                (HotkeyAction::AddSubtask, "subtask", None),
+                (HotkeyAction::AddDependency, "block", None),
+                (HotkeyAction::RemoveDependency, "unblock", None),
+                (HotkeyAction::UploadAttachment, "attach", None),
+                (HotkeyAction::DownloadAttachment, "save file", None),
+                (HotkeyAction::AddToProject, "add to project", None),
+                (HotkeyAction::MoveToProject, "move to project", None),
+                (HotkeyAction::RemoveFromProject, "remove from project", None),
+                (HotkeyAction::EditFollowers, "followers", None),
+                (HotkeyAction::ToggleFollow, "follow/unfollow", None),
+                (HotkeyAction::EditComment, "edit comment", None),
+                (HotkeyAction::DeleteComment, "delete comment", None),
+                (HotkeyAction::LikeComment, "like", None),
+                (HotkeyAction::PinComment, "pin", None),
+                (HotkeyAction::ToggleTaskComplete, "toggle subtask", None),
                 (HotkeyAction::Back, "back", None),
                 (HotkeyAction::Quit, "quit", None),
             ],
@@ -76,12 +95,74 @@ fn format_hotkeys_for_view(view: &crate::state::View, state: &State) -> String {
                 (HotkeyAction::ViewTask, "view", None),
                 (HotkeyAction::CreateTask, "create", None),
                 (HotkeyAction::MoveTask, "move", None),
+                (HotkeyAction::MarkTask, "mark", None),
+                (HotkeyAction::AddComment, "comment on marked", None),
                 (HotkeyAction::FilterByAssignee, "filter by assignee", None),
+                (HotkeyAction::AddSection, "add column", None),
+                (HotkeyAction::RenameSection, "rename column", None),
+                (HotkeyAction::DeleteSection, "delete empty column", None),
+                (
+                    HotkeyAction::MoveSectionLeft,
+                    "reorder column",
+                    Some(HotkeyAction::MoveSectionRight),
+                ),
                 (HotkeyAction::EnterSearch, "search", None),
                 (HotkeyAction::Back, "back", None),
                 (HotkeyAction::Quit, "quit", None),
             ],
         ),
+        crate::state::View::Portfolios => build_footer_text(
+            view_hotkeys,
+            &[
+                (
+                    HotkeyAction::NavigateNext,
+                    "navigate",
+                    Some(HotkeyAction::NavigatePrev),
+                ),
+                (HotkeyAction::Select, "open project", None),
+                (HotkeyAction::PostStatusUpdate, "post status update", None),
+                (HotkeyAction::Back, "back", None),
+                (HotkeyAction::Quit, "quit", None),
+            ],
+        ),
+        crate::state::View::CreateStatusUpdate => {
+            if state.is_field_editing_mode() {
+                " Type to edit, Esc: back to navigation".to_string()
+            } else {
+                build_footer_text(
+                    view_hotkeys,
+                    &[
+                        (
+                            HotkeyAction::NavigateNext,
+                            "navigate fields",
+                            Some(HotkeyAction::NavigatePrev),
+                        ),
+                        (
+                            HotkeyAction::NavigateLeft,
+                            "change status",
+                            Some(HotkeyAction::NavigateRight),
+                        ),
+                        (HotkeyAction::EditField, "edit field", None),
+                        (HotkeyAction::SubmitForm, "post", None),
+                        (HotkeyAction::Cancel, "cancel", None),
+                    ],
+                )
+            }
+        }
+        crate::state::View::TaskSearch => build_footer_text(
+            view_hotkeys,
+            &[
+                (
+                    HotkeyAction::NavigateNext,
+                    "navigate",
+                    Some(HotkeyAction::NavigatePrev),
+                ),
+                (HotkeyAction::Select, "open task", None),
+                (HotkeyAction::OpenTaskSearch, "edit filters", None),
+                (HotkeyAction::Back, "back", None),
+                (HotkeyAction::Quit, "quit", None),
+            ],
+        ),
         crate::state::View::Welcome => {
             // For Welcome view, we need special handling for the 4-key navigation display
             let mut parts = Vec::new();
@@ -105,9 +186,16 @@ fn format_hotkeys_for_view(view: &crate::state::View, state: &State) -> String {
                 view_hotkeys,
                 &[
                     (HotkeyAction::ToggleStar, "add/remove shortcut", None),
+                    (HotkeyAction::CreateProject, "new project", None),
+                    (HotkeyAction::EditProject, "edit project", None),
+                    (HotkeyAction::ArchiveProject, "archive/unarchive", None),
+                    (HotkeyAction::ToggleArchivedProjects, "show archived", None),
+                    (HotkeyAction::ToggleTeamBrowser, "my teams", None),
                     (HotkeyAction::EnterSearch, "search", None),
+                    (HotkeyAction::OpenTaskSearch, "find tasks", None),
                     (HotkeyAction::EnterDebug, "debug mode", None),
                     (HotkeyAction::OpenThemeSelector, "themes", None),
+                    (HotkeyAction::OpenWorkspaceSelector, "workspaces", None),
                     (HotkeyAction::OpenHotkeyEditor, "hotkeys", None),
                     (HotkeyAction::Select, "select", None),
                     (HotkeyAction::Cancel, "cancel", None),
@@ -182,7 +270,7 @@ pub fn footer(frame: &mut Frame, size: Rect, state: &State) {
         )
     } else if state.has_assignee_filter() {
         format!(
-            " Type to search, ↑↓: navigate, {}",
+            " Type to search, ↑↓: navigate, Tab: assignees/tags, {}",
             build_footer_text(
                 &hotkeys.assignee_filter,
                 &[
@@ -300,7 +388,9 @@ pub fn footer(frame: &mut Frame, size: Rect, state: &State) {
         ])
     } else if matches!(
         state.current_view(),
-        crate::state::View::CreateTask | crate::state::View::EditTask
+        crate::state::View::CreateTask
+            | crate::state::View::EditTask
+            | crate::state::View::CreateStatusUpdate
     ) && state.is_field_editing_mode()
     {
         // Show EDIT mode indicator
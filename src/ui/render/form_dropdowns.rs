@@ -83,21 +83,7 @@ fn render_dropdown_generic(
 /// Uses the same component as custom field dropdowns.
 pub fn render_assignee_dropdown(frame: &mut Frame, area: Rect, state: &State) {
     let search_text = state.get_assignee_search();
-    let filtered: Vec<_> = state
-        .get_workspace_users()
-        .iter()
-        .filter(|user| {
-            search_text.is_empty()
-                || user
-                    .name
-                    .to_lowercase()
-                    .contains(&search_text.to_lowercase())
-                || user
-                    .email
-                    .to_lowercase()
-                    .contains(&search_text.to_lowercase())
-        })
-        .collect();
+    let filtered = state.get_filtered_assignees();
     let selected_index = state.get_assignee_dropdown_index();
 
     let items: Vec<ListItem> = filtered
@@ -127,6 +113,40 @@ pub fn render_assignee_dropdown(frame: &mut Frame, area: Rect, state: &State) {
     );
 }
 
+/// Render follower multi-select dropdown over the filtered workspace users.
+pub fn render_follower_dropdown(frame: &mut Frame, area: Rect, state: &State) {
+    let filtered = state.get_filtered_assignees();
+    let items: Vec<ListItem> = filtered
+        .iter()
+        .map(|user| {
+            let prefix = if state.is_task_follower(&user.gid) {
+                "[✓] "
+            } else {
+                "[ ] "
+            };
+            if !user.email.is_empty() {
+                ListItem::new(format!("{}{} ({})", prefix, user.name, user.email))
+            } else {
+                ListItem::new(format!("{}{}", prefix, user.name))
+            }
+        })
+        .collect();
+
+    render_dropdown_generic(
+        frame,
+        area,
+        state.get_assignee_search(),
+        "Search Followers",
+        items,
+        state.get_assignee_dropdown_index(),
+        &format!(
+            "Followers ({} results, ↑ / ↓ to navigate, Enter to toggle, Esc to close)",
+            filtered.len()
+        ),
+        state,
+    );
+}
+
 /// Render section dropdown with search and filtered list.
 /// Uses the same component as custom field dropdowns.
 pub fn render_section_dropdown(frame: &mut Frame, area: Rect, state: &State) {
@@ -164,6 +184,108 @@ pub fn render_section_dropdown(frame: &mut Frame, area: Rect, state: &State) {
     );
 }
 
+/// Render the task project picker: a searchable list of projects, then of
+/// sections of the chosen project.
+pub fn render_project_picker_dropdown(frame: &mut Frame, area: Rect, state: &State) {
+    use crate::state::ProjectPickerAction;
+
+    let picker = match state.get_project_picker() {
+        Some(picker) => picker,
+        None => return,
+    };
+    let filtered = state.get_project_picker_items();
+    let mut items: Vec<ListItem> = filtered
+        .iter()
+        .map(|(_, name)| ListItem::new(name.clone()))
+        .collect();
+
+    let (search_title, dropdown_title) = match (&picker.target, &picker.sections) {
+        (Some((_, project)), None) => {
+            items.push(ListItem::new("Loading sections..."));
+            (
+                format!("Search Sections of {}", project),
+                "Section".to_string(),
+            )
+        }
+        (Some((_, project)), Some(sections)) => {
+            if let Some(error) = &picker.sections_error {
+                items.push(ListItem::new(format!(
+                    "Couldn't load sections ({}), Enter to add without one",
+                    error
+                )));
+            } else if sections.is_empty() {
+                items.push(ListItem::new("No sections, Enter to add without one"));
+            }
+            (
+                format!("Search Sections of {}", project),
+                "Section".to_string(),
+            )
+        }
+        (None, _) => {
+            let title = match picker.action {
+                ProjectPickerAction::Add => "Add to Project",
+                ProjectPickerAction::Move => "Move to Project",
+                ProjectPickerAction::Remove => "Remove from Project",
+            };
+            ("Search Projects".to_string(), title.to_string())
+        }
+    };
+
+    render_dropdown_generic(
+        frame,
+        area,
+        &picker.search,
+        &search_title,
+        items,
+        picker.index,
+        &format!(
+            "{} ({} results, ↑ / ↓ arrow to navigate, Enter to select)",
+            dropdown_title,
+            filtered.len()
+        ),
+        state,
+    );
+}
+
+/// Render tag multi-select dropdown with search and filtered list.
+/// Offers to create a new tag when the search matches no existing tag name.
+pub fn render_tag_dropdown(frame: &mut Frame, area: Rect, state: &State) {
+    let search_text = state.get_tag_search();
+    let filtered = state.get_filtered_tags();
+    let selected_tags = state.get_form_tags();
+    let selected_index = state.get_tag_dropdown_index();
+
+    let mut items: Vec<ListItem> = filtered
+        .iter()
+        .map(|tag| {
+            let prefix = if selected_tags.contains(&tag.gid) {
+                "[✓] "
+            } else {
+                "[ ] "
+            };
+            ListItem::new(format!("{}{}", prefix, tag.name))
+        })
+        .collect();
+    if let Some(name) = state.get_new_tag_name() {
+        items.push(ListItem::new(format!("+ Create tag \"{}\"", name)));
+    }
+
+    render_dropdown_generic(
+        frame,
+        area,
+        search_text,
+        "Search Tags",
+        items,
+        selected_index,
+        &format!(
+            "Tags ({} results, {} selected, ↑ / ↓ to navigate, Enter to toggle)",
+            filtered.len(),
+            selected_tags.len()
+        ),
+        state,
+    );
+}
+
 /// Render enum dropdown for custom fields.
 /// Uses the same component as assignee and section dropdowns.
 pub fn render_enum_dropdown(
@@ -205,3 +327,23 @@ pub fn render_enum_dropdown(
         state,
     );
 }
+
+/// Render a validation error over the bottom border of a form field.
+///
+pub fn render_field_error(frame: &mut Frame, area: Rect, error: &str, state: &State) {
+    if area.height < 2 || area.width < 4 {
+        return;
+    }
+    let error_area = Rect {
+        x: area.x + 1,
+        y: area.y + area.height - 1,
+        width: area.width - 2,
+        height: 1,
+    };
+    let text = Paragraph::new(format!(" {} ", error)).style(
+        Style::default()
+            .fg(state.get_theme().error.to_color())
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(text, error_area);
+}
@@ -194,16 +194,42 @@ fn format_action_name(action: &HotkeyAction) -> String {
         HotkeyAction::Quit => "Quit".to_string(),
         HotkeyAction::OpenThemeSelector => "Open Theme Selector".to_string(),
         HotkeyAction::OpenHotkeyEditor => "Open Hotkey Editor".to_string(),
+        HotkeyAction::OpenWorkspaceSelector => "Open Workspace Selector".to_string(),
+        HotkeyAction::CreateProject => "Create Project".to_string(),
+        HotkeyAction::EditProject => "Edit Project".to_string(),
+        HotkeyAction::ArchiveProject => "Archive Project".to_string(),
+        HotkeyAction::ToggleArchivedProjects => "Toggle Archived Projects".to_string(),
+        HotkeyAction::ToggleTeamBrowser => "Toggle Team Browser".to_string(),
+        HotkeyAction::OpenTaskSearch => "Open Task Search".to_string(),
         HotkeyAction::ViewTask => "View Task".to_string(),
         HotkeyAction::CreateTask => "Create Task".to_string(),
         HotkeyAction::MoveTask => "Move Task".to_string(),
+        HotkeyAction::MarkTask => "Mark Task".to_string(),
         HotkeyAction::ToggleTaskComplete => "Toggle Task Complete".to_string(),
         HotkeyAction::DeleteTask => "Delete Task".to_string(),
         HotkeyAction::Back => "Back".to_string(),
         HotkeyAction::EditTask => "Edit Task".to_string(),
         HotkeyAction::AddComment => "Add Comment".to_string(),
+        HotkeyAction::AddSubtask => "Add Subtask".to_string(),
+        HotkeyAction::AddDependency => "Add Dependency".to_string(),
+        HotkeyAction::RemoveDependency => "Remove Dependency".to_string(),
+        HotkeyAction::UploadAttachment => "Upload Attachment".to_string(),
+        HotkeyAction::DownloadAttachment => "Download Attachment".to_string(),
+        HotkeyAction::AddToProject => "Add To Project".to_string(),
+        HotkeyAction::MoveToProject => "Move To Project".to_string(),
+        HotkeyAction::RemoveFromProject => "Remove From Project".to_string(),
+        HotkeyAction::EditFollowers => "Edit Followers".to_string(),
+        HotkeyAction::ToggleFollow => "Toggle Follow".to_string(),
+        HotkeyAction::EditComment => "Edit Comment".to_string(),
+        HotkeyAction::DeleteComment => "Delete Comment".to_string(),
+        HotkeyAction::LikeComment => "Like Comment".to_string(),
+        HotkeyAction::PinComment => "Pin Comment".to_string(),
         HotkeyAction::EditField => "Edit Field".to_string(),
         HotkeyAction::SubmitForm => "Submit Form".to_string(),
+        HotkeyAction::CalendarPrevMonth => "Calendar Previous Month".to_string(),
+        HotkeyAction::CalendarNextMonth => "Calendar Next Month".to_string(),
+        HotkeyAction::CalendarToday => "Calendar Today".to_string(),
+        HotkeyAction::PostStatusUpdate => "Post Status Update".to_string(),
         HotkeyAction::SearchModeExit => "Search Mode Exit".to_string(),
         HotkeyAction::DebugModeCopyLog => "Debug Mode Copy Log".to_string(),
         HotkeyAction::DebugModeExit => "Debug Mode Exit".to_string(),
@@ -213,6 +239,11 @@ fn format_action_name(action: &HotkeyAction) -> String {
         HotkeyAction::ThemeSelectorSelect => "Theme Selector Select".to_string(),
         HotkeyAction::ThemeSelectorCancel => "Theme Selector Cancel".to_string(),
         HotkeyAction::FilterByAssignee => "Filter By Assignee".to_string(),
+        HotkeyAction::AddSection => "Add Section".to_string(),
+        HotkeyAction::RenameSection => "Rename Section".to_string(),
+        HotkeyAction::DeleteSection => "Delete Section".to_string(),
+        HotkeyAction::MoveSectionLeft => "Move Section Left".to_string(),
+        HotkeyAction::MoveSectionRight => "Move Section Right".to_string(),
         HotkeyAction::AssigneeFilterSelect => "Assignee Filter Select".to_string(),
         HotkeyAction::AssigneeFilterCancel => "Assignee Filter Cancel".to_string(),
     }
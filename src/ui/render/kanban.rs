@@ -1,3 +1,4 @@
+use super::widgets::spinner;
 use super::Frame;
 use crate::state::State;
 use crate::ui::widgets::styling;
@@ -15,11 +16,22 @@ pub fn kanban(frame: &mut Frame, size: Rect, state: &State) {
 
     if sections.is_empty() {
         let block = Block::default().borders(Borders::ALL).title("Kanban Board");
-        use super::widgets::spinner;
         frame.render_widget(spinner::widget(state, size.height).block(block), size);
         return;
     }
 
+    // Reserve a line at the bottom while further pages are still loading
+    let size = if state.is_loading_more_tasks() {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(size);
+        render_loading_more(frame, rows[1], state);
+        rows[0]
+    } else {
+        size
+    };
+
     // Always show exactly 3 section columns + 1 detail column
     // Calculate widths: 3 columns get 75% total, detail gets 25%
     // Each column gets 25% of total width
@@ -40,6 +52,23 @@ pub fn kanban(frame: &mut Frame, size: Rect, state: &State) {
     render_kanban_details(frame, chunks[3], state);
 }
 
+/// Render the indicator shown while more pages of tasks are being fetched.
+///
+fn render_loading_more(frame: &mut Frame, size: Rect, state: &State) {
+    let theme = state.get_theme();
+    let line = Line::from(vec![
+        Span::styled(
+            spinner::FRAMES[*state.get_spinner_index()],
+            Style::default().fg(theme.accent.to_color()),
+        ),
+        Span::styled(
+            format!(" Loading more tasks… ({} loaded)", state.get_tasks().len()),
+            Style::default().fg(theme.text_muted.to_color()),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(line), size);
+}
+
 fn render_kanban_columns(frame: &mut Frame, column_chunks: &[Rect], state: &State) {
     let sections = state.get_sections();
     let tasks = state.get_filtered_tasks(); // Use filtered tasks
@@ -177,6 +206,9 @@ fn render_kanban_column(
             // Task name (bold if selected)
             let name_style = if is_selected && selected_task_index == Some(idx) {
                 styling::active_list_item_style(theme)
+            } else if state.is_task_highlighted(&task.gid) {
+                // Briefly mark tasks changed elsewhere by a sync
+                styling::updated_list_item_style(theme)
             } else {
                 styling::normal_text_style(theme)
             };
@@ -184,6 +216,11 @@ fn render_kanban_column(
             // Build the full text with all indicators
             let mut full_text = task.name.clone();
 
+            // Prefix tasks marked for bulk actions
+            if state.is_task_marked(&task.gid) {
+                full_text.insert_str(0, "◆ ");
+            }
+
             // Add assignee indicator
             if let Some(ref assignee) = task.assignee {
                 full_text.push_str(&format!(" (@{})", assignee.name));
@@ -199,6 +236,11 @@ fn render_kanban_column(
                 full_text.push_str(" ✓");
             }
 
+            // Flag tasks still waiting on incomplete dependencies
+            if task.is_blocked() && !task.completed {
+                full_text.push_str(" [blocked]");
+            }
+
             // Split text into multiple lines that fit within available width
             let mut lines: Vec<Line> = vec![];
             let words: Vec<String> = full_text
@@ -1,8 +1,12 @@
 use super::hotkey_editor;
 use super::welcome;
-use super::{create_task, edit_task, kanban, task_detail, Frame};
+use super::{
+    create_status_update, create_task, edit_task, form_dropdowns, kanban, portfolios, task_detail,
+    task_search, Frame,
+};
 use crate::config::hotkeys::build_move_task_instructions;
-use crate::state::{State, View};
+use crate::state::{FilterTarget, State, View};
+use crate::ui::widgets::date_input;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 /// Render main widget according to state.
@@ -28,35 +32,82 @@ pub fn main(frame: &mut Frame, size: Rect, state: &mut State) {
 
             // Check if we need to show delete confirmation dialog (render on top of everything)
             if state.has_delete_confirmation() {
-                let task_name = state
-                    .get_kanban_selected_task()
-                    .map(|t| t.name.clone())
-                    .unwrap_or_else(|| "this task".to_string());
-                render_delete_confirmation(frame, size, &task_name, state);
+                let marked = state.get_marked_tasks().len();
+                if marked > 0 {
+                    let count = format!("{} marked tasks", marked);
+                    render_delete_confirmation(frame, size, "tasks", &count, state);
+                } else {
+                    let task_name = state
+                        .get_kanban_selected_task()
+                        .map(|t| t.name.clone())
+                        .unwrap_or_else(|| "this task".to_string());
+                    render_delete_confirmation(frame, size, "task", &task_name, state);
+                }
             }
 
             // Check if we need to show assignee filter modal (render on top)
             if state.has_assignee_filter() {
                 render_assignee_filter_modal(frame, size, state);
             }
+
+            // Check if a board column is being named, or the marked tasks
+            // commented on (render on top)
+            if state.is_section_input_mode() || state.is_marked_comment_input_mode() {
+                render_section_input_modal(frame, size, state);
+            }
         }
         View::TaskDetail => {
             task_detail::task_detail(frame, size, state);
 
             // Check if we need to show delete confirmation dialog (render on top)
             if state.has_delete_confirmation() {
-                let task_name = state
-                    .get_task_detail()
-                    .map(|t| t.name.clone())
-                    .unwrap_or_else(|| "this task".to_string());
-                render_delete_confirmation(frame, size, &task_name, state);
+                if let Some(comment) = state.get_comment_delete_confirmation() {
+                    let text = comment.text.clone();
+                    render_delete_confirmation(frame, size, "comment", &text, state);
+                } else {
+                    let task_name = state
+                        .get_task_detail()
+                        .map(|t| t.name.clone())
+                        .unwrap_or_else(|| "this task".to_string());
+                    render_delete_confirmation(frame, size, "task", &task_name, state);
+                }
+            }
+
+            // Check if the task's projects are being changed (render on top)
+            if state.has_project_picker() {
+                let popup_area = centered_rect(50, 40, size);
+                frame.render_widget(ratatui::widgets::Clear, popup_area);
+                form_dropdowns::render_project_picker_dropdown(frame, popup_area, state);
+            }
+
+            // Check if the task's followers are being edited (render on top)
+            if state.has_follower_picker() {
+                let popup_area = centered_rect(50, 40, size);
+                frame.render_widget(ratatui::widgets::Clear, popup_area);
+                form_dropdowns::render_follower_dropdown(frame, popup_area, state);
             }
         }
         View::CreateTask => {
             create_task::create_task(frame, size, state);
+            render_date_picker(frame, size, state);
         }
         View::EditTask => {
             edit_task::edit_task(frame, size, state);
+            render_date_picker(frame, size, state);
+        }
+        View::Portfolios => {
+            portfolios::portfolios(frame, size, state);
+        }
+        View::CreateStatusUpdate => {
+            create_status_update::create_status_update(frame, size, state);
+        }
+        View::TaskSearch => {
+            task_search::task_search(frame, size, state);
+
+            // Check if the search filters are being edited (render on top)
+            if state.has_task_search_form() {
+                render_task_search_form_modal(frame, size, state);
+            }
         }
     }
 
@@ -65,6 +116,16 @@ pub fn main(frame: &mut Frame, size: Rect, state: &mut State) {
         render_theme_selector_modal(frame, size, state);
     }
 
+    // Render workspace selector modal on top of everything (only on Welcome view)
+    if state.has_workspace_selector() && matches!(state.current_view(), View::Welcome) {
+        render_workspace_selector_modal(frame, size, state);
+    }
+
+    // Render project form modal on top of the projects list
+    if state.has_project_form() {
+        render_project_form_modal(frame, size, state);
+    }
+
     // Render hotkey editor modal on top of everything
     if state.has_hotkey_editor() {
         hotkey_editor::render_hotkey_editor(frame, size, state);
@@ -75,7 +136,13 @@ fn welcome(frame: &mut Frame, size: Rect, state: &mut State) {
     welcome::render_welcome(frame, size, state);
 }
 
-fn render_delete_confirmation(frame: &mut Frame, size: Rect, task_name: &str, state: &State) {
+fn render_delete_confirmation(
+    frame: &mut Frame,
+    size: Rect,
+    kind: &str,
+    name: &str,
+    state: &State,
+) {
     use ratatui::{
         layout::Alignment,
         style::{Modifier, Style},
@@ -89,18 +156,18 @@ fn render_delete_confirmation(frame: &mut Frame, size: Rect, task_name: &str, st
     // Clear the area first (ratatui modal pattern)
     frame.render_widget(Clear, popup_area);
 
-    // Format the text - truncate long task names
-    let display_name = if task_name.len() > 45 {
-        format!("{}...", &task_name[..45])
+    // Format the text - truncate long names
+    let display_name = if name.chars().count() > 45 {
+        format!("{}...", name.chars().take(45).collect::<String>())
     } else {
-        task_name.to_string()
+        name.to_string()
     };
 
     let theme = state.get_theme();
     let text = vec![
         Line::from(""),
         Line::from(Span::styled(
-            format!("Delete task: \"{}\"?", display_name),
+            format!("Delete {}: \"{}\"?", kind, display_name),
             Style::default()
                 .fg(theme.text.to_color())
                 .add_modifier(Modifier::BOLD),
@@ -141,6 +208,262 @@ fn render_delete_confirmation(frame: &mut Frame, size: Rect, task_name: &str, st
     frame.render_widget(paragraph, popup_area);
 }
 
+fn render_section_input_modal(frame: &mut Frame, size: Rect, state: &State) {
+    use crate::ui::widgets::styling;
+    use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+    // Create a centered popup with room for a single input line
+    let area = centered_rect(50, 20, size);
+    let popup_area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+
+    // Clear the area first (ratatui modal pattern)
+    frame.render_widget(Clear, popup_area);
+
+    let theme = state.get_theme();
+    let marked_title;
+    let title = if state.is_marked_comment_input_mode() {
+        marked_title = format!(
+            "Comment on {} marked tasks (Enter: post, Esc: cancel)",
+            state.get_marked_tasks().len()
+        );
+        marked_title.as_str()
+    } else if state.is_rename_section_input_mode() {
+        "Rename Column (Enter: save, Esc: cancel)"
+    } else {
+        "New Column (Enter: create, Esc: cancel)"
+    };
+    let input = Paragraph::new(format!("> {}", state.get_comment_input_text()))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(styling::active_block_border_style(theme)),
+        )
+        .style(styling::normal_text_style(theme));
+    frame.render_widget(input, popup_area);
+}
+
+fn render_project_form_modal(frame: &mut Frame, size: Rect, state: &State) {
+    use crate::state::ProjectFormField;
+    use crate::ui::widgets::styling;
+    use ratatui::{
+        style::{Modifier, Style},
+        text::{Line, Span},
+        widgets::{Block, Borders, Clear, Paragraph},
+    };
+
+    let form = match state.get_project_form() {
+        Some(form) => form,
+        None => return,
+    };
+
+    // Create a centered popup with one line per field plus instructions
+    let area = centered_rect(60, 40, size);
+    let popup_area = Rect {
+        height: area.height.min(9),
+        ..area
+    };
+
+    // Clear the area first (ratatui modal pattern)
+    frame.render_widget(Clear, popup_area);
+
+    let theme = state.get_theme();
+    let team_name = match &form.team_gid {
+        Some(gid) => state
+            .get_teams()
+            .iter()
+            .find(|t| &t.gid == gid)
+            .map(|t| t.name.clone())
+            .unwrap_or_else(|| gid.clone()),
+        None => "None (workspace)".to_string(),
+    };
+    let mut fields = vec![
+        (ProjectFormField::Name, "Name", format!("{}_", form.name)),
+        (ProjectFormField::Notes, "Notes", format!("{}_", form.notes)),
+        (
+            ProjectFormField::Color,
+            "Color",
+            format!("< {} >", form.color.as_deref().unwrap_or("none")),
+        ),
+    ];
+    if form.gid.is_none() {
+        fields.push((ProjectFormField::Team, "Team", format!("< {} >", team_name)));
+    }
+
+    let mut lines: Vec<Line> = fields
+        .into_iter()
+        .map(|(field, label, value)| {
+            let label_style = if field == form.field {
+                Style::default()
+                    .fg(theme.highlight_fg.to_color())
+                    .bg(theme.highlight_bg.to_color())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.info.to_color())
+            };
+            Line::from(vec![
+                Span::styled(format!(" {:<6}", label), label_style),
+                Span::raw(" "),
+                Span::styled(value, styling::normal_text_style(theme)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Tab: next field, ←/→: change, Enter: save, Esc: cancel",
+        Style::default().fg(theme.info.to_color()),
+    )));
+
+    let title = if form.gid.is_some() {
+        "Edit Project"
+    } else {
+        "New Project"
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(theme.info.to_color())
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .border_style(styling::active_block_border_style(theme)),
+        )
+        .style(styling::normal_text_style(theme));
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn render_task_search_form_modal(frame: &mut Frame, size: Rect, state: &State) {
+    use crate::state::TaskSearchField;
+    use crate::ui::widgets::styling;
+    use ratatui::{
+        style::{Modifier, Style},
+        text::{Line, Span},
+        widgets::{Block, Borders, Clear, Paragraph},
+    };
+
+    let form = state.get_task_search_form();
+
+    // Create a centered popup with one line per filter plus error and instructions
+    let area = centered_rect(70, 60, size);
+    let popup_area = Rect {
+        height: area.height.min(16),
+        ..area
+    };
+
+    // Clear the area first (ratatui modal pattern)
+    frame.render_widget(Clear, popup_area);
+
+    let theme = state.get_theme();
+    let completed = match form.completed {
+        None => "Any",
+        Some(false) => "Incomplete",
+        Some(true) => "Completed",
+    };
+    let fields = vec![
+        (TaskSearchField::Text, "Text", format!("{}_", form.text)),
+        (
+            TaskSearchField::Assignee,
+            "Assignee",
+            format!("{}_", form.assignee),
+        ),
+        (
+            TaskSearchField::Project,
+            "Project",
+            format!("{}_", form.project),
+        ),
+        (
+            TaskSearchField::Section,
+            "Section",
+            format!("{}_", form.section),
+        ),
+        (TaskSearchField::Tag, "Tag", format!("{}_", form.tag)),
+        (
+            TaskSearchField::Completion,
+            "Status",
+            format!("< {} >", completed),
+        ),
+        (
+            TaskSearchField::DueAfter,
+            "Due after",
+            format!("{}_", form.due_after),
+        ),
+        (
+            TaskSearchField::DueBefore,
+            "Due before",
+            format!("{}_", form.due_before),
+        ),
+        (
+            TaskSearchField::CustomField,
+            "Field",
+            format!("{}_", form.custom_field),
+        ),
+        (
+            TaskSearchField::CustomFieldValue,
+            "Value",
+            format!("{}_", form.custom_field_value),
+        ),
+    ];
+
+    let mut lines: Vec<Line> = fields
+        .into_iter()
+        .map(|(field, label, value)| {
+            let label_style = if field == form.field {
+                Style::default()
+                    .fg(theme.highlight_fg.to_color())
+                    .bg(theme.highlight_bg.to_color())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.info.to_color())
+            };
+            let mut spans = vec![
+                Span::styled(format!(" {:<10}", label), label_style),
+                Span::raw(" "),
+                Span::styled(value, styling::normal_text_style(theme)),
+            ];
+            // Show which option a typed name resolves to
+            if let Some(name) = state.get_task_search_match(field) {
+                spans.push(Span::styled(
+                    format!("  → {}", name),
+                    Style::default().fg(theme.text_muted.to_color()),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    lines.push(match &form.error {
+        Some(error) => Line::from(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(theme.error.to_color()),
+        )),
+        None => Line::from(""),
+    });
+    lines.push(Line::from(Span::styled(
+        " Tab: next filter, ←/→: change status, Enter: search, Esc: cancel",
+        Style::default().fg(theme.info.to_color()),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Span::styled(
+                    "Search Tasks",
+                    Style::default()
+                        .fg(theme.info.to_color())
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .border_style(styling::active_block_border_style(theme)),
+        )
+        .style(styling::normal_text_style(theme));
+    frame.render_widget(paragraph, popup_area);
+}
+
 fn render_move_task_modal(frame: &mut Frame, size: Rect, _task_name: &str, state: &State) {
     use crate::ui::widgets::styling;
     use ratatui::{
@@ -167,12 +490,18 @@ fn render_move_task_modal(frame: &mut Frame, size: Rect, _task_name: &str, state
         .constraints([Constraint::Length(3), Constraint::Min(7)])
         .split(popup_area);
 
-    // Title block - just "Move"
+    // Title block - "Move", or how many marked tasks are being moved
     let theme = state.get_theme();
+    let marked = state.get_marked_tasks().len();
+    let title = if marked > 0 {
+        format!("Move {} marked tasks", marked)
+    } else {
+        String::from("Move")
+    };
     let title_block = Block::default()
         .borders(Borders::ALL)
         .title(Span::styled(
-            "Move",
+            title,
             Style::default()
                 .fg(theme.info.to_color())
                 .add_modifier(Modifier::BOLD),
@@ -359,11 +688,12 @@ fn render_theme_selector_modal(frame: &mut Frame, size: Rect, state: &State) {
     frame.render_stateful_widget(list, chunks[1], &mut list_state);
 }
 
-fn render_assignee_filter_modal(frame: &mut Frame, size: Rect, state: &State) {
+fn render_workspace_selector_modal(frame: &mut Frame, size: Rect, state: &State) {
     use crate::ui::widgets::styling;
     use ratatui::{
-        layout::{Constraint, Direction, Layout},
+        layout::{Alignment, Constraint, Direction, Layout},
         style::{Modifier, Style},
+        text::Span,
         widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
     };
 
@@ -373,61 +703,151 @@ fn render_assignee_filter_modal(frame: &mut Frame, size: Rect, state: &State) {
     // Clear the area first (ratatui modal pattern)
     frame.render_widget(Clear, popup_area);
 
-    // Split popup into search and dropdown areas
+    let workspaces = state.get_workspaces();
+    let selected_index = state.get_workspace_dropdown_index();
+
+    // Split popup into title and list areas
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(3), Constraint::Min(7)])
         .split(popup_area);
 
-    // Search input area
+    // Title block
     let theme = state.get_theme();
-    let search_text = state.get_assignee_filter_search();
-    let search_block = Block::default()
+    let title_block = Block::default()
         .borders(Borders::ALL)
-        .title("Search Assignee")
+        .title(Span::styled(
+            "Select Workspace",
+            Style::default()
+                .fg(theme.info.to_color())
+                .add_modifier(Modifier::BOLD),
+        ))
         .border_style(styling::active_block_border_style(theme));
-    let search_para = Paragraph::new(format!("> {}", search_text))
-        .block(search_block)
-        .style(styling::normal_text_style(theme));
-    frame.render_widget(search_para, chunks[0]);
 
-    // Get filtered users and selected index
-    let search_text = state.get_assignee_filter_search();
-    let users = state.get_workspace_users();
-    let filtered: Vec<_> = users
-        .iter()
-        .filter(|user| {
-            search_text.is_empty()
-                || user
-                    .name
-                    .to_lowercase()
-                    .contains(&search_text.to_lowercase())
-                || user
-                    .email
-                    .to_lowercase()
-                    .contains(&search_text.to_lowercase())
-        })
-        .collect();
-    let selected_index = state.get_assignee_filter_dropdown_index();
+    let title_text = Paragraph::new("j/k: navigate, Enter: select, Esc: cancel")
+        .block(title_block)
+        .alignment(Alignment::Center);
+    frame.render_widget(title_text, chunks[0]);
 
-    // Create list items: "All" at index 0, "Unassigned" at index 1, then users
-    let mut items: Vec<ListItem> = vec![
-        ListItem::new("All (clear filter)"),
-        ListItem::new("Unassigned"),
-    ];
-    items.extend(
-        filtered
+    // Show indicator on the active workspace
+    let items: Vec<ListItem> = if workspaces.is_empty() {
+        vec![ListItem::new("No workspaces available")]
+    } else {
+        workspaces
             .iter()
-            .map(|user| {
+            .map(|workspace| {
+                let current_indicator =
+                    if Some(workspace.gid.as_str()) == state.get_active_workspace_gid() {
+                        " (current)"
+                    } else {
+                        ""
+                    };
+                ListItem::new(format!("{}{}", workspace.name, current_indicator))
+            })
+            .collect()
+    };
+
+    // Use ListState so long lists scroll to the selection
+    let mut list_state = ratatui::widgets::ListState::default();
+    if !workspaces.is_empty() {
+        list_state.select(Some(selected_index.min(workspaces.len() - 1)));
+    }
+
+    // Create list block with workspace count
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("Workspaces ({})", workspaces.len()))
+        .border_style(styling::active_block_border_style(theme));
+
+    let list = List::new(items)
+        .block(list_block)
+        .style(styling::normal_text_style(theme))
+        .highlight_style(
+            Style::default()
+                .fg(theme.highlight_fg.to_color())
+                .bg(theme.highlight_bg.to_color())
+                .add_modifier(Modifier::BOLD),
+        );
+
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+fn render_assignee_filter_modal(frame: &mut Frame, size: Rect, state: &State) {
+    use crate::ui::widgets::styling;
+    use ratatui::{
+        layout::{Constraint, Direction, Layout},
+        style::{Modifier, Style},
+        widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    };
+
+    // Create a centered popup dialog using ratatui pattern
+    let popup_area = centered_rect(50, 50, size);
+
+    // Clear the area first (ratatui modal pattern)
+    frame.render_widget(Clear, popup_area);
+
+    // Split popup into search and dropdown areas
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(7)])
+        .split(popup_area);
+
+    // The modal lists either assignees or tags; Tab switches between them
+    let theme = state.get_theme();
+    let search_text = state.get_assignee_filter_search();
+    let selected_index = state.get_assignee_filter_dropdown_index();
+    let (search_title, items, list_title) = match state.get_filter_target() {
+        FilterTarget::Assignee => {
+            let users = state.get_workspace_users();
+            let filtered = state.get_filtered_assignee_filter_users();
+
+            // Create list items: "All" at index 0, "Unassigned" at index 1, then users
+            let mut items: Vec<ListItem> = vec![
+                ListItem::new("All (clear filter)"),
+                ListItem::new("Unassigned"),
+            ];
+            items.extend(filtered.iter().map(|user| {
                 let display_text = if !user.email.is_empty() {
                     format!("{} ({})", user.name, user.email)
                 } else {
                     user.name.clone()
                 };
                 ListItem::new(display_text)
-            })
-            .collect::<Vec<_>>(),
-    );
+            }));
+            (
+                "Search Assignee (Tab: tags)",
+                items,
+                format!(
+                    "Assignees ({} found, {} total)",
+                    filtered.len(),
+                    users.len()
+                ),
+            )
+        }
+        FilterTarget::Tag => {
+            let tags = state.get_workspace_tags();
+            let filtered = state.get_filtered_tag_filter_tags();
+
+            // Create list items: "All" at index 0, then tags
+            let mut items: Vec<ListItem> = vec![ListItem::new("All (clear filter)")];
+            items.extend(filtered.iter().map(|tag| ListItem::new(tag.name.clone())));
+            (
+                "Search Tag (Tab: assignees)",
+                items,
+                format!("Tags ({} found, {} total)", filtered.len(), tags.len()),
+            )
+        }
+    };
+
+    // Search input area
+    let search_block = Block::default()
+        .borders(Borders::ALL)
+        .title(search_title)
+        .border_style(styling::active_block_border_style(theme));
+    let search_para = Paragraph::new(format!("> {}", search_text))
+        .block(search_block)
+        .style(styling::normal_text_style(theme));
+    frame.render_widget(search_para, chunks[0]);
 
     // Calculate visible range (show max 8 items, centered around selected)
     let max_visible = 8;
@@ -453,11 +873,7 @@ fn render_assignee_filter_modal(frame: &mut Frame, size: Rect, state: &State) {
     // Create list block
     let list_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(
-            "Assignees ({} found, {} total)",
-            filtered.len(),
-            users.len()
-        ))
+        .title(list_title)
         .border_style(styling::active_block_border_style(theme));
 
     let list = List::new(visible_items.iter().cloned())
@@ -473,6 +889,16 @@ fn render_assignee_filter_modal(frame: &mut Frame, size: Rect, state: &State) {
     frame.render_stateful_widget(list, chunks[1], &mut list_state);
 }
 
+/// Render the calendar over a task form while a date is being picked.
+///
+fn render_date_picker(frame: &mut Frame, size: Rect, state: &State) {
+    if let Some(picker) = state.get_date_picker() {
+        let popup_area = date_input::calendar_area(size);
+        frame.render_widget(ratatui::widgets::Clear, popup_area);
+        date_input::render_calendar(frame, popup_area, picker, state);
+    }
+}
+
 /// Helper function to create a centered rectangle (ratatui modal pattern)
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
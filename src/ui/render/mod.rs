@@ -4,6 +4,7 @@
 //! in the application, including kanban boards, task details, forms, and status displays.
 
 mod all;
+mod create_status_update;
 mod create_task;
 mod edit_task;
 mod footer;
@@ -12,9 +13,11 @@ mod hotkey_editor;
 mod kanban;
 mod log;
 mod main;
+mod portfolios;
 mod shortcuts;
 mod status;
 mod task_detail;
+mod task_search;
 mod top_list;
 mod welcome;
 
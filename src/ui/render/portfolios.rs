@@ -0,0 +1,118 @@
+use super::widgets::spinner;
+use super::Frame;
+use crate::asana::PortfolioProject;
+use crate::state::{PortfolioRow, State};
+use crate::ui::theme::Theme;
+use crate::ui::widgets::styling;
+use chrono::DateTime;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+const BLOCK_TITLE: &str = "Portfolios";
+
+/// Render the portfolios of the current user with the latest status update
+/// of each of their projects.
+///
+pub fn portfolios(frame: &mut Frame, size: Rect, state: &mut State) {
+    let theme = state.get_theme().clone();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styling::active_block_border_style(&theme))
+        .title(Span::styled(
+            BLOCK_TITLE,
+            styling::active_block_title_style(),
+        ));
+
+    let rows = state.get_portfolio_rows();
+    if rows.is_empty() {
+        frame.render_widget(spinner::widget(state, size.height).block(block), size);
+        return;
+    }
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            PortfolioRow::Portfolio(portfolio, expanded) => {
+                let marker = if *expanded { "▼" } else { "▶" };
+                ListItem::new(Line::from(vec![Span::styled(
+                    format!("{} {}", marker, portfolio.name),
+                    Style::default()
+                        .fg(theme.info.to_color())
+                        .add_modifier(Modifier::BOLD),
+                )]))
+            }
+            PortfolioRow::Project(project) => project_item(&theme, project),
+            PortfolioRow::Loading => ListItem::new(Line::from(vec![Span::styled(
+                "  Loading...",
+                styling::normal_text_style(&theme).add_modifier(Modifier::DIM),
+            )])),
+            PortfolioRow::Failed(error) => ListItem::new(Line::from(vec![Span::styled(
+                format!("  Couldn't load projects ({}), Enter to retry", error),
+                Style::default().fg(theme.error.to_color()),
+            )])),
+        })
+        .collect();
+
+    let list = List::new(items)
+        .style(styling::normal_text_style(&theme))
+        .highlight_style(styling::active_list_item_style(&theme))
+        .block(block);
+
+    frame.render_stateful_widget(list, size, state.get_portfolios_list_state());
+}
+
+/// Return a portfolio project list item showing its latest status, title,
+/// author and date.
+///
+fn project_item<'a>(theme: &Theme, project: &PortfolioProject) -> ListItem<'a> {
+    let mut spans = vec![Span::raw(format!("  {}  ", project.project.name))];
+    match &project.status_update {
+        Some(update) => {
+            let (label, color) = status_label(theme, &update.status_type);
+            spans.push(Span::styled(
+                format!("[{}]", label),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            if !update.title.is_empty() {
+                spans.push(Span::raw(format!(" {}", update.title)));
+            }
+            let author = update
+                .created_by
+                .as_ref()
+                .map(|u| u.name.as_str())
+                .unwrap_or("Unknown");
+            let date = update
+                .created_at
+                .as_ref()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            spans.push(Span::styled(
+                format!(" • {} {}", author, date),
+                Style::default().fg(theme.text_muted.to_color()),
+            ));
+        }
+        None => spans.push(Span::styled(
+            "[No status]",
+            Style::default().fg(theme.text_muted.to_color()),
+        )),
+    }
+    ListItem::new(Line::from(spans))
+}
+
+/// Return the display label and color of a status type.
+///
+pub fn status_label(theme: &Theme, status_type: &str) -> (&'static str, ratatui::style::Color) {
+    match status_type {
+        "on_track" => ("On track", theme.success.to_color()),
+        "at_risk" => ("At risk", theme.warning.to_color()),
+        "off_track" => ("Off track", theme.error.to_color()),
+        "on_hold" => ("On hold", theme.info.to_color()),
+        "complete" => ("Complete", theme.secondary.to_color()),
+        _ => ("No status", theme.text_muted.to_color()),
+    }
+}
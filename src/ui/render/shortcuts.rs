@@ -31,14 +31,14 @@ pub fn shortcuts(frame: &mut Frame, size: Rect, state: &mut State) {
         block = block.title(BLOCK_TITLE);
     }
 
-    // Wait for projects to load before showing starred projects in shortcuts
-    // Show spinner if projects haven't loaded yet and we have starred projects from config
+    // Wait for the workspace to be known before showing starred projects in shortcuts
+    // Show spinner if it isn't yet and we have starred projects from config
     let has_starred_projects = !state.get_starred_project_gids().is_empty();
-    let projects_loaded = !state.get_projects().is_empty();
+    let workspace_loaded = state.get_active_workspace_gid().is_some();
 
-    // Only show spinner if we have starred projects from config but projects haven't loaded yet
-    // Once projects load, always show shortcuts (even if empty)
-    if has_starred_projects && !projects_loaded {
+    // Only show spinner if we have starred projects from config but no workspace yet
+    // Once it is known, always show shortcuts (even if empty)
+    if has_starred_projects && !workspace_loaded {
         // Show spinner while waiting for projects to load
         frame.render_widget(spinner::widget(state, size.height).block(block), size);
         return;
@@ -49,7 +49,7 @@ pub fn shortcuts(frame: &mut Frame, size: Rect, state: &mut State) {
 
     let items: Vec<ListItem> = all_shortcuts
         .iter()
-        .map(|s| ListItem::new(s.to_owned()))
+        .map(|s| ListItem::new(s.name().to_owned()))
         .collect();
 
     let list = List::new(items)
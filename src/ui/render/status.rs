@@ -4,6 +4,7 @@ use crate::state::{Focus, Menu, State};
 use crate::ui::widgets::styling;
 use ratatui::{
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
@@ -42,7 +43,7 @@ pub fn status(frame: &mut Frame, size: Rect, state: &mut State) {
             return;
         }
     };
-    let text = vec![
+    let mut text = vec![
         Line::from(vec![Span::styled(
             format!("User: {}", &user.name),
             styling::normal_text_style(theme),
@@ -56,6 +57,24 @@ pub fn status(frame: &mut Frame, size: Rect, state: &mut State) {
             styling::normal_text_style(theme),
         )]),
     ];
+    if let Some(retry) = state.get_retry_status() {
+        let reason = if retry.status == 429 {
+            "Rate limited"
+        } else {
+            "Server error"
+        };
+        text.push(Line::from(vec![Span::styled(
+            format!(
+                "{} ({}): retry {}/{} in {:.1}s",
+                reason,
+                retry.status,
+                retry.attempt,
+                retry.max_retries,
+                retry.wait.as_secs_f64()
+            ),
+            Style::default().fg(theme.warning.to_color()),
+        )]));
+    }
     let paragraph = Paragraph::new(text).block(block);
 
     frame.render_widget(paragraph, size);
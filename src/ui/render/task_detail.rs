@@ -1,6 +1,9 @@
 use super::Frame;
-use crate::state::{State, TaskDetailPanel};
-use crate::ui::widgets::styling;
+use crate::asana::parse_html;
+use crate::config::hotkeys::{format_hotkey_display, HotkeyAction};
+use crate::state::{DependencyKind, State, TaskDetailPanel};
+use crate::ui::widgets::{rich_text, styling};
+use crate::utils::dates;
 use chrono::DateTime;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -8,6 +11,7 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
+use ratatui_image::StatefulImage;
 
 /// Render task detail view (full screen).
 ///
@@ -15,7 +19,26 @@ pub fn task_detail(frame: &mut Frame, size: Rect, state: &mut State) {
     // Get task and panel info before borrowing state mutably
     let task_opt = state.get_task_detail().cloned();
     let current_panel = state.get_current_task_panel();
-    let is_comment_input = state.is_comment_input_mode();
+    let is_subtask_input = state.is_subtask_input_mode();
+    let is_dependency_input = state.is_dependency_input_mode();
+    let is_attachment_input = state.is_attachment_input_mode();
+    let is_comment_input = state.is_comment_input_mode()
+        && !is_subtask_input
+        && !is_dependency_input
+        && !is_attachment_input;
+
+    // An open input box always shows the panel it belongs to
+    let panel_to_show = if is_subtask_input {
+        TaskDetailPanel::Subtasks
+    } else if is_dependency_input {
+        TaskDetailPanel::Dependencies
+    } else if is_attachment_input {
+        TaskDetailPanel::Attachments
+    } else if is_comment_input {
+        TaskDetailPanel::Comments
+    } else {
+        current_panel
+    };
 
     if let Some(task) = task_opt {
         let chunks = Layout::default()
@@ -26,24 +49,38 @@ pub fn task_detail(frame: &mut Frame, size: Rect, state: &mut State) {
             ])
             .split(size);
 
-        let panel_indicators = format!(
-            "{} {} {}",
-            if current_panel == TaskDetailPanel::Details {
-                "[Details]"
-            } else {
-                " Details "
-            },
-            if current_panel == TaskDetailPanel::Comments || is_comment_input {
-                "[Comments]"
-            } else {
-                " Comments "
-            },
-            if current_panel == TaskDetailPanel::Notes {
-                "[Notes]"
-            } else {
-                " Notes "
-            },
-        );
+        let panels = [
+            (TaskDetailPanel::Details, "Details".to_string()),
+            (
+                TaskDetailPanel::Subtasks,
+                format!("Subtasks ({})", task.num_subtasks),
+            ),
+            (
+                TaskDetailPanel::Dependencies,
+                if task.is_blocked() {
+                    "Dependencies (blocked)".to_string()
+                } else {
+                    "Dependencies".to_string()
+                },
+            ),
+            (
+                TaskDetailPanel::Attachments,
+                format!("Attachments ({})", state.get_task_attachments().len()),
+            ),
+            (TaskDetailPanel::Comments, "Comments".to_string()),
+            (TaskDetailPanel::Notes, "Notes".to_string()),
+        ];
+        let panel_indicators = panels
+            .iter()
+            .map(|(panel, label)| {
+                if *panel == panel_to_show {
+                    format!("[{}]", label)
+                } else {
+                    format!(" {} ", label)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
 
         let theme = state.get_theme();
         let header = Block::default()
@@ -66,17 +103,19 @@ pub fn task_detail(frame: &mut Frame, size: Rect, state: &mut State) {
         frame.render_widget(name_para, chunks[0]);
 
         // Main content area - show only the active panel
-        // If comment input mode is active, always show Comments panel
-        let panel_to_show = if is_comment_input {
-            TaskDetailPanel::Comments
-        } else {
-            current_panel
-        };
-
         match panel_to_show {
             TaskDetailPanel::Details => {
                 render_task_properties(frame, chunks[1], &task, state);
             }
+            TaskDetailPanel::Subtasks => {
+                render_subtasks(frame, chunks[1], state);
+            }
+            TaskDetailPanel::Dependencies => {
+                render_dependencies(frame, chunks[1], state, &task);
+            }
+            TaskDetailPanel::Attachments => {
+                render_attachments(frame, chunks[1], state);
+            }
             TaskDetailPanel::Comments => {
                 render_comments(frame, chunks[1], state, &task);
             }
@@ -114,11 +153,24 @@ fn render_task_properties(frame: &mut Frame, size: Rect, task: &crate::asana::Ta
         ]));
     }
 
-    // Due date
-    if let Some(ref due_on) = task.due_on {
+    // Start and due dates, with the due time in the local timezone
+    if let Some(ref start_on) = task.start_on {
+        lines.push(Line::from(vec![
+            Span::styled("Start: ", Style::default().fg(theme.warning.to_color())),
+            Span::styled(start_on, styling::normal_text_style(theme)),
+        ]));
+    }
+    let due = match (&task.due_at, &task.due_on) {
+        (Some(due_at), _) => {
+            dates::utc_to_local(due_at).map(|(date, time)| format!("{} {}", date, time))
+        }
+        (None, Some(due_on)) => Some(due_on.clone()),
+        (None, None) => None,
+    };
+    if let Some(due) = due {
         lines.push(Line::from(vec![
             Span::styled("Due: ", Style::default().fg(theme.warning.to_color())),
-            Span::styled(due_on, styling::normal_text_style(theme)),
+            Span::styled(due, styling::normal_text_style(theme)),
         ]));
     }
 
@@ -130,6 +182,56 @@ fn render_task_properties(frame: &mut Frame, size: Rect, task: &crate::asana::Ta
         ]));
     }
 
+    // Projects the task belongs to, with its section in each
+    if !task.memberships.is_empty() {
+        let projects: Vec<String> = task
+            .memberships
+            .iter()
+            .map(|m| match &m.section {
+                Some(section) => format!("{} ({})", m.project_name, section.name),
+                None => m.project_name.clone(),
+            })
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled("Projects: ", Style::default().fg(theme.warning.to_color())),
+            Span::styled(projects.join(", "), styling::normal_text_style(theme)),
+        ]));
+    }
+
+    // Followers, marking the current user
+    if !task.followers.is_empty() {
+        let current_user = state.get_user().map(|u| u.gid.as_str());
+        let followers: Vec<String> = task
+            .followers
+            .iter()
+            .map(|u| {
+                if Some(u.gid.as_str()) == current_user {
+                    format!("{} (you)", u.name)
+                } else {
+                    u.name.clone()
+                }
+            })
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled("Followers: ", Style::default().fg(theme.warning.to_color())),
+            Span::styled(followers.join(", "), styling::normal_text_style(theme)),
+        ]));
+    }
+
+    // Blocked by incomplete dependencies
+    if task.is_blocked() {
+        let blockers: Vec<&str> = task
+            .dependencies
+            .iter()
+            .filter(|t| !t.completed)
+            .map(|t| t.name.as_str())
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled("Blocked by: ", Style::default().fg(theme.error.to_color())),
+            Span::styled(blockers.join(", "), styling::normal_text_style(theme)),
+        ]));
+    }
+
     // Tags
     if !task.tags.is_empty() {
         let tag_names: Vec<String> = task.tags.iter().map(|t| t.name.clone()).collect();
@@ -187,20 +289,404 @@ fn render_task_properties(frame: &mut Frame, size: Rect, task: &crate::asana::Ta
     frame.render_widget(text, size);
 }
 
+fn render_subtasks(frame: &mut Frame, size: Rect, state: &mut State) {
+    let is_subtask_input = state.is_subtask_input_mode();
+    let is_active = state.get_current_task_panel() == TaskDetailPanel::Subtasks || is_subtask_input;
+    let mut subtasks_list_state = state.get_subtasks_list_state().clone();
+    let subtasks: Vec<crate::asana::Task> = state.get_task_subtasks().to_vec();
+
+    let chunks = if is_subtask_input {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(size)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0)])
+            .split(size)
+    };
+
+    let theme = state.get_theme();
+    let completed = subtasks.iter().filter(|t| t.completed).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("Subtasks ({}/{} done)", completed, subtasks.len()))
+        .border_style(if is_active {
+            styling::active_block_border_style(theme)
+        } else {
+            styling::normal_block_border_style(theme)
+        });
+
+    if subtasks.is_empty() && !is_subtask_input {
+        let text = Paragraph::new(empty_panel_text(
+            state,
+            "No subtasks yet.",
+            HotkeyAction::AddSubtask,
+            "add a subtask",
+        ))
+        .block(block)
+        .alignment(Alignment::Center);
+        frame.render_widget(text, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = subtasks
+            .iter()
+            .map(|subtask| {
+                let (marker, style) = if subtask.completed {
+                    (
+                        "✓ ",
+                        Style::default()
+                            .fg(theme.text_muted.to_color())
+                            .add_modifier(Modifier::CROSSED_OUT),
+                    )
+                } else {
+                    ("☐ ", styling::normal_text_style(theme))
+                };
+                let mut spans = vec![
+                    Span::styled(marker, style),
+                    Span::styled(&subtask.name, style),
+                ];
+                if let Some(ref assignee) = subtask.assignee {
+                    spans.push(Span::styled(
+                        format!("  @{}", assignee.name),
+                        Style::default().fg(theme.text_muted.to_color()),
+                    ));
+                }
+                if let Some(ref due_on) = subtask.due_on {
+                    spans.push(Span::styled(
+                        format!("  due {}", due_on),
+                        Style::default().fg(theme.warning.to_color()),
+                    ));
+                }
+                if subtask.num_subtasks > 0 {
+                    spans.push(Span::styled(
+                        format!("  [{} subtasks]", subtask.num_subtasks),
+                        Style::default().fg(theme.text_muted.to_color()),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .style(styling::normal_text_style(theme))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.info.to_color())
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        frame.render_stateful_widget(list, chunks[0], &mut subtasks_list_state);
+    }
+
+    if is_subtask_input {
+        let input_block = Block::default()
+            .borders(Borders::ALL)
+            .title("Add Subtask (Enter: create, Esc: cancel)");
+        let input = Paragraph::new(format!("> {}", state.get_comment_input_text()))
+            .block(input_block)
+            .style(styling::normal_text_style(theme));
+        frame.render_widget(input, chunks[1]);
+    }
+}
+
+fn render_dependencies(
+    frame: &mut Frame,
+    size: Rect,
+    state: &mut State,
+    task: &crate::asana::Task,
+) {
+    let input_kind = state.get_dependency_input_kind();
+    let is_active =
+        state.get_current_task_panel() == TaskDetailPanel::Dependencies || input_kind.is_some();
+    let mut dependencies_list_state = state.get_dependencies_list_state().clone();
+
+    let chunks = if input_kind.is_some() {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(10)])
+            .split(size)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0)])
+            .split(size)
+    };
+
+    let theme = state.get_theme();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Blocked by ({}) / Blocking ({})",
+            task.dependencies.len(),
+            task.dependents.len()
+        ))
+        .border_style(if is_active {
+            styling::active_block_border_style(theme)
+        } else {
+            styling::normal_block_border_style(theme)
+        });
+
+    if task.dependencies.is_empty() && task.dependents.is_empty() {
+        let text = Paragraph::new(empty_panel_text(
+            state,
+            "No dependencies.",
+            HotkeyAction::AddDependency,
+            "add one",
+        ))
+        .block(block)
+        .alignment(Alignment::Center);
+        frame.render_widget(text, chunks[0]);
+    } else {
+        let item = |label: &'static str, linked: &crate::asana::TaskCompact| {
+            let (marker, style) = if linked.completed {
+                (
+                    "✓ ",
+                    Style::default()
+                        .fg(theme.text_muted.to_color())
+                        .add_modifier(Modifier::CROSSED_OUT),
+                )
+            } else {
+                ("☐ ", styling::normal_text_style(theme))
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(label, Style::default().fg(theme.warning.to_color())),
+                Span::styled(marker, style),
+                Span::styled(linked.name.clone(), style),
+            ]))
+        };
+        let items: Vec<ListItem> = task
+            .dependencies
+            .iter()
+            .map(|t| item("Blocked by: ", t))
+            .chain(task.dependents.iter().map(|t| item("Blocking:   ", t)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .style(styling::normal_text_style(theme))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.info.to_color())
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_stateful_widget(list, chunks[0], &mut dependencies_list_state);
+    }
+
+    if let Some(kind) = input_kind {
+        let title = match kind {
+            DependencyKind::BlockedBy => "Blocked by (Tab: blocking, Enter: add, Esc: cancel)",
+            DependencyKind::Blocking => "Blocking (Tab: blocked by, Enter: add, Esc: cancel)",
+        };
+        let selected = state.get_dependency_candidate_index();
+        let mut lines = vec![Line::from(Span::styled(
+            format!("> {}", state.get_comment_input_text()),
+            styling::normal_text_style(theme),
+        ))];
+        let candidates = state.get_dependency_candidates();
+        if candidates.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No matching tasks in this project",
+                Style::default().fg(theme.text_muted.to_color()),
+            )));
+        }
+        // Keep the selected match within the visible window
+        let visible = chunks[1].height.saturating_sub(3) as usize;
+        let skip = (selected + 1).saturating_sub(visible);
+        for (index, candidate) in candidates.iter().enumerate().skip(skip).take(visible) {
+            let style = if index == selected {
+                Style::default()
+                    .fg(theme.info.to_color())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                styling::normal_text_style(theme)
+            };
+            lines.push(Line::from(Span::styled(
+                format!("  {}", candidate.name),
+                style,
+            )));
+        }
+        let input_block = Block::default().borders(Borders::ALL).title(title);
+        frame.render_widget(Paragraph::new(lines).block(input_block), chunks[1]);
+    }
+}
+
+fn render_attachments(frame: &mut Frame, size: Rect, state: &mut State) {
+    let is_attachment_input = state.is_attachment_input_mode();
+    let is_active =
+        state.get_current_task_panel() == TaskDetailPanel::Attachments || is_attachment_input;
+    let mut attachments_list_state = state.get_attachments_list_state().clone();
+    let attachments: Vec<crate::asana::Attachment> = state.get_task_attachments().to_vec();
+
+    let chunks = if is_attachment_input {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(size)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0)])
+            .split(size)
+    };
+
+    let theme = state.get_theme();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Attachments ({}) - saving to {}",
+            attachments.len(),
+            state.get_download_directory().display()
+        ))
+        .border_style(if is_active {
+            styling::active_block_border_style(theme)
+        } else {
+            styling::normal_block_border_style(theme)
+        });
+
+    let mut preview_area = None;
+    if attachments.is_empty() && !is_attachment_input {
+        let text = Paragraph::new(empty_panel_text(
+            state,
+            "No attachments yet.",
+            HotkeyAction::UploadAttachment,
+            "upload a file",
+        ))
+        .block(block)
+        .alignment(Alignment::Center);
+        frame.render_widget(text, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = attachments
+            .iter()
+            .map(|attachment| {
+                let mut spans = vec![Span::styled(
+                    attachment.name.clone(),
+                    styling::normal_text_style(theme),
+                )];
+                if let Some(size) = attachment.size {
+                    spans.push(Span::styled(
+                        format!("  {}", format_file_size(size)),
+                        Style::default().fg(theme.text_muted.to_color()),
+                    ));
+                }
+                if attachment.host != "asana" {
+                    spans.push(Span::styled(
+                        format!("  [{}]", attachment.host),
+                        Style::default().fg(theme.warning.to_color()),
+                    ));
+                }
+                if let Some(created_at) = attachment
+                    .created_at
+                    .as_ref()
+                    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                {
+                    spans.push(Span::styled(
+                        format!("  {}", created_at.format("%Y-%m-%d %H:%M")),
+                        Style::default().fg(theme.text_muted.to_color()),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        // Show a thumbnail beside the list when an image is selected
+        let columns = if state.has_selected_attachment_preview() {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[0])
+        } else {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0)])
+                .split(chunks[0])
+        };
+
+        let list = List::new(items)
+            .block(block)
+            .style(styling::normal_text_style(theme))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.info.to_color())
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_stateful_widget(list, columns[0], &mut attachments_list_state);
+
+        preview_area = columns.get(1).copied();
+    }
+
+    if is_attachment_input {
+        let input_block = Block::default()
+            .borders(Borders::ALL)
+            .title("Attach File (path, Enter: upload, Esc: cancel)");
+        let input = Paragraph::new(format!("> {}", state.get_comment_input_text()))
+            .block(input_block)
+            .style(styling::normal_text_style(theme));
+        frame.render_widget(input, chunks[1]);
+    }
+
+    if let Some(area) = preview_area {
+        render_attachment_preview(frame, area, state);
+    }
+}
+
+/// Render the thumbnail of the selected image attachment.
+///
+fn render_attachment_preview(frame: &mut Frame, size: Rect, state: &mut State) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Preview")
+        .border_style(styling::normal_block_border_style(state.get_theme()));
+    let inner = block.inner(size);
+    frame.render_widget(block, size);
+
+    if let Some(error) = state.get_selected_attachment_preview_error() {
+        let text = Paragraph::new(format!("Preview failed: {}", error))
+            .style(Style::default().fg(state.get_theme().error.to_color()))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(text, inner);
+        return;
+    }
+    match state.get_selected_attachment_preview() {
+        Some(preview) => {
+            frame.render_stateful_widget(StatefulImage::new(None), inner, preview);
+        }
+        None => {
+            let text = Paragraph::new("Loading preview...")
+                .style(Style::default().fg(state.get_theme().text_muted.to_color()))
+                .alignment(Alignment::Center);
+            frame.render_widget(text, inner);
+        }
+    }
+}
+
+/// Return a byte count in the largest whole unit, e.g. "1.5 MB".
+///
+fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
 fn render_comments(frame: &mut Frame, size: Rect, state: &mut State, _task: &crate::asana::Task) {
     let is_active = state.get_current_task_panel() == TaskDetailPanel::Comments;
     let is_comment_input = state.is_comment_input_mode();
     let mut comments_list_state = state.get_comments_list_state().clone();
 
-    // Get all comments
-    let stories: Vec<crate::asana::Story> = state.get_task_stories().to_vec();
-    let comments: Vec<&crate::asana::Story> = stories
-        .iter()
-        .filter(|s| match &s.resource_subtype {
-            Some(subtype) => subtype == "comment_added",
-            None => s.created_by.is_some(),
-        })
-        .collect();
+    // Get all comments, pinned ones first
+    let comments: Vec<crate::asana::Story> =
+        state.get_task_comments().into_iter().cloned().collect();
 
     // Split into comments area and input area if in comment input mode
     // Calculate dynamic height for comment input based on content
@@ -301,6 +787,18 @@ fn render_comments(frame: &mut Frame, size: Rect, state: &mut State, _task: &cra
                         Style::default().fg(theme.text_muted.to_color()),
                     ),
                 ])];
+                if story.is_pinned {
+                    lines[0].spans.push(Span::styled(
+                        " [pinned]",
+                        Style::default().fg(theme.info.to_color()),
+                    ));
+                }
+                if story.liked {
+                    lines[0].spans.push(Span::styled(
+                        " [liked]",
+                        Style::default().fg(theme.error.to_color()),
+                    ));
+                }
 
                 // Wrap comment text into multiple lines
                 let comment_text = &story.text;
@@ -372,9 +870,14 @@ fn render_comments(frame: &mut Frame, size: Rect, state: &mut State, _task: &cra
 
     // Show comment input if in comment input mode
     if is_comment_input {
-        let input_block = Block::default()
-            .borders(Borders::ALL)
-            .title("Add Comment (Enter: submit, Esc: cancel)");
+        let input_block =
+            Block::default()
+                .borders(Borders::ALL)
+                .title(if state.is_edit_comment_input_mode() {
+                    "Edit Comment (Enter: save, Esc: cancel)"
+                } else {
+                    "Add Comment (Enter: submit, Esc: cancel)"
+                });
 
         // Get input text and wrap it manually to ensure proper wrapping
         let input_text = state.get_comment_input_text();
@@ -440,10 +943,33 @@ fn render_notes(frame: &mut Frame, size: Rect, task: &crate::asana::Task, state:
             styling::normal_block_border_style(theme)
         });
 
-    let notes_text = task.notes.as_deref().unwrap_or("No notes");
-    let text = Paragraph::new(notes_text)
-        .block(block)
-        .wrap(Wrap { trim: true })
-        .style(styling::normal_text_style(theme));
+    // Rich text keeps its formatting; indentation matters for nested lists
+    let rich_notes = task
+        .html_notes
+        .as_deref()
+        .map(parse_html)
+        .filter(|lines| !lines.is_empty());
+    let text = match rich_notes {
+        Some(lines) => Paragraph::new(rich_text::lines(&lines, theme)).wrap(Wrap { trim: false }),
+        None => {
+            Paragraph::new(task.notes.as_deref().unwrap_or("No notes")).wrap(Wrap { trim: true })
+        }
+    };
+    let text = text.block(block).style(styling::normal_text_style(theme));
     frame.render_widget(text, size);
 }
+
+/// Return the message of an empty panel, telling the key that adds to it
+/// when one is bound.
+///
+fn empty_panel_text(state: &State, message: &str, action: HotkeyAction, hint: &str) -> String {
+    match state.get_hotkeys().task_detail.get(&action) {
+        Some(hotkey) => format!(
+            "{} Press '{}' to {}.",
+            message,
+            format_hotkey_display(hotkey),
+            hint
+        ),
+        None => message.to_string(),
+    }
+}
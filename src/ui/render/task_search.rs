@@ -0,0 +1,99 @@
+use super::widgets::spinner;
+use super::Frame;
+use crate::asana::Task;
+use crate::state::State;
+use crate::ui::theme::Theme;
+use crate::ui::widgets::styling;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+/// Render the results of the workspace-wide task search.
+///
+pub fn task_search(frame: &mut Frame, size: Rect, state: &mut State) {
+    let theme = state.get_theme().clone();
+    let results = state.get_task_search_results().to_vec();
+    let loading = state.is_task_search_loading();
+    let more = if state.has_more_task_search_results() {
+        "+"
+    } else {
+        ""
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styling::active_block_border_style(&theme))
+        .title(Span::styled(
+            format!("Search Results ({}{})", results.len(), more),
+            styling::active_block_title_style(),
+        ));
+
+    if results.is_empty() {
+        if loading {
+            frame.render_widget(spinner::widget(state, size.height).block(block), size);
+        } else {
+            let message = Paragraph::new(Span::styled(
+                " No tasks match the search",
+                Style::default().fg(theme.text_muted.to_color()),
+            ))
+            .block(block);
+            frame.render_widget(message, size);
+        }
+        return;
+    }
+
+    let mut items: Vec<ListItem> = results.iter().map(|t| task_item(&theme, t)).collect();
+    if loading {
+        items.push(ListItem::new(Line::from(vec![Span::styled(
+            "  Loading more...",
+            styling::normal_text_style(&theme).add_modifier(Modifier::DIM),
+        )])));
+    }
+
+    let list = List::new(items)
+        .style(styling::normal_text_style(&theme))
+        .highlight_style(styling::active_list_item_style(&theme))
+        .block(block);
+
+    frame.render_stateful_widget(list, size, state.get_task_search_list_state());
+}
+
+/// Return a search result list item showing where the task lives, who it is
+/// assigned to and when it is due.
+///
+fn task_item<'a>(theme: &Theme, task: &Task) -> ListItem<'a> {
+    let (check, check_style) = if task.completed {
+        ("✓ ", Style::default().fg(theme.success.to_color()))
+    } else {
+        ("☐ ", styling::normal_text_style(theme))
+    };
+    let mut details = vec![];
+    if let Some(membership) = task.memberships.first() {
+        match &membership.section {
+            Some(section) => {
+                details.push(format!("{} / {}", membership.project_name, section.name))
+            }
+            None => details.push(membership.project_name.clone()),
+        }
+    }
+    if let Some(assignee) = &task.assignee {
+        details.push(assignee.name.clone());
+    }
+    if let Some(due_on) = &task.due_on {
+        details.push(format!("due {}", due_on));
+    }
+
+    let mut spans = vec![
+        Span::styled(check, check_style),
+        Span::raw(task.name.clone()),
+    ];
+    if !details.is_empty() {
+        spans.push(Span::styled(
+            format!("  • {}", details.join(" • ")),
+            Style::default().fg(theme.text_muted.to_color()),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
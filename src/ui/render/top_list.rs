@@ -1,6 +1,7 @@
 use super::widgets::spinner;
 use super::Frame;
-use crate::state::{Focus, Menu, State};
+use crate::asana::Project;
+use crate::state::{Focus, Menu, State, TeamListRow};
 use crate::ui::widgets::styling;
 use ratatui::{
     layout::Rect,
@@ -31,6 +32,10 @@ pub fn top_list(frame: &mut Frame, size: Rect, state: &mut State) {
     {
         // Show query even if not in search mode (after exiting search)
         format!("{} /{}", BLOCK_TITLE, state.get_search_query())
+    } else if state.is_team_browser() {
+        format!("{} (my teams)", BLOCK_TITLE)
+    } else if state.shows_archived_projects() {
+        format!("{} (incl. archived)", BLOCK_TITLE)
     } else {
         BLOCK_TITLE.to_string()
     };
@@ -54,6 +59,11 @@ pub fn top_list(frame: &mut Frame, size: Rect, state: &mut State) {
         block = block.title(title_text);
     }
 
+    if state.is_team_browser() {
+        team_list(frame, size, state, block, list_item_style);
+        return;
+    }
+
     // Show spinner only if we're not searching and have no projects loaded yet
     if filtered_projects.is_empty() && !state.is_search_mode() && state.get_projects().is_empty() {
         frame.render_widget(spinner::widget(state, size.height).block(block), size);
@@ -75,17 +85,7 @@ pub fn top_list(frame: &mut Frame, size: Rect, state: &mut State) {
         } else {
             filtered_projects
                 .iter()
-                .map(|p| {
-                    // Make starred projects italic
-                    if state.is_project_starred(&p.gid) {
-                        ListItem::new(Line::from(vec![Span::styled(
-                            p.name.to_owned(),
-                            styling::normal_text_style(theme).add_modifier(Modifier::ITALIC),
-                        )]))
-                    } else {
-                        ListItem::new(p.name.to_owned())
-                    }
-                })
+                .map(|p| project_item(state, p, ""))
                 .collect()
         };
 
@@ -96,3 +96,65 @@ pub fn top_list(frame: &mut Frame, size: Rect, state: &mut State) {
 
     frame.render_stateful_widget(list, size, state.get_projects_list_state());
 }
+
+/// Render the projects list grouped by the current user's teams.
+///
+fn team_list(
+    frame: &mut Frame,
+    size: Rect,
+    state: &mut State,
+    block: Block,
+    list_item_style: ratatui::style::Style,
+) {
+    let rows = state.get_team_list_rows();
+    if rows.is_empty() {
+        frame.render_widget(spinner::widget(state, size.height).block(block), size);
+        return;
+    }
+
+    let theme = state.get_theme();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            TeamListRow::Team(team, expanded) => {
+                let marker = if *expanded { "▼" } else { "▶" };
+                ListItem::new(Line::from(vec![Span::styled(
+                    format!("{} {}", marker, team.name),
+                    styling::normal_text_style(theme).add_modifier(Modifier::BOLD),
+                )]))
+            }
+            TeamListRow::Project(p) => project_item(state, p, "  "),
+            TeamListRow::Loading => ListItem::new(Line::from(vec![Span::styled(
+                "  Loading...",
+                styling::normal_text_style(theme).add_modifier(Modifier::DIM),
+            )])),
+        })
+        .collect();
+
+    let list = List::new(items)
+        .style(styling::normal_text_style(theme))
+        .highlight_style(list_item_style)
+        .block(block);
+
+    frame.render_stateful_widget(list, size, state.get_team_list_state());
+}
+
+/// Return a projects list item, dimming archived projects and making
+/// starred projects italic.
+///
+fn project_item<'a>(state: &State, p: &Project, indent: &str) -> ListItem<'a> {
+    let theme = state.get_theme();
+    if p.archived {
+        ListItem::new(Line::from(vec![Span::styled(
+            format!("{}{} [archived]", indent, p.name),
+            styling::normal_text_style(theme).add_modifier(Modifier::DIM),
+        )]))
+    } else if state.is_project_starred(&p.gid) {
+        ListItem::new(Line::from(vec![Span::styled(
+            format!("{}{}", indent, p.name),
+            styling::normal_text_style(theme).add_modifier(Modifier::ITALIC),
+        )]))
+    } else {
+        ListItem::new(format!("{}{}", indent, p.name))
+    }
+}
@@ -0,0 +1,148 @@
+//! Date input widget.
+//!
+//! This module renders what a typed date parses to beneath date fields, and
+//! the month calendar used to pick a date with the keyboard.
+
+use crate::config::hotkeys::build_calendar_instructions;
+use crate::state::{DatePicker, State};
+use crate::ui::widgets::styling;
+use crate::utils::dates;
+use chrono::{Datelike, Days, Local};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, Paragraph,
+    },
+    Frame,
+};
+
+/// Width of the calendar popup: seven three-column days plus borders.
+///
+const CALENDAR_WIDTH: u16 = 25;
+
+/// Height of the calendar popup: weekday header and up to six weeks plus
+/// borders.
+///
+const CALENDAR_HEIGHT: u16 = 9;
+
+/// Render the date a field's text parses to on its bottom border, or why it
+/// doesn't parse. An error from the form's own checks takes precedence.
+///
+pub fn render_preview(
+    frame: &mut Frame,
+    area: Rect,
+    value: &str,
+    error: Option<String>,
+    is_editing: bool,
+    state: &State,
+) {
+    if area.height < 2 || area.width < 4 {
+        return;
+    }
+    let theme = state.get_theme();
+    let error_style = Style::default()
+        .fg(theme.error.to_color())
+        .add_modifier(Modifier::BOLD);
+    let calendar_hint = if is_editing { " · ↓ calendar" } else { "" };
+    let line = match (error, value.trim().is_empty()) {
+        (Some(error), _) => Line::from(Span::styled(format!(" {} ", error), error_style)),
+        (None, true) if is_editing => Line::from(Span::styled(
+            " today, fri, +3d or 2024-05-01 · ↓ calendar ",
+            Style::default().fg(theme.text_muted.to_color()),
+        )),
+        (None, true) => return,
+        (None, false) => match dates::parse_date(value) {
+            Ok(date) => Line::from(vec![
+                Span::styled(
+                    format!(" → {}", date.format("%a, %-d %b %Y")),
+                    Style::default().fg(theme.success.to_color()),
+                ),
+                Span::styled(
+                    format!("{} ", calendar_hint),
+                    Style::default().fg(theme.text_muted.to_color()),
+                ),
+            ]),
+            Err(error) => Line::from(Span::styled(format!(" {} ", error), error_style)),
+        },
+    };
+    let preview_area = Rect {
+        x: area.x + 1,
+        y: area.y + area.height - 1,
+        width: area.width - 2,
+        height: 1,
+    };
+    frame.render_widget(Paragraph::new(line), preview_area);
+}
+
+/// Return the area of the calendar popup, centered in the given area.
+///
+pub fn calendar_area(r: Rect) -> Rect {
+    let width = CALENDAR_WIDTH.min(r.width);
+    let height = CALENDAR_HEIGHT.min(r.height);
+    Rect {
+        x: r.x + (r.width - width) / 2,
+        y: r.y + (r.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Render the month of the date picker's highlighted day, marking today.
+///
+pub fn render_calendar(frame: &mut Frame, area: Rect, picker: &DatePicker, state: &State) {
+    let theme = state.get_theme();
+    let today = Local::now().date_naive();
+    let cursor = picker.cursor;
+    let first = cursor.with_day(1).unwrap_or(cursor);
+
+    let mut lines = vec![Line::from(Span::styled(
+        " Mo Tu We Th Fr Sa Su",
+        Style::default().fg(theme.text_muted.to_color()),
+    ))];
+    // Pad the first week up to the weekday the month starts on; each day
+    // takes a separator and a number span
+    let mut week: Vec<Span> = (0..first.weekday().num_days_from_monday())
+        .flat_map(|_| [Span::raw(" "), Span::raw("  ")])
+        .collect();
+    let mut day = Some(first);
+    while let Some(date) = day.filter(|d| d.month() == first.month()) {
+        let style = if date == cursor {
+            styling::active_list_item_style(theme)
+        } else if date == today {
+            Style::default()
+                .fg(theme.info.to_color())
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            styling::normal_text_style(theme)
+        };
+        week.push(Span::raw(" "));
+        week.push(Span::styled(format!("{:>2}", date.day()), style));
+        if week.len() == 14 {
+            lines.push(Line::from(std::mem::take(&mut week)));
+        }
+        day = date.checked_add_days(Days::new(1));
+    }
+    if !week.is_empty() {
+        lines.push(Line::from(week));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styling::active_block_border_style(theme))
+        .title(Span::styled(
+            cursor.format(" %B %Y ").to_string(),
+            styling::active_block_title_style(),
+        ))
+        .title(
+            Title::from(Span::styled(
+                build_calendar_instructions(state.get_hotkeys(), state.current_view()),
+                Style::default().fg(theme.text_muted.to_color()),
+            ))
+            .position(Position::Bottom)
+            .alignment(Alignment::Right),
+        );
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -1,6 +1,9 @@
 //! Reusable UI widget components.
 //!
-//! This module contains reusable widget components such as spinners and styling utilities.
+//! This module contains reusable widget components such as spinners, date
+//! inputs, rich text and styling utilities.
 
+pub mod date_input;
+pub mod rich_text;
 pub mod spinner;
 pub mod styling;
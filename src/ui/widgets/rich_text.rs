@@ -0,0 +1,82 @@
+//! Rich text widget.
+//!
+//! This module turns Asana rich text into styled lines for display.
+
+use crate::asana::{LineKind, Link, Marks, RichLine};
+use crate::ui::theme::Theme;
+use crate::ui::widgets::styling;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Return styled lines for rich text, with list bullets, quote bars and
+/// code blocks drawn in.
+///
+pub fn lines(rich_lines: &[RichLine], theme: &Theme) -> Vec<Line<'static>> {
+    let muted = Style::default().fg(theme.text_muted.to_color());
+    rich_lines
+        .iter()
+        .map(|line| {
+            let (prefix, base) = match line.kind {
+                LineKind::Paragraph => (Span::raw(""), styling::normal_text_style(theme)),
+                LineKind::Heading(_) => (
+                    Span::raw(""),
+                    Style::default()
+                        .fg(theme.accent.to_color())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                LineKind::Bullet(depth) => (
+                    Span::styled(format!("{}• ", "  ".repeat(depth)), muted),
+                    styling::normal_text_style(theme),
+                ),
+                LineKind::Numbered(depth, number) => (
+                    Span::styled(format!("{}{}. ", "  ".repeat(depth), number), muted),
+                    styling::normal_text_style(theme),
+                ),
+                LineKind::Quote => (
+                    Span::styled("│ ", muted),
+                    muted.add_modifier(Modifier::ITALIC),
+                ),
+                LineKind::Code => (
+                    Span::styled("  ", muted),
+                    Style::default().fg(theme.secondary.to_color()),
+                ),
+                LineKind::Rule => return Line::from(Span::styled("─".repeat(24), muted)),
+            };
+            let mut spans = vec![prefix];
+            spans.extend(
+                line.runs
+                    .iter()
+                    .map(|run| Span::styled(run.text.clone(), run_style(base, &run.marks, theme))),
+            );
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Return the style of a run of rich text on top of its line's style.
+///
+fn run_style(base: Style, marks: &Marks, theme: &Theme) -> Style {
+    let mut style = base;
+    for (on, modifier) in [
+        (marks.bold, Modifier::BOLD),
+        (marks.italic, Modifier::ITALIC),
+        (marks.underline, Modifier::UNDERLINED),
+        (marks.strike, Modifier::CROSSED_OUT),
+    ] {
+        if on {
+            style = style.add_modifier(modifier);
+        }
+    }
+    if marks.code {
+        style = style.fg(theme.secondary.to_color());
+    }
+    match marks.link {
+        Some(Link::Url(_)) => style
+            .fg(theme.info.to_color())
+            .add_modifier(Modifier::UNDERLINED),
+        Some(Link::Mention(_)) => style.fg(theme.info.to_color()).add_modifier(Modifier::BOLD),
+        None => style,
+    }
+}
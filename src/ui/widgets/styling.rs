@@ -33,6 +33,14 @@ pub fn active_list_item_style(theme: &Theme) -> Style {
     current_list_item_style(theme).fg(theme.primary.to_color())
 }
 
+/// Return the style for list items recently changed elsewhere.
+///
+pub fn updated_list_item_style(theme: &Theme) -> Style {
+    Style::default()
+        .fg(theme.warning.to_color())
+        .add_modifier(Modifier::BOLD)
+}
+
 /// Return the style for normal text.
 ///
 pub fn normal_text_style(theme: &Theme) -> Style {
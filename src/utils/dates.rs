@@ -0,0 +1,189 @@
+//! Date and time utilities.
+//!
+//! This module parses the dates and times typed into task forms and converts
+//! due times between the user's local timezone and the UTC timestamps Asana
+//! stores.
+
+use chrono::{
+    DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveTime, SecondsFormat, TimeZone, Utc,
+    Weekday,
+};
+
+/// Format of the dates Asana expects.
+///
+pub const ISO_DATE: &str = "%Y-%m-%d";
+
+/// Parse a typed date relative to today: an ISO date like 2024-05-01,
+/// `today`, `tomorrow`, `yesterday`, a weekday like `fri` or `next fri`, or
+/// an offset like `+3d`, `2w` or `-1m`.
+///
+pub fn parse_date(input: &str) -> Result<NaiveDate, String> {
+    parse_date_from(input, Local::now().date_naive())
+}
+
+/// Parse a typed date relative to the given day.
+///
+pub fn parse_date_from(input: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    let text = input.trim().to_lowercase();
+    let invalid = || {
+        format!(
+            "'{}' is not a date; try 2024-05-01, today, next fri or +3d",
+            input.trim()
+        )
+    };
+    if let Ok(date) = NaiveDate::parse_from_str(&text, ISO_DATE) {
+        return Ok(date);
+    }
+    match text.as_str() {
+        "today" | "tod" => return Ok(today),
+        "tomorrow" | "tom" | "tmr" => return Ok(today + Days::new(1)),
+        "yesterday" => return Ok(today - Days::new(1)),
+        _ => {}
+    }
+    let weekday = text.strip_prefix("next ").unwrap_or(&text);
+    if let Ok(weekday) = weekday.parse::<Weekday>() {
+        // The next such day after today
+        let ahead =
+            (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday() - 1) % 7
+                + 1;
+        return Ok(today + Days::new(ahead as u64));
+    }
+    parse_offset(&text, today).ok_or_else(invalid)
+}
+
+/// Parse an offset from the given day, like `+3d`, `2w`, `-1m` or `3 days`.
+///
+fn parse_offset(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (negative, rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let count: u32 = rest[..digits].parse().ok()?;
+    let (days, months) = match rest[digits..].trim() {
+        "d" | "day" | "days" => (count, 0),
+        "w" | "week" | "weeks" => (count.checked_mul(7)?, 0),
+        "m" | "month" | "months" => (0, count),
+        "y" | "year" | "years" => (0, count.checked_mul(12)?),
+        _ => return None,
+    };
+    if negative {
+        today
+            .checked_sub_days(Days::new(days.into()))?
+            .checked_sub_months(Months::new(months))
+    } else {
+        today
+            .checked_add_days(Days::new(days.into()))?
+            .checked_add_months(Months::new(months))
+    }
+}
+
+/// Parse a time of day typed as HH:MM on a 24-hour clock.
+///
+pub fn parse_time(input: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(input.trim(), "%H:%M")
+        .map_err(|_| format!("'{}' is not a time like 09:30 or 17:00", input.trim()))
+}
+
+/// Return the UTC timestamp of a date and time in the local timezone, as
+/// Asana expects for `due_at`.
+///
+pub fn local_to_utc(date: NaiveDate, time: NaiveTime) -> Result<String, String> {
+    to_utc(&Local, date, time)
+}
+
+/// Return the local date and time of a UTC timestamp, formatted for the task
+/// form as YYYY-MM-DD and HH:MM.
+///
+pub fn utc_to_local(timestamp: &str) -> Option<(String, String)> {
+    from_utc(&Local, timestamp)
+}
+
+/// Return the UTC timestamp of a date and time in the given timezone.
+///
+fn to_utc<Tz: TimeZone>(tz: &Tz, date: NaiveDate, time: NaiveTime) -> Result<String, String> {
+    // Times skipped by a daylight saving change don't exist; repeated ones
+    // resolve to the first occurrence
+    tz.from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|dt| {
+            dt.with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Millis, true)
+        })
+        .ok_or_else(|| format!("{} {} does not exist in your timezone", date, time))
+}
+
+/// Return the date and time of a UTC timestamp in the given timezone.
+///
+fn from_utc<Tz: TimeZone>(tz: &Tz, timestamp: &str) -> Option<(String, String)>
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let local = DateTime::parse_from_rfc3339(timestamp)
+        .ok()?
+        .with_timezone(tz);
+    Some((
+        local.format("%Y-%m-%d").to_string(),
+        local.format("%H:%M").to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn test_parse_date_and_time() {
+        assert_eq!(
+            parse_date(" 2024-05-01 "),
+            Ok(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+        );
+        assert!(parse_date("2024-13-01").is_err());
+        assert!(parse_date("May 1").is_err());
+        assert_eq!(
+            parse_time("09:30"),
+            Ok(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+        );
+        assert!(parse_time("25:00").is_err());
+        assert!(parse_time("9am").is_err());
+    }
+
+    #[test]
+    fn test_parse_natural_language_dates() {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let parse =
+            |text: &str| parse_date_from(text, today).map(|d| d.format(ISO_DATE).to_string());
+        assert_eq!(parse("today"), Ok("2024-05-01".to_string()));
+        assert_eq!(parse("Tomorrow"), Ok("2024-05-02".to_string()));
+        assert_eq!(parse("fri"), Ok("2024-05-03".to_string()));
+        assert_eq!(parse("next fri"), Ok("2024-05-03".to_string()));
+        assert_eq!(parse("next wednesday"), Ok("2024-05-08".to_string()));
+        assert_eq!(parse("+3d"), Ok("2024-05-04".to_string()));
+        assert_eq!(parse("2w"), Ok("2024-05-15".to_string()));
+        assert_eq!(parse("-1d"), Ok("2024-04-30".to_string()));
+        assert_eq!(parse("1m"), Ok("2024-06-01".to_string()));
+        assert_eq!(parse("3 days"), Ok("2024-05-04".to_string()));
+        assert_eq!(parse("2024-12-24"), Ok("2024-12-24".to_string()));
+        assert!(parse("someday").is_err());
+        assert!(parse("3x").is_err());
+        assert!(parse("next").is_err());
+    }
+
+    #[test]
+    fn test_due_times_round_trip_through_utc() {
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let time = NaiveTime::from_hms_opt(21, 15, 0).unwrap();
+        let utc = to_utc(&tz, date, time).unwrap();
+        assert_eq!(utc, "2024-05-02T02:15:00.000Z");
+        assert_eq!(
+            from_utc(&tz, &utc),
+            Some(("2024-05-01".to_string(), "21:15".to_string()))
+        );
+        assert_eq!(from_utc(&tz, "not a timestamp"), None);
+    }
+}
@@ -1,3 +1,5 @@
 //! Utility modules for common functionality.
 
+pub mod dates;
 pub mod text_processing;
+pub mod thumbnails;
@@ -0,0 +1,174 @@
+//! Image attachment thumbnails.
+//!
+//! This module contains utilities for recognizing image attachments, scaling
+//! them down to preview size and caching the result on disk so reopening a
+//! task does not download the original again. The cache keeps only the most
+//! recently used thumbnails.
+
+use image::{DynamicImage, ImageFormat};
+use std::{
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Largest width or height of a cached thumbnail, in pixels.
+///
+const THUMBNAIL_SIZE: u32 = 512;
+
+/// Most thumbnails kept in the cache, about 50 MB at full size.
+///
+const CACHE_LIMIT: usize = 200;
+
+/// Extensions of attachments that can be previewed.
+///
+const IMAGE_EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "gif"];
+
+/// Check if an attachment name looks like a previewable image.
+///
+pub fn is_image(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .is_some_and(|extension| IMAGE_EXTENSIONS.contains(&extension.as_str()))
+}
+
+/// Returns the directory thumbnails are cached in, if the platform has one.
+///
+pub fn cache_directory() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("asana-tui").join("thumbnails"))
+}
+
+/// Returns the cache path of the thumbnail for an attachment.
+///
+pub fn cache_path(directory: &Path, attachment_gid: &str) -> PathBuf {
+    directory.join(format!("{}.png", attachment_gid))
+}
+
+/// Decode image bytes and scale them down to thumbnail size.
+///
+pub fn create_thumbnail(bytes: &[u8]) -> image::ImageResult<DynamicImage> {
+    let image = image::load_from_memory(bytes)?;
+    if image.width() <= THUMBNAIL_SIZE && image.height() <= THUMBNAIL_SIZE {
+        return Ok(image);
+    }
+    Ok(image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE))
+}
+
+/// Load a cached thumbnail, returning none when it is missing or unreadable.
+/// Loading marks the thumbnail as recently used.
+///
+pub fn load_cached(directory: &Path, attachment_gid: &str) -> Option<DynamicImage> {
+    let path = cache_path(directory, attachment_gid);
+    let thumbnail = image::open(&path).ok()?;
+    if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+    Some(thumbnail)
+}
+
+/// Save a thumbnail to the cache, removing the least recently used ones
+/// beyond the cache limit.
+///
+pub fn save_cached(
+    directory: &Path,
+    attachment_gid: &str,
+    thumbnail: &DynamicImage,
+) -> image::ImageResult<()> {
+    std::fs::create_dir_all(directory)?;
+    thumbnail.save_with_format(cache_path(directory, attachment_gid), ImageFormat::Png)?;
+    prune_cache(directory, CACHE_LIMIT)?;
+    Ok(())
+}
+
+/// Remove all but the `limit` most recently used thumbnails, by modification
+/// time.
+///
+fn prune_cache(directory: &Path, limit: usize) -> std::io::Result<()> {
+    let mut thumbnails: Vec<(SystemTime, PathBuf)> = std::fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|e| e == "png"))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    if thumbnails.len() <= limit {
+        return Ok(());
+    }
+    thumbnails.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    for (_, path) in thumbnails.into_iter().skip(limit) {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+    use std::io::Cursor;
+
+    fn encoded_png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn recognizes_image_names() {
+        assert!(is_image("screenshot.png"));
+        assert!(is_image("Photo.JPG"));
+        assert!(is_image("diagram.jpeg"));
+        assert!(is_image("loop.gif"));
+        assert!(!is_image("notes.pdf"));
+        assert!(!is_image("png"));
+    }
+
+    #[test]
+    fn thumbnails_keep_aspect_ratio() {
+        let thumbnail = create_thumbnail(&encoded_png(2048, 1024)).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (512, 256));
+
+        let small = create_thumbnail(&encoded_png(64, 32)).unwrap();
+        assert_eq!((small.width(), small.height()), (64, 32));
+
+        assert!(create_thumbnail(b"not an image").is_err());
+    }
+
+    #[test]
+    fn cached_thumbnails_round_trip() {
+        let directory = std::env::temp_dir().join(format!("thumbnails-{}", rand::random::<u64>()));
+        assert!(load_cached(&directory, "1").is_none());
+
+        let thumbnail = create_thumbnail(&encoded_png(16, 8)).unwrap();
+        save_cached(&directory, "1", &thumbnail).unwrap();
+        let cached = load_cached(&directory, "1").unwrap();
+        assert_eq!((cached.width(), cached.height()), (16, 8));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn pruning_keeps_most_recently_used_thumbnails() {
+        let directory = std::env::temp_dir().join(format!("thumbnails-{}", rand::random::<u64>()));
+        let thumbnail = create_thumbnail(&encoded_png(4, 4)).unwrap();
+        let start = SystemTime::now() - std::time::Duration::from_secs(60);
+        for (i, gid) in ["1", "2", "3"].iter().enumerate() {
+            save_cached(&directory, gid, &thumbnail).unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(cache_path(&directory, gid))
+                .unwrap()
+                .set_modified(start + std::time::Duration::from_secs(i as u64))
+                .unwrap();
+        }
+        // Loading the oldest makes it the most recently used
+        assert!(load_cached(&directory, "1").is_some());
+
+        prune_cache(&directory, 2).unwrap();
+        assert!(cache_path(&directory, "1").exists());
+        assert!(!cache_path(&directory, "2").exists());
+        assert!(cache_path(&directory, "3").exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}